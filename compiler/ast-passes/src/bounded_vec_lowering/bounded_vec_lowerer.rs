@@ -0,0 +1,495 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, AstError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The circuit implementing `BoundedVec<ELEMENT, CAPACITY>`.
+/// The items of `new()` are filled with the default value of the element type once parsed.
+const BOUNDED_VEC: &str = "
+circuit BoundedVec {
+    items: [ELEMENT; CAPACITY];
+    length: u32;
+
+    function new() -> Self {
+        return Self { items: [false; CAPACITY], length: 0u32 };
+    }
+
+    function capacity() -> u32 {
+        return CAPACITYu32;
+    }
+
+    function len(self) -> u32 {
+        return self.length;
+    }
+
+    function is_empty(self) -> bool {
+        return self.length == 0u32;
+    }
+
+    function is_full(self) -> bool {
+        return self.length == CAPACITYu32;
+    }
+
+    function push(&self, item: ELEMENT) {
+        console.assert(self.length < CAPACITYu32);
+        self.items[self.length] = item;
+        self.length += 1u32;
+    }
+
+    function pop(&self) -> ELEMENT {
+        console.assert(self.length > 0u32);
+        self.length -= 1u32;
+        return self.items[self.length];
+    }
+
+    function get(self, index: u32) -> ELEMENT {
+        console.assert(index < self.length);
+        return self.items[index];
+    }
+
+    function set(&self, index: u32, item: ELEMENT) {
+        console.assert(index < self.length);
+        self.items[index] = item;
+    }
+
+    function clear(&self) {
+        self.length = 0u32;
+    }
+}
+";
+
+/// Replaces each `BoundedVec<element, capacity>` type with a circuit holding an array of `capacity`
+/// elements and the number of them in use, such that the length is never tracked by hand.
+///
+/// A circuit named after the type, e.g., `BoundedVec<u8, 4>`, is added to the program for each distinct type.
+/// Its free slots hold the default value of the element type, e.g., `0u8`, so addresses cannot be held.
+/// `push`, `pop`, `get` and `set` fail once the list is full, or out of its length.
+///
+/// `BoundedVec::new()` creates a list of the type of the variable it is assigned to, or of the output
+/// of the function it is returned from. Unless the program defines its own `BoundedVec` circuit,
+/// a static call of `BoundedVec` anywhere else is rejected.
+#[derive(Default)]
+pub struct BoundedVecLowerer {
+    /// The circuits of the program as written, for the default values of their instances.
+    circuits: IndexMap<Symbol, Circuit>,
+    /// The aliases of the program as written.
+    aliases: IndexMap<Symbol, Type>,
+    /// The circuits implementing the `BoundedVec` types found so far, by name.
+    instances: IndexMap<Symbol, Circuit>,
+    /// The circuit being lowered, if any, which `Self` names.
+    circuit: Option<Identifier>,
+    /// The output type of the function being lowered.
+    output: Option<Type>,
+    /// The circuits whose default value is being built, to stop at recursive circuits.
+    defaults: Vec<Symbol>,
+    /// Whether the program defines its own `BoundedVec` circuit.
+    shadowed: bool,
+}
+
+impl BoundedVecLowerer {
+    /// Returns `program` with its `BoundedVec` types lowered to circuits.
+    pub fn lower_program(program: Program) -> Result<Program> {
+        let mut lowerer = Self {
+            circuits: program
+                .circuits
+                .iter()
+                .map(|(name, circuit)| (name.name, circuit.clone()))
+                .collect(),
+            aliases: program
+                .aliases
+                .iter()
+                .map(|(name, alias)| (name.name, alias.represents.clone()))
+                .collect(),
+            shadowed: program.circuits.keys().any(|name| name.name == sym::BoundedVec),
+            ..Self::default()
+        };
+
+        let mut aliases = IndexMap::new();
+        for (name, alias) in program.aliases.iter() {
+            let represents = lowerer.reconstruct_type(alias.represents.clone(), &alias.span)?;
+            aliases.insert(
+                name.clone(),
+                Alias {
+                    represents,
+                    ..alias.clone()
+                },
+            );
+        }
+        let mut program = lowerer.reconstruct_program(Program { aliases, ..program })?;
+        for (_, circuit) in lowerer.instances {
+            program.circuits.insert(circuit.circuit_name.clone(), circuit);
+        }
+        Ok(program)
+    }
+
+    /// Returns the name of the circuit implementing `BoundedVec<element, capacity>`, written at `span`,
+    /// adding it to the instances if it is new.
+    fn instance(&mut self, element: Type, capacity: PositiveNumber, span: &Span) -> Result<Identifier> {
+        let element = self.replace_self(element);
+        let element = self.reconstruct_type(element, span)?;
+        let identifier = Identifier::new_with_span(
+            Symbol::intern(&format!("BoundedVec<{}, {}>", element.source_name(), capacity)),
+            span.clone(),
+        );
+        if self.instances.contains_key(&identifier.name) {
+            return Ok(identifier);
+        }
+
+        capacity
+            .value
+            .parse::<u32>()
+            .ok()
+            .filter(|capacity| *capacity > 0)
+            .ok_or_else(|| AstError::invalid_bounded_vec_capacity(&capacity, span))?;
+        let default = self
+            .default_value(&element, span)?
+            .ok_or_else(|| AstError::bounded_vec_without_default(element.source_name(), span))?;
+
+        let source = BOUNDED_VEC
+            .replace("ELEMENT", &element.source_name())
+            .replace("CAPACITY", &capacity.value);
+        let program = leo_parser::parse(&Handler::default(), "BoundedVec", &source)?;
+        let (_, mut circuit) = program
+            .circuits
+            .into_iter()
+            .next()
+            .expect("the circuit of `BoundedVec`");
+        circuit.circuit_name = identifier.clone();
+        circuit.docs = vec![format!(" A list of up to {} `{}`.", capacity, element.source_name())];
+        fill_items(&mut circuit, default);
+
+        // The element type may be another instance, which is lowered as it is written.
+        let circuit = self.reconstruct_circuit(circuit)?;
+        self.instances.insert(identifier.name, circuit);
+        Ok(identifier)
+    }
+
+    /// Returns `type_` with `Self` replaced by the circuit being lowered.
+    fn replace_self(&self, type_: Type) -> Type {
+        match (type_, &self.circuit) {
+            (Type::SelfType, Some(circuit)) => Type::Identifier(circuit.clone()),
+            (Type::Array(element, dimensions), _) => Type::Array(Box::new(self.replace_self(*element)), dimensions),
+            (Type::Tuple(types), _) => Type::Tuple(types.into_iter().map(|type_| self.replace_self(type_)).collect()),
+            (type_, _) => type_,
+        }
+    }
+
+    /// Returns the default value of the lowered type `type_` at `span`, or `None` if it has none.
+    fn default_value(&mut self, type_: &Type, span: &Span) -> Result<Option<Expression>> {
+        let literal = |value: ValueExpression| Ok(Some(Expression::Value(value.into())));
+        match type_ {
+            Type::Boolean => literal(ValueExpression::Boolean("false".to_string(), span.clone())),
+            Type::Char => literal(ValueExpression::Char(CharValue {
+                character: Char::Scalar('\0'),
+                span: span.clone(),
+            })),
+            Type::Field => literal(ValueExpression::Field("0".to_string(), span.clone())),
+            Type::Group => literal(ValueExpression::Group(Box::new(GroupValue::Single(
+                "0".to_string(),
+                span.clone(),
+            )))),
+            Type::IntegerType(type_) => literal(ValueExpression::Integer(*type_, "0".to_string(), span.clone())),
+            Type::Array(element, dimensions) => Ok(self.default_value(element, span)?.map(|element| {
+                Expression::ArrayInit(ArrayInitExpression {
+                    element: Box::new(element),
+                    dimensions: dimensions.clone(),
+                    span: span.clone(),
+                    id: NodeID::new(),
+                })
+            })),
+            Type::Tuple(types) => {
+                let mut elements = Vec::with_capacity(types.len());
+                for type_ in types {
+                    match self.default_value(type_, span)? {
+                        Some(element) => elements.push(element),
+                        None => return Ok(None),
+                    }
+                }
+                Ok(Some(Expression::TupleInit(TupleInitExpression {
+                    elements,
+                    span: span.clone(),
+                    id: NodeID::new(),
+                })))
+            }
+            Type::BoundedVec(..) => {
+                let type_ = self.reconstruct_type(type_.clone(), span)?;
+                self.default_value(&type_, span)
+            }
+            Type::Identifier(name) if self.instances.contains_key(&name.name) => {
+                Ok(Some(Expression::Call(CallExpression {
+                    function: Box::new(Expression::Access(AccessExpression::Static(StaticAccess {
+                        inner: Box::new(Expression::Identifier(name.clone())),
+                        name: Identifier::new_with_span(Symbol::intern("new"), span.clone()),
+                        type_: None,
+                        span: span.clone(),
+                        id: NodeID::new(),
+                    }))),
+                    arguments: Vec::new(),
+                    span: span.clone(),
+                    id: NodeID::new(),
+                })))
+            }
+            Type::Identifier(name) if self.aliases.contains_key(&name.name) && !self.defaults.contains(&name.name) => {
+                let represents = self.aliases[&name.name].clone();
+                self.defaults.push(name.name);
+                let represents = self.reconstruct_type(represents, span);
+                let value = represents.and_then(|represents| self.default_value(&represents, span));
+                self.defaults.pop();
+                value
+            }
+            Type::Identifier(name) if self.circuits.contains_key(&name.name) && !self.defaults.contains(&name.name) => {
+                let variables: Vec<(Identifier, Type)> = self.circuits[&name.name]
+                    .members
+                    .iter()
+                    .filter_map(|member| match member {
+                        CircuitMember::CircuitVariable(identifier, type_) => Some((identifier.clone(), type_.clone())),
+                        _ => None,
+                    })
+                    .collect();
+                self.defaults.push(name.name);
+                let mut members = Vec::with_capacity(variables.len());
+                for (identifier, type_) in variables {
+                    let type_ = self.reconstruct_type(type_, span)?;
+                    match self.default_value(&type_, span)? {
+                        Some(value) => members.push(CircuitVariableInitializer {
+                            identifier,
+                            expression: Some(value),
+                        }),
+                        None => {
+                            self.defaults.pop();
+                            return Ok(None);
+                        }
+                    }
+                }
+                self.defaults.pop();
+                Ok(Some(Expression::CircuitInit(CircuitInitExpression {
+                    name: name.clone(),
+                    members,
+                    span: span.clone(),
+                    id: NodeID::new(),
+                })))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns `expression` creating a list of `type_`, replacing the `BoundedVec` of a static call,
+    /// e.g., `BoundedVec::new()`, with the instance `type_` names.
+    fn instantiate(&self, mut expression: Expression, type_: Option<&Type>) -> Expression {
+        if let (Expression::Call(call), Some(Type::Identifier(instance))) = (&mut expression, type_) {
+            if let Expression::Access(AccessExpression::Static(access)) = &mut *call.function {
+                match &mut *access.inner {
+                    Expression::Identifier(name)
+                        if name.name == sym::BoundedVec
+                            && !self.shadowed
+                            && self.instances.contains_key(&instance.name) =>
+                    {
+                        name.name = instance.name;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        expression
+    }
+}
+
+/// Fills the array of items created by `new()` in the circuit of a `BoundedVec` with `default`.
+fn fill_items(circuit: &mut Circuit, default: Expression) {
+    for member in circuit.members.iter_mut() {
+        if let CircuitMember::CircuitFunction(function) = member {
+            for statement in function.block.statements.iter_mut() {
+                if let Statement::Return(ReturnStatement {
+                    expression: Expression::CircuitInit(init),
+                    ..
+                }) = statement
+                {
+                    for member in init.members.iter_mut() {
+                        if let Some(Expression::ArrayInit(array)) = &mut member.expression {
+                            *array.element = default.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ProgramReconstructor for BoundedVecLowerer {
+    fn reconstruct_call(&mut self, call: CallExpression) -> Result<Expression> {
+        if let Expression::Access(AccessExpression::Static(access)) = &*call.function {
+            if matches!(&*access.inner, Expression::Identifier(name) if name.name == sym::BoundedVec) && !self.shadowed
+            {
+                return Err(AstError::bounded_vec_without_type(&call.span).into());
+            }
+        }
+        Ok(Expression::Call(CallExpression {
+            function: Box::new(self.reconstruct_expression(*call.function)?),
+            arguments: call
+                .arguments
+                .into_iter()
+                .map(|argument| self.reconstruct_expression(argument))
+                .collect::<Result<_>>()?,
+            ..call
+        }))
+    }
+
+    fn reconstruct_return(&mut self, return_: ReturnStatement) -> Result<Statement> {
+        let expression = self.instantiate(return_.expression, self.output.as_ref());
+        Ok(Statement::Return(ReturnStatement {
+            expression: self.reconstruct_expression(expression)?,
+            ..return_
+        }))
+    }
+
+    fn reconstruct_definition(&mut self, definition: DefinitionStatement) -> Result<Statement> {
+        let type_ = definition
+            .type_
+            .map(|type_| self.reconstruct_type(type_, &definition.span))
+            .transpose()?;
+        let value = self.instantiate(definition.value, type_.as_ref());
+        Ok(Statement::Definition(DefinitionStatement {
+            type_,
+            value: self.reconstruct_expression(value)?,
+            ..definition
+        }))
+    }
+
+    fn reconstruct_circuit(&mut self, circuit: Circuit) -> Result<Circuit> {
+        let outer = self.circuit.replace(circuit.circuit_name.clone());
+        let mut members = Vec::with_capacity(circuit.members.len());
+        for member in circuit.members {
+            members.push(match member {
+                CircuitMember::CircuitConst(identifier, type_, value) => {
+                    let type_ = self.reconstruct_type(type_, &identifier.span)?;
+                    CircuitMember::CircuitConst(identifier, type_, self.reconstruct_expression(value)?)
+                }
+                CircuitMember::CircuitVariable(identifier, type_) => {
+                    let type_ = self.reconstruct_type(type_, &identifier.span)?;
+                    CircuitMember::CircuitVariable(identifier, type_)
+                }
+                CircuitMember::CircuitFunction(function) => {
+                    CircuitMember::CircuitFunction(Box::new(self.reconstruct_function(*function)?))
+                }
+            });
+        }
+        self.circuit = outer;
+        Ok(Circuit { members, ..circuit })
+    }
+
+    fn reconstruct_function(&mut self, mut function: Function) -> Result<Function> {
+        for input in function.input.iter_mut() {
+            if let FunctionInput::Variable(variable) = input {
+                variable.type_ = self.reconstruct_type(variable.type_.clone(), &variable.span)?;
+            }
+        }
+        function.output = function
+            .output
+            .take()
+            .map(|type_| self.reconstruct_type(type_, &function.span))
+            .transpose()?;
+
+        let output = std::mem::replace(&mut self.output, function.output.clone());
+        let block = self.reconstruct_block(function.block);
+        self.output = output;
+        Ok(Function {
+            block: block?,
+            ..function
+        })
+    }
+
+    fn reconstruct_type(&mut self, type_: Type, span: &Span) -> Result<Type> {
+        Ok(match type_ {
+            Type::Array(element, dimensions) => {
+                Type::Array(Box::new(self.reconstruct_type(*element, span)?), dimensions)
+            }
+            Type::Tuple(types) => Type::Tuple(
+                types
+                    .into_iter()
+                    .map(|type_| self.reconstruct_type(type_, span))
+                    .collect::<Result<_>>()?,
+            ),
+            Type::BoundedVec(element, capacity) => Type::Identifier(self.instance(*element, capacity, span)?),
+            type_ => type_,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    fn lower(source: &str) -> Result<Program> {
+        let program = parse(source);
+        BoundedVecLowerer::lower_program(program)
+    }
+
+    #[test]
+    fn lowers_types_to_circuits() {
+        create_session_if_not_set_then(|_| {
+            let program = lower(
+                "function main(a: u8) -> u32 {
+                     let list: BoundedVec<u8, 4> = BoundedVec::new();
+                     list.push(a);
+                     return list.len() + nested().len();
+                 }
+                 function nested() -> BoundedVec<BoundedVec<bool, 2>, 3> {
+                     return BoundedVec::new();
+                 }",
+            )
+            .unwrap();
+
+            let names: Vec<String> = program.circuits.keys().map(|name| name.to_string()).collect();
+            assert_eq!(
+                names,
+                [
+                    "BoundedVec<u8, 4>",
+                    "BoundedVec<bool, 2>",
+                    "BoundedVec<BoundedVec<bool, 2>, 3>"
+                ]
+            );
+            let main = program.functions.values().next().unwrap();
+            assert_eq!(
+                main.block.statements[0].to_string(),
+                "let mut list: circuit BoundedVec<u8, 4> = BoundedVec<u8, 4>::new();"
+            );
+            let nested = &program.circuits.values().last().unwrap();
+            assert!(nested
+                .members
+                .iter()
+                .any(|member| member.to_string() == "items: [circuit BoundedVec<bool, 2>; 3]"));
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_lists() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "function main() { let list: BoundedVec<u8, 0> = BoundedVec::new(); }",
+                "function main() { let list: BoundedVec<address, 2> = BoundedVec::new(); }",
+                "function main() { let list = BoundedVec::new(); }",
+            ] {
+                assert!(lower(source).is_err(), "{}", source);
+            }
+        });
+    }
+}
@@ -0,0 +1,370 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ExpressionTypes;
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// The name of a function in the call graph.
+/// Circuit member functions are qualified with the name of their circuit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionName {
+    /// The circuit the function is a member of, if any.
+    pub circuit: Option<Symbol>,
+    /// The name of the function itself.
+    pub name: Symbol,
+}
+
+impl FunctionName {
+    /// Returns the name of a free function.
+    pub fn free(name: Symbol) -> Self {
+        Self { circuit: None, name }
+    }
+
+    /// Returns the name of a member function of `circuit`.
+    pub fn member(circuit: Symbol, name: Symbol) -> Self {
+        Self {
+            circuit: Some(circuit),
+            name,
+        }
+    }
+}
+
+impl fmt::Display for FunctionName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.circuit {
+            Some(circuit) => write!(f, "{}::{}", circuit, self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// The call graph of a program.
+///
+/// Nodes are the free functions and circuit member functions defined in the program.
+/// An edge `caller -> callee` exists if the body of `caller` contains a call that
+/// resolves to `callee`. Free function calls, `Circuit::function` static calls, and
+/// `self.function` calls inside a circuit are resolved syntactically. Calls of member functions
+/// on other receivers, as `p.function()`, are resolved by the circuit type the [`TypeInferrer`] gives the receiver.
+/// Calls on receivers of unknown type are not recorded.
+///
+/// [`TypeInferrer`]: crate::TypeInferrer
+#[derive(Clone, Debug, Default)]
+pub struct CallGraph {
+    /// The functions in the program mapped to the span of their definition.
+    functions: IndexMap<FunctionName, Span>,
+    /// Each function mapped to its callees and the span of the first call to each.
+    edges: IndexMap<FunctionName, IndexMap<FunctionName, Span>>,
+    /// The known types of the expressions of the program, to resolve the receivers of member calls.
+    types: ExpressionTypes,
+}
+
+impl CallGraph {
+    /// Builds the call graph of `program`.
+    pub fn new(program: &Program) -> Self {
+        let mut graph = Self {
+            types: ExpressionTypes::new(program),
+            ..Self::default()
+        };
+
+        for (name, function) in program.functions.iter() {
+            graph.add_function(FunctionName::free(name.name), function.span.clone());
+        }
+        for (name, circuit) in program.circuits.iter() {
+            for function in circuit_functions(circuit) {
+                graph.add_function(FunctionName::member(name.name, function.name()), function.span.clone());
+            }
+        }
+
+        for function in program.functions.values() {
+            let mut builder = EdgeBuilder::new(&graph, FunctionName::free(function.name()), None);
+            builder.visit_block(&function.block);
+            graph.edges.insert(builder.caller, builder.callees);
+        }
+        for (name, circuit) in program.circuits.iter() {
+            for function in circuit_functions(circuit) {
+                let caller = FunctionName::member(name.name, function.name());
+                let mut builder = EdgeBuilder::new(&graph, caller, Some(name.name));
+                builder.visit_block(&function.block);
+                graph.edges.insert(builder.caller, builder.callees);
+            }
+        }
+
+        graph
+    }
+
+    /// Adds `function` as a node without any edges.
+    fn add_function(&mut self, function: FunctionName, span: Span) {
+        self.functions.insert(function, span);
+        self.edges.entry(function).or_default();
+    }
+
+    /// Returns `true` if `function` is defined in the program.
+    pub fn contains(&self, function: &FunctionName) -> bool {
+        self.functions.contains_key(function)
+    }
+
+    /// Resolves the callee of `function`, the function of a call in a member function of `circuit`, if any.
+    /// Returns `None` if the callee is not known statically, or is not defined in the program.
+    pub fn resolve(&self, function: &Expression, circuit: Option<Symbol>) -> Option<FunctionName> {
+        let name = match function {
+            Expression::Identifier(identifier) => FunctionName::free(identifier.name),
+            Expression::Access(AccessExpression::Static(access)) => match &*access.inner {
+                Expression::Identifier(circuit_name) if circuit_name.name == sym::SelfUpper => {
+                    FunctionName::member(circuit?, access.name.name)
+                }
+                Expression::Identifier(circuit_name) => FunctionName::member(circuit_name.name, access.name.name),
+                _ => return None,
+            },
+            Expression::Access(AccessExpression::Member(access)) => match &*access.inner {
+                Expression::Identifier(receiver) if receiver.name == sym::SelfLower => {
+                    FunctionName::member(circuit?, access.name.name)
+                }
+                receiver => match self.types.get(receiver.id())? {
+                    Type::Identifier(circuit_name) if circuit_name.name == sym::SelfUpper => {
+                        FunctionName::member(circuit?, access.name.name)
+                    }
+                    Type::Identifier(circuit_name) => FunctionName::member(circuit_name.name, access.name.name),
+                    _ => return None,
+                },
+            },
+            _ => return None,
+        };
+        self.contains(&name).then(|| name)
+    }
+
+    /// Returns every function in the program, in definition order.
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionName> {
+        self.functions.keys()
+    }
+
+    /// Returns the span of the definition of `function`, if it exists.
+    pub fn definition_span(&self, function: &FunctionName) -> Option<&Span> {
+        self.functions.get(function)
+    }
+
+    /// Returns the functions called directly by `function`.
+    pub fn callees(&self, function: &FunctionName) -> impl Iterator<Item = &FunctionName> {
+        self.edges.get(function).into_iter().flat_map(|callees| callees.keys())
+    }
+
+    /// Returns the functions that call `function` directly.
+    pub fn callers<'a>(&'a self, function: &'a FunctionName) -> impl Iterator<Item = &'a FunctionName> + 'a {
+        self.edges
+            .iter()
+            .filter(move |(_, callees)| callees.contains_key(function))
+            .map(|(caller, _)| caller)
+    }
+
+    /// Returns all functions reachable from `function` through one or more calls.
+    /// The result only includes `function` itself if it is recursive.
+    pub fn reachable_from(&self, function: &FunctionName) -> IndexSet<FunctionName> {
+        let mut reachable = IndexSet::new();
+        let mut stack: Vec<FunctionName> = self.callees(function).copied().collect();
+        while let Some(next) = stack.pop() {
+            if reachable.insert(next) {
+                stack.extend(self.callees(&next).copied());
+            }
+        }
+        reachable
+    }
+
+    /// Returns the functions which are not reachable from any of `roots`.
+    pub fn unreachable_from(&self, roots: &[FunctionName]) -> Vec<FunctionName> {
+        let mut reachable: IndexSet<FunctionName> = roots.iter().copied().collect();
+        for root in roots {
+            reachable.extend(self.reachable_from(root));
+        }
+        self.functions().filter(|f| !reachable.contains(*f)).copied().collect()
+    }
+
+    /// Returns the first cycle in the graph, if any, in definition order.
+    /// Each element is a function and the span of its call to the next function in the cycle.
+    pub fn find_cycle(&self) -> Option<Vec<(FunctionName, Span)>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Visiting,
+            Done,
+        }
+
+        fn visit(
+            graph: &CallGraph,
+            function: FunctionName,
+            marks: &mut IndexMap<FunctionName, Mark>,
+            path: &mut Vec<(FunctionName, Span)>,
+        ) -> Option<Vec<(FunctionName, Span)>> {
+            marks.insert(function, Mark::Visiting);
+            for (callee, span) in graph.edges.get(&function).into_iter().flatten() {
+                path.push((function, span.clone()));
+                match marks.get(callee) {
+                    Some(Mark::Visiting) => {
+                        let start = path.iter().position(|(f, _)| f == callee).unwrap();
+                        return Some(path.split_off(start));
+                    }
+                    Some(Mark::Done) => {}
+                    None => {
+                        if let Some(cycle) = visit(graph, *callee, marks, path) {
+                            return Some(cycle);
+                        }
+                    }
+                }
+                path.pop();
+            }
+            marks.insert(function, Mark::Done);
+            None
+        }
+
+        let mut marks = IndexMap::new();
+        for function in self.functions() {
+            if !marks.contains_key(function) {
+                if let Some(cycle) = visit(self, *function, &mut marks, &mut Vec::new()) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns an error if any function in the program calls itself, directly or indirectly.
+    pub fn check_no_recursion(&self) -> Result<()> {
+        if let Some(cycle) = self.find_cycle() {
+            let (first, _) = &cycle[0];
+            let (_, closing_call) = cycle.last().unwrap();
+            let path = cycle
+                .iter()
+                .map(|(f, _)| format!("`{}`", f))
+                .chain(std::iter::once(format!("`{}`", first)))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(AstError::recursive_function_call(first, path, closing_call).into());
+        }
+        Ok(())
+    }
+}
+
+/// Returns the member functions of `circuit`.
+pub fn circuit_functions(circuit: &Circuit) -> impl Iterator<Item = &Function> {
+    circuit.members.iter().filter_map(|member| match member {
+        CircuitMember::CircuitFunction(function) => Some(&**function),
+        _ => None,
+    })
+}
+
+/// Walks the body of a single function and records the calls it makes.
+struct EdgeBuilder<'a> {
+    graph: &'a CallGraph,
+    caller: FunctionName,
+    /// The circuit the caller is defined in, if any.
+    circuit: Option<Symbol>,
+    callees: IndexMap<FunctionName, Span>,
+}
+
+impl<'a> EdgeBuilder<'a> {
+    fn new(graph: &'a CallGraph, caller: FunctionName, circuit: Option<Symbol>) -> Self {
+        Self {
+            graph,
+            caller,
+            circuit,
+            callees: IndexMap::new(),
+        }
+    }
+}
+
+impl ExpressionVisitor for EdgeBuilder<'_> {
+    fn visit_call(&mut self, call: &CallExpression) {
+        if let Some(callee) = self.graph.resolve(&call.function, self.circuit) {
+            self.callees.entry(callee).or_insert_with(|| call.span.clone());
+        }
+        self.visit_expression(&call.function);
+        call.arguments.iter().for_each(|e| self.visit_expression(e));
+    }
+}
+
+impl StatementVisitor for EdgeBuilder<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    fn graph(source: &str) -> CallGraph {
+        let program = parse(source);
+        CallGraph::new(&program)
+    }
+
+    #[test]
+    fn reachability() {
+        create_session_if_not_set_then(|_| {
+            let graph = graph(
+                "function main() { let a = foo(); return Bar::baz(a); }
+                 function foo() -> u8 { return 1u8; }
+                 function unused() {}
+                 circuit Bar { function baz(x: u8) -> u8 { return Self::qux(x); } function qux(x: u8) -> u8 { return x; } }",
+            );
+            let main = FunctionName::free(Symbol::intern("main"));
+            let reachable: Vec<String> = graph.reachable_from(&main).iter().map(|f| f.to_string()).collect();
+            assert_eq!(reachable.len(), 3);
+            assert!(reachable.contains(&"Bar::qux".to_string()));
+            assert_eq!(
+                graph.unreachable_from(&[main]),
+                vec![FunctionName::free(Symbol::intern("unused"))]
+            );
+            assert!(graph.check_no_recursion().is_ok());
+        });
+    }
+
+    #[test]
+    fn resolves_typed_receivers() {
+        create_session_if_not_set_then(|_| {
+            let graph = graph(
+                "circuit Point {
+                    x: u8,
+                    function norm(self) -> u8 { return self.x; }
+                    function twice(self) -> u8 { let p = Self { x: self.x }; return p.norm() * 2u8; }
+                }
+                function main(q: Point) -> u8 { let p = Point { x: 1u8 }; return p.twice() + q.norm(); }",
+            );
+            let callees = |function: FunctionName| graph.callees(&function).map(|f| f.to_string()).collect::<Vec<_>>();
+            let point = Symbol::intern("Point");
+            assert_eq!(
+                callees(FunctionName::free(Symbol::intern("main"))),
+                ["Point::twice", "Point::norm"]
+            );
+            assert_eq!(
+                callees(FunctionName::member(point, Symbol::intern("twice"))),
+                ["Point::norm"]
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_recursion() {
+        create_session_if_not_set_then(|_| {
+            let graph = graph("function main() { a(); } function a() { b(); } function b() { a(); }");
+            let cycle = graph.find_cycle().unwrap();
+            let names: Vec<String> = cycle.iter().map(|(f, _)| f.to_string()).collect();
+            assert_eq!(names, vec!["a", "b"]);
+            let error = graph.check_no_recursion().unwrap_err().to_string();
+            assert!(error.contains("`a` -> `b` -> `a`"));
+        });
+    }
+}
@@ -53,6 +53,7 @@ impl Canonicalizer {
                         array: left,
                         index: Box::new(index),
                         span: span.clone(),
+                        id: NodeID::new(),
                     })));
                 }
                 AssigneeAccess::ArrayRange(start, stop) => {
@@ -61,6 +62,7 @@ impl Canonicalizer {
                         left: start.map(Box::new),
                         right: stop.map(Box::new),
                         span: span.clone(),
+                        id: NodeID::new(),
                     })));
                 }
                 AssigneeAccess::Tuple(positive_number, _) => {
@@ -68,6 +70,7 @@ impl Canonicalizer {
                         tuple: left,
                         index: positive_number,
                         span: span.clone(),
+                        id: NodeID::new(),
                     })));
                 }
                 AssigneeAccess::Member(identifier) => {
@@ -75,6 +78,7 @@ impl Canonicalizer {
                         inner: left,
                         name: identifier,
                         span: span.clone(),
+                        id: NodeID::new(),
                         type_: None,
                     })));
                 }
@@ -146,6 +150,7 @@ impl Canonicalizer {
                     inner,
                     op: unary.op.clone(),
                     span: unary.span.clone(),
+                    id: unary.id,
                 });
             }
             Expression::Binary(binary) => {
@@ -157,6 +162,7 @@ impl Canonicalizer {
                     right,
                     op: binary.op,
                     span: binary.span.clone(),
+                    id: binary.id,
                 });
             }
             Expression::Ternary(ternary) => {
@@ -169,6 +175,7 @@ impl Canonicalizer {
                     if_true,
                     if_false,
                     span: ternary.span.clone(),
+                    id: ternary.id,
                 });
             }
 
@@ -180,6 +187,7 @@ impl Canonicalizer {
                     inner,
                     target_type,
                     span: cast.span.clone(),
+                    id: cast.id,
                 });
             }
 
@@ -193,6 +201,7 @@ impl Canonicalizer {
                             array,
                             index,
                             span: array_access.span.clone(),
+                            id: array_access.id,
                         })
                     }
                     AccessExpression::ArrayRange(array_range_access) => {
@@ -211,12 +220,14 @@ impl Canonicalizer {
                             left,
                             right,
                             span: array_range_access.span.clone(),
+                            id: array_range_access.id,
                         })
                     }
                     AccessExpression::Member(member_access) => AccessExpression::Member(MemberAccess {
                         inner: Box::new(self.canonicalize_expression(&member_access.inner)),
                         name: member_access.name.clone(),
                         span: member_access.span.clone(),
+                        id: member_access.id,
                         type_: None,
                     }),
                     AccessExpression::Tuple(tuple_access) => {
@@ -226,6 +237,7 @@ impl Canonicalizer {
                             tuple,
                             index: tuple_access.index.clone(),
                             span: tuple_access.span.clone(),
+                            id: tuple_access.id,
                         })
                     }
                     AccessExpression::Static(static_access) => AccessExpression::Static(StaticAccess {
@@ -233,6 +245,7 @@ impl Canonicalizer {
                         name: static_access.name.clone(),
                         type_: self.canonicalize_self_type(static_access.type_.as_ref()),
                         span: static_access.span.clone(),
+                        id: static_access.id,
                     }),
                 };
 
@@ -256,6 +269,7 @@ impl Canonicalizer {
                 return Expression::ArrayInline(ArrayInlineExpression {
                     elements,
                     span: array_inline.span.clone(),
+                    id: array_inline.id,
                 });
             }
 
@@ -266,6 +280,7 @@ impl Canonicalizer {
                     dimensions: array_init.dimensions.clone(),
                     element,
                     span: array_init.span.clone(),
+                    id: array_init.id,
                 });
             }
 
@@ -279,6 +294,7 @@ impl Canonicalizer {
                 return Expression::TupleInit(TupleInitExpression {
                     elements,
                     span: tuple_init.span.clone(),
+                    id: tuple_init.id,
                 });
             }
 
@@ -296,6 +312,7 @@ impl Canonicalizer {
                         .map(|member| self.canonicalize_circuit_variable_initializer(member))
                         .collect(),
                     span: circuit_init.span.clone(),
+                    id: circuit_init.id,
                 });
             }
             Expression::Call(call) => {
@@ -307,6 +324,7 @@ impl Canonicalizer {
                         .map(|arg| self.canonicalize_expression(arg))
                         .collect(),
                     span: call.span.clone(),
+                    id: call.id,
                 });
             }
             Expression::Identifier(identifier) => {
@@ -357,6 +375,7 @@ impl Canonicalizer {
         Block {
             statements,
             span: block.span.clone(),
+            id: block.id,
         }
     }
 
@@ -367,6 +386,7 @@ impl Canonicalizer {
                 Statement::Return(ReturnStatement {
                     expression,
                     span: return_statement.span.clone(),
+                    id: return_statement.id,
                 })
             }
             Statement::Definition(definition) => {
@@ -379,6 +399,8 @@ impl Canonicalizer {
                     type_,
                     value,
                     span: definition.span.clone(),
+                    id: definition.id,
+                    annotations: definition.annotations.clone(),
                 })
             }
             Statement::Assign(assign) => {
@@ -390,6 +412,7 @@ impl Canonicalizer {
                     value,
                     operation: assign.operation,
                     span: assign.span.clone(),
+                    id: assign.id,
                 }))
             }
             Statement::Conditional(conditional) => {
@@ -405,6 +428,7 @@ impl Canonicalizer {
                     block,
                     next,
                     span: conditional.span.clone(),
+                    id: conditional.id,
                 })
             }
             Statement::Iteration(iteration) => {
@@ -419,6 +443,7 @@ impl Canonicalizer {
                     inclusive: iteration.inclusive,
                     block,
                     span: iteration.span.clone(),
+                    id: iteration.id,
                 }))
             }
             Statement::Console(console_function_call) => {
@@ -450,11 +475,13 @@ impl Canonicalizer {
                 Statement::Console(ConsoleStatement {
                     function,
                     span: console_function_call.span.clone(),
+                    id: console_function_call.id,
                 })
             }
             Statement::Expression(expression) => Statement::Expression(ExpressionStatement {
                 expression: self.canonicalize_expression(&expression.expression),
                 span: expression.span.clone(),
+                id: expression.id,
             }),
             Statement::Block(block) => Statement::Block(self.canonicalize_block(block)),
         }
@@ -466,6 +493,7 @@ impl Canonicalizer {
 
             return FunctionInput::Variable(FunctionInputVariable {
                 identifier: variable.identifier.clone(),
+                mode: variable.mode,
                 const_: variable.const_,
                 mutable: variable.mutable,
                 type_,
@@ -496,11 +524,14 @@ impl Canonicalizer {
                 let block = self.canonicalize_block(&function.block);
 
                 return CircuitMember::CircuitFunction(Box::new(Function {
+                    docs: function.docs.clone(),
                     annotations: function.annotations.clone(),
+                    visibility: function.visibility,
                     identifier: function.identifier.clone(),
                     const_: function.const_,
                     input,
                     output,
+                    output_mode: function.output_mode,
                     block,
                     core_mapping: function.core_mapping.clone(),
                     span: function.span.clone(),
@@ -548,7 +579,7 @@ impl ReconstructingReducer for Canonicalizer {
         let mut col_adder = 0;
         for (index, character) in string.iter().enumerate() {
             let col_start = span.col_start + index + 1 + col_adder; // account for open quote
-            let bytes = span.content.clone().into_bytes();
+            let bytes = span.content.as_bytes();
             let col_stop = if bytes[col_start - 1] == b'\\' {
                 let mut width = 0;
 
@@ -582,7 +613,8 @@ impl ReconstructingReducer for Canonicalizer {
                         span.path.clone(),
                         span.content.clone(),
                     ),
-                }),
+                })
+                .into(),
             )));
         }
 
@@ -593,6 +625,7 @@ impl ReconstructingReducer for Canonicalizer {
         Ok(Expression::ArrayInline(ArrayInlineExpression {
             elements,
             span: span.clone(),
+            id: NodeID::new(),
         }))
     }
 
@@ -605,12 +638,16 @@ impl ReconstructingReducer for Canonicalizer {
             element,
             dimensions: ArrayDimensions::single(dim),
             span: array_init.span.clone(),
+            id: NodeID::new(),
         };
 
         let mut iter = array_init.dimensions.iter().rev().cloned();
         // We know the array has non-zero dimensions.
         let init = mk_expr(Box::new(element), iter.next().unwrap());
-        Ok(iter.fold(init, |elem, dim| mk_expr(Box::new(Expression::ArrayInit(elem)), dim)))
+        let mut outer = iter.fold(init, |elem, dim| mk_expr(Box::new(Expression::ArrayInit(elem)), dim));
+        // The outermost array stands for `array_init`.
+        outer.id = array_init.id;
+        Ok(outer)
     }
 
     fn reduce_definition(
@@ -628,6 +665,8 @@ impl ReconstructingReducer for Canonicalizer {
             type_,
             value,
             span: definition.span.clone(),
+            id: definition.id,
+            annotations: definition.annotations.clone(),
         })
     }
 
@@ -652,6 +691,7 @@ impl ReconstructingReducer for Canonicalizer {
                     right,
                     op,
                     span: assign.span.clone(),
+                    id: NodeID::new(),
                 });
 
                 Ok(AssignStatement {
@@ -659,6 +699,7 @@ impl ReconstructingReducer for Canonicalizer {
                     assignee,
                     value: new_value,
                     span: assign.span.clone(),
+                    id: assign.id,
                 })
             }
             value => Ok(AssignStatement {
@@ -666,6 +707,7 @@ impl ReconstructingReducer for Canonicalizer {
                 assignee,
                 value,
                 span: assign.span.clone(),
+                id: assign.id,
             }),
         }
     }
@@ -686,11 +728,14 @@ impl ReconstructingReducer for Canonicalizer {
         };
 
         Ok(Function {
+            docs: function.docs.clone(),
             identifier,
             annotations,
+            visibility: function.visibility,
             input,
             const_,
             output: new_output,
+            output_mode: function.output_mode,
             block,
             core_mapping: function.core_mapping.clone(),
             span: function.span.clone(),
@@ -699,12 +744,14 @@ impl ReconstructingReducer for Canonicalizer {
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit> {
         self.circuit_name = Some(circuit_name.clone());
         let circ = Circuit {
+            docs: circuit.docs.clone(),
+            visibility: circuit.visibility,
             circuit_name,
             members: members
                 .iter()
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Pass;
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{sym, Symbol};
+
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+/// A build configuration, mapping names such as `network` to their values, e.g., `testnet`.
+pub type Cfg = BTreeMap<String, String>;
+
+/// Removes the functions, circuit member functions, and global constants annotated with a `@cfg`
+/// which does not match the build configuration.
+///
+/// An item annotated with `@cfg(network = "testnet", mode = "debug")` is kept only if the
+/// configuration sets every one of the names to the given value.
+#[derive(Clone, Debug, Default)]
+pub struct CfgPruner {
+    /// The configuration the `@cfg` annotations are evaluated against.
+    cfg: Cfg,
+}
+
+impl CfgPruner {
+    /// Returns a pruner keeping the items active under `cfg`.
+    pub fn new(cfg: Cfg) -> Self {
+        Self { cfg }
+    }
+
+    /// Returns `true` if the item with `annotations` is kept under the configuration.
+    pub fn is_active(&self, annotations: &IndexMap<Symbol, Annotation>) -> bool {
+        match annotations.get(&sym::cfg) {
+            None => true,
+            Some(cfg) => cfg
+                .values
+                .iter()
+                .all(|(name, value)| self.cfg.get(&*name.as_str()) == Some(value)),
+        }
+    }
+
+    /// Returns `program` without the items which are inactive under the configuration.
+    pub fn prune_program(&self, mut program: Program) -> Program {
+        program
+            .functions
+            .retain(|_, function| self.is_active(&function.annotations));
+        program
+            .global_consts
+            .retain(|_, definition| self.is_active(&definition.annotations));
+        for circuit in program.circuits.values_mut() {
+            circuit.members.retain(|member| match member {
+                CircuitMember::CircuitFunction(function) => self.is_active(&function.annotations),
+                CircuitMember::CircuitConst(..) | CircuitMember::CircuitVariable(..) => true,
+            });
+        }
+        program
+    }
+}
+
+impl Pass for CfgPruner {
+    fn name(&self) -> &'static str {
+        "cfg-pruning"
+    }
+
+    fn run(&mut self, _handler: &Handler, ast: Ast) -> Result<Ast> {
+        Ok(Ast::new(self.prune_program(ast.into_repr())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = r#"
+        @cfg(network = "testnet")
+        const FEE: u64 = 1u64;
+        @cfg(network = "mainnet")
+        const FEE: u64 = 10u64;
+
+        circuit Foo {
+            @cfg(network = "testnet", mode = "debug")
+            function debug() {}
+        }
+
+        @cfg(network = "testnet")
+        function main() {}
+        @cfg(network = "mainnet")
+        function main() {}
+        function helper() {}
+    "#;
+
+    fn prune(cfg: &[(&str, &str)]) -> Program {
+        let program = parse(PROGRAM);
+        let cfg = cfg
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        CfgPruner::new(cfg).prune_program(program)
+    }
+
+    #[test]
+    fn keeps_active_items() {
+        create_session_if_not_set_then(|_| {
+            let program = prune(&[("network", "testnet"), ("mode", "debug")]);
+            let main = program
+                .functions
+                .values()
+                .find(|f| f.name() == Symbol::intern("main"))
+                .unwrap();
+            assert_eq!(main.annotations[&sym::cfg].values[0].1, "testnet");
+            assert_eq!(program.functions.len(), 2);
+
+            let fee = program.global_consts.values().next().unwrap();
+            assert_eq!(fee.value.to_string(), "1u64");
+            assert_eq!(program.global_consts.len(), 1);
+
+            assert_eq!(program.circuits.values().next().unwrap().members.len(), 1);
+        });
+    }
+
+    #[test]
+    fn removes_items_without_matching_cfg() {
+        create_session_if_not_set_then(|_| {
+            let program = prune(&[("network", "mainnet")]);
+            let fee = program.global_consts.values().next().unwrap();
+            assert_eq!(fee.value.to_string(), "10u64");
+            assert!(program.circuits.values().next().unwrap().members.is_empty());
+
+            let program = prune(&[]);
+            assert!(program.global_consts.is_empty());
+            assert_eq!(program.functions.len(), 1);
+        });
+    }
+}
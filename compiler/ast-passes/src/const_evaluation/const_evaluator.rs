@@ -0,0 +1,309 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{circuit_functions, CallGraph, FunctionName};
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+use leo_interpreter::{Integer, Interpreter, Value};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// Replaces the calls of `@const` functions on constant arguments with the values they return.
+///
+/// A `@const` function may only compute with its inputs, constants and other `@const` functions:
+/// it takes no `self`, has no `console` statements and calls no other functions.
+/// An argument is constant if it is a literal, or an array, tuple or circuit of literals,
+/// such as the value of a call evaluated before.
+///
+/// The calls are evaluated by the interpreter, so they fail as they would when the program runs,
+/// e.g., on an overflow. A call returning a group element is kept, as the value has no literal form.
+pub struct ConstEvaluator<'a> {
+    /// The program whose calls are evaluated.
+    program: &'a Program,
+    /// The call graph the calls are resolved with.
+    graph: CallGraph,
+    /// The `@const` functions of the program.
+    functions: IndexMap<FunctionName, &'a Function>,
+    /// The interpreter evaluating the calls, created on the first one.
+    interpreter: Option<Interpreter<'a>>,
+    in_circuit: bool,
+}
+
+impl<'a> ConstEvaluator<'a> {
+    /// Returns an evaluator of the calls of the `@const` functions of `program`,
+    /// or an error if one of them uses an operation which cannot be evaluated at compile time.
+    pub fn new(program: &'a Program) -> Result<Self> {
+        let mut functions = IndexMap::new();
+        for function in program.functions.values() {
+            functions.insert(FunctionName::free(function.name()), function);
+        }
+        for (name, circuit) in program.circuits.iter() {
+            for function in circuit_functions(circuit) {
+                functions.insert(FunctionName::member(name.name, function.name()), function);
+            }
+        }
+        functions.retain(|_, function| function.annotations.contains_key(&sym::Const));
+
+        let evaluator = Self {
+            program,
+            graph: CallGraph::new(program),
+            functions,
+            interpreter: None,
+            in_circuit: false,
+        };
+        for (name, function) in evaluator.functions.iter() {
+            evaluator.check_function(name, function)?;
+        }
+        Ok(evaluator)
+    }
+
+    /// Returns `program` with the calls of its `@const` functions on constant arguments evaluated.
+    pub fn evaluate_program(program: &Program) -> Result<Program> {
+        ReconstructingDirector::new(ConstEvaluator::new(program)?).reduce_program(program)
+    }
+
+    /// Checks that the `@const` function `name` only uses operations which can be evaluated at compile time.
+    fn check_function(&self, name: &FunctionName, function: &Function) -> Result<()> {
+        if let Some(input) = function.input.iter().find(|input| input.is_self()) {
+            return Err(AstError::non_const_operation(name, format!("`{}`", input), input.span()).into());
+        }
+
+        let mut finder = NonConstFinder {
+            evaluator: self,
+            circuit: name.circuit,
+            found: None,
+        };
+        finder.visit_block(&function.block);
+        match finder.found {
+            Some((operation, span)) => Err(AstError::non_const_operation(name, operation, &span).into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the value of `call` as an expression, if it calls a `@const` function on constant arguments
+    /// and the value has a literal form.
+    fn evaluate(&mut self, call: &CallExpression) -> Result<Option<Expression>> {
+        let function = match self.graph.resolve(&call.function, None) {
+            Some(name) => match self.functions.get(&name) {
+                Some(function) => *function,
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        if !call.arguments.iter().all(is_constant) {
+            return Ok(None);
+        }
+
+        if self.interpreter.is_none() {
+            self.interpreter = Some(Interpreter::new(self.program)?);
+        }
+        let interpreter = self.interpreter.as_mut().unwrap();
+        let arguments = call
+            .arguments
+            .iter()
+            .map(|argument| interpreter.expression(argument))
+            .collect::<Result<Vec<_>>>()?;
+        let value = interpreter.call(function, arguments, &call.span)?;
+        Ok(value_expression(&value, &call.span))
+    }
+}
+
+/// Returns `true` if `expression` is a literal, or an array, tuple or circuit of literals.
+fn is_constant(expression: &Expression) -> bool {
+    match expression {
+        Expression::Value(_) => true,
+        Expression::Unary(unary) => {
+            matches!(unary.op, UnaryOperation::Negate) && matches!(&*unary.inner, Expression::Value(_))
+        }
+        Expression::ArrayInline(array) => array.elements.iter().all(|element| match element {
+            SpreadOrExpression::Spread(expression) | SpreadOrExpression::Expression(expression) => {
+                is_constant(expression)
+            }
+        }),
+        Expression::TupleInit(tuple) => tuple.elements.iter().all(is_constant),
+        Expression::CircuitInit(circuit) => circuit
+            .members
+            .iter()
+            .all(|member| member.expression.as_ref().map_or(false, is_constant)),
+        _ => false,
+    }
+}
+
+/// Returns the literal form of `value` at `span`, or `None` if it contains a group element.
+fn value_expression(value: &Value, span: &Span) -> Option<Expression> {
+    let literal = |value: ValueExpression| Some(Expression::Value(value.into()));
+    match value {
+        Value::Address(address) => literal(ValueExpression::Address(address.clone(), span.clone())),
+        Value::Boolean(boolean) => literal(ValueExpression::Boolean(boolean.to_string(), span.clone())),
+        Value::Char(character) => literal(ValueExpression::Char(CharValue {
+            character: character.clone(),
+            span: span.clone(),
+        })),
+        Value::Field(field) => literal(ValueExpression::Field(field.to_string(), span.clone())),
+        Value::Group(_) => None,
+        Value::Integer(Integer::Signed(type_, integer)) => {
+            literal(ValueExpression::Integer(*type_, integer.to_string(), span.clone()))
+        }
+        Value::Integer(Integer::Unsigned(type_, integer)) => {
+            literal(ValueExpression::Integer(*type_, integer.to_string(), span.clone()))
+        }
+        Value::Implicit(value) => literal(ValueExpression::Implicit(value.clone(), span.clone())),
+        Value::Array(elements) => Some(Expression::ArrayInline(ArrayInlineExpression {
+            elements: elements
+                .iter()
+                .map(|element| value_expression(element, span).map(SpreadOrExpression::Expression))
+                .collect::<Option<_>>()?,
+            span: span.clone(),
+            id: NodeID::new(),
+        })),
+        Value::Tuple(elements) => Some(Expression::TupleInit(TupleInitExpression {
+            elements: elements
+                .iter()
+                .map(|element| value_expression(element, span))
+                .collect::<Option<_>>()?,
+            span: span.clone(),
+            id: NodeID::new(),
+        })),
+        Value::Circuit(name, members) => Some(Expression::CircuitInit(CircuitInitExpression {
+            name: Identifier::new_with_span(*name, span.clone()),
+            members: members
+                .iter()
+                .map(|(name, value)| {
+                    Some(CircuitVariableInitializer {
+                        identifier: Identifier::new_with_span(*name, span.clone()),
+                        expression: Some(value_expression(value, span)?),
+                    })
+                })
+                .collect::<Option<_>>()?,
+            span: span.clone(),
+            id: NodeID::new(),
+        })),
+    }
+}
+
+impl ReconstructingReducer for ConstEvaluator<'_> {
+    fn in_circuit(&self) -> bool {
+        self.in_circuit
+    }
+
+    fn swap_in_circuit(&mut self) {
+        self.in_circuit = !self.in_circuit;
+    }
+
+    fn reduce_expression(&mut self, _expression: &Expression, new: Expression) -> Result<Expression> {
+        if let Expression::Call(call) = &new {
+            if let Some(value) = self.evaluate(call)? {
+                return Ok(value);
+            }
+        }
+        Ok(new)
+    }
+}
+
+/// Finds the first operation of a `@const` function which cannot be evaluated at compile time.
+struct NonConstFinder<'a, 'b> {
+    evaluator: &'b ConstEvaluator<'a>,
+    /// The circuit the visited function is a member of, if any.
+    circuit: Option<Symbol>,
+    /// The operation found and its span, if any.
+    found: Option<(String, Span)>,
+}
+
+impl NonConstFinder<'_, '_> {
+    fn report(&mut self, operation: String, span: &Span) {
+        if self.found.is_none() {
+            self.found = Some((operation, span.clone()));
+        }
+    }
+}
+
+impl ExpressionVisitor for NonConstFinder<'_, '_> {
+    fn visit_call(&mut self, call: &CallExpression) {
+        let callee = self.evaluator.graph.resolve(&call.function, self.circuit);
+        if !callee.map_or(false, |callee| self.evaluator.functions.contains_key(&callee)) {
+            self.report(
+                format!("a call to `{}`, which is not `@const`", call.function),
+                &call.span,
+            );
+        }
+        self.visit_expression(&call.function);
+        call.arguments.iter().for_each(|e| self.visit_expression(e));
+    }
+}
+
+impl StatementVisitor for NonConstFinder<'_, '_> {
+    fn visit_console(&mut self, console: &ConsoleStatement) {
+        self.report("`console` statements".to_string(), &console.span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const FUNCTIONS: &str = "
+        @const
+        function square(a: u32) -> u32 { return a * a; }
+        @const
+        function pair(a: u32) -> (u32, [bool; 2]) { return (square(a) + 1u32, [true, a > 2u32]); }
+        circuit Point {
+            x: u8;
+            @const
+            function origin() -> Point { return Point { x: 0u8 }; }
+        }
+    ";
+
+    /// Returns the value returned by `main` after the calls are evaluated.
+    fn evaluate(expression: &str) -> Result<String> {
+        let source = format!("{}\nfunction main(x: u32) {{ return {}; }}", FUNCTIONS, expression);
+        let program = parse(&source);
+        let program = ConstEvaluator::evaluate_program(&program)?;
+        match &program.functions.values().last().unwrap().block.statements[0] {
+            Statement::Return(return_) => Ok(return_.expression.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn evaluates_calls_on_constants() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(evaluate("square(4u32)").unwrap(), "16u32");
+            assert_eq!(evaluate("square(square(3u32))").unwrap(), "81u32");
+            assert_eq!(evaluate("pair(3u32)").unwrap(), "(10u32, [true, true])");
+            assert_eq!(evaluate("Point::origin()").unwrap(), "Point {x: 0u8, }");
+            assert_eq!(evaluate("square(x) + square(2u32)").unwrap(), "square(x) + 4u32");
+            assert!(evaluate("square(65536u32)").is_err());
+        });
+    }
+
+    #[test]
+    fn rejects_non_const_operations() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "@const function f() { console.log(\"f\"); }",
+                "@const function f(a: u8) -> u8 { return g(a); }\nfunction g(a: u8) -> u8 { return a; }",
+                "circuit Foo { x: u8; @const function get(self) -> u8 { return self.x; } }",
+            ] {
+                let program = parse(source);
+                assert!(ConstEvaluator::new(&program).is_err(), "{}", source);
+            }
+        });
+    }
+}
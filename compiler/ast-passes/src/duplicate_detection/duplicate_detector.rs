@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, AsgError, LeoError, Result};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// Finds names defined more than once in the same scope: functions, circuits, global constants,
+/// and aliases of a program, members of a circuit, inputs of a function, and variables of a block.
+///
+/// Each error points at the repeated definition and carries the first definition as a label.
+pub struct DuplicateDetector<'a> {
+    /// The handler all errors but the last are emitted to.
+    handler: &'a Handler,
+    /// The errors found so far.
+    errors: Vec<LeoError>,
+}
+
+impl<'a> DuplicateDetector<'a> {
+    /// Returns a detector emitting its errors to `handler`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Checks every scope of `program`.
+    /// All errors but the last are emitted to the handler, and the last is returned.
+    pub fn check_program(mut self, program: &Program) -> Result<()> {
+        self.check_names(program.functions.keys(), AsgError::duplicate_function_definition);
+        self.check_names(program.circuits.keys(), AsgError::duplicate_circuit_definition);
+        self.check_names(
+            program.global_consts.keys().flatten(),
+            AsgError::duplicate_global_const_definition,
+        );
+        self.check_names(program.aliases.keys(), AsgError::duplicate_alias_definition);
+
+        for function in program.functions.values() {
+            self.check_function(function);
+        }
+        for (name, circuit) in program.circuits.iter() {
+            let members = circuit.members.iter().map(|member| match member {
+                CircuitMember::CircuitConst(identifier, ..) | CircuitMember::CircuitVariable(identifier, _) => {
+                    identifier
+                }
+                CircuitMember::CircuitFunction(function) => &function.identifier,
+            });
+            self.check_names(members, |member, span| {
+                AsgError::redefined_circuit_member(name.name, member, span)
+            });
+            for member in circuit.members.iter() {
+                if let CircuitMember::CircuitFunction(function) = member {
+                    self.check_function(function);
+                }
+            }
+        }
+
+        let last = self.errors.pop();
+        for error in self.errors {
+            self.handler.emit_err(error);
+        }
+        last.map_or(Ok(()), Err)
+    }
+
+    /// Records an error for each of `names` whose name appeared earlier in `names`.
+    fn check_names<'b>(
+        &mut self,
+        names: impl IntoIterator<Item = &'b Identifier>,
+        error: impl Fn(Symbol, &Span) -> AsgError,
+    ) {
+        let mut first_definitions: IndexMap<Symbol, &Span> = IndexMap::new();
+        for identifier in names {
+            match first_definitions.get(&identifier.name) {
+                Some(first) => self.errors.push(
+                    error(identifier.name, &identifier.span)
+                        .with_label(format!("`{}` is first defined here", identifier.name), first)
+                        .into(),
+                ),
+                None => {
+                    first_definitions.insert(identifier.name, &identifier.span);
+                }
+            }
+        }
+    }
+
+    fn check_function(&mut self, function: &Function) {
+        let inputs = function.input.iter().filter_map(FunctionInput::get_variable);
+        self.check_names(
+            inputs.map(|input| &input.identifier),
+            AsgError::duplicate_function_input_definition,
+        );
+        self.check_block(&function.block);
+    }
+
+    fn check_block(&mut self, block: &Block) {
+        let variables = block.statements.iter().flat_map(|statement| match statement {
+            Statement::Definition(definition) => definition.variable_names.as_slice(),
+            _ => &[],
+        });
+        self.check_names(
+            variables.map(|variable| &variable.identifier),
+            AsgError::duplicate_variable_definition,
+        );
+
+        for statement in block.statements.iter() {
+            self.check_statement(statement);
+        }
+    }
+
+    fn check_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Conditional(conditional) => {
+                self.check_block(&conditional.block);
+                if let Some(next) = &conditional.next {
+                    self.check_statement(next);
+                }
+            }
+            Statement::Iteration(iteration) => self.check_block(&iteration.block),
+            Statement::Block(block) => self.check_block(block),
+            Statement::Return(_)
+            | Statement::Definition(_)
+            | Statement::Assign(_)
+            | Statement::Console(_)
+            | Statement::Expression(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn rejects_duplicates() {
+        create_session_if_not_set_then(|_| {
+            let (handler, buf) = Handler::new_with_buf();
+            let program = leo_parser::parse(
+                &handler,
+                "test",
+                "function foo(a: u8, a: u8) { let b = 1u8; if a == 1u8 { let b = 2u8; } let b = 3u8; }
+                 function foo() {}
+                 circuit Bar { x: u8, function x() {} }",
+            )
+            .unwrap();
+
+            let last = DuplicateDetector::new(&handler).check_program(&program).unwrap_err();
+            let mut errors = buf.extract().into_inner();
+            errors.push(last);
+            let messages: Vec<String> = errors
+                .iter()
+                .map(|error| error.backtraced().unwrap().message.clone())
+                .collect();
+            assert_eq!(
+                messages,
+                vec![
+                    "a function named \"foo\" already exists in this scope",
+                    "a function input named \"a\" already exists in this scope",
+                    "a variable named \"b\" already exists in this scope",
+                    "cannot declare circuit member 'x' multiple times in circuit 'Bar'",
+                ]
+            );
+
+            let first_foo = &errors[0].labels()[0];
+            assert_eq!(first_foo.message, "`foo` is first defined here");
+            assert_eq!((first_foo.span.line_start, first_foo.span.col_start), (1, 10));
+            assert!(errors[0].to_string().contains("= note: `foo` is first defined here"));
+        });
+    }
+
+    #[test]
+    fn accepts_distinct_names() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let program = leo_parser::parse(
+                &handler,
+                "test",
+                "function foo(a: u8) { let b = a; if b == 1u8 { let c = 2u8; } } function bar() {}",
+            )
+            .unwrap();
+            assert!(DuplicateDetector::new(&handler).check_program(&program).is_ok());
+        });
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Creates a struct that implements a ReconstructingReducer
+//! such that affine group literals with numeric coordinates, and field literals, are checked.
+//! A coordinate must be an element of the base field, and if both coordinates
+//! are given the point must lie on the twisted Edwards curve over BLS12-377.
+//! A field literal must be an element of the same field.
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+
+use std::cmp::Ordering;
+
+/// An element of the base field as little-endian 64-bit limbs.
+type Limbs = [u64; 4];
+
+/// The modulus of the base field, i.e., the scalar field of BLS12-377.
+const MODULUS: Limbs = [
+    0x0a11800000000001,
+    0x59aa76fed0000001,
+    0x60b44d1e5c37b001,
+    0x12ab655e9a2ca556,
+];
+
+/// The coefficient `d` of the curve `-x^2 + y^2 = 1 + d * x^2 * y^2`.
+const COEFF_D: u64 = 3021;
+
+/// Rejects group tuples whose coordinates are out of range or not on the curve,
+/// and field literals which are out of range.
+/// Coordinates recovered from a sign or inferred are left to be computed later.
+#[derive(Default)]
+pub struct GroupValidator {
+    in_circuit: bool,
+}
+
+impl GroupValidator {
+    /// Returns the field element `coordinate` stands for, if it is a number.
+    fn coordinate(coordinate: &GroupCoordinate) -> Result<Option<Limbs>> {
+        let (number, span) = match coordinate {
+            GroupCoordinate::Number(number, span) => (number, span),
+            _ => return Ok(None),
+        };
+        let (negative, digits) = match number.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, number.as_str()),
+        };
+        let value = parse_decimal(digits)
+            .filter(|value| cmp(value, &MODULUS) == Ordering::Less)
+            .ok_or_else(|| AstError::group_coordinate_out_of_range(number, span))?;
+        Ok(Some(if negative { sub_mod(&[0; 4], &value) } else { value }))
+    }
+
+    /// Returns `true` if `(x, y)` is on the curve.
+    fn is_on_curve(x: &Limbs, y: &Limbs) -> bool {
+        let xx = mul_mod(x, x);
+        let yy = mul_mod(y, y);
+        let lhs = sub_mod(&yy, &xx);
+        let rhs = add_mod(&[1, 0, 0, 0], &mul_mod(&[COEFF_D, 0, 0, 0], &mul_mod(&xx, &yy)));
+        lhs == rhs
+    }
+}
+
+impl ReconstructingReducer for GroupValidator {
+    fn in_circuit(&self) -> bool {
+        self.in_circuit
+    }
+
+    fn swap_in_circuit(&mut self) {
+        self.in_circuit = !self.in_circuit;
+    }
+
+    fn reduce_group_tuple(&mut self, group_tuple: &GroupTuple) -> Result<GroupTuple> {
+        let x = Self::coordinate(&group_tuple.x)?;
+        let y = Self::coordinate(&group_tuple.y)?;
+        if let (Some(x), Some(y)) = (x, y) {
+            if !Self::is_on_curve(&x, &y) {
+                return Err(
+                    AstError::group_point_not_on_curve(&group_tuple.x, &group_tuple.y, &group_tuple.span).into(),
+                );
+            }
+        }
+
+        Ok(group_tuple.clone())
+    }
+
+    fn reduce_value(&mut self, _value: &ValueExpression, new: Expression) -> Result<Expression> {
+        // The magnitude of a field literal must be in range, whether or not it is negative.
+        if let Expression::Value(LiteralExpression {
+            value: ValueExpression::Field(value, span),
+            ..
+        }) = &new
+        {
+            let magnitude = value.strip_prefix('-').unwrap_or(value);
+            if !matches!(parse_decimal(magnitude), Some(limbs) if cmp(&limbs, &MODULUS) == Ordering::Less) {
+                return Err(AstError::field_out_of_range(value, span).into());
+            }
+        }
+        Ok(new)
+    }
+}
+
+/// Parses a decimal number, returning `None` if it does not fit in 256 bits.
+fn parse_decimal(digits: &str) -> Option<Limbs> {
+    let mut value = [0u64; 4];
+    for digit in digits.chars() {
+        let mut carry = digit.to_digit(10)? as u128;
+        for limb in value.iter_mut() {
+            let product = (*limb as u128) * 10 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+    Some(value)
+}
+
+/// Compares two 256-bit numbers.
+fn cmp(a: &Limbs, b: &Limbs) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Returns `a + b`, ignoring overflow out of the top limb.
+fn add(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut sum = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        sum[i] = s;
+        carry = c1 || c2;
+    }
+    sum
+}
+
+/// Returns `a - b`, wrapping below zero.
+fn sub(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut difference = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        difference[i] = d;
+        borrow = b1 || b2;
+    }
+    difference
+}
+
+/// Returns `a + b mod MODULUS` for `a, b < MODULUS`.
+/// The modulus has 253 bits, so the sum cannot overflow.
+fn add_mod(a: &Limbs, b: &Limbs) -> Limbs {
+    let sum = add(a, b);
+    if cmp(&sum, &MODULUS) == Ordering::Less {
+        sum
+    } else {
+        sub(&sum, &MODULUS)
+    }
+}
+
+/// Returns `a - b mod MODULUS` for `a, b < MODULUS`.
+fn sub_mod(a: &Limbs, b: &Limbs) -> Limbs {
+    if cmp(a, b) == Ordering::Less {
+        sub(&add(a, &MODULUS), b)
+    } else {
+        sub(a, b)
+    }
+}
+
+/// Returns `a * b mod MODULUS` for `a, b < MODULUS` by double-and-add.
+fn mul_mod(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut product = [0u64; 4];
+    for bit in (0..256).rev() {
+        product = add_mod(&product, &product);
+        if (b[bit / 64] >> (bit % 64)) & 1 == 1 {
+            product = add_mod(&product, a);
+        }
+    }
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// The affine coordinates of the generator of the group.
+    const GENERATOR: (&str, &str) = (
+        "1540945439182663264862696551825005342995406165131907382295858612069623286213",
+        "8003546896475222703853313610036801932325312921786952001586936882361378122196",
+    );
+
+    fn validate(group: &str) -> Result<()> {
+        let source = format!("function main() {{ return {}; }}", group);
+        let program = parse(&source);
+        GroupValidator::default().do_pass(program).map(|_| ())
+    }
+
+    #[test]
+    fn accepts_points_on_the_curve() {
+        create_session_if_not_set_then(|_| {
+            assert!(validate(&format!("({}, {})group", GENERATOR.0, GENERATOR.1)).is_ok());
+            assert!(validate(&format!("(-{}, {})group", GENERATOR.0, GENERATOR.1)).is_ok());
+            assert!(validate("(0, 1)group").is_ok());
+            assert!(validate("(123, +)group").is_ok());
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_points() {
+        create_session_if_not_set_then(|_| {
+            assert!(validate("(123, 456)group").is_err());
+            assert!(validate(&format!("({}, {})group", GENERATOR.1, GENERATOR.0)).is_err());
+            let modulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+            assert!(validate(&format!("({}, _)group", modulus)).is_err());
+        });
+    }
+
+    #[test]
+    fn rejects_field_literals_out_of_range() {
+        create_session_if_not_set_then(|_| {
+            let max = "8444461749428370424248824938781546531375899335154063827935233455917409239040";
+            assert!(validate(&format!("{}field", max)).is_ok());
+            assert!(validate(&format!("-{}field", max)).is_ok());
+            // The modulus, with separators.
+            let modulus =
+                "8_444_461_749_428_370_424_248_824_938_781_546_531_375_899_335_154_063_827_935_233_455_917_409_239_041";
+            assert!(validate(&format!("{}field", modulus)).is_err());
+            assert!(validate(&format!("1{}field", max)).is_err());
+        });
+    }
+}
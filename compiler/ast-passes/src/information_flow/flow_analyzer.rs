@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::SideEffects;
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// A value returned by a function which depends on some of its private inputs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leak {
+    /// The private inputs the value depends on, in the order of the function's inputs.
+    pub inputs: Vec<Symbol>,
+    /// The span of the returned expression.
+    pub span: Span,
+}
+
+/// Tracks the private inputs each variable of a function depends on, to find the returned values
+/// which reveal them. An input is private unless it is `public` or constant.
+///
+/// A value depends on the variables it is computed from, and on the conditions of the `if` statements,
+/// loops, and ternary expressions it is computed under, as which branch runs reveals them too.
+/// The result of a core function, such as a hash, reveals nothing of its arguments, while that of any
+/// other function depends on all of them. As the analysis does not follow the flow of assignments,
+/// a variable depends on every value assigned to it anywhere in the function, and variables of the
+/// same name in different scopes are treated as one.
+pub struct FlowAnalyzer<'a> {
+    /// The functions of the program, to tell the core functions apart.
+    side_effects: &'a SideEffects,
+    /// The circuit the function is a member of, if any.
+    circuit: Option<Symbol>,
+    /// The private inputs each variable depends on.
+    dependencies: IndexMap<Symbol, IndexSet<Symbol>>,
+    /// The private inputs the conditions of the enclosing branches and loops depend on.
+    context: IndexSet<Symbol>,
+    /// Whether a variable got a new dependency since the body was last analyzed.
+    changed: bool,
+    /// The returned values found to depend on private inputs.
+    leaks: Vec<Leak>,
+}
+
+impl<'a> FlowAnalyzer<'a> {
+    /// Returns the values returned by `function`, a member of `circuit` if any, which depend on its private inputs.
+    pub fn analyze_function(function: &Function, circuit: Option<Symbol>, side_effects: &'a SideEffects) -> Vec<Leak> {
+        let inputs: Vec<Symbol> = function
+            .input
+            .iter()
+            .filter_map(FunctionInput::get_variable)
+            .filter(|input| input.mode != Some(Mode::Public) && !input.is_constant())
+            .map(|input| input.identifier.name)
+            .collect();
+
+        let mut analyzer = Self {
+            side_effects,
+            circuit,
+            dependencies: inputs
+                .iter()
+                .map(|input| (*input, std::iter::once(*input).collect()))
+                .collect(),
+            context: IndexSet::new(),
+            changed: true,
+            leaks: Vec::new(),
+        };
+        // An assignment may add a dependency to a variable read earlier, e.g., in a loop,
+        // so the body is analyzed until no variable gets a new one.
+        while analyzer.changed {
+            analyzer.changed = false;
+            analyzer.leaks.clear();
+            analyzer.visit_block(&function.block);
+        }
+
+        analyzer
+            .leaks
+            .into_iter()
+            .map(|leak| Leak {
+                inputs: inputs
+                    .iter()
+                    .copied()
+                    .filter(|input| leak.inputs.contains(input))
+                    .collect(),
+                ..leak
+            })
+            .collect()
+    }
+
+    /// Returns the private inputs `expression` depends on, in the current context.
+    fn dependencies(&self, expression: &Expression) -> IndexSet<Symbol> {
+        let mut finder = DependencyFinder {
+            analyzer: self,
+            found: self.context.clone(),
+        };
+        finder.visit_expression(expression);
+        finder.found
+    }
+
+    /// Adds `inputs` to the dependencies of the variable `name`.
+    fn depend(&mut self, name: Symbol, inputs: IndexSet<Symbol>) {
+        let dependencies = self.dependencies.entry(name).or_default();
+        let before = dependencies.len();
+        dependencies.extend(inputs);
+        self.changed |= dependencies.len() > before;
+    }
+}
+
+impl ExpressionVisitor for FlowAnalyzer<'_> {}
+
+impl StatementVisitor for FlowAnalyzer<'_> {
+    fn visit_return(&mut self, return_: &ReturnStatement) {
+        let inputs = self.dependencies(&return_.expression);
+        if !inputs.is_empty() {
+            self.leaks.push(Leak {
+                inputs: inputs.into_iter().collect(),
+                span: return_.expression.span().clone(),
+            });
+        }
+    }
+
+    fn visit_definition(&mut self, definition: &DefinitionStatement) {
+        let inputs = self.dependencies(&definition.value);
+        for variable in definition.variable_names.iter() {
+            self.depend(variable.identifier.name, inputs.clone());
+        }
+    }
+
+    fn visit_assign(&mut self, assign: &AssignStatement) {
+        let mut inputs = self.dependencies(&assign.value);
+        for access in assign.assignee.accesses.iter() {
+            match access {
+                AssigneeAccess::ArrayIndex(index) => inputs.extend(self.dependencies(index)),
+                AssigneeAccess::ArrayRange(left, right) => {
+                    for bound in left.iter().chain(right.iter()) {
+                        inputs.extend(self.dependencies(bound));
+                    }
+                }
+                AssigneeAccess::Tuple(..) | AssigneeAccess::Member(_) => {}
+            }
+        }
+        self.depend(assign.assignee.identifier.name, inputs);
+    }
+
+    fn visit_conditional(&mut self, conditional: &ConditionalStatement) {
+        let outer = self.context.clone();
+        self.context = self.dependencies(&conditional.condition);
+        self.visit_block(&conditional.block);
+        if let Some(next) = &conditional.next {
+            self.visit_statement(next);
+        }
+        self.context = outer;
+    }
+
+    fn visit_iteration(&mut self, iteration: &IterationStatement) {
+        let outer = self.context.clone();
+        let mut inputs = self.dependencies(&iteration.start);
+        inputs.extend(self.dependencies(&iteration.stop));
+        self.depend(iteration.variable.name, inputs.clone());
+        self.context = inputs;
+        self.visit_block(&iteration.block);
+        self.context = outer;
+    }
+
+    fn visit_console(&mut self, _console: &ConsoleStatement) {}
+
+    fn visit_expression_statement(&mut self, _expression: &ExpressionStatement) {}
+}
+
+/// Collects the private inputs the expressions it visits depend on.
+struct DependencyFinder<'a> {
+    analyzer: &'a FlowAnalyzer<'a>,
+    /// The private inputs found so far.
+    found: IndexSet<Symbol>,
+}
+
+impl ExpressionVisitor for DependencyFinder<'_> {
+    fn visit_identifier(&mut self, identifier: &Identifier) {
+        if let Some(inputs) = self.analyzer.dependencies.get(&identifier.name) {
+            self.found.extend(inputs.iter().copied());
+        }
+    }
+
+    fn visit_call(&mut self, call: &CallExpression) {
+        let side_effects = self.analyzer.side_effects;
+        let core = side_effects
+            .resolve(&call.function, self.analyzer.circuit)
+            .map_or(false, |callee| side_effects.is_core(&callee));
+        if !core {
+            self.visit_expression(&call.function);
+            call.arguments
+                .iter()
+                .for_each(|argument| self.visit_expression(argument));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn finds_leaks() {
+        create_session_if_not_set_then(|_| {
+            let mut program = parse(
+                "function main(a: u8, public b: u8, constant c: u8, private d: [u8; 32], e: bool) -> u8 {
+                     let x = a + b;
+                     let y = 0u8;
+                     for i in 0..4 { y = z; }
+                     let z = c;
+                     if e { return c; }
+                     let h = Blake2s::hash(d, d);
+                     if h[0] == c { return h[1]; }
+                     for i in 0..4 { z = x; }
+                     return y;
+                 }
+                 circuit Blake2s {
+                     @CoreFunction
+                     function hash(seed: [u8; 32], message: [u8; 32]) -> [u8; 32] { return seed; }
+                 }",
+            );
+            program.handle_internal_annotations();
+            let side_effects = SideEffects::new(&program);
+            let main = program.functions.values().next().unwrap();
+
+            let leaks: Vec<(Vec<String>, usize)> = FlowAnalyzer::analyze_function(main, None, &side_effects)
+                .into_iter()
+                .map(|leak| {
+                    let inputs = leak.inputs.iter().map(|input| input.to_string()).collect();
+                    (inputs, leak.span.line_start)
+                })
+                .collect();
+            // `y` gets `a` from `z` through both loops, and `return c` is only reached if `e` is true.
+            assert_eq!(leaks, vec![(vec!["e".to_string()], 6), (vec!["a".to_string()], 10)]);
+        });
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Creates a struct that implements a ReconstructingReducer
+//! such that constants associated with primitive types become literals.
+//! For example, `u8::MAX` becomes `255u8`.
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+use leo_span::{sym, Symbol};
+
+/// The largest element of the BLS12-377 scalar field, i.e., its modulus minus one.
+const FIELD_MAX: &str = "8444461749428370424248824938781546531375899335154063827935233455917409239040";
+
+/// Replaces `T::MIN` and `T::MAX` for every integer type `T`, and `field::MAX`, with literals.
+/// Any other constant accessed on these types is an error.
+#[derive(Default)]
+pub struct IntrinsicResolver {
+    in_circuit: bool,
+}
+
+impl IntrinsicResolver {
+    /// Returns the value of the constant `name` of the integer type `type_`.
+    fn integer_constant(type_: IntegerType, name: Symbol) -> Option<String> {
+        let bits = type_.bits();
+        Some(match (name.as_str().as_ref(), type_.is_signed()) {
+            ("MIN", false) => "0".to_string(),
+            ("MAX", false) => (u128::MAX >> (128 - bits)).to_string(),
+            ("MIN", true) => (i128::MIN >> (128 - bits)).to_string(),
+            ("MAX", true) => (i128::MAX >> (128 - bits)).to_string(),
+            _ => return None,
+        })
+    }
+}
+
+impl ReconstructingReducer for IntrinsicResolver {
+    fn in_circuit(&self) -> bool {
+        self.in_circuit
+    }
+
+    fn swap_in_circuit(&mut self) {
+        self.in_circuit = !self.in_circuit;
+    }
+
+    fn reduce_expression(&mut self, _expression: &Expression, new: Expression) -> Result<Expression> {
+        let access = match &new {
+            Expression::Access(AccessExpression::Static(access)) => access,
+            _ => return Ok(new),
+        };
+        let type_ = match &*access.inner {
+            Expression::Identifier(type_) => type_.name,
+            _ => return Ok(new),
+        };
+        let span = access.span.clone();
+
+        let (value, constants): (_, &[&str]) = if let Some(integer_type) = IntegerType::from_symbol(type_) {
+            let value = Self::integer_constant(integer_type, access.name.name)
+                .map(|value| ValueExpression::Integer(integer_type, value, span.clone()));
+            (value, &["MIN", "MAX"])
+        } else if type_ == sym::field {
+            let value = (access.name.name.as_str().as_ref() == "MAX")
+                .then(|| ValueExpression::Field(FIELD_MAX.to_string(), span.clone()));
+            (value, &["MAX"])
+        } else {
+            return Ok(new);
+        };
+
+        match value {
+            Some(value) => Ok(Expression::Value(value.into())),
+            None => Err(AstError::unknown_intrinsic_constant(type_, access.name.name, &span)
+                .with_similar_name(&access.name.name.as_str(), constants.iter().copied(), &access.name.span)
+                .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the value returned by `main` after resolving intrinsics.
+    fn resolve(expression: &str) -> Result<String> {
+        let source = format!("function main() {{ return {}; }}", expression);
+        let program = parse(&source);
+        let program = IntrinsicResolver::default().do_pass(program)?.into_repr();
+        match &program.functions.values().next().unwrap().block.statements[0] {
+            Statement::Return(return_) => Ok(return_.expression.to_string()),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn resolves_integer_bounds() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(resolve("u8::MAX").unwrap(), "255u8");
+            assert_eq!(resolve("u128::MIN").unwrap(), "0u128");
+            assert_eq!(resolve("i8::MIN").unwrap(), "-128i8");
+            assert_eq!(resolve("i64::MAX").unwrap(), "9223372036854775807i64");
+            assert_eq!(resolve("x < u16::MAX").unwrap(), "x < 65535u16");
+            assert_eq!(resolve("field::MAX").unwrap(), FIELD_MAX);
+        });
+    }
+
+    #[test]
+    fn rejects_unknown_constants() {
+        create_session_if_not_set_then(|_| {
+            assert!(resolve("u8::BITS").is_err());
+            assert!(resolve("field::MIN").is_err());
+            assert!(resolve("field::MODULUS").is_err());
+
+            let error = resolve("u32::MAXX").unwrap_err();
+            assert_eq!(error.suggestion().unwrap().replacement, "MAX");
+            assert!(resolve("i8::BITS").unwrap_err().suggestion().is_none());
+        });
+    }
+}
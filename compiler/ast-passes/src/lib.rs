@@ -16,9 +16,60 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod bounded_vec_lowering;
+pub use bounded_vec_lowering::*;
+
+pub mod call_graph;
+pub use call_graph::*;
+
 pub mod canonicalization;
 pub use canonicalization::*;
 
+pub mod cfg_pruning;
+pub use cfg_pruning::*;
+
+pub mod const_evaluation;
+pub use const_evaluation::*;
+
+pub mod duplicate_detection;
+pub use duplicate_detection::*;
+
+pub mod group_validation;
+pub use group_validation::*;
+
+pub mod information_flow;
+pub use information_flow::*;
+
+pub mod intrinsic_resolution;
+pub use intrinsic_resolution::*;
+
+pub mod linting;
+pub use linting::*;
+
+pub mod pass_manager;
+pub use pass_manager::*;
+
+pub mod program_validation;
+pub use program_validation::*;
+
+pub mod purity;
+pub use purity::*;
+
+pub mod range_analysis;
+pub use range_analysis::*;
+
+pub mod strength_reduction;
+pub use strength_reduction::*;
+
+pub mod type_inference;
+pub use type_inference::*;
+
+pub mod visibility_check;
+pub use visibility_check::*;
+
+#[cfg(test)]
+mod test;
+
 // Temporarily disable import resolution
 // until we migrate stdlib and then import resolution.
 /* pub mod import_resolution;
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, LeoWarning, LintName};
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// How a variable was brought into scope.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingKind {
+    /// An input of the function.
+    Input,
+    /// A `let` or `const` definition, or the variable of a loop.
+    Variable,
+}
+
+/// A variable in scope.
+#[derive(Clone, Debug)]
+pub struct Binding {
+    /// The name of the variable.
+    pub name: Symbol,
+    /// The span of the name in the declaration.
+    pub span: Span,
+    /// How the variable was brought into scope.
+    pub kind: BindingKind,
+    /// Whether the variable has been read.
+    pub used: bool,
+    /// The source deleted to remove the variable, if it is the only variable of a definition whose
+    /// value cannot fail and it is never assigned to, so the program does the same without it.
+    pub removal: Option<Span>,
+}
+
+/// The state of the linter shared with each lint: the variables in scope,
+/// and where warnings go.
+pub struct LintContext<'a> {
+    /// The handler the warnings are emitted to.
+    pub(crate) handler: &'a Handler,
+    /// The lints allowed in the current function.
+    pub(crate) allowed: Vec<LintName>,
+    /// The circuit the current function is a member of, if any.
+    pub(crate) circuit: Option<Symbol>,
+    /// The variables in scope, innermost scope last.
+    pub(crate) scopes: Vec<IndexMap<Symbol, Binding>>,
+}
+
+impl<'a> LintContext<'a> {
+    /// Returns a context emitting its warnings to `handler`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            handler,
+            allowed: Vec::new(),
+            circuit: None,
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Emits `warning`, unless its lint is allowed in the current function.
+    pub fn warn(&self, warning: LeoWarning) {
+        if !self.allowed.contains(&warning.lint) {
+            self.handler.emit_warning(warning);
+        }
+    }
+
+    /// Returns the circuit the current function is a member of, if any.
+    pub fn circuit(&self) -> Option<Symbol> {
+        self.circuit
+    }
+
+    /// Returns the innermost variable in scope called `name`, if any.
+    pub fn lookup(&self, name: Symbol) -> Option<&Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(&name))
+    }
+}
+
+/// Returns the source deleted to remove the statement at `span`: the statement with its `;`,
+/// or its whole lines if there is nothing else on them, so no blank line is left behind.
+/// Returns `None` if the statement is not followed by a `;`.
+pub fn statement_removal(span: &Span) -> Option<Span> {
+    let first_line = span.content.lines().next()?;
+    let last_line = span.content.lines().last()?;
+    let before = first_line.get(..span.col_start.checked_sub(1)?)?;
+    let after = last_line.get(span.col_stop.checked_sub(1)?..)?;
+    let rest = after.trim_start().strip_prefix(';')?;
+
+    let removal = if before.trim().is_empty() && rest.trim().is_empty() {
+        Span::new(span.line_start, span.line_stop + 1, 1, 1, span.path.clone(), "")
+    } else {
+        // Also remove the spaces before the next statement on the line.
+        let col_stop = span.col_stop + after.len() - rest.trim_start().len();
+        Span::new(
+            span.line_start,
+            span.line_stop,
+            span.col_start,
+            col_stop,
+            span.path.clone(),
+            "",
+        )
+    };
+    Some(removal)
+}
+
+/// A check run on every function of a program, reporting warnings through the `LintContext`.
+///
+/// The linter walks each function and calls the hooks of every lint on the nodes it visits,
+/// keeping track of the variables in scope and whether they are read.
+/// All hooks do nothing by default, so a lint only implements those it needs.
+pub trait Lint {
+    /// Returns the name under which the warnings of the lint are reported and allowed.
+    fn name(&self) -> LintName;
+
+    /// Called on the program, before its functions.
+    fn check_program(&mut self, _cx: &LintContext, _program: &Program) {}
+
+    /// Called on each function, before its body.
+    fn check_function(&mut self, _cx: &LintContext, _function: &Function) {}
+
+    /// Called on each block, before its statements.
+    fn check_block(&mut self, _cx: &LintContext, _block: &Block) {}
+
+    /// Called on each statement, before its children.
+    fn check_statement(&mut self, _cx: &LintContext, _statement: &Statement) {}
+
+    /// Called on each expression, before its children.
+    fn check_expression(&mut self, _cx: &LintContext, _expression: &Expression) {}
+
+    /// Called on each declared variable, before it is brought into scope.
+    fn check_declaration(&mut self, _cx: &LintContext, _identifier: &Identifier, _kind: BindingKind) {}
+
+    /// Called on each variable when it goes out of scope.
+    fn check_binding(&mut self, _cx: &LintContext, _binding: &Binding) {}
+}
@@ -0,0 +1,541 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    statement_removal, ArithmeticOverflow, Binding, BindingKind, ConstantConditions, DivisionBeforeMultiplication,
+    Lint, LintContext, PrivateDataLeaks, SelfAssignment, Shadowing, UncheckedCallResults, UnreachableCode,
+    UnusedParameters, UnusedResults, UnusedVariables,
+};
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, AstError, LintName, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// Runs lints on every function of a program, emitting a warning for each finding.
+///
+/// A lint is skipped in a function annotated with `@allow(lint)`.
+/// The built-in lints run by default, and other lints are added with `with_lint`.
+pub struct Linter<'a> {
+    /// The state shared with the lints.
+    cx: LintContext<'a>,
+    /// The lints to run.
+    lints: Vec<Box<dyn Lint>>,
+}
+
+impl<'a> Linter<'a> {
+    /// Returns a linter running the built-in lints, emitting its warnings to `handler`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self {
+            cx: LintContext::new(handler),
+            lints: vec![
+                Box::new(UnusedVariables),
+                Box::new(UnusedParameters),
+                Box::new(UnreachableCode),
+                Box::new(Shadowing),
+                Box::new(ConstantConditions),
+                Box::new(SelfAssignment),
+                Box::new(ArithmeticOverflow),
+                Box::new(UnusedResults::default()),
+                Box::new(DivisionBeforeMultiplication::default()),
+                Box::new(UncheckedCallResults::default()),
+                Box::new(PrivateDataLeaks::default()),
+            ],
+        }
+    }
+
+    /// Adds `lint` to the lints to run.
+    pub fn with_lint(mut self, lint: impl Lint + 'static) -> Self {
+        self.lints.push(Box::new(lint));
+        self
+    }
+
+    /// Lints every function in `program`.
+    /// Returns an error if any warning was reported as an error, as warnings are denied.
+    pub fn check_program(mut self, program: &Program) -> Result<()> {
+        let handler = self.cx.handler;
+        let errors_before = handler.err_count();
+        self.each_lint(|lint, cx| lint.check_program(cx, program));
+
+        for function in program.functions.values() {
+            self.check_function(function);
+        }
+        for (name, circuit) in program.circuits.iter() {
+            self.cx.circuit = Some(name.name);
+            for member in circuit.members.iter() {
+                if let CircuitMember::CircuitFunction(function) = member {
+                    self.check_function(function);
+                }
+            }
+        }
+        self.cx.circuit = None;
+
+        match handler.err_count() - errors_before {
+            0 => Ok(()),
+            count => Err(AstError::denied_warnings(count).into()),
+        }
+    }
+
+    /// Calls `hook` on every lint.
+    fn each_lint(&mut self, mut hook: impl FnMut(&mut dyn Lint, &LintContext)) {
+        for lint in self.lints.iter_mut() {
+            hook(lint.as_mut(), &self.cx);
+        }
+    }
+
+    fn check_function(&mut self, function: &Function) {
+        self.cx.allowed = LintName::ALL
+            .iter()
+            .copied()
+            .filter(|lint| function.allows(*lint))
+            .collect();
+        self.each_lint(|lint, cx| lint.check_function(cx, function));
+
+        self.cx.scopes.push(IndexMap::new());
+        for input in function.input.iter().filter_map(FunctionInput::get_variable) {
+            self.declare(&input.identifier, BindingKind::Input, None);
+        }
+        self.check_block(&function.block);
+        self.pop_scope();
+    }
+
+    /// Brings `identifier` into the innermost scope, removed by removing `removal`, if any.
+    fn declare(&mut self, identifier: &Identifier, kind: BindingKind, removal: Option<Span>) {
+        self.each_lint(|lint, cx| lint.check_declaration(cx, identifier, kind));
+        let binding = Binding {
+            name: identifier.name,
+            span: identifier.span.clone(),
+            kind,
+            used: false,
+            removal,
+        };
+        self.cx.scopes.last_mut().unwrap().insert(identifier.name, binding);
+    }
+
+    /// Marks the innermost variable called `name` as read.
+    fn use_variable(&mut self, name: Symbol) {
+        if let Some(binding) = self.cx.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name)) {
+            binding.used = true;
+        }
+    }
+
+    /// Marks the innermost variable called `name` as one which cannot be removed.
+    fn keep_variable(&mut self, name: Symbol) {
+        if let Some(binding) = self.cx.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(&name)) {
+            binding.removal = None;
+        }
+    }
+
+    /// Leaves the innermost scope, checking each of its variables.
+    fn pop_scope(&mut self) {
+        for (name, binding) in self.cx.scopes.pop().unwrap_or_default() {
+            if name != sym::SelfLower {
+                self.each_lint(|lint, cx| lint.check_binding(cx, &binding));
+            }
+        }
+    }
+
+    fn check_block(&mut self, block: &Block) {
+        self.each_lint(|lint, cx| lint.check_block(cx, block));
+        self.cx.scopes.push(IndexMap::new());
+        for statement in block.statements.iter() {
+            self.check_statement(statement);
+        }
+        self.pop_scope();
+    }
+
+    fn check_statement(&mut self, statement: &Statement) {
+        self.each_lint(|lint, cx| lint.check_statement(cx, statement));
+        match statement {
+            Statement::Return(return_) => self.check_expression(&return_.expression),
+            Statement::Definition(definition) => {
+                self.check_expression(&definition.value);
+                let removal = match definition.variable_names.as_slice() {
+                    [_] if cannot_fail(&definition.value) => statement_removal(&definition.span),
+                    _ => None,
+                };
+                for variable in definition.variable_names.iter() {
+                    self.declare(&variable.identifier, BindingKind::Variable, removal.clone());
+                }
+            }
+            Statement::Assign(assign) => {
+                // Only compound assignments such as `x += 1` read the assignee.
+                if assign.operation != AssignOperation::Assign {
+                    self.use_variable(assign.assignee.identifier.name);
+                }
+                // The assignment would be left without a variable.
+                self.keep_variable(assign.assignee.identifier.name);
+                for access in assign.assignee.accesses.iter() {
+                    match access {
+                        AssigneeAccess::ArrayIndex(index) => self.check_expression(index),
+                        AssigneeAccess::ArrayRange(left, right) => {
+                            left.iter().chain(right.iter()).for_each(|e| self.check_expression(e))
+                        }
+                        AssigneeAccess::Tuple(..) | AssigneeAccess::Member(_) => {}
+                    }
+                }
+                self.check_expression(&assign.value);
+            }
+            Statement::Conditional(conditional) => {
+                self.check_expression(&conditional.condition);
+                self.check_block(&conditional.block);
+                if let Some(next) = &conditional.next {
+                    self.check_statement(next);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                self.check_expression(&iteration.start);
+                self.check_expression(&iteration.stop);
+                self.cx.scopes.push(IndexMap::new());
+                self.declare(&iteration.variable, BindingKind::Variable, None);
+                self.check_block(&iteration.block);
+                self.pop_scope();
+            }
+            Statement::Console(console) => match &console.function {
+                ConsoleFunction::Assert(expression) => self.check_expression(expression),
+                ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => {
+                    args.parameters.iter().for_each(|e| self.check_expression(e))
+                }
+            },
+            Statement::Expression(expression) => self.check_expression(&expression.expression),
+            Statement::Block(block) => self.check_block(block),
+        }
+    }
+
+    fn check_expression(&mut self, expression: &Expression) {
+        self.each_lint(|lint, cx| lint.check_expression(cx, expression));
+        match expression {
+            Expression::Identifier(identifier) => self.use_variable(identifier.name),
+            Expression::Value(_) | Expression::Err(_) => {}
+            Expression::Binary(binary) => {
+                self.check_expression(&binary.left);
+                self.check_expression(&binary.right);
+            }
+            Expression::Unary(unary) => self.check_expression(&unary.inner),
+            Expression::Ternary(ternary) => {
+                self.check_expression(&ternary.condition);
+                self.check_expression(&ternary.if_true);
+                self.check_expression(&ternary.if_false);
+            }
+            Expression::Cast(cast) => self.check_expression(&cast.inner),
+            Expression::Access(access) => match access {
+                AccessExpression::Array(access) => {
+                    self.check_expression(&access.array);
+                    self.check_expression(&access.index);
+                }
+                AccessExpression::ArrayRange(access) => {
+                    self.check_expression(&access.array);
+                    access
+                        .left
+                        .iter()
+                        .chain(access.right.iter())
+                        .for_each(|e| self.check_expression(e));
+                }
+                AccessExpression::Member(access) => self.check_expression(&access.inner),
+                AccessExpression::Tuple(access) => self.check_expression(&access.tuple),
+                AccessExpression::Static(access) => self.check_expression(&access.inner),
+            },
+            Expression::ArrayInline(array) => array.elements.iter().for_each(|element| match element {
+                SpreadOrExpression::Spread(e) | SpreadOrExpression::Expression(e) => self.check_expression(e),
+            }),
+            Expression::ArrayInit(array) => self.check_expression(&array.element),
+            Expression::TupleInit(tuple) => tuple.elements.iter().for_each(|e| self.check_expression(e)),
+            Expression::CircuitInit(init) => {
+                for member in init.members.iter() {
+                    match &member.expression {
+                        Some(expression) => self.check_expression(expression),
+                        // The shorthand `Foo { x }` reads the variable `x`.
+                        None => self.use_variable(member.identifier.name),
+                    }
+                }
+            }
+            Expression::Call(call) => {
+                self.check_expression(&call.function);
+                call.arguments.iter().for_each(|e| self.check_expression(e));
+            }
+        }
+    }
+}
+
+/// Returns whether evaluating `expression` can neither fail nor call a function,
+/// so a definition of a variable to it can be removed without changing what the program does.
+pub fn cannot_fail(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(_) | Expression::Value(_) => true,
+        Expression::Binary(binary) => {
+            matches!(
+                binary.op,
+                BinaryOperation::Or
+                    | BinaryOperation::And
+                    | BinaryOperation::Eq
+                    | BinaryOperation::Ne
+                    | BinaryOperation::Ge
+                    | BinaryOperation::Gt
+                    | BinaryOperation::Le
+                    | BinaryOperation::Lt
+            ) && cannot_fail(&binary.left)
+                && cannot_fail(&binary.right)
+        }
+        Expression::Unary(unary) => unary.op == UnaryOperation::Not && cannot_fail(&unary.inner),
+        Expression::Ternary(ternary) => {
+            cannot_fail(&ternary.condition) && cannot_fail(&ternary.if_true) && cannot_fail(&ternary.if_false)
+        }
+        Expression::Access(AccessExpression::Member(access)) => cannot_fail(&access.inner),
+        Expression::Access(AccessExpression::Tuple(access)) => cannot_fail(&access.tuple),
+        Expression::Access(AccessExpression::Static(access)) => cannot_fail(&access.inner),
+        Expression::ArrayInline(array) => array.elements.iter().all(|element| match element {
+            SpreadOrExpression::Spread(e) | SpreadOrExpression::Expression(e) => cannot_fail(e),
+        }),
+        Expression::ArrayInit(array) => cannot_fail(&array.element),
+        Expression::TupleInit(tuple) => tuple.elements.iter().all(cannot_fail),
+        Expression::CircuitInit(init) => init
+            .members
+            .iter()
+            .all(|member| member.expression.iter().all(cannot_fail)),
+        // Indices may be out of bounds, and casts and calls may fail.
+        Expression::Access(_) | Expression::Cast(_) | Expression::Call(_) | Expression::Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::{LeoWarning, Severity};
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Lints `source` with the built-in lints and those added by `add_lints`.
+    fn lint_with(
+        add_lints: impl FnOnce(Linter) -> Linter,
+        source: &str,
+        deny_warnings: bool,
+    ) -> (Result<()>, Vec<String>) {
+        let (handler, buf) = Handler::new_with_buf();
+        let handler = handler.with_deny_warnings(deny_warnings);
+        let program = leo_parser::parse(&handler, "test", source).unwrap();
+        let result = add_lints(Linter::new(&handler)).check_program(&program);
+        let mut messages: Vec<String> = buf
+            .extract_warnings()
+            .into_inner()
+            .into_iter()
+            .map(|warning| format!("{}: {}", warning.lint, warning.message))
+            .collect();
+        messages.extend(buf.extract().into_inner().into_iter().map(|error| error.to_string()));
+        (result, messages)
+    }
+
+    fn lint(source: &str, deny_warnings: bool) -> (Result<()>, Vec<String>) {
+        lint_with(|linter| linter, source, deny_warnings)
+    }
+
+    #[test]
+    fn reports_lints() {
+        create_session_if_not_set_then(|_| {
+            let (result, warnings) = lint(
+                "function main(a: u8, b: u8) -> u8 {
+                     let x = 1u8;
+                     let y = a;
+                     for i in 0..2 { let y = i; y += 1; }
+                     if 1u8 == 2u8 { y = y; }
+                     let z = true ? y : 0u8;
+                     return z;
+                     console.log(\"unreachable\");
+                 }
+                 @allow(unused_parameters)
+                 function quiet(c: u8) {}",
+                false,
+            );
+            assert!(result.is_ok());
+            assert_eq!(
+                warnings,
+                vec![
+                    "unreachable_code: unreachable statement",
+                    "shadowing: `y` shadows a variable of the same name",
+                    "constant_conditions: this condition is constant",
+                    "self_assignment: `y` is assigned to itself",
+                    "constant_conditions: this condition is constant",
+                    "unused_variables: unused variable `x`",
+                    "unused_parameters: unused function input `b`",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn reports_overflows() {
+        create_session_if_not_set_then(|_| {
+            let (_, warnings) = lint(
+                "function main(a: u8) -> u8 { return a / 4u8 - 64u8; }
+                 @allow(arithmetic_overflow)
+                 function quiet() -> u8 { return 255u8 + 1u8; }",
+                false,
+            );
+            assert_eq!(
+                warnings,
+                vec!["arithmetic_overflow: `a / 4u8 - 64u8` always overflows `u8`"]
+            );
+        });
+    }
+
+    #[test]
+    fn reports_unused_results() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main(a: u8, b: u8) {
+    a + b;
+    a == b;
+    add(a, b);
+    log(a);
+    console.log(\"{}\", add(a, b));
+}
+function add(a: u8, b: u8) -> u8 { return a + b; }
+function log(a: u8) -> u8 { console.log(\"{}\", a); return a; }
+";
+            let (handler, buf) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "test", source).unwrap();
+            Linter::new(&handler).check_program(&program).unwrap();
+            let warnings = buf.extract_warnings().into_inner();
+            let messages: Vec<String> = warnings.iter().map(|warning| warning.message.clone()).collect();
+            assert_eq!(messages, vec!["unused result of an expression without side effects"; 3]);
+
+            // Only `a == b` cannot fail, so only it is removed.
+            let (fixed, applied) = leo_errors::apply_suggestions(
+                source,
+                warnings.iter().filter_map(|warning| warning.suggestion.as_deref()),
+            );
+            assert_eq!(applied, 1);
+            assert!(!fixed.contains("a == b"));
+            assert!(fixed.contains("a + b;"));
+        });
+    }
+
+    #[test]
+    fn reports_security_lints() {
+        create_session_if_not_set_then(|_| {
+            let source = "import token.*;
+function main(a: u32, b: u32, c: u32, x: field) -> u32 {
+    transfer(a, b);
+    log(a);
+    console.assert(transfer(a, b));
+    Token::mint(a);
+    console.assert(x / 2field * x == x);
+    return a / b * c + a * c / b;
+}
+";
+            let imported = "function transfer(a: u32, b: u32) -> bool { return a > b; }
+function log(a: u32) { console.log(\"{}\", a); }
+circuit Token { function mint(a: u32) -> u32 { return a; } }
+";
+            let (handler, buf) = Handler::new_with_buf();
+            let mut program = leo_parser::parse(&handler, "test", source).unwrap();
+            let imported = leo_parser::parse(&handler, "token", imported).unwrap();
+            program.imports.insert(vec![Symbol::intern("token")], imported);
+            Linter::new(&handler).check_program(&program).unwrap();
+
+            let warnings = buf.extract_warnings().into_inner();
+            let lints: Vec<(LintName, Option<Severity>, usize)> = warnings
+                .iter()
+                .map(|warning| (warning.lint, warning.lint.severity(), warning.span.line_start))
+                .collect();
+            assert_eq!(
+                lints,
+                vec![
+                    (LintName::UncheckedCallResults, Some(Severity::High), 3),
+                    (LintName::UncheckedCallResults, Some(Severity::High), 6),
+                    (LintName::DivisionBeforeMultiplication, Some(Severity::Medium), 8),
+                ]
+            );
+            assert!(warnings[0]
+                .to_string()
+                .contains("[unchecked_call_results, high severity]"));
+        });
+    }
+
+    #[test]
+    fn denies_warnings() {
+        create_session_if_not_set_then(|_| {
+            let (result, errors) = lint("function main() { let x = 1u8; }", true);
+            assert!(result.is_err());
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0].contains("unused variable `x`"));
+        });
+    }
+
+    #[test]
+    fn suggests_fixes() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main(a: u8) -> u8 {
+    let x = 1u8;
+    let y = a + 1u8;
+    let z = a;
+    z = 2u8;
+    let w = a; let v = a;
+    v = v;
+    return v;
+}
+";
+            let (handler, buf) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "test", source).unwrap();
+            Linter::new(&handler).check_program(&program).unwrap();
+            let warnings = buf.extract_warnings().into_inner();
+            assert_eq!(warnings.len(), 5);
+
+            // `y` may overflow and `z` is assigned, so neither is removed.
+            let (fixed, applied) = leo_errors::apply_suggestions(
+                source,
+                warnings.iter().filter_map(|warning| warning.suggestion.as_deref()),
+            );
+            assert_eq!(applied, 3);
+            assert_eq!(
+                fixed,
+                "function main(a: u8) -> u8 {
+    let y = a + 1u8;
+    let z = a;
+    z = 2u8;
+    let v = a;
+    return v;
+}
+"
+            );
+        });
+    }
+
+    /// A lint flagging every call, to check that lints can be added.
+    struct NoCalls;
+
+    impl Lint for NoCalls {
+        fn name(&self) -> LintName {
+            LintName::UnreachableCode
+        }
+
+        fn check_expression(&mut self, cx: &LintContext, expression: &Expression) {
+            if let Expression::Call(call) = expression {
+                cx.warn(LeoWarning::new(self.name(), "call", None, &call.span));
+            }
+        }
+    }
+
+    #[test]
+    fn runs_added_lints() {
+        create_session_if_not_set_then(|_| {
+            let (_, warnings) = lint_with(
+                |linter| linter.with_lint(NoCalls),
+                "function main() -> u8 { return foo(); } function foo() -> u8 { return 1u8; }",
+                false,
+            );
+            assert_eq!(warnings, vec!["unreachable_code: call"]);
+        });
+    }
+}
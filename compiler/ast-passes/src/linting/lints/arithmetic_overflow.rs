@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext, RangeAnalyzer};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about unsigned integer operations and casts which overflow whatever the values of
+/// their operands, as proven by the [`RangeAnalyzer`].
+#[derive(Default)]
+pub struct ArithmeticOverflow;
+
+impl Lint for ArithmeticOverflow {
+    fn name(&self) -> LintName {
+        LintName::ArithmeticOverflow
+    }
+
+    fn check_function(&mut self, cx: &LintContext, function: &Function) {
+        for overflow in RangeAnalyzer::analyze_function(function).overflows {
+            cx.warn(LeoWarning::arithmetic_overflow(
+                overflow.expression,
+                overflow.type_,
+                &overflow.span,
+            ));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about `if` statements and ternary expressions whose condition only depends on literals.
+#[derive(Default)]
+pub struct ConstantConditions;
+
+impl ConstantConditions {
+    /// Returns `true` if `expression` is built from literals and operators alone.
+    fn is_constant(expression: &Expression) -> bool {
+        match expression {
+            Expression::Value(_) => true,
+            Expression::Binary(binary) => Self::is_constant(&binary.left) && Self::is_constant(&binary.right),
+            Expression::Unary(unary) => Self::is_constant(&unary.inner),
+            Expression::Cast(cast) => Self::is_constant(&cast.inner),
+            _ => false,
+        }
+    }
+
+    fn check_condition(cx: &LintContext, condition: &Expression) {
+        if Self::is_constant(condition) {
+            cx.warn(LeoWarning::constant_condition(condition.span()));
+        }
+    }
+}
+
+impl Lint for ConstantConditions {
+    fn name(&self) -> LintName {
+        LintName::ConstantConditions
+    }
+
+    fn check_statement(&mut self, cx: &LintContext, statement: &Statement) {
+        if let Statement::Conditional(conditional) = statement {
+            Self::check_condition(cx, &conditional.condition);
+        }
+    }
+
+    fn check_expression(&mut self, cx: &LintContext, expression: &Expression) {
+        if let Expression::Ternary(ternary) = expression {
+            Self::check_condition(cx, &ternary.condition);
+        }
+    }
+}
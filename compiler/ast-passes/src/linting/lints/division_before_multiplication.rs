@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Warns about multiplications of the result of a division, as in `a / b * c`.
+///
+/// Integer division rounds down, so multiplying its result scales the error up, while
+/// `a * c / b` only rounds once. Field division is exact, so a product whose operands are known
+/// to be fields, as field literals or variables declared as fields, is left alone.
+#[derive(Default)]
+pub struct DivisionBeforeMultiplication {
+    /// The variables of the current function declared as fields.
+    fields: IndexSet<Symbol>,
+}
+
+impl DivisionBeforeMultiplication {
+    /// Returns `true` if `expression` is known to be a field.
+    fn is_field(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Value(LiteralExpression {
+                value: ValueExpression::Field(..),
+                ..
+            }) => true,
+            Expression::Identifier(identifier) => self.fields.contains(&identifier.name),
+            Expression::Binary(binary) => self.is_field(&binary.left) || self.is_field(&binary.right),
+            Expression::Unary(unary) => self.is_field(&unary.inner),
+            Expression::Cast(cast) => cast.target_type == Type::Field,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `expression` is a division which may round.
+    fn is_integer_division(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Binary(binary) => binary.op == BinaryOperation::Div && !self.is_field(expression),
+            _ => false,
+        }
+    }
+}
+
+impl Lint for DivisionBeforeMultiplication {
+    fn name(&self) -> LintName {
+        LintName::DivisionBeforeMultiplication
+    }
+
+    fn check_function(&mut self, _cx: &LintContext, function: &Function) {
+        self.fields = function
+            .input
+            .iter()
+            .filter_map(FunctionInput::get_variable)
+            .filter(|input| input.type_ == Type::Field)
+            .map(|input| input.identifier.name)
+            .collect();
+    }
+
+    fn check_statement(&mut self, _cx: &LintContext, statement: &Statement) {
+        if let Statement::Definition(definition) = statement {
+            let is_field = match &definition.type_ {
+                Some(type_) => *type_ == Type::Field,
+                None => self.is_field(&definition.value),
+            };
+            if let (true, [variable]) = (is_field, definition.variable_names.as_slice()) {
+                self.fields.insert(variable.identifier.name);
+            }
+        }
+    }
+
+    fn check_expression(&mut self, cx: &LintContext, expression: &Expression) {
+        if let Expression::Binary(binary) = expression {
+            if binary.op == BinaryOperation::Mul
+                && !self.is_field(expression)
+                && (self.is_integer_division(&binary.left) || self.is_integer_division(&binary.right))
+            {
+                cx.warn(LeoWarning::division_before_multiplication(&binary.span));
+            }
+        }
+    }
+}
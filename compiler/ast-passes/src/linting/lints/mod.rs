@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The lints run on every program.
+
+pub mod arithmetic_overflow;
+pub use arithmetic_overflow::*;
+
+pub mod constant_conditions;
+pub use constant_conditions::*;
+
+pub mod division_before_multiplication;
+pub use division_before_multiplication::*;
+
+pub mod private_data_leaks;
+pub use private_data_leaks::*;
+
+pub mod self_assignment;
+pub use self_assignment::*;
+
+pub mod shadowing;
+pub use shadowing::*;
+
+pub mod unchecked_call_results;
+pub use unchecked_call_results::*;
+
+pub mod unreachable_code;
+pub use unreachable_code::*;
+
+pub mod unused_parameters;
+pub use unused_parameters::*;
+
+pub mod unused_results;
+pub use unused_results::*;
+
+pub mod unused_variables;
+pub use unused_variables::*;
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FlowAnalyzer, Lint, LintContext, SideEffects};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+use leo_span::sym;
+
+/// Warns about values returned as the `public` output of `main` which depend on its private inputs,
+/// as found by the [`FlowAnalyzer`], since the proof of an execution reveals them to anyone.
+#[derive(Default)]
+pub struct PrivateDataLeaks {
+    /// The functions of the program, to tell the core functions, such as hashes, apart.
+    side_effects: SideEffects,
+}
+
+impl Lint for PrivateDataLeaks {
+    fn name(&self) -> LintName {
+        LintName::PrivateDataLeaks
+    }
+
+    fn check_program(&mut self, _cx: &LintContext, program: &Program) {
+        self.side_effects = SideEffects::new(program);
+    }
+
+    fn check_function(&mut self, cx: &LintContext, function: &Function) {
+        if cx.circuit().is_some() || function.name() != sym::main || function.output_mode != Some(Mode::Public) {
+            return;
+        }
+        for leak in FlowAnalyzer::analyze_function(function, None, &self.side_effects) {
+            cx.warn(LeoWarning::private_data_leak(&leak.inputs, &leak.span));
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{statement_removal, Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about assignments of a variable, or a part of one, to itself, e.g., `x = x;`.
+#[derive(Default)]
+pub struct SelfAssignment;
+
+impl Lint for SelfAssignment {
+    fn name(&self) -> LintName {
+        LintName::SelfAssignment
+    }
+
+    fn check_statement(&mut self, cx: &LintContext, statement: &Statement) {
+        let assign = match statement {
+            Statement::Assign(assign) if assign.operation == AssignOperation::Assign => assign,
+            _ => return,
+        };
+        // The assignee and the value are compared as written, as both denote the same place exactly
+        // when they print the same.
+        if matches!(assign.value, Expression::Identifier(_) | Expression::Access(_))
+            && assign.assignee.to_string() == assign.value.to_string()
+        {
+            let mut warning = LeoWarning::self_assignment(&assign.assignee, &assign.span);
+            // Indices may be out of bounds, so only assignments which cannot fail are removed.
+            let cannot_fail = assign
+                .assignee
+                .accesses
+                .iter()
+                .all(|access| matches!(access, AssigneeAccess::Tuple(..) | AssigneeAccess::Member(_)));
+            if let Some(removal) = statement_removal(&assign.span).filter(|_| cannot_fail) {
+                warning = warning.with_suggestion("remove the assignment", "", &removal);
+            }
+            cx.warn(warning);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{BindingKind, Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about variables hiding a variable of the same name in an enclosing scope.
+#[derive(Default)]
+pub struct Shadowing;
+
+impl Lint for Shadowing {
+    fn name(&self) -> LintName {
+        LintName::Shadowing
+    }
+
+    fn check_declaration(&mut self, cx: &LintContext, identifier: &Identifier, _kind: BindingKind) {
+        if cx.lookup(identifier.name).is_some() {
+            cx.warn(LeoWarning::shadowed_variable(identifier.name, &identifier.span));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{circuit_functions, FunctionName, Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Warns about expression statements calling a function of an imported package which returns a value,
+/// such as `transfer(a, b);`, as the value is likely how the function reports whether it succeeded.
+///
+/// Calls are resolved by name, as free functions or `Circuit::function` static calls, and names
+/// defined in the program itself hide those of its imports. Core functions are not reported.
+#[derive(Default)]
+pub struct UncheckedCallResults {
+    /// The functions of the imported packages which return a value.
+    external: IndexSet<FunctionName>,
+}
+
+impl UncheckedCallResults {
+    /// Returns the function of an imported package called by `call`, if any.
+    fn resolve(&self, call: &CallExpression) -> Option<FunctionName> {
+        let name = match &*call.function {
+            Expression::Identifier(identifier) => FunctionName::free(identifier.name),
+            Expression::Access(AccessExpression::Static(access)) => match &*access.inner {
+                Expression::Identifier(circuit) => FunctionName::member(circuit.name, access.name.name),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        self.external.contains(&name).then(|| name)
+    }
+}
+
+/// Returns `true` if `function` returns a value.
+fn returns_value(function: &Function) -> bool {
+    match &function.output {
+        None => false,
+        Some(Type::Tuple(types)) => !types.is_empty(),
+        Some(_) => true,
+    }
+}
+
+impl Lint for UncheckedCallResults {
+    fn name(&self) -> LintName {
+        LintName::UncheckedCallResults
+    }
+
+    fn check_program(&mut self, _cx: &LintContext, program: &Program) {
+        // Identifiers compare their spans, so names are compared by symbol.
+        let functions: IndexSet<Symbol> = program.functions.keys().map(|name| name.name).collect();
+        let circuits: IndexSet<Symbol> = program.circuits.keys().map(|name| name.name).collect();
+
+        self.external.clear();
+        for module in program.imports.values() {
+            for (name, function) in module.functions.iter() {
+                if returns_value(function) && !functions.contains(&name.name) {
+                    self.external.insert(FunctionName::free(name.name));
+                }
+            }
+            for (name, circuit) in module.circuits.iter() {
+                if circuits.contains(&name.name) {
+                    continue;
+                }
+                for function in circuit_functions(circuit) {
+                    if returns_value(function) && function.core_mapping.get().is_none() {
+                        self.external.insert(FunctionName::member(name.name, function.name()));
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_statement(&mut self, cx: &LintContext, statement: &Statement) {
+        if let Statement::Expression(statement) = statement {
+            if let Expression::Call(call) = &statement.expression {
+                if let Some(function) = self.resolve(call) {
+                    cx.warn(LeoWarning::unchecked_call_result(function, &statement.span));
+                }
+            }
+        }
+    }
+}
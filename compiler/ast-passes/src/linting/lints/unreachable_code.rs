@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Lint, LintContext};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about statements following a `return` in the same block.
+/// Only the first such statement is reported, as the rest follow from it.
+#[derive(Default)]
+pub struct UnreachableCode;
+
+impl Lint for UnreachableCode {
+    fn name(&self) -> LintName {
+        LintName::UnreachableCode
+    }
+
+    fn check_block(&mut self, cx: &LintContext, block: &Block) {
+        let mut statements = block.statements.iter();
+        if statements.any(|statement| matches!(statement, Statement::Return(_))) {
+            if let Some(unreachable) = statements.next() {
+                cx.warn(LeoWarning::unreachable_statement(unreachable.span()));
+            }
+        }
+    }
+}
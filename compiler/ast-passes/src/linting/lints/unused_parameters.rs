@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Binding, BindingKind, Lint, LintContext};
+
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about function inputs which are never read.
+#[derive(Default)]
+pub struct UnusedParameters;
+
+impl Lint for UnusedParameters {
+    fn name(&self) -> LintName {
+        LintName::UnusedParameters
+    }
+
+    fn check_binding(&mut self, cx: &LintContext, binding: &Binding) {
+        if binding.kind == BindingKind::Input && !binding.used {
+            cx.warn(LeoWarning::unused_parameter(binding.name, &binding.span));
+        }
+    }
+}
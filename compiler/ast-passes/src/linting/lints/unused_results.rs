@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{cannot_fail, statement_removal, Lint, LintContext, SideEffects};
+
+use leo_ast::*;
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about expression statements, such as `a + b;`, which have no side effects,
+/// so they are only evaluated for a value which is then discarded.
+#[derive(Default)]
+pub struct UnusedResults {
+    /// The functions of the program with side effects.
+    side_effects: SideEffects,
+}
+
+impl Lint for UnusedResults {
+    fn name(&self) -> LintName {
+        LintName::UnusedResults
+    }
+
+    fn check_program(&mut self, _cx: &LintContext, program: &Program) {
+        self.side_effects = SideEffects::new(program);
+    }
+
+    fn check_statement(&mut self, cx: &LintContext, statement: &Statement) {
+        if let Statement::Expression(statement) = statement {
+            if self.side_effects.is_pure(&statement.expression, cx.circuit()) {
+                let mut warning = LeoWarning::unused_result(&statement.span);
+                // Removing an expression which may fail would change what the program does.
+                if cannot_fail(&statement.expression) {
+                    if let Some(removal) = statement_removal(&statement.span) {
+                        warning = warning.with_suggestion("remove the statement", "", &removal);
+                    }
+                }
+                cx.warn(warning);
+            }
+        }
+    }
+}
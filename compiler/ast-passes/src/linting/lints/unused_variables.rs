@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Binding, BindingKind, Lint, LintContext};
+
+use leo_errors::{LeoWarning, LintName};
+
+/// Warns about variables which are never read.
+#[derive(Default)]
+pub struct UnusedVariables;
+
+impl Lint for UnusedVariables {
+    fn name(&self) -> LintName {
+        LintName::UnusedVariables
+    }
+
+    fn check_binding(&mut self, cx: &LintContext, binding: &Binding) {
+        if binding.kind == BindingKind::Variable && !binding.used {
+            let mut warning = LeoWarning::unused_variable(binding.name, &binding.span);
+            if let Some(removal) = &binding.removal {
+                warning = warning.with_suggestion("remove the variable", "", removal);
+            }
+            cx.warn(warning);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A framework of lints reporting code that is valid but likely a mistake as warnings,
+//! with built-in lints for unused variables and inputs, unreachable statements,
+//! shadowed variables, constant conditions, self-assignments, arithmetic that always overflows,
+//! and discarded values of expressions without side effects.
+
+pub mod lint;
+pub use lint::*;
+
+pub mod lints;
+pub use lints::*;
+
+pub mod linter;
+pub use linter::*;
@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AstProperty, OptLevel, Pass, StandardPass};
+
+use leo_ast::Ast;
+use leo_errors::{emitter::Handler, AstError, Result};
+
+/// Runs a pipeline of passes over the AST: first the checks, then the optimizations.
+///
+/// Before running, the pipeline is rejected if a pass requires a property of the AST
+/// that no pass before it provides.
+#[derive(Default)]
+pub struct PassManager {
+    /// The passes which check the program, in the order they run.
+    checks: Vec<Box<dyn Pass>>,
+    /// The passes which optimize the checked program, in the order they run.
+    optimizations: Vec<Box<dyn Pass>>,
+}
+
+impl PassManager {
+    /// Returns a pass manager running the checks of the compiler and the optimizations of `level`.
+    pub fn new(level: OptLevel) -> Self {
+        StandardPass::CHECKS
+            .iter()
+            .chain(level.passes())
+            .fold(Self::default(), |passes, pass| passes.with_pass(*pass))
+    }
+
+    /// Adds `pass` after the other checks, or after the other optimizations if it optimizes.
+    pub fn with_pass(mut self, pass: impl Pass + 'static) -> Self {
+        self.passes_mut(&pass).push(Box::new(pass));
+        self
+    }
+
+    /// Inserts `pass` right before the pass named `name`.
+    pub fn insert_before(&mut self, name: &str, pass: impl Pass + 'static) -> Result<()> {
+        let index = self.position(name, &pass)?;
+        self.passes_mut(&pass).insert(index, Box::new(pass));
+        Ok(())
+    }
+
+    /// Inserts `pass` right after the pass named `name`.
+    pub fn insert_after(&mut self, name: &str, pass: impl Pass + 'static) -> Result<()> {
+        let index = self.position(name, &pass)?;
+        self.passes_mut(&pass).insert(index + 1, Box::new(pass));
+        Ok(())
+    }
+
+    /// Removes the optimizations, so only the checks run.
+    pub fn without_optimizations(mut self) -> Self {
+        self.optimizations.clear();
+        self
+    }
+
+    /// Removes the pass named `name`, if it is in the pipeline.
+    pub fn without_pass(mut self, name: &str) -> Self {
+        self.checks.retain(|pass| pass.name() != name);
+        self.optimizations.retain(|pass| pass.name() != name);
+        self
+    }
+
+    /// Returns the names of the passes, in the order they run.
+    pub fn pass_names(&self) -> Vec<&'static str> {
+        self.passes().map(|pass| pass.name()).collect()
+    }
+
+    /// Returns an error if a pass requires a property no pass before it provides.
+    pub fn validate(&self) -> Result<()> {
+        let mut provided: Vec<AstProperty> = Vec::new();
+        for pass in self.passes() {
+            if let Some(property) = pass.requires().iter().find(|property| !provided.contains(property)) {
+                return Err(AstError::pass_requirement_not_met(pass.name(), property).into());
+            }
+            provided.extend(pass.provides());
+        }
+        Ok(())
+    }
+
+    /// Runs the checks on `ast`, calling `after` with each pass and the AST it returned.
+    pub fn run_checks(
+        &mut self,
+        handler: &Handler,
+        ast: Ast,
+        after: impl FnMut(&dyn Pass, &Ast) -> Result<()>,
+    ) -> Result<Ast> {
+        self.validate()?;
+        Self::run_passes(&mut self.checks, handler, ast, after)
+    }
+
+    /// Runs the optimizations on the checked `ast`, calling `after` with each pass and the AST it returned.
+    pub fn run_optimizations(
+        &mut self,
+        handler: &Handler,
+        ast: Ast,
+        after: impl FnMut(&dyn Pass, &Ast) -> Result<()>,
+    ) -> Result<Ast> {
+        self.validate()?;
+        Self::run_passes(&mut self.optimizations, handler, ast, after)
+    }
+
+    /// Runs the checks and then the optimizations on `ast`.
+    pub fn run(&mut self, handler: &Handler, ast: Ast) -> Result<Ast> {
+        let ast = self.run_checks(handler, ast, |_, _| Ok(()))?;
+        self.run_optimizations(handler, ast, |_, _| Ok(()))
+    }
+
+    fn run_passes(
+        passes: &mut [Box<dyn Pass>],
+        handler: &Handler,
+        mut ast: Ast,
+        mut after: impl FnMut(&dyn Pass, &Ast) -> Result<()>,
+    ) -> Result<Ast> {
+        for pass in passes.iter_mut() {
+            ast = pass.run(handler, ast)?;
+            after(pass.as_ref(), &ast)?;
+        }
+        Ok(ast)
+    }
+
+    fn passes(&self) -> impl Iterator<Item = &Box<dyn Pass>> {
+        self.checks.iter().chain(self.optimizations.iter())
+    }
+
+    /// Returns the passes `pass` is run among.
+    fn passes_mut(&mut self, pass: &dyn Pass) -> &mut Vec<Box<dyn Pass>> {
+        if pass.optimizes() {
+            &mut self.optimizations
+        } else {
+            &mut self.checks
+        }
+    }
+
+    /// Returns the index of the pass named `name` among the passes `pass` is run among.
+    fn position(&mut self, name: &str, pass: &dyn Pass) -> Result<usize> {
+        self.passes_mut(pass)
+            .iter()
+            .position(|other| other.name() == name)
+            .ok_or_else(|| AstError::unknown_pass(name).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// A pass counting the functions of the program.
+    struct CountFunctions(usize);
+
+    impl Pass for CountFunctions {
+        fn name(&self) -> &'static str {
+            "count-functions"
+        }
+
+        fn requires(&self) -> &'static [AstProperty] {
+            &[AstProperty::Canonical]
+        }
+
+        fn run(&mut self, _handler: &Handler, ast: Ast) -> Result<Ast> {
+            self.0 = ast.as_repr().functions.len();
+            Ok(ast)
+        }
+    }
+
+    #[test]
+    fn presets() {
+        let checks = StandardPass::CHECKS.map(|pass| pass.name());
+        assert_eq!(PassManager::new(OptLevel::O0).pass_names(), checks);
+        let mut optimized = checks.to_vec();
+        optimized.push("strength-reduction");
+        assert_eq!(PassManager::new(OptLevel::O1).pass_names(), optimized);
+        assert_eq!(
+            PassManager::new(OptLevel::O1).without_optimizations().pass_names(),
+            checks
+        );
+        assert_eq!(
+            PassManager::new(OptLevel::O1)
+                .without_pass("strength-reduction")
+                .pass_names(),
+            checks
+        );
+        for level in OptLevel::ALL {
+            assert_eq!(level.to_string().parse(), Ok(level));
+        }
+    }
+
+    #[test]
+    fn custom_passes() {
+        let mut passes = PassManager::new(OptLevel::O1);
+        passes.insert_after("canonicalization", CountFunctions(0)).unwrap();
+        let names = passes.pass_names();
+        let canonicalization = names.iter().position(|name| *name == "canonicalization").unwrap();
+        assert_eq!(names[canonicalization + 1], "count-functions");
+        assert!(passes.insert_before("constant-folding", CountFunctions(0)).is_err());
+
+        // A pass running before the properties it requires are established is rejected.
+        let mut passes = PassManager::default().with_pass(CountFunctions(0));
+        assert!(passes.validate().is_err());
+
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let ast = leo_parser::parse_ast(&handler, "test", "function main() {}\nfunction f() {}").unwrap();
+            assert!(passes.run(&handler, ast.clone()).is_err());
+
+            let mut passes = PassManager::new(OptLevel::O1);
+            passes.insert_before("group-validation", CountFunctions(0)).unwrap();
+            let mut counted = None;
+            passes
+                .run_checks(&handler, ast, |pass, ast| {
+                    if pass.name() == "count-functions" {
+                        counted = Some(ast.as_repr().functions.len());
+                    }
+                    Ok(())
+                })
+                .unwrap();
+            assert_eq!(counted, Some(2));
+        });
+    }
+}
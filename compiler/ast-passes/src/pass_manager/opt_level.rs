@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::StandardPass;
+
+use std::{fmt, str::FromStr};
+
+/// A preset of the optimizations the compiler runs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptLevel {
+    /// No optimizations, the program is compiled as checked.
+    O0,
+    /// The optimizations which never make a program larger.
+    O1,
+}
+
+impl OptLevel {
+    /// The presets, from the least to the most optimizing.
+    pub const ALL: [OptLevel; 2] = [OptLevel::O0, OptLevel::O1];
+
+    /// Returns the optimization passes of this preset, in the order they run.
+    pub fn passes(self) -> &'static [StandardPass] {
+        match self {
+            OptLevel::O0 => &[],
+            OptLevel::O1 => &[StandardPass::StrengthReduction],
+        }
+    }
+}
+
+impl Default for OptLevel {
+    fn default() -> Self {
+        OptLevel::O1
+    }
+}
+
+impl FromStr for OptLevel {
+    type Err = String;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level {
+            "0" => Ok(OptLevel::O0),
+            "1" => Ok(OptLevel::O1),
+            _ => Err(format!(
+                "unknown optimization level `{}`, expected one of {}",
+                level,
+                OptLevel::ALL.map(|level| format!("`{}`", level)).join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for OptLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OptLevel::O0 => write!(f, "0"),
+            OptLevel::O1 => write!(f, "1"),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+use leo_ast::{Ast, AstPass};
+use leo_errors::{emitter::Handler, Result};
+
+use std::fmt;
+
+/// A property of the AST which a pass establishes, and which the passes after it may require.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AstProperty {
+    /// No name is defined twice in the same scope.
+    NoDuplicates,
+    /// The AST is in canonical form, e.g., `Self` is replaced with the name of its circuit.
+    Canonical,
+    /// Every group literal is a point on the curve, and every field literal is an element of the field.
+    ValidGroups,
+    /// Constants such as `u8::MAX` are replaced with their values.
+    IntrinsicsResolved,
+    /// Every definition has a type.
+    Typed,
+    /// No function calls itself, directly or indirectly.
+    NonRecursive,
+}
+
+impl fmt::Display for AstProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AstProperty::NoDuplicates => write!(f, "no duplicate definitions"),
+            AstProperty::Canonical => write!(f, "canonical form"),
+            AstProperty::ValidGroups => write!(f, "valid group literals"),
+            AstProperty::IntrinsicsResolved => write!(f, "resolved intrinsic constants"),
+            AstProperty::Typed => write!(f, "inferred types"),
+            AstProperty::NonRecursive => write!(f, "no recursion"),
+        }
+    }
+}
+
+/// A pass run by a [`PassManager`].
+///
+/// A pass declares the properties of the AST it requires and those it establishes,
+/// so that the pass manager rejects a pipeline running it too early.
+pub trait Pass {
+    /// The name of the pass, by which other passes are inserted before or after it.
+    fn name(&self) -> &'static str;
+
+    /// The properties the AST must have before the pass runs.
+    fn requires(&self) -> &'static [AstProperty] {
+        &[]
+    }
+
+    /// The properties the AST has after the pass runs.
+    fn provides(&self) -> &'static [AstProperty] {
+        &[]
+    }
+
+    /// Whether the pass optimizes the program. Optimizations run after all checks,
+    /// and a program is checked without running them.
+    fn optimizes(&self) -> bool {
+        false
+    }
+
+    /// Runs the pass on `ast`, reporting warnings to `handler`.
+    fn run(&mut self, handler: &Handler, ast: Ast) -> Result<Ast>;
+}
+
+/// The passes of the Leo compiler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StandardPass {
+    /// Rejects names defined twice in the same scope.
+    DuplicateDetection,
+    /// Replaces `BoundedVec<element, capacity>` types with circuits.
+    BoundedVecLowering,
+    /// Canonicalizes the AST.
+    Canonicalization,
+    /// Rejects group literals which are not points on the curve, and field literals out of the field.
+    GroupValidation,
+    /// Replaces constants such as `u8::MAX` with their values.
+    IntrinsicResolution,
+    /// Infers the types of unannotated definitions, and of unsuffixed integer literals from their context.
+    TypeInference,
+    /// Checks the version and the constructor of the program, and sets its header.
+    ProgramValidation,
+    /// Rejects uses of private functions and circuits from where they are not visible.
+    VisibilityCheck,
+    /// Warns about code that is valid but likely a mistake.
+    Linting,
+    /// Rejects recursive functions.
+    RecursionCheck,
+    /// Replaces the calls of `@const` functions on constant arguments with their values.
+    ConstEvaluation,
+    /// Replaces unsigned multiplications and divisions by powers of two, which cannot overflow, with shifts.
+    StrengthReduction,
+}
+
+impl StandardPass {
+    /// The passes checking a program, in the order they run.
+    pub const CHECKS: [StandardPass; 11] = [
+        StandardPass::DuplicateDetection,
+        StandardPass::BoundedVecLowering,
+        StandardPass::Canonicalization,
+        StandardPass::GroupValidation,
+        StandardPass::IntrinsicResolution,
+        StandardPass::TypeInference,
+        StandardPass::ProgramValidation,
+        StandardPass::VisibilityCheck,
+        StandardPass::Linting,
+        StandardPass::RecursionCheck,
+        StandardPass::ConstEvaluation,
+    ];
+}
+
+impl Pass for StandardPass {
+    fn name(&self) -> &'static str {
+        match self {
+            StandardPass::DuplicateDetection => "duplicate-detection",
+            StandardPass::BoundedVecLowering => "bounded-vec-lowering",
+            StandardPass::Canonicalization => "canonicalization",
+            StandardPass::GroupValidation => "group-validation",
+            StandardPass::IntrinsicResolution => "intrinsic-resolution",
+            StandardPass::TypeInference => "type-inference",
+            StandardPass::ProgramValidation => "program-validation",
+            StandardPass::VisibilityCheck => "visibility-check",
+            StandardPass::Linting => "linting",
+            StandardPass::RecursionCheck => "recursion-check",
+            StandardPass::ConstEvaluation => "const-evaluation",
+            StandardPass::StrengthReduction => "strength-reduction",
+        }
+    }
+
+    fn requires(&self) -> &'static [AstProperty] {
+        use AstProperty::*;
+        match self {
+            StandardPass::DuplicateDetection | StandardPass::BoundedVecLowering | StandardPass::Canonicalization => &[],
+            StandardPass::GroupValidation
+            | StandardPass::IntrinsicResolution
+            | StandardPass::ProgramValidation
+            | StandardPass::VisibilityCheck
+            | StandardPass::RecursionCheck => &[Canonical],
+            StandardPass::TypeInference => &[Canonical, IntrinsicsResolved],
+            StandardPass::Linting => &[Typed],
+            StandardPass::ConstEvaluation => &[Typed, NonRecursive],
+            StandardPass::StrengthReduction => &[NoDuplicates, ValidGroups, Typed, NonRecursive],
+        }
+    }
+
+    fn provides(&self) -> &'static [AstProperty] {
+        match self {
+            StandardPass::DuplicateDetection => &[AstProperty::NoDuplicates],
+            StandardPass::Canonicalization => &[AstProperty::Canonical],
+            StandardPass::GroupValidation => &[AstProperty::ValidGroups],
+            StandardPass::IntrinsicResolution => &[AstProperty::IntrinsicsResolved],
+            StandardPass::TypeInference => &[AstProperty::Typed],
+            StandardPass::BoundedVecLowering
+            | StandardPass::ProgramValidation
+            | StandardPass::VisibilityCheck
+            | StandardPass::Linting
+            | StandardPass::ConstEvaluation
+            | StandardPass::StrengthReduction => &[],
+            StandardPass::RecursionCheck => &[AstProperty::NonRecursive],
+        }
+    }
+
+    fn optimizes(&self) -> bool {
+        matches!(self, StandardPass::StrengthReduction)
+    }
+
+    fn run(&mut self, handler: &Handler, ast: Ast) -> Result<Ast> {
+        match self {
+            StandardPass::DuplicateDetection => {
+                DuplicateDetector::new(handler).check_program(ast.as_repr())?;
+                Ok(ast)
+            }
+            StandardPass::BoundedVecLowering => Ok(Ast::new(BoundedVecLowerer::lower_program(ast.into_repr())?)),
+            StandardPass::Canonicalization => Canonicalizer::do_pass(Default::default(), ast.into_repr()),
+            StandardPass::GroupValidation => GroupValidator::do_pass(Default::default(), ast.into_repr()),
+            StandardPass::IntrinsicResolution => IntrinsicResolver::do_pass(Default::default(), ast.into_repr()),
+            StandardPass::TypeInference => TypeInferrer::do_pass(Default::default(), ast.into_repr()),
+            StandardPass::ProgramValidation => Ok(Ast::new(ProgramValidator::validate_program(ast.into_repr())?)),
+            StandardPass::VisibilityCheck => {
+                VisibilityChecker::new(handler, ast.as_repr()).check_program()?;
+                Ok(ast)
+            }
+            StandardPass::Linting => {
+                Linter::new(handler).check_program(ast.as_repr())?;
+                Ok(ast)
+            }
+            StandardPass::RecursionCheck => {
+                CallGraph::new(ast.as_repr()).check_no_recursion()?;
+                Ok(ast)
+            }
+            StandardPass::ConstEvaluation => Ok(Ast::new(ConstEvaluator::evaluate_program(ast.as_repr())?)),
+            StandardPass::StrengthReduction => StrengthReducer::new(ast.as_repr()).do_pass(ast.into_repr()),
+        }
+    }
+}
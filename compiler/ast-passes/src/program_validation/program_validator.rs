@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{AstError, Result};
+use leo_span::sym;
+
+/// Checks the `@program(version = ...)` annotation and the `constructor` function of a program,
+/// and returns the [`ProgramHeader`] describing them.
+///
+/// The version must be an integer of at least `1`. The constructor runs once, when the program
+/// is deployed, so it takes no inputs and returns nothing.
+///
+/// Also checks the `constant`, `private` and `public` modes, which only the inputs and output of
+/// `main` may declare.
+pub struct ProgramValidator;
+
+impl ProgramValidator {
+    /// Returns `program` with its header set, if it has a `@program` annotation or a constructor.
+    pub fn validate_program(mut program: Program) -> Result<Program> {
+        Self::check_modes(&program)?;
+        program.header = Self::header(&program)?;
+        Ok(program)
+    }
+
+    /// Returns the header of `program`, or `None` if it has neither a `@program` annotation nor a constructor.
+    pub fn header(program: &Program) -> Result<Option<ProgramHeader>> {
+        let version = program.annotations.get(&sym::program).map(Self::version).transpose()?;
+
+        let constructor = program
+            .functions
+            .values()
+            .find(|function| function.name() == sym::constructor);
+        if let Some(constructor) = constructor {
+            let returns_nothing = match &constructor.output {
+                None => true,
+                Some(Type::Tuple(types)) => types.is_empty(),
+                Some(_) => false,
+            };
+            if constructor.const_ || !constructor.input.is_empty() || !returns_nothing {
+                return Err(AstError::invalid_constructor(&constructor.identifier.span).into());
+            }
+        }
+
+        if version.is_none() && constructor.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(ProgramHeader {
+            version: version.unwrap_or(1),
+            constructor: constructor.is_some(),
+        }))
+    }
+
+    /// Checks the modes of the inputs and outputs of the functions of `program`.
+    pub fn check_modes(program: &Program) -> Result<()> {
+        let circuit_functions = program.circuits.values().flat_map(|circuit| {
+            circuit.members.iter().filter_map(|member| match member {
+                CircuitMember::CircuitFunction(function) => Some(function.as_ref()),
+                _ => None,
+            })
+        });
+        for function in circuit_functions {
+            Self::check_function_modes(function, false)?;
+        }
+        for function in program.functions.values() {
+            Self::check_function_modes(function, function.is_main())?;
+        }
+        Ok(())
+    }
+
+    /// Checks the modes of `function`, which may only have modes if it is the entry point `main`.
+    fn check_function_modes(function: &Function, main: bool) -> Result<()> {
+        let inputs = function.input.iter().filter_map(FunctionInput::get_variable);
+        let has_modes = function.output_mode.is_some() || inputs.clone().any(|input| input.mode.is_some());
+        if has_modes && !main {
+            return Err(AstError::mode_outside_main(function.identifier.name, &function.identifier.span).into());
+        }
+        for input in inputs {
+            if let (Some(mode), true) = (input.mode, input.const_) {
+                return Err(AstError::const_input_with_mode(&input.identifier, mode, &input.span).into());
+            }
+        }
+        if function.output_mode == Some(Mode::Constant) {
+            return Err(AstError::constant_output(&function.identifier.span).into());
+        }
+        Ok(())
+    }
+
+    /// Returns the version set by the `@program` `annotation`.
+    fn version(annotation: &Annotation) -> Result<u32> {
+        match (annotation.arguments.as_slice(), annotation.values.as_slice()) {
+            ([], [(name, version)]) if *name == sym::version => version
+                .parse()
+                .ok()
+                .filter(|version| *version >= 1)
+                .ok_or_else(|| AstError::invalid_program_annotation(&annotation.span).into()),
+            _ => Err(AstError::invalid_program_annotation(&annotation.span).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    fn header(source: &str) -> Result<Option<ProgramHeader>> {
+        let program = parse(source);
+        ProgramValidator::header(&program)
+    }
+
+    #[test]
+    fn reads_version_and_constructor() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(header("function main() {}").unwrap(), None);
+            assert_eq!(
+                header("@program(version = 2)\nfunction main() {}").unwrap(),
+                Some(ProgramHeader {
+                    version: 2,
+                    constructor: false
+                })
+            );
+            assert_eq!(
+                header("function constructor() {}\nfunction main() {}").unwrap(),
+                Some(ProgramHeader {
+                    version: 1,
+                    constructor: true
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn rejects_invalid_metadata() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "@program(version = 0)\nfunction main() {}",
+                "@program(version = \"two\")\nfunction main() {}",
+                "@program(edition = 2)\nfunction main() {}",
+                "@program\nfunction main() {}",
+                "function constructor(a: u8) {}",
+                "function constructor() -> u8 { return 1u8; }",
+                "const function constructor() {}",
+            ] {
+                assert!(header(source).is_err(), "{}", source);
+            }
+        });
+    }
+
+    fn check_modes(source: &str) -> Result<()> {
+        let program = parse(source);
+        ProgramValidator::check_modes(&program)
+    }
+
+    #[test]
+    fn checks_modes() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "function main(public a: u8, private b: u8, constant c: u8, const d: u8) -> public u8 { return a; }",
+                "function main(a: u8) -> private u8 { return a; }",
+                "function foo(public: u8, constant: bool) {}",
+            ] {
+                assert!(check_modes(source).is_ok(), "{}", source);
+            }
+            for source in [
+                "function foo(public a: u8) {}",
+                "function foo() -> public u8 { return 1u8; }",
+                "circuit Foo { function bar(private a: u8) {} }",
+                "function main(public const a: u8) {}",
+                "function main(constant const a: u8) {}",
+                "function main() -> constant u8 { return 1u8; }",
+            ] {
+                assert!(check_modes(source).is_err(), "{}", source);
+            }
+        });
+    }
+}
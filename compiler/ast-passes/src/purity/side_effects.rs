@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{circuit_functions, CallGraph, FunctionName};
+
+use leo_ast::*;
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// The functions of a program which have side effects.
+///
+/// A function has side effects if it logs or asserts with a `console` statement, changes the
+/// circuit it is called on through `&self`, or calls a function with side effects, or one which
+/// cannot be resolved statically. Core functions, implemented by the compiler, have none.
+/// Failing, e.g., on an overflow, is not a side effect.
+#[derive(Clone, Debug, Default)]
+pub struct SideEffects {
+    /// The call graph the calls are resolved with.
+    graph: CallGraph,
+    /// The core functions, defined in the program or its imports.
+    core: IndexSet<FunctionName>,
+    /// The functions with side effects.
+    effectful: IndexSet<FunctionName>,
+}
+
+impl SideEffects {
+    /// Finds the functions of `program` which have side effects.
+    pub fn new(program: &Program) -> Self {
+        let mut side_effects = Self {
+            graph: CallGraph::new(program),
+            ..Self::default()
+        };
+
+        for module in program.imports.values().chain(std::iter::once(program)) {
+            for (name, circuit) in module.circuits.iter() {
+                for function in circuit_functions(circuit).filter(|f| f.core_mapping.get().is_some()) {
+                    side_effects
+                        .core
+                        .insert(FunctionName::member(name.name, function.name()));
+                }
+            }
+        }
+
+        // The functions with side effects of their own.
+        let mut functions: Vec<(FunctionName, Option<Symbol>, &Function)> = program
+            .functions
+            .values()
+            .map(|function| (FunctionName::free(function.name()), None, function))
+            .collect();
+        for (name, circuit) in program.circuits.iter() {
+            for function in circuit_functions(circuit) {
+                functions.push((
+                    FunctionName::member(name.name, function.name()),
+                    Some(name.name),
+                    function,
+                ));
+            }
+        }
+        for (name, circuit, function) in functions {
+            if side_effects.core.contains(&name) {
+                continue;
+            }
+            let mut finder = EffectFinder::new(&side_effects, circuit);
+            finder.visit_block(&function.block);
+            if function.contains_mut_self() || finder.found {
+                side_effects.effectful.insert(name);
+            }
+        }
+
+        // Then their callers, until none is left.
+        loop {
+            let callers: Vec<FunctionName> = side_effects
+                .graph
+                .functions()
+                .filter(|function| {
+                    !side_effects.effectful.contains(*function)
+                        && !side_effects.core.contains(*function)
+                        && side_effects
+                            .graph
+                            .callees(function)
+                            .any(|callee| side_effects.effectful.contains(callee))
+                })
+                .copied()
+                .collect();
+            if callers.is_empty() {
+                break;
+            }
+            side_effects.effectful.extend(callers);
+        }
+
+        side_effects
+    }
+
+    /// Returns `true` if `function` is a core function, implemented by the compiler.
+    pub fn is_core(&self, function: &FunctionName) -> bool {
+        self.core.contains(function)
+    }
+
+    /// Returns `true` if calling `function` may have side effects.
+    pub fn has_side_effects(&self, function: &FunctionName) -> bool {
+        self.effectful.contains(function)
+    }
+
+    /// Returns `true` if evaluating `expression`, in a member function of `circuit` if any,
+    /// has no side effects, so it is only evaluated for its value.
+    pub fn is_pure(&self, expression: &Expression, circuit: Option<Symbol>) -> bool {
+        let mut finder = EffectFinder::new(self, circuit);
+        finder.visit_expression(expression);
+        !finder.found
+    }
+
+    /// Returns the function called by `function`, in a member function of `circuit` if any,
+    /// if it is a core function, or a function defined in the program.
+    pub fn resolve(&self, function: &Expression, circuit: Option<Symbol>) -> Option<FunctionName> {
+        if let Expression::Access(AccessExpression::Static(access)) = function {
+            if let Expression::Identifier(circuit) = &*access.inner {
+                let name = FunctionName::member(circuit.name, access.name.name);
+                if self.core.contains(&name) {
+                    return Some(name);
+                }
+            }
+        }
+        self.graph.resolve(function, circuit)
+    }
+}
+
+/// Looks for side effects in the statements and expressions it visits,
+/// given the functions with side effects found so far.
+struct EffectFinder<'a> {
+    side_effects: &'a SideEffects,
+    /// The circuit the visited function is a member of, if any.
+    circuit: Option<Symbol>,
+    /// Whether a side effect was found.
+    found: bool,
+}
+
+impl<'a> EffectFinder<'a> {
+    fn new(side_effects: &'a SideEffects, circuit: Option<Symbol>) -> Self {
+        Self {
+            side_effects,
+            circuit,
+            found: false,
+        }
+    }
+}
+
+impl ExpressionVisitor for EffectFinder<'_> {
+    fn visit_call(&mut self, call: &CallExpression) {
+        match self.side_effects.resolve(&call.function, self.circuit) {
+            Some(callee) => self.found |= self.side_effects.has_side_effects(&callee),
+            None => self.found = true,
+        }
+        self.visit_expression(&call.function);
+        call.arguments.iter().for_each(|e| self.visit_expression(e));
+    }
+}
+
+impl StatementVisitor for EffectFinder<'_> {
+    fn visit_console(&mut self, _console: &ConsoleStatement) {
+        self.found = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn classifies_functions() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut program = parse(
+                "function main(a: u8) -> u8 { log(a); return add(a, a); }
+                 function add(a: u8, b: u8) -> u8 { return a + b; }
+                 function log(a: u8) { console.log(\"{}\", a); }
+                 function logs_later(a: u8) -> u8 { return Counter::make(a).get(); }
+                 circuit Counter {
+                     x: u8;
+                     function make(x: u8) -> Self { return Self { x: log_first(x) }; }
+                     function get(self) -> u8 { return self.x; }
+                     function bump(&self) { self.x += 1u8; }
+                 }
+                 function log_first(a: u8) -> u8 { log(a); return a; }
+                 circuit Blake2s {
+                     @CoreFunction
+                     function hash(seed: [u8; 32], message: [u8; 32]) -> [u8; 32] { log(1u8); return seed; }
+                 }",
+            );
+            program.handle_internal_annotations();
+            let side_effects = SideEffects::new(&program);
+
+            let effectful: Vec<String> = side_effects.effectful.iter().map(|f| f.to_string()).collect();
+            assert_eq!(
+                effectful,
+                vec![
+                    "log",
+                    "logs_later",
+                    "log_first",
+                    "Counter::make",
+                    "Counter::bump",
+                    "main"
+                ]
+            );
+
+            let main = program.functions.values().next().unwrap();
+            let statements: Vec<bool> = main
+                .block
+                .statements
+                .iter()
+                .map(|statement| match statement {
+                    Statement::Expression(statement) => side_effects.is_pure(&statement.expression, None),
+                    Statement::Return(return_) => side_effects.is_pure(&return_.expression, None),
+                    _ => unreachable!(),
+                })
+                .collect();
+            assert_eq!(statements, vec![false, true]);
+
+            match leo_parser::parse_repl_statement(&handler, "test", "Blake2s::hash(a, a);").unwrap() {
+                Statement::Expression(hash) => assert!(side_effects.is_pure(&hash.expression, None)),
+                _ => unreachable!(),
+            }
+        });
+    }
+}
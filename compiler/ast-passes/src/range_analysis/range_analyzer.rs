@@ -0,0 +1,409 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_span::{Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::fmt;
+
+/// The values an unsigned integer expression may take, from `min` to `max` inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Range {
+    /// The type of the expression.
+    pub type_: IntegerType,
+    /// The smallest value of the expression.
+    pub min: u128,
+    /// The largest value of the expression.
+    pub max: u128,
+}
+
+impl Range {
+    /// Returns the range of every value of `type_`, or `None` if `type_` is signed.
+    pub fn full(type_: IntegerType) -> Option<Self> {
+        match type_.is_signed() {
+            true => None,
+            false => Some(Self {
+                type_,
+                min: 0,
+                max: type_max(type_),
+            }),
+        }
+    }
+
+    /// Returns the range of the single value `value` of `type_`.
+    pub fn exact(type_: IntegerType, value: u128) -> Self {
+        Self {
+            type_,
+            min: value,
+            max: value,
+        }
+    }
+
+    /// Returns the smallest range containing both `self` and `other`, if they have the same type.
+    pub fn union(self, other: Self) -> Option<Self> {
+        (self.type_ == other.type_).then(|| Self {
+            type_: self.type_,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        })
+    }
+}
+
+/// Returns the largest value of the unsigned integer type `type_`.
+fn type_max(type_: IntegerType) -> u128 {
+    u128::MAX >> (128 - type_.bits())
+}
+
+/// Returns the largest value with as many bits as `value`.
+fn bit_mask(value: u128) -> u128 {
+    match value {
+        0 => 0,
+        _ => u128::MAX >> value.leading_zeros(),
+    }
+}
+
+/// An operation which overflows the range of its type whatever the values of its operands.
+#[derive(Clone, Debug)]
+pub struct Overflow {
+    /// The operation, as written in the program.
+    pub expression: String,
+    /// The type whose range the operation overflows.
+    pub type_: IntegerType,
+    /// The span of the operation.
+    pub span: Span,
+}
+
+/// What the range analysis proves about the arithmetic and casts of a function.
+#[derive(Clone, Debug, Default)]
+pub struct RangeAnalysis {
+    /// The operations which always overflow.
+    pub overflows: Vec<Overflow>,
+    /// The spans of the operations which never overflow, so they need no overflow check.
+    pub safe: Vec<Span>,
+}
+
+impl RangeAnalysis {
+    /// Returns `true` if the operation at `span` is proven to never overflow.
+    pub fn is_safe(&self, span: &Span) -> bool {
+        self.safe.contains(span)
+    }
+}
+
+/// Collects the variables assigned anywhere in a function.
+#[derive(Default)]
+struct AssignedVariables(IndexSet<Symbol>);
+
+impl ExpressionVisitor for AssignedVariables {}
+
+impl StatementVisitor for AssignedVariables {
+    fn visit_assign(&mut self, assign: &AssignStatement) {
+        self.0.insert(assign.assignee.identifier.name);
+    }
+}
+
+/// Tracks the ranges of the unsigned integer variables and expressions of a function.
+///
+/// A variable defined with `let` or `const` takes the range of its value, a function input
+/// and a loop variable that of their type and bounds, and the result of an operation is
+/// computed from the ranges of its operands. As the analysis does not follow the flow of
+/// assignments, a variable assigned anywhere in the function can hold any value of its type.
+/// Operations on signed integers, or on values of unknown range, are not analyzed.
+#[derive(Default)]
+pub struct RangeAnalyzer {
+    /// The ranges of the variables in scope, innermost scope last, or `None` if unknown.
+    scopes: Vec<IndexMap<Symbol, Option<Range>>>,
+    /// The variables assigned anywhere in the function.
+    assigned: IndexSet<Symbol>,
+    /// The findings so far.
+    analysis: RangeAnalysis,
+}
+
+impl RangeAnalyzer {
+    /// Returns what the analysis proves about the operations in `function`.
+    pub fn analyze_function(function: &Function) -> RangeAnalysis {
+        let mut assigned = AssignedVariables::default();
+        assigned.visit_block(&function.block);
+
+        let mut analyzer = Self {
+            assigned: assigned.0,
+            ..Self::default()
+        };
+        analyzer.scopes.push(IndexMap::new());
+        for input in function.input.iter().filter_map(FunctionInput::get_variable) {
+            analyzer.declare(input.identifier.name, None, Some(&input.type_));
+        }
+        analyzer.visit_block(&function.block);
+        analyzer.analysis
+    }
+
+    /// Brings the variable `name` of type `type_`, if known, holding the values of `range` into scope.
+    /// The variable holds every value of its type if it is assigned to, or if `range` is unknown.
+    fn declare(&mut self, name: Symbol, range: Option<Range>, type_: Option<&Type>) {
+        let type_ = match type_ {
+            Some(Type::IntegerType(type_)) => Some(*type_),
+            Some(_) => None,
+            None => range.map(|range| range.type_),
+        };
+        let range = match (type_, range) {
+            (Some(type_), Some(range)) if range.type_ == type_ && !self.assigned.contains(&name) => Some(range),
+            (Some(type_), _) => Range::full(type_),
+            (None, _) => None,
+        };
+        self.scopes.last_mut().unwrap().insert(name, range);
+    }
+
+    /// Returns the range of the variable `name`, if known.
+    fn lookup(&self, name: Symbol) -> Option<Range> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name))
+            .copied()
+            .flatten()
+    }
+
+    /// Records whether `expression` at `span` of `type_` always overflows, or never does.
+    /// Returns `true` if it always overflows.
+    fn classify(
+        &mut self,
+        expression: &dyn fmt::Display,
+        span: &Span,
+        type_: IntegerType,
+        always: bool,
+        never: bool,
+    ) -> bool {
+        if always {
+            self.analysis.overflows.push(Overflow {
+                expression: expression.to_string(),
+                type_,
+                span: span.clone(),
+            });
+        } else if never {
+            self.analysis.safe.push(span.clone());
+        }
+        always
+    }
+
+    /// Returns the range of `expression`, if it is an unsigned integer of known range,
+    /// after analyzing the operations in it.
+    pub fn range(&mut self, expression: &Expression) -> Option<Range> {
+        match expression {
+            Expression::Identifier(identifier) => self.lookup(identifier.name),
+            Expression::Value(LiteralExpression {
+                value: ValueExpression::Integer(type_, value, _),
+                ..
+            }) => {
+                let value = value.parse().ok()?;
+                Range::full(*type_)
+                    .filter(|range| value <= range.max)
+                    .map(|_| Range::exact(*type_, value))
+            }
+            Expression::Binary(binary) => self.binary(binary),
+            Expression::Cast(cast) => self.cast(cast),
+            Expression::Ternary(ternary) => {
+                self.visit_expression(&ternary.condition);
+                let if_true = self.range(&ternary.if_true);
+                let if_false = self.range(&ternary.if_false);
+                if_true?.union(if_false?)
+            }
+            _ => {
+                walk_expression(self, expression);
+                None
+            }
+        }
+    }
+
+    fn binary(&mut self, binary: &BinaryExpression) -> Option<Range> {
+        let left = self.range(&binary.left);
+        let right = self.range(&binary.right);
+        let (left, right) = match (left, right) {
+            (Some(left), Some(right)) if left.type_ == right.type_ => (left, right),
+            _ => return None,
+        };
+        let type_ = left.type_;
+        let type_max = type_max(type_);
+
+        let (min, max) = match binary.op {
+            BinaryOperation::Add | BinaryOperation::Mul => {
+                let apply = |a: u128, b: u128| match binary.op {
+                    BinaryOperation::Add => a.checked_add(b),
+                    _ => a.checked_mul(b),
+                };
+                // `None` is beyond the largest `u128`, so it overflows any type.
+                let lowest = apply(left.min, right.min).filter(|lowest| *lowest <= type_max);
+                let highest = apply(left.max, right.max).filter(|highest| *highest <= type_max);
+                if self.classify(binary, &binary.span, type_, lowest.is_none(), highest.is_some()) {
+                    return None;
+                }
+                (lowest?, highest.unwrap_or(type_max))
+            }
+            BinaryOperation::Sub => {
+                let always = left.max < right.min;
+                let never = left.min >= right.max;
+                if self.classify(binary, &binary.span, type_, always, never) {
+                    return None;
+                }
+                (left.min.saturating_sub(right.max), left.max - right.min)
+            }
+            BinaryOperation::Div if right.max > 0 => (left.min / right.max, left.max / right.min.max(1)),
+            BinaryOperation::Mod if right.max > 0 => match left.max < right.min {
+                true => (left.min, left.max),
+                false => (0, left.max.min(right.max - 1)),
+            },
+            BinaryOperation::BitAnd => (0, left.max.min(right.max)),
+            BinaryOperation::BitOr => (left.min.max(right.min), bit_mask(left.max.max(right.max))),
+            BinaryOperation::BitXor => (0, bit_mask(left.max.max(right.max))),
+            _ => return None,
+        };
+        Some(Range { type_, min, max })
+    }
+
+    fn cast(&mut self, cast: &CastExpression) -> Option<Range> {
+        let inner = self.range(&cast.inner);
+        let target = match &cast.target_type {
+            Type::IntegerType(type_) => Range::full(*type_)?,
+            _ => return None,
+        };
+        let inner = match inner {
+            Some(inner) => inner,
+            None => return Some(target),
+        };
+        let always = inner.min > target.max;
+        let never = inner.max <= target.max;
+        if self.classify(cast, &cast.span, target.type_, always, never) {
+            return None;
+        }
+        Some(Range {
+            type_: target.type_,
+            min: inner.min,
+            max: inner.max.min(target.max),
+        })
+    }
+}
+
+impl ExpressionVisitor for RangeAnalyzer {
+    fn visit_expression(&mut self, expression: &Expression) {
+        self.range(expression);
+    }
+}
+
+impl StatementVisitor for RangeAnalyzer {
+    fn visit_definition(&mut self, definition: &DefinitionStatement) {
+        let range = self.range(&definition.value);
+        match definition.variable_names.as_slice() {
+            [variable] => self.declare(variable.identifier.name, range, definition.type_.as_ref()),
+            variables => {
+                for variable in variables {
+                    self.declare(variable.identifier.name, None, None);
+                }
+            }
+        }
+    }
+
+    fn visit_iteration(&mut self, iteration: &IterationStatement) {
+        let start = self.range(&iteration.start);
+        let stop = self.range(&iteration.stop);
+        let range = match (start, stop) {
+            (Some(start), Some(stop)) if start.type_ == stop.type_ => {
+                let max = match iteration.inclusive {
+                    true => Some(stop.max),
+                    false => stop.max.checked_sub(1),
+                };
+                // Without a value, the loop never runs, so the range of its variable does not matter.
+                max.filter(|max| start.min <= *max).map(|max| Range {
+                    type_: start.type_,
+                    min: start.min,
+                    max,
+                })
+            }
+            _ => None,
+        };
+
+        self.scopes.push(IndexMap::new());
+        let type_ = start.or(stop).map(|range| Type::IntegerType(range.type_));
+        self.declare(iteration.variable.name, range, type_.as_ref());
+        self.visit_block(&iteration.block);
+        self.scopes.pop();
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        self.scopes.push(IndexMap::new());
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+        self.scopes.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the overflowing operations and the number of safe ones in the function `main` of `source`.
+    fn analyze(source: &str) -> (Vec<String>, usize) {
+        let program = parse(source);
+        let main = program.functions.values().next().unwrap();
+        let analysis = RangeAnalyzer::analyze_function(main);
+        let overflows = analysis
+            .overflows
+            .iter()
+            .map(|overflow| format!("{}: {}", overflow.type_, overflow.expression))
+            .collect();
+        (overflows, analysis.safe.len())
+    }
+
+    #[test]
+    fn finds_overflows() {
+        create_session_if_not_set_then(|_| {
+            let (overflows, _) = analyze(
+                "function main(a: u8, b: u16) {
+                     let x = 200u8;
+                     let y = x + 100u8;
+                     let z = (b / 2u16 + 256u16) as u8;
+                     let w = a / 10u8 - 30u8;
+                     let v = x - a;
+                 }",
+            );
+            assert_eq!(
+                overflows,
+                vec!["u8: x + 100u8", "u8: b / 2u16 + 256u16 as u8", "u8: a / 10u8 - 30u8"]
+            );
+        });
+    }
+
+    #[test]
+    fn proves_safe_operations() {
+        create_session_if_not_set_then(|_| {
+            let (overflows, safe) = analyze(
+                "function main(a: u8, b: u32) -> u8 {
+                     let total = 0u32;
+                     for i in 0u8..10u8 {
+                         let x = (a / 2u8 + i) as u32;
+                         total += x;
+                     }
+                     let y = b / 50000000u32;
+                     return (y - 0u32 + total - total) as u8;
+                 }",
+            );
+            assert!(overflows.is_empty());
+            // `a / 2u8 + i`, its cast, and `y - 0u32`, but none of the operations on the assigned `total`.
+            assert_eq!(safe, 3);
+        });
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Creates a struct that implements a ReconstructingReducer
+//! such that multiplications and divisions by powers of two become shifts.
+//! A shift is far cheaper in constraints than a multiplication or division.
+
+use crate::{RangeAnalysis, RangeAnalyzer};
+
+use leo_ast::*;
+use leo_errors::Result;
+use leo_span::Span;
+
+/// Rewrites `x * 2^k` and `2^k * x` into `x << k`, and `x / 2^k` into `x >> k`, for unsigned integer operands.
+///
+/// Only multiplications and divisions by a suffixed integer literal are rewritten,
+/// since the suffix guarantees that the other operand is an integer as well.
+/// Signed operations are left untouched: a signed division rounds towards zero whereas a shift rounds down.
+/// As a shift discards overflowing bits where the multiplication would fail, a multiplication
+/// is only rewritten if the [`RangeAnalyzer`] proves that it never overflows.
+#[derive(Default)]
+pub struct StrengthReducer {
+    in_circuit: bool,
+    /// What the range analysis proves about the functions of the program.
+    analysis: RangeAnalysis,
+}
+
+impl StrengthReducer {
+    /// Returns a reducer for `program`, with the range analysis of its functions.
+    pub fn new(program: &Program) -> Self {
+        let mut analysis = RangeAnalysis::default();
+        let circuit_functions = program.circuits.values().flat_map(|circuit| {
+            circuit.members.iter().filter_map(|member| match member {
+                CircuitMember::CircuitFunction(function) => Some(function.as_ref()),
+                _ => None,
+            })
+        });
+        for function in program.functions.values().chain(circuit_functions) {
+            analysis.safe.extend(RangeAnalyzer::analyze_function(function).safe);
+        }
+        Self {
+            in_circuit: false,
+            analysis,
+        }
+    }
+
+    /// Returns `Some(k)` if `expression` is an integer literal equal to `2^k` with `k > 0`.
+    fn power_of_two(expression: &Expression) -> Option<(IntegerType, u32)> {
+        match expression {
+            Expression::Value(LiteralExpression {
+                value: ValueExpression::Integer(type_, value, _),
+                ..
+            }) => {
+                let value = value.parse::<u128>().ok()?;
+                (value > 1 && value.is_power_of_two()).then(|| (*type_, value.trailing_zeros()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the shift `operand op k`.
+    fn shift(operand: Expression, op: BinaryOperation, k: u32, span: &Span) -> BinaryExpression {
+        let amount = Expression::Value(ValueExpression::Integer(IntegerType::U32, k.to_string(), span.clone()).into());
+        BinaryExpression {
+            left: Box::new(operand),
+            right: Box::new(amount),
+            op,
+            span: span.clone(),
+            id: NodeID::new(),
+        }
+    }
+}
+
+impl ReconstructingReducer for StrengthReducer {
+    fn in_circuit(&self) -> bool {
+        self.in_circuit
+    }
+
+    fn swap_in_circuit(&mut self) {
+        self.in_circuit = !self.in_circuit;
+    }
+
+    fn reduce_binary(
+        &mut self,
+        binary: &BinaryExpression,
+        left: Expression,
+        right: Expression,
+        op: BinaryOperation,
+    ) -> Result<BinaryExpression> {
+        match op {
+            BinaryOperation::Mul if self.analysis.is_safe(&binary.span) => {
+                match (Self::power_of_two(&left), Self::power_of_two(&right)) {
+                    (_, Some((type_, k))) if !type_.is_signed() => {
+                        return Ok(Self::shift(left, BinaryOperation::Shl, k, &binary.span));
+                    }
+                    (Some((type_, k)), _) if !type_.is_signed() => {
+                        return Ok(Self::shift(right, BinaryOperation::Shl, k, &binary.span));
+                    }
+                    _ => {}
+                }
+            }
+            BinaryOperation::Div => match Self::power_of_two(&right) {
+                Some((type_, k)) if !type_.is_signed() => {
+                    return Ok(Self::shift(left, BinaryOperation::Shr, k, &binary.span));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        Ok(BinaryExpression {
+            left: Box::new(left),
+            right: Box::new(right),
+            op,
+            span: binary.span.clone(),
+            id: binary.id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the value returned by `main` after strength reduction.
+    fn reduce(expression: &str) -> String {
+        let source = format!("function main(x: u32, y: i32, z: u8) {{ return {}; }}", expression);
+        let program = parse(&source);
+        let program = StrengthReducer::new(&program).do_pass(program).unwrap().into_repr();
+        match &program.functions.values().next().unwrap().block.statements[0] {
+            Statement::Return(return_) => return_.expression.to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn rewrites_powers_of_two() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(reduce("x / 16u32"), "x >> 4u32");
+            assert_eq!(reduce("(x / 16u32) * 8u32"), "x >> 4u32 << 3u32");
+            assert_eq!(reduce("2u32 * (z / 2u8) as u32"), "z >> 1u32 as u32 << 1u32");
+        });
+    }
+
+    #[test]
+    fn keeps_other_operations() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(reduce("x * 6u32"), "x * 6u32");
+            assert_eq!(reduce("x * 1u32"), "x * 1u32");
+            assert_eq!(reduce("y / 4i32"), "y / 4i32");
+            assert_eq!(reduce("x * 8"), "x * 8");
+        });
+    }
+
+    #[test]
+    fn keeps_multiplications_which_may_overflow() {
+        create_session_if_not_set_then(|_| {
+            assert_eq!(reduce("x * 8u32"), "x * 8u32");
+            assert_eq!(reduce("z * 128u8"), "z * 128u8");
+            assert_eq!(reduce("y * 4i32"), "y * 4i32");
+        });
+    }
+}
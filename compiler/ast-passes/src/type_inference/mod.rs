@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Infers the types of `let` and `const` bindings declared without a type annotation,
+//! and of integer literals declared without a suffix.
+
+pub mod type_inferrer;
+pub use type_inferrer::*;
+
+use leo_ast::{Ast, AstPass, Program};
+use leo_errors::Result;
+
+impl AstPass for TypeInferrer {
+    fn do_pass(mut self, mut ast: Program) -> Result<Ast> {
+        self.infer_program(&mut ast);
+        Ok(Ast::new(ast))
+    }
+}
@@ -0,0 +1,841 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Fills in the missing types of definition statements from their initializers,
+//! and the types of unsuffixed integer literals from their context.
+//! For example, `let x = 1u8 + y;` is given the type `u8`, and the `3` of `let z: u64 = 3;` becomes `3u64`.
+
+use leo_ast::*;
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The type of the unsuffixed integer literals whose type has no context to come from.
+const DEFAULT_INTEGER_TYPE: IntegerType = IntegerType::U32;
+
+/// Infers the type of every definition statement which has no explicit type.
+///
+/// The type of an initializer is derived from literal suffixes, casts, the types of
+/// variables already in scope, circuit initializers, and the outputs of called functions.
+/// Bindings whose type cannot be inferred are left unannotated.
+///
+/// Unsuffixed integer literals take the integer type their context expects: the type annotation of a definition,
+/// the output type of the function they are returned from, the type of the variable they are assigned to,
+/// the type of the parameter or circuit member they are passed to, or the type of the other operand of a binary operation.
+/// The expected type flows through operators, ternaries, arrays, and tuples.
+/// The literals of a definition or loop bound without such a context default to `u32`, as `i` in `let i = 1;`.
+/// Other literals without a context, such as the arguments of `console.log`, are left unsuffixed.
+#[derive(Default)]
+pub struct TypeInferrer {
+    /// The signatures of free functions.
+    functions: IndexMap<Symbol, Signature>,
+    /// The types of the variables and constants of each circuit.
+    circuit_variables: IndexMap<Symbol, IndexMap<Symbol, Type>>,
+    /// The signatures of the member functions of each circuit.
+    circuit_functions: IndexMap<Symbol, IndexMap<Symbol, Signature>>,
+    /// The variables in scope, innermost scope last.
+    scopes: Vec<IndexMap<Symbol, Type>>,
+    /// The output type of the function being inferred.
+    output: Option<Type>,
+    /// The types of the expressions visited so far, if they are recorded.
+    expression_types: Option<ExpressionTypes>,
+}
+
+/// The types of the parameters and the output of a function.
+#[derive(Clone)]
+struct Signature {
+    /// The types of the parameters, excluding `self`.
+    inputs: Vec<Type>,
+    output: Type,
+}
+
+impl Signature {
+    fn new(function: &Function) -> Self {
+        Self {
+            inputs: function
+                .input
+                .iter()
+                .filter_map(|input| match input {
+                    FunctionInput::Variable(variable) => Some(variable.type_.clone()),
+                    _ => None,
+                })
+                .collect(),
+            output: function.output.clone().unwrap_or_else(|| Type::Tuple(vec![])),
+        }
+    }
+}
+
+/// The known types of the expressions of a program, keyed by the IDs of the expressions.
+#[derive(Clone, Debug, Default)]
+pub struct ExpressionTypes {
+    /// The span and type of each expression.
+    types: IndexMap<NodeID, (Span, Type)>,
+}
+
+impl ExpressionTypes {
+    /// Returns the types of the expressions of `program`, e.g., of the AST the checks returned,
+    /// as far as the [`TypeInferrer`] can tell them.
+    pub fn new(program: &Program) -> Self {
+        let mut inferrer = TypeInferrer {
+            expression_types: Some(Self::default()),
+            ..Default::default()
+        };
+        inferrer.infer_program(&mut program.clone());
+        inferrer.expression_types.unwrap_or_default()
+    }
+
+    /// Returns the type of the expression `id`.
+    pub fn get(&self, id: NodeID) -> Option<&Type> {
+        self.types.get(&id).map(|(_, type_)| type_)
+    }
+
+    /// Returns the span and type of the innermost expression of a known type at the 1-based line and column `position`.
+    pub fn at(&self, (line, column): (usize, usize)) -> Option<(&Span, &Type)> {
+        self.types
+            .values()
+            .map(|(span, type_)| (span, type_))
+            .filter(|(span, _)| {
+                (span.line_start, span.col_start) <= (line, column) && (line, column) <= (span.line_stop, span.col_stop)
+            })
+            .max_by_key(|(span, _)| {
+                (
+                    (span.line_start, span.col_start),
+                    std::cmp::Reverse((span.line_stop, span.col_stop)),
+                )
+            })
+    }
+}
+
+/// Records the types of expressions and their subexpressions.
+struct ExpressionRecorder<'a> {
+    inferrer: &'a TypeInferrer,
+    types: &'a mut ExpressionTypes,
+}
+
+impl ExpressionVisitor for ExpressionRecorder<'_> {
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Some(type_) = self.inferrer.type_of(expression) {
+            self.types
+                .types
+                .insert(expression.id(), (expression.span().clone(), type_));
+        }
+        walk_expression(self, expression);
+    }
+}
+
+impl TypeInferrer {
+    /// Returns the typed AST of `ast`, e.g., of the AST the checks returned,
+    /// with the types of its expressions as far as they can be inferred.
+    pub fn typed_ast(ast: Ast) -> TypedAst {
+        let types = ExpressionTypes::new(ast.as_repr())
+            .types
+            .into_iter()
+            .map(|(id, (_, type_))| (id, type_))
+            .collect();
+        TypedAst::new(ast, types)
+    }
+
+    /// Infers the types of all definitions in `program`.
+    pub fn infer_program(&mut self, program: &mut Program) {
+        self.functions = program
+            .functions
+            .iter()
+            .map(|(name, function)| (name.name, Signature::new(function)))
+            .collect();
+        for (name, circuit) in program.circuits.iter() {
+            let mut variables = IndexMap::new();
+            let mut functions = IndexMap::new();
+            for member in circuit.members.iter() {
+                match member {
+                    CircuitMember::CircuitConst(identifier, type_, _)
+                    | CircuitMember::CircuitVariable(identifier, type_) => {
+                        variables.insert(identifier.name, type_.clone());
+                    }
+                    CircuitMember::CircuitFunction(function) => {
+                        functions.insert(function.name(), Signature::new(function));
+                    }
+                }
+            }
+            self.circuit_variables.insert(name.name, variables);
+            self.circuit_functions.insert(name.name, functions);
+        }
+
+        self.scopes.push(IndexMap::new());
+        for definition in program.global_consts.values_mut() {
+            self.infer_definition(definition);
+        }
+
+        for function in program.functions.values_mut() {
+            self.infer_function(function, None);
+        }
+        for (name, circuit) in program.circuits.iter_mut() {
+            for member in circuit.members.iter_mut() {
+                match member {
+                    CircuitMember::CircuitConst(_, type_, value) => self.type_literals(value, Some(&*type_)),
+                    CircuitMember::CircuitVariable(..) => {}
+                    CircuitMember::CircuitFunction(function) => self.infer_function(function, Some(name)),
+                }
+            }
+        }
+        self.scopes.pop();
+    }
+
+    fn infer_function(&mut self, function: &mut Function, circuit: Option<&Identifier>) {
+        let mut inputs = IndexMap::new();
+        for input in function.input.iter() {
+            match input {
+                FunctionInput::Variable(variable) => {
+                    inputs.insert(variable.identifier.name, variable.type_.clone());
+                }
+                _ => {
+                    if let Some(circuit) = circuit {
+                        inputs.insert(sym::SelfLower, Type::Identifier(circuit.clone()));
+                    }
+                }
+            }
+        }
+        self.scopes.push(inputs);
+        self.output = Some(Signature::new(function).output);
+        self.infer_block(&mut function.block);
+        self.output = None;
+        self.scopes.pop();
+    }
+
+    fn infer_block(&mut self, block: &mut Block) {
+        self.scopes.push(IndexMap::new());
+        block
+            .statements
+            .iter_mut()
+            .for_each(|statement| self.infer_statement(statement));
+        self.scopes.pop();
+    }
+
+    fn infer_statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Definition(definition) => self.infer_definition(definition),
+            Statement::Conditional(conditional) => {
+                self.type_literals(&mut conditional.condition, Some(&Type::Boolean));
+                self.record(&conditional.condition);
+                self.infer_block(&mut conditional.block);
+                if let Some(next) = conditional.next.as_deref_mut() {
+                    self.infer_statement(next);
+                }
+            }
+            Statement::Iteration(iteration) => {
+                let type_ = self.type_of(&iteration.start).or_else(|| self.type_of(&iteration.stop));
+                self.type_literals(&mut iteration.start, type_.as_ref());
+                self.type_literals(&mut iteration.stop, type_.as_ref());
+                if type_.is_none() {
+                    self.default_literals(&mut iteration.start);
+                    self.default_literals(&mut iteration.stop);
+                }
+                let type_ = type_.or_else(|| self.type_of(&iteration.start));
+                self.record(&iteration.start);
+                self.record(&iteration.stop);
+                let mut scope = IndexMap::new();
+                if let Some(type_) = type_ {
+                    scope.insert(iteration.variable.name, type_);
+                }
+                self.scopes.push(scope);
+                self.infer_block(&mut iteration.block);
+                self.scopes.pop();
+            }
+            Statement::Block(block) => self.infer_block(block),
+            Statement::Return(return_) => {
+                self.type_literals(&mut return_.expression, self.output.as_ref());
+                self.record(&return_.expression);
+            }
+            Statement::Assign(assign) => {
+                let type_ = self.type_of_assignee(&assign.assignee);
+                self.type_literals(&mut assign.value, type_.as_ref());
+                for access in assign.assignee.accesses.iter() {
+                    match access {
+                        AssigneeAccess::ArrayIndex(index) => self.record(index),
+                        AssigneeAccess::ArrayRange(left, right) => {
+                            left.iter().chain(right.iter()).for_each(|bound| self.record(bound))
+                        }
+                        AssigneeAccess::Tuple(..) | AssigneeAccess::Member(_) => {}
+                    }
+                }
+                self.record(&assign.value);
+            }
+            Statement::Console(console) => match &mut console.function {
+                ConsoleFunction::Assert(expression) => {
+                    self.type_literals(expression, Some(&Type::Boolean));
+                    self.record(expression);
+                }
+                ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => {
+                    for parameter in args.parameters.iter_mut() {
+                        self.type_literals(parameter, None);
+                        self.record(parameter);
+                    }
+                }
+            },
+            Statement::Expression(expression) => {
+                self.type_literals(&mut expression.expression, None);
+                self.record(&expression.expression);
+            }
+        }
+    }
+
+    /// Records the types of `expression` and its subexpressions, if they are recorded.
+    fn record(&mut self, expression: &Expression) {
+        if let Some(mut types) = self.expression_types.take() {
+            ExpressionRecorder {
+                inferrer: self,
+                types: &mut types,
+            }
+            .visit_expression(expression);
+            self.expression_types = Some(types);
+        }
+    }
+
+    fn infer_definition(&mut self, definition: &mut DefinitionStatement) {
+        self.type_literals(&mut definition.value, definition.type_.as_ref());
+        if definition.type_.is_none() {
+            self.default_literals(&mut definition.value);
+        }
+        self.record(&definition.value);
+        if definition.type_.is_none() {
+            definition.type_ = self.type_of(&definition.value);
+        }
+
+        let scope = self.scopes.last_mut().expect("a scope is always open");
+        match (&definition.type_, definition.variable_names.as_slice()) {
+            (Some(type_), [variable]) => {
+                scope.insert(variable.identifier.name, type_.clone());
+            }
+            (Some(Type::Tuple(types)), variables) if types.len() == variables.len() => {
+                for (variable, type_) in variables.iter().zip(types.iter()) {
+                    scope.insert(variable.identifier.name, type_.clone());
+                }
+            }
+            // Shadow any outer binding of the same name whose type no longer applies.
+            (_, variables) => {
+                for variable in variables {
+                    scope.insert(variable.identifier.name, Type::Err);
+                }
+            }
+        }
+    }
+
+    /// Gives the unsuffixed integer literals of `expression` the integer type their context expects,
+    /// where `expected` is the type the context of `expression` expects, if any.
+    fn type_literals(&self, expression: &mut Expression, expected: Option<&Type>) {
+        match expression {
+            Expression::Value(literal) => {
+                if let (ValueExpression::Implicit(value, span), Some(Type::IntegerType(type_))) =
+                    (&literal.value, expected)
+                {
+                    literal.value = ValueExpression::Integer(*type_, value.clone(), span.clone());
+                }
+            }
+            Expression::Binary(binary) => {
+                let (left, right) = match binary.op {
+                    BinaryOperation::And | BinaryOperation::Or => (Some(Type::Boolean), Some(Type::Boolean)),
+                    // The operands of a comparison have the same type, whatever the expected type is.
+                    BinaryOperation::Eq
+                    | BinaryOperation::Ne
+                    | BinaryOperation::Ge
+                    | BinaryOperation::Gt
+                    | BinaryOperation::Le
+                    | BinaryOperation::Lt => {
+                        let operand = self.type_of(&binary.left).or_else(|| self.type_of(&binary.right));
+                        (operand.clone(), operand)
+                    }
+                    // The right operand of a shift or power may have a different type.
+                    BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned | BinaryOperation::Pow => {
+                        (expected.cloned().or_else(|| self.type_of(&binary.left)), None)
+                    }
+                    _ => {
+                        let operand = expected
+                            .cloned()
+                            .or_else(|| self.type_of(&binary.left))
+                            .or_else(|| self.type_of(&binary.right));
+                        (operand.clone(), operand)
+                    }
+                };
+                self.type_literals(&mut binary.left, left.as_ref());
+                self.type_literals(&mut binary.right, right.as_ref());
+            }
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::Not => self.type_literals(&mut unary.inner, Some(&Type::Boolean)),
+                UnaryOperation::Negate | UnaryOperation::BitNot => self.type_literals(&mut unary.inner, expected),
+            },
+            Expression::Ternary(ternary) => {
+                let branch = expected
+                    .cloned()
+                    .or_else(|| self.type_of(&ternary.if_true))
+                    .or_else(|| self.type_of(&ternary.if_false));
+                self.type_literals(&mut ternary.condition, Some(&Type::Boolean));
+                self.type_literals(&mut ternary.if_true, branch.as_ref());
+                self.type_literals(&mut ternary.if_false, branch.as_ref());
+            }
+            Expression::Cast(cast) => self.type_literals(&mut cast.inner, None),
+            Expression::Access(access) => self.type_access_literals(access),
+            Expression::ArrayInline(array) => {
+                // The elements have the same type, which another element may tell.
+                let elements_type = expected.and_then(|type_| element_type(type_, 1)).or_else(|| {
+                    array.elements.iter().find_map(|element| match element {
+                        SpreadOrExpression::Expression(element) => self.type_of(element),
+                        SpreadOrExpression::Spread(_) => None,
+                    })
+                });
+                for element in array.elements.iter_mut() {
+                    match element {
+                        SpreadOrExpression::Expression(element) => self.type_literals(element, elements_type.as_ref()),
+                        SpreadOrExpression::Spread(spread) => self.type_literals(spread, expected),
+                    }
+                }
+            }
+            Expression::ArrayInit(array) => {
+                let element = expected.and_then(|type_| element_type(type_, array.dimensions.0.len()));
+                self.type_literals(&mut array.element, element.as_ref());
+            }
+            Expression::TupleInit(tuple) => {
+                let types = match expected {
+                    Some(Type::Tuple(types)) if types.len() == tuple.elements.len() => Some(types),
+                    _ => None,
+                };
+                for (index, element) in tuple.elements.iter_mut().enumerate() {
+                    self.type_literals(element, types.map(|types| &types[index]));
+                }
+            }
+            Expression::CircuitInit(init) => {
+                let variables = self.circuit_variables.get(&init.name.name);
+                for member in init.members.iter_mut() {
+                    if let Some(expression) = &mut member.expression {
+                        let type_ = variables.and_then(|variables| variables.get(&member.identifier.name));
+                        self.type_literals(expression, type_);
+                    }
+                }
+            }
+            Expression::Call(call) => {
+                let inputs = self.callee(&call.function).map(|signature| signature.inputs.clone());
+                if let Expression::Access(AccessExpression::Member(access)) = &mut *call.function {
+                    self.type_literals(&mut access.inner, None);
+                }
+                for (index, argument) in call.arguments.iter_mut().enumerate() {
+                    let input = inputs.as_ref().and_then(|inputs| inputs.get(index));
+                    self.type_literals(argument, input);
+                }
+            }
+            Expression::Identifier(_) | Expression::Err(_) => {}
+        }
+    }
+
+    /// Gives the unsuffixed integer literals of `access` the integer type their context expects.
+    /// Indices and range bounds expect no particular type.
+    /// Gives the parts of `expression` built from unsuffixed integer literals alone the default integer type.
+    fn default_literals(&self, expression: &mut Expression) {
+        if self.type_of(expression).is_some() {
+            return;
+        }
+        match expression {
+            Expression::TupleInit(tuple) => tuple
+                .elements
+                .iter_mut()
+                .for_each(|element| self.default_literals(element)),
+            Expression::ArrayInline(array) => array.elements.iter_mut().for_each(|element| match element {
+                SpreadOrExpression::Expression(element) => self.default_literals(element),
+                SpreadOrExpression::Spread(_) => {}
+            }),
+            Expression::ArrayInit(array) => self.default_literals(&mut array.element),
+            expression if is_unsuffixed(expression) => {
+                self.type_literals(expression, Some(&Type::IntegerType(DEFAULT_INTEGER_TYPE)))
+            }
+            _ => {}
+        }
+    }
+
+    fn type_access_literals(&self, access: &mut AccessExpression) {
+        match access {
+            AccessExpression::Array(access) => {
+                self.type_literals(&mut access.array, None);
+                self.type_literals(&mut access.index, None);
+            }
+            AccessExpression::ArrayRange(access) => {
+                self.type_literals(&mut access.array, None);
+                for bound in access.left.iter_mut().chain(access.right.iter_mut()) {
+                    self.type_literals(bound, None);
+                }
+            }
+            AccessExpression::Member(access) => self.type_literals(&mut access.inner, None),
+            AccessExpression::Tuple(access) => self.type_literals(&mut access.tuple, None),
+            AccessExpression::Static(_) => {}
+        }
+    }
+
+    /// Returns the type of the place `assignee` assigns to, if it can be determined.
+    fn type_of_assignee(&self, assignee: &Assignee) -> Option<Type> {
+        let mut type_ = self.lookup(assignee.identifier.name)?;
+        for access in assignee.accesses.iter() {
+            type_ = match (access, type_) {
+                (AssigneeAccess::ArrayIndex(_), type_) => element_type(&type_, 1)?,
+                (AssigneeAccess::Member(member), Type::Identifier(circuit)) => {
+                    self.circuit_variables.get(&circuit.name)?.get(&member.name).cloned()?
+                }
+                (AssigneeAccess::Tuple(index, _), Type::Tuple(mut types)) => {
+                    let index = index.value.parse::<usize>().ok()?;
+                    (index < types.len()).then(|| types.swap_remove(index))?
+                }
+                _ => return None,
+            };
+        }
+        Some(type_)
+    }
+
+    /// Looks up the type of the variable `name` in the innermost scope that declares it.
+    fn lookup(&self, name: Symbol) -> Option<Type> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&name))
+            .filter(|type_| **type_ != Type::Err)
+            .cloned()
+    }
+
+    /// Returns the type of `expression`, if it can be determined without context.
+    fn type_of(&self, expression: &Expression) -> Option<Type> {
+        match expression {
+            Expression::Identifier(identifier) => self.lookup(identifier.name),
+            Expression::Value(literal) => literal.value.type_(),
+            Expression::Binary(binary) => match binary.op.class() {
+                BinaryOperationClass::Boolean => Some(Type::Boolean),
+                BinaryOperationClass::Numeric => match binary.op {
+                    // The right operand of a shift or power may have a different type.
+                    BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned | BinaryOperation::Pow => {
+                        self.type_of(&binary.left)
+                    }
+                    _ => self.type_of(&binary.left).or_else(|| self.type_of(&binary.right)),
+                },
+            },
+            Expression::Unary(unary) => match unary.op {
+                UnaryOperation::Not => Some(Type::Boolean),
+                UnaryOperation::Negate | UnaryOperation::BitNot => self.type_of(&unary.inner),
+            },
+            Expression::Ternary(ternary) => self
+                .type_of(&ternary.if_true)
+                .or_else(|| self.type_of(&ternary.if_false)),
+            Expression::Cast(cast) => Some(cast.target_type.clone()),
+            Expression::Access(access) => self.type_of_access(access),
+            Expression::ArrayInline(array) => {
+                let mut elements = array.elements.iter();
+                let element = match elements.next()? {
+                    SpreadOrExpression::Expression(element) => self.type_of(element)?,
+                    SpreadOrExpression::Spread(_) => return None,
+                };
+                // The length is only known if there are no spreads.
+                if elements.any(|element| matches!(element, SpreadOrExpression::Spread(_))) {
+                    return None;
+                }
+                let length = PositiveNumber {
+                    value: array.elements.len().to_string(),
+                };
+                Some(Type::Array(Box::new(element), ArrayDimensions::single(length)))
+            }
+            Expression::ArrayInit(array) => Some(Type::Array(
+                Box::new(self.type_of(&array.element)?),
+                array.dimensions.clone(),
+            )),
+            Expression::TupleInit(tuple) => Some(Type::Tuple(
+                tuple
+                    .elements
+                    .iter()
+                    .map(|element| self.type_of(element))
+                    .collect::<Option<Vec<_>>>()?,
+            )),
+            Expression::CircuitInit(init) => Some(Type::Identifier(init.name.clone())),
+            Expression::Call(call) => self.callee(&call.function).map(|signature| signature.output.clone()),
+            Expression::Err(_) => None,
+        }
+    }
+
+    fn type_of_access(&self, access: &AccessExpression) -> Option<Type> {
+        match access {
+            AccessExpression::Array(access) => element_type(&self.type_of(&access.array)?, 1),
+            // The length of a slice is only known once its bounds are evaluated.
+            AccessExpression::ArrayRange(_) => None,
+            AccessExpression::Member(access) => match &access.type_ {
+                Some(type_) => Some(type_.clone()),
+                None => match self.type_of(&access.inner)? {
+                    Type::Identifier(circuit) => self
+                        .circuit_variables
+                        .get(&circuit.name)?
+                        .get(&access.name.name)
+                        .cloned(),
+                    _ => None,
+                },
+            },
+            AccessExpression::Tuple(access) => match self.type_of(&access.tuple)? {
+                Type::Tuple(mut types) => {
+                    let index = access.index.value.parse::<usize>().ok()?;
+                    (index < types.len()).then(|| types.swap_remove(index))
+                }
+                _ => None,
+            },
+            AccessExpression::Static(access) => match &*access.inner {
+                Expression::Identifier(circuit) => self
+                    .circuit_variables
+                    .get(&circuit.name)?
+                    .get(&access.name.name)
+                    .cloned(),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the signature of the function `function` evaluates to, if it is known.
+    fn callee(&self, function: &Expression) -> Option<&Signature> {
+        match function {
+            Expression::Identifier(function) => self.functions.get(&function.name),
+            Expression::Access(AccessExpression::Static(access)) => match &*access.inner {
+                Expression::Identifier(circuit) => self.circuit_function(circuit.name, access.name.name),
+                _ => None,
+            },
+            Expression::Access(AccessExpression::Member(access)) => match self.type_of(&access.inner)? {
+                Type::Identifier(circuit) => self.circuit_function(circuit.name, access.name.name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn circuit_function(&self, circuit: Symbol, function: Symbol) -> Option<&Signature> {
+        self.circuit_functions.get(&circuit)?.get(&function)
+    }
+}
+
+/// Returns whether `expression` computes an integer from unsuffixed literals alone.
+fn is_unsuffixed(expression: &Expression) -> bool {
+    match expression {
+        Expression::Value(literal) => matches!(literal.value, ValueExpression::Implicit(..)),
+        Expression::Binary(binary) => {
+            binary.op.class() == BinaryOperationClass::Numeric
+                && is_unsuffixed(&binary.left)
+                && match binary.op {
+                    // The right operand of a shift or power may have a different type.
+                    BinaryOperation::Shl | BinaryOperation::Shr | BinaryOperation::ShrSigned | BinaryOperation::Pow => {
+                        true
+                    }
+                    _ => is_unsuffixed(&binary.right),
+                }
+        }
+        Expression::Unary(unary) => unary.op != UnaryOperation::Not && is_unsuffixed(&unary.inner),
+        Expression::Ternary(ternary) => is_unsuffixed(&ternary.if_true) && is_unsuffixed(&ternary.if_false),
+        _ => false,
+    }
+}
+
+/// Returns the type of the elements `depth` dimensions deep into the array type `type_`, if it is one.
+fn element_type(type_: &Type, depth: usize) -> Option<Type> {
+    match type_ {
+        Type::Array(element, dimensions) => {
+            let mut dimensions = dimensions.clone();
+            for _ in 0..depth {
+                dimensions.remove_first()?;
+            }
+            if dimensions.0.is_empty() {
+                Some(*element.clone())
+            } else {
+                Some(Type::Array(element.clone(), dimensions))
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the inferred type of each definition in `main`, in order.
+    fn inferred(source: &str) -> Vec<Option<String>> {
+        let mut program = parse(source);
+        TypeInferrer::default().infer_program(&mut program);
+        let main = program.functions.values().next().unwrap();
+        main.block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Definition(definition) => Some(definition.type_.as_ref().map(|t| t.to_string())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn infers_from_initializers() {
+        create_session_if_not_set_then(|_| {
+            let types = inferred(
+                "function main(y: u8) {
+                    let a = 1u8 + y;
+                    let b = 2 * a;
+                    let c = a == b;
+                    let d = (a, c);
+                    let e = d.1;
+                    let f = [a; 3];
+                    let g = f[0] as u32;
+                    let h = foo();
+                    let i = 1;
+                    let j = (2, 3 * 4, a);
+                    let k = z + 5;
+                }
+                function foo() -> field { return 0field; }",
+            );
+            let expected = [
+                "u8",
+                "u8",
+                "bool",
+                "(u8, bool)",
+                "bool",
+                "[u8; 3]",
+                "u32",
+                "field",
+                "u32",
+                "(u32, u32, u8)",
+            ];
+            let mut expected: Vec<Option<String>> = expected.iter().map(|t| Some(t.to_string())).collect();
+            // The type of `z` is unknown, so `5` takes no default.
+            expected.push(None);
+            assert_eq!(types, expected);
+        });
+    }
+
+    #[test]
+    fn types_literals_from_context() {
+        /// Collects the literals of a program, in order.
+        struct Literals(Vec<String>);
+
+        impl ExpressionVisitor for Literals {
+            fn visit_value(&mut self, value: &LiteralExpression) {
+                self.0.push(value.to_string());
+            }
+        }
+
+        impl StatementVisitor for Literals {}
+
+        impl ProgramVisitor for Literals {}
+
+        create_session_if_not_set_then(|_| {
+            let source = "circuit Pair {
+                    a: u16,
+                    b: u32,
+                    function scale(self, k: u16) -> u16 { return self.a * k; }
+                }
+                function double(x: u8) -> u8 { return x * 2; }
+                function main(y: i8) -> u64 {
+                    let a: u64 = 3;
+                    let b = y + 1;
+                    let c = double(4);
+                    let p = Pair { a: 5, b: 6 };
+                    let d = p.scale(7);
+                    let e: [u8; 2] = [8, 9];
+                    let f: (u8, bool) = (10, true);
+                    let g = 11;
+                    if y > 12 { return 13; }
+                    return a * 14;
+                }";
+            let mut program = parse(source);
+            TypeInferrer::default().infer_program(&mut program);
+
+            let mut literals = Literals(Vec::new());
+            literals.visit_program(&program);
+            let expected = [
+                "2u8", "3u64", "1i8", "4u8", "5u16", "6u32", "7u16", "8u8", "9u8", "10u8", "true", "11u32", "12i8",
+                "13u64", "14u64",
+            ];
+            assert_eq!(literals.0, expected);
+        });
+    }
+
+    #[test]
+    fn records_expression_types() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main(y: u8) -> bool {\n    let a = (y, 1u32);\n    return a.1 > 2u32;\n}";
+            let program = parse(source);
+            let types = ExpressionTypes::new(&program);
+
+            let type_at = |position| types.at(position).map(|(_, type_)| type_.to_string());
+            assert_eq!(type_at((2, 14)), Some("u8".to_string()));
+            assert_eq!(type_at((2, 13)), Some("(u8, u32)".to_string()));
+            // `a` is typed by the definition, and `a.1` by the type of `a`.
+            assert_eq!(type_at((3, 12)), Some("(u8, u32)".to_string()));
+            assert_eq!(type_at((3, 14)), Some("u32".to_string()));
+            assert_eq!(type_at((3, 17)), Some("bool".to_string()));
+            assert_eq!(type_at((1, 1)), None);
+        });
+    }
+
+    #[test]
+    fn keys_expression_types_by_id() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main(a: (u8, u32)) -> bool {\n    return a.1 > 2u32;\n}";
+            let mut program = parse(source);
+            // Nodes a pass constructs may share a span.
+            let function = program.functions.values_mut().next().unwrap();
+            let comparison = match &mut function.block.statements[0] {
+                Statement::Return(ReturnStatement {
+                    expression: Expression::Binary(comparison),
+                    ..
+                }) => comparison,
+                _ => unreachable!(),
+            };
+            comparison.left.set_span(comparison.span.clone());
+            let (comparison, access) = (comparison.id, comparison.left.id());
+
+            let types = ExpressionTypes::new(&program);
+            assert_eq!(types.get(comparison), Some(&Type::Boolean));
+            assert_eq!(types.get(access), Some(&Type::IntegerType(IntegerType::U32)));
+        });
+    }
+
+    #[test]
+    fn annotates_typed_ast() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main(a: u8) -> u8 {\n    let b = a + 1;\n    return b * 2u8;\n}";
+            let program = parse(source);
+            let typed = TypeInferrer::typed_ast(Ast::new(program));
+
+            let returned = match &typed.as_repr().functions.values().next().unwrap().block.statements[1] {
+                Statement::Return(return_) => &return_.expression,
+                _ => unreachable!(),
+            };
+            assert_eq!(typed.type_of(returned), Some(Type::IntegerType(IntegerType::U8)));
+
+            let json = typed.to_json_value().unwrap();
+            let types: Vec<(&str, &str)> = json["types"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|entry| (entry["expression"].as_str().unwrap(), entry["type"].as_str().unwrap()))
+                .collect();
+            assert_eq!(
+                types,
+                [
+                    ("a + 1", "u8"),
+                    ("a", "u8"),
+                    ("1", "u8"),
+                    ("b * 2u8", "u8"),
+                    ("b", "u8"),
+                    ("2u8", "u8")
+                ]
+            );
+        });
+    }
+}
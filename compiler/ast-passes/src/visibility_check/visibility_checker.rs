@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::*;
+use leo_errors::{emitter::Handler, AstError, LeoError, Result};
+use leo_span::Symbol;
+
+/// Finds uses of private functions and circuits from where they are not visible.
+///
+/// A private circuit function may only be called by the functions of its circuit, and the
+/// private functions and circuits of a package may not be imported. Calls through a value,
+/// such as `foo.bar()`, need the type of the value and are not checked.
+pub struct VisibilityChecker<'a> {
+    /// The handler all errors but the last are emitted to.
+    handler: &'a Handler,
+    /// The program being checked.
+    program: &'a Program,
+    /// The circuit whose members are visited, if any.
+    circuit: Option<Symbol>,
+    /// The errors found so far.
+    errors: Vec<LeoError>,
+}
+
+impl<'a> VisibilityChecker<'a> {
+    /// Returns a checker of `program`, emitting its errors to `handler`.
+    pub fn new(handler: &'a Handler, program: &'a Program) -> Self {
+        Self {
+            handler,
+            program,
+            circuit: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Checks the imports and the calls of the program.
+    /// All errors but the last are emitted to the handler, and the last is returned.
+    pub fn check_program(mut self) -> Result<()> {
+        for import in self.program.import_statements.iter() {
+            self.check_import(&[], &import.tree);
+        }
+        self.visit_program(self.program);
+
+        let last = self.errors.pop();
+        for error in self.errors {
+            self.handler.emit_err(error);
+        }
+        last.map_or(Ok(()), Err)
+    }
+
+    /// Records an error for each private item imported by `tree`, whose path starts with `prefix`.
+    fn check_import(&mut self, prefix: &[Symbol], tree: &ImportTree) {
+        let path: Vec<Symbol> = prefix
+            .iter()
+            .copied()
+            .chain(tree.base.iter().map(|identifier| identifier.name))
+            .collect();
+        match &tree.kind {
+            // Globs only import the public items.
+            ImportTreeKind::Glob { .. } => {}
+            ImportTreeKind::Leaf { .. } => {
+                let (name, package) = path.split_last().unwrap();
+                let package_program = match self.program.imports.get(package) {
+                    Some(program) => program,
+                    None => return,
+                };
+                let private_function = package_program
+                    .functions
+                    .values()
+                    .any(|function| function.name() == *name && function.is_private());
+                let private_circuit = package_program
+                    .circuits
+                    .iter()
+                    .any(|(circuit_name, circuit)| circuit_name.name == *name && circuit.is_private());
+                if private_function || private_circuit {
+                    let package = package.iter().map(|part| part.to_string()).collect::<Vec<_>>();
+                    self.errors
+                        .push(AstError::private_import(name, package.join("."), &tree.span).into());
+                }
+            }
+            ImportTreeKind::Nested { tree } => {
+                for tree in tree.iter() {
+                    self.check_import(&path, tree);
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the function `name` of the circuit `circuit` is private.
+    fn is_private_circuit_function(&self, circuit: Symbol, name: Symbol) -> bool {
+        self.program
+            .circuits
+            .iter()
+            .filter(|(circuit_name, _)| circuit_name.name == circuit)
+            .flat_map(|(_, circuit)| circuit.members.iter())
+            .any(|member| matches!(member, CircuitMember::CircuitFunction(function) if function.name() == name && function.is_private()))
+    }
+}
+
+impl ExpressionVisitor for VisibilityChecker<'_> {
+    fn visit_call(&mut self, call: &CallExpression) {
+        // `Self` is replaced with the name of the circuit in canonical form.
+        if let Expression::Access(AccessExpression::Static(access)) = &*call.function {
+            if let Expression::Identifier(circuit) = &*access.inner {
+                if self.circuit != Some(circuit.name)
+                    && self.is_private_circuit_function(circuit.name, access.name.name)
+                {
+                    self.errors
+                        .push(AstError::private_circuit_function(circuit, &access.name, &call.span).into());
+                }
+            }
+        }
+        self.visit_expression(&call.function);
+        call.arguments.iter().for_each(|e| self.visit_expression(e));
+    }
+}
+
+impl StatementVisitor for VisibilityChecker<'_> {}
+
+impl ProgramVisitor for VisibilityChecker<'_> {
+    fn visit_program(&mut self, program: &Program) {
+        program
+            .global_consts
+            .values()
+            .for_each(|definition| self.visit_definition(definition));
+        for (name, circuit) in program.circuits.iter() {
+            self.circuit = Some(name.name);
+            self.visit_circuit(circuit);
+        }
+        self.circuit = None;
+        program
+            .functions
+            .values()
+            .for_each(|function| self.visit_function(function));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::parse;
+    use crate::Canonicalizer;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const CIRCUIT: &str = "
+        circuit Foo {
+            x: u8;
+            private function helper(a: u8) -> u8 { return a; }
+            public function get(self) -> u8 { return Self::helper(self.x); }
+        }";
+
+    fn check(source: &str) -> Result<()> {
+        let handler = Handler::default();
+        let program = parse(source);
+        let program = Canonicalizer::do_pass(Default::default(), program).unwrap().into_repr();
+        VisibilityChecker::new(&handler, &program).check_program()
+    }
+
+    #[test]
+    fn allows_calls_from_the_circuit() {
+        create_session_if_not_set_then(|_| {
+            let source = format!("{} function main() -> u8 {{ return Foo {{ x: 1u8 }}.get(); }}", CIRCUIT);
+            assert!(check(&source).is_ok());
+        });
+    }
+
+    #[test]
+    fn rejects_calls_from_outside_the_circuit() {
+        create_session_if_not_set_then(|_| {
+            let source = format!("{} function main() -> u8 {{ return Foo::helper(1u8); }}", CIRCUIT);
+            let error = check(&source).unwrap_err().to_string();
+            assert!(error.contains("function `Foo::helper` is private"));
+        });
+    }
+
+    #[test]
+    fn rejects_private_imports() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let mut program =
+                leo_parser::parse(&handler, "test", "import lib.(shown, hidden); function main() {}").unwrap();
+            let library = leo_parser::parse(
+                &handler,
+                "lib",
+                "function shown() {} private function hidden() {} private circuit Unused {}",
+            )
+            .unwrap();
+            program.imports.insert(vec![Symbol::intern("lib")], library);
+
+            let error = VisibilityChecker::new(&handler, &program)
+                .check_program()
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("`hidden` is private to package `lib`"));
+        });
+    }
+}
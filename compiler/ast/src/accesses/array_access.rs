@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 use leo_span::Span;
 
 use std::fmt;
@@ -30,6 +30,9 @@ pub struct ArrayAccess {
     pub index: Box<Expression>,
     /// The span of the entire expression `array[index]`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayAccess {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Identifier, Node};
+use crate::{Expression, Identifier, Node, NodeID};
 use leo_span::Span;
 
 use std::fmt;
@@ -32,6 +32,9 @@ pub struct MemberAccess {
     pub name: Identifier,
     /// The span covering all of `inner.name`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
     // FIXME(Centril): Type information shouldn't be injected into an AST,
     // so this field should eventually be removed.
     pub type_: Option<crate::Type>,
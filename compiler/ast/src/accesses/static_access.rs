@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Identifier, Node, Type};
+use crate::{Expression, Identifier, Node, NodeID, Type};
 
 use leo_span::Span;
 
@@ -35,6 +35,9 @@ pub struct StaticAccess {
     pub type_: Option<Type>,
     /// The span for the entire expression `inner::name`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for StaticAccess {
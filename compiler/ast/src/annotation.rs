@@ -29,10 +29,13 @@ pub struct Annotation {
     pub name: Identifier,
     /// Arguments for the annotation, if any.
     pub arguments: Vec<Symbol>,
+    /// Arguments setting a name to a string or an integer, e.g., `network = "testnet"`, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<(Symbol, String)>,
 }
 
 /// The set of allowed annotations.
-const ALLOWED_ANNOTATIONS: &[Symbol] = &[sym::test];
+const ALLOWED_ANNOTATIONS: &[Symbol] = &[sym::allow, sym::cfg, sym::Const, sym::program, sym::test];
 
 impl Annotation {
     /// Is the annotation valid?
@@ -47,6 +50,9 @@ impl fmt::Display for Annotation {
         for arg in &self.arguments {
             write!(f, "{:},", arg)?;
         }
+        for (name, value) in &self.values {
+            write!(f, "{:} = {:?},", name, value)?;
+        }
         write!(f, ")")
     }
 }
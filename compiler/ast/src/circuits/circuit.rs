@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CircuitMember, Identifier};
+use crate::{CircuitMember, Identifier, Visibility};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -27,6 +27,12 @@ use std::fmt;
 /// The fields are named so `circuit Foo(u8, u16)` is not allowed.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Circuit {
+    /// The doc comments (`///`) preceding the circuit, one entry per line.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
+    /// The visibility modifier of the circuit, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
     /// The name of the type in the type system in this module.
     pub circuit_name: Identifier,
     /// The fields, constant variables, and functions of this structure.
@@ -34,7 +40,15 @@ pub struct Circuit {
 }
 
 impl Circuit {
+    /// Returns `true` if the circuit is declared `private`.
+    pub fn is_private(&self) -> bool {
+        self.visibility == Some(Visibility::Private)
+    }
+
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(visibility) = self.visibility {
+            write!(f, "{} ", visibility)?;
+        }
         writeln!(f, "circuit {} {{ ", self.circuit_name)?;
         for field in self.members.iter() {
             writeln!(f, "    {}", field)?;
@@ -47,10 +47,7 @@ pub fn deserialize<'de, D: Deserializer<'de>>(
         .map(|(name, program)| {
             (
                 name.split(',')
-                    .map(|ident_name| Identifier {
-                        name: Symbol::intern(ident_name),
-                        span: Default::default(),
-                    })
+                    .map(|ident_name| Identifier::new(Symbol::intern(ident_name)))
                     .collect::<Vec<Identifier>>(),
                 program,
             )
@@ -16,7 +16,7 @@
 
 use leo_span::{Span, Symbol};
 
-use crate::Node;
+use crate::{Node, NodeID};
 use serde::{
     de::{
         Visitor, {self},
@@ -40,6 +40,8 @@ pub struct Identifier {
     pub name: Symbol,
     /// A span locating where the identifier occured in the source.
     pub span: Span,
+    /// The ID of the identifier, which is neither serialized nor compared.
+    pub id: NodeID,
 }
 
 impl Node for Identifier {
@@ -55,9 +57,15 @@ impl Node for Identifier {
 impl Identifier {
     /// Constructs a new identifier with `name` and a default span.
     pub fn new(name: Symbol) -> Self {
+        Self::new_with_span(name, Span::default())
+    }
+
+    /// Constructs a new identifier with `name` at `span`.
+    pub fn new_with_span(name: Symbol, span: Span) -> Self {
         Self {
             name,
-            span: Span::default(),
+            span,
+            id: NodeID::new(),
         }
     }
 
@@ -143,7 +151,7 @@ impl<'de> Deserialize<'de> for Identifier {
                     None => return Err(E::custom("missing 'span' in serialized Identifier struct")),
                 };
 
-                Ok(Identifier { name, span })
+                Ok(Identifier::new_with_span(name, span))
             }
         }
 
@@ -28,9 +28,15 @@ pub use identifier::*;
 pub mod imported_modules;
 pub use imported_modules::*;
 
+pub mod mode;
+pub use mode::*;
+
 pub mod mut_self_keyword;
 pub use mut_self_keyword::*;
 
+pub mod node_id;
+pub use node_id::*;
+
 pub mod positive_number;
 pub use positive_number::*;
 
@@ -41,3 +47,6 @@ pub mod spread_or_expression;
 pub use spread_or_expression::*;
 
 pub mod vec_tendril_json;
+
+pub mod visibility;
+pub use visibility::*;
@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::symbol::with_session_globals;
+
+/// The ID of an expression, a statement, or an identifier.
+///
+/// A node is given an ID no other node has when it is constructed, and keeps it when it is reconstructed.
+/// Tables of the nodes of a program, such as of their types, are keyed by their IDs rather than by their spans,
+/// which nodes share, e.g., `a[0]` and the `a[0] + 1u8` that `a[0] += 1u8` is desugared to,
+/// or the nodes a pass constructs with the default span.
+/// A copy of a node keeps its ID, as it is the same expression, e.g., `b` in the desugaring of `a < b < c`.
+///
+/// IDs are counted in the session of the compilation, like symbols are interned in it,
+/// so that the same program is given the same IDs each time it is compiled.
+/// IDs are not serialized; a deserialized node is given a new one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeID(usize);
+
+impl NodeID {
+    /// Returns an ID which no other node of the session has.
+    pub fn new() -> Self {
+        Self(with_session_globals(|globals| globals.next_node_id()))
+    }
+}
+
+impl Default for NodeID {
+    /// Returns a new ID, as each node has its own.
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -29,6 +29,9 @@ pub struct ArrayInitExpression {
     pub dimensions: ArrayDimensions,
     /// The span of the entire expression from `[` to `]`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayInitExpression {
@@ -25,6 +25,9 @@ pub struct ArrayInlineExpression {
     pub elements: Vec<SpreadOrExpression>,
     /// The span of the entire expression from `[` to `]`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for ArrayInlineExpression {
@@ -26,6 +26,9 @@ pub struct CallExpression {
     pub arguments: Vec<Expression>,
     /// Span of the entire call `function(arguments)`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CallExpression {
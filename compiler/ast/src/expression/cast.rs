@@ -27,6 +27,9 @@ pub struct CastExpression {
     pub target_type: Type,
     /// Span for the entire expression `e as U` to.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CastExpression {
@@ -49,6 +49,9 @@ pub struct CircuitInitExpression {
     pub members: Vec<CircuitVariableInitializer>,
     /// A span from `name` to `}`.
     pub span: Span,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for CircuitInitExpression {
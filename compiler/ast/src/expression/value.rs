@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::{Char, CharValue};
+use crate::{Char, CharValue, Type};
 
 /// A literal expression.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -41,6 +41,22 @@ pub enum ValueExpression {
     String(Vec<Char>, #[serde(with = "leo_span::span_json")] Span),
 }
 
+impl ValueExpression {
+    /// Returns the type of the literal, unless it has none of its own, as an unsuffixed or string literal.
+    pub fn type_(&self) -> Option<Type> {
+        use ValueExpression::*;
+        match self {
+            Address(..) => Some(Type::Address),
+            Boolean(..) => Some(Type::Boolean),
+            Char(..) => Some(Type::Char),
+            Field(..) => Some(Type::Field),
+            Group(..) => Some(Type::Group),
+            Integer(type_, ..) => Some(Type::IntegerType(*type_)),
+            Implicit(..) | String(..) => None,
+        }
+    }
+}
+
 impl fmt::Display for ValueExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use ValueExpression::*;
@@ -62,6 +78,44 @@ impl fmt::Display for ValueExpression {
     }
 }
 
+/// A literal, as an expression of the program.
+/// It serializes as its value alone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LiteralExpression {
+    /// The value of the literal.
+    pub value: ValueExpression,
+    /// The ID of the expression.
+    #[serde(skip)]
+    pub id: NodeID,
+}
+
+impl From<ValueExpression> for LiteralExpression {
+    /// Returns `value` as a new expression.
+    fn from(value: ValueExpression) -> Self {
+        Self {
+            value,
+            id: NodeID::new(),
+        }
+    }
+}
+
+impl fmt::Display for LiteralExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl Node for LiteralExpression {
+    fn span(&self) -> &Span {
+        self.value.span()
+    }
+
+    fn set_span(&mut self, span: Span) {
+        self.value.set_span(span)
+    }
+}
+
 impl Node for ValueExpression {
     fn span(&self) -> &Span {
         use ValueExpression::*;
@@ -14,7 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Annotation, Block, FunctionInput, Identifier, Node, Type};
+use crate::{Annotation, Block, FunctionInput, Identifier, Mode, Node, Type, Visibility};
+use leo_errors::LintName;
 use leo_span::{sym, Span, Symbol};
 
 use indexmap::IndexMap;
@@ -25,8 +26,14 @@ use std::fmt;
 /// A function definition.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Function {
+    /// The doc comments (`///`) preceding the function, one entry per line.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub docs: Vec<String>,
     /// A map of all the annotations from their base names to the whole.
     pub annotations: IndexMap<Symbol, Annotation>,
+    /// The visibility modifier of the function, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
     /// The function's parameters.
@@ -35,6 +42,9 @@ pub struct Function {
     pub const_: bool,
     /// The function return type, if explicitly specified, or `()` if not.
     pub output: Option<Type>,
+    /// The `private` or `public` mode of the return value, if any, e.g., `public` in `-> public u8`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_mode: Option<Mode>,
     /// Any mapping to the core library.
     /// Always `None` when initially parsed.
     pub core_mapping: Cell<Option<Symbol>>,
@@ -63,6 +73,18 @@ impl Function {
         self.name() == sym::main
     }
 
+    /// Returns `true` if the function is declared `private`.
+    pub fn is_private(&self) -> bool {
+        self.visibility == Some(Visibility::Private)
+    }
+
+    /// Returns `true` if the function is annotated with `@allow(lint)`.
+    pub fn allows(&self, lint: LintName) -> bool {
+        self.annotations.get(&sym::allow).map_or(false, |allow| {
+            allow.arguments.iter().any(|arg| *arg.as_str() == *lint.name())
+        })
+    }
+
     ///
     /// Returns `true` if the function has input `self` or `mut self`.
     /// Returns `false` otherwise.
@@ -86,14 +108,44 @@ impl Function {
         self.input.iter().filter(|input| !input.is_self())
     }
 
+    ///
+    /// Returns the signature of the function as written in source, e.g., `function add(a: u8, b: u8) -> u8`.
+    ///
+    pub fn signature(&self) -> String {
+        let inputs = self
+            .input
+            .iter()
+            .map(FunctionInput::signature)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut signature = format!(
+            "{}{}function {}({})",
+            self.visibility
+                .map(|visibility| format!("{} ", visibility))
+                .unwrap_or_default(),
+            if self.const_ { "const " } else { "" },
+            self.identifier,
+            inputs
+        );
+        if let Some(output) = &self.output {
+            let mode = self.output_mode.map(|mode| format!("{} ", mode)).unwrap_or_default();
+            signature.push_str(&format!(" -> {}{}", mode, output.source_name()));
+        }
+        signature
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(visibility) = self.visibility {
+            write!(f, "{} ", visibility)?;
+        }
         write!(f, "function {}", self.identifier)?;
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
-        let returns = self.output.as_ref().map(|type_| type_.to_string());
+        let mode = self.output_mode.map(|mode| format!("{} ", mode)).unwrap_or_default();
+        let returns = self.output.as_ref().map(|type_| format!("{}{}", mode, type_));
         if returns.is_none() {
             write!(f, "({}) {}", parameters, self.block)
         } else {
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Identifier, Node, Type};
+use crate::{Identifier, Mode, Node, Type};
 use leo_span::Span;
 
 use serde::{Deserialize, Serialize};
@@ -25,6 +25,9 @@ use std::fmt;
 pub struct FunctionInputVariable {
     /// The name the parameter is accessible as in the function's body.
     pub identifier: Identifier,
+    /// The `constant`, `private` or `public` mode of the parameter, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
     /// Is it a const parameter?
     pub const_: bool,
     /// Is it a mutable parameter?
@@ -36,8 +39,16 @@ pub struct FunctionInputVariable {
 }
 
 impl FunctionInputVariable {
+    /// Returns `true` if the parameter is declared `const` or `constant`.
+    pub fn is_constant(&self) -> bool {
+        self.const_ || self.mode == Some(Mode::Constant)
+    }
+
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // mut var: bool
+        if let Some(mode) = self.mode {
+            write!(f, "{} ", mode)?;
+        }
         if self.const_ {
             write!(f, "const ")?;
         }
@@ -85,6 +85,20 @@ impl FunctionInput {
         }
     }
 
+    /// Returns the parameter as written in a signature, e.g., `const a: u8` or `public b: u8`.
+    pub fn signature(&self) -> String {
+        match self {
+            FunctionInput::Variable(variable) => format!(
+                "{}{}{}: {}",
+                variable.mode.map(|mode| format!("{} ", mode)).unwrap_or_default(),
+                if variable.const_ { "const " } else { "" },
+                variable.identifier,
+                variable.type_.source_name()
+            ),
+            _ => self.to_string(),
+        }
+    }
+
     /// Formats the parameter to `f`.
     fn format(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -37,7 +37,7 @@ impl TryFrom<(Type, Expression)> for InputValue {
     fn try_from(value: (Type, Expression)) -> Result<Self> {
         Ok(match value {
             (type_, Expression::Value(value)) => {
-                match (type_, value) {
+                match (type_, value.value) {
                     (Type::Address, ValueExpression::Address(value, _)) => Self::Address(value),
                     (Type::Boolean, ValueExpression::Boolean(value, span)) => {
                         let bool_value = value.parse::<bool>().map_err(|_| ParserError::unexpected_eof(&span))?; // TODO: change error
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The versioned JSON format of programs, which tools outside the compiler can rely on.
+//!
+//! A program is written as `{ "version": AST_JSON_VERSION, "program": <program> }`, where `<program>`
+//! is the serialization of its [`Program`]. When the serialization of a node changes, the version is
+//! incremented and a migration from the previous version is added to [`MIGRATIONS`], so that the JSON
+//! written by earlier compilers can still be read.
+
+use crate::Program;
+use leo_errors::{AstError, Result};
+
+use serde_json::{json, Value};
+
+/// The version of the JSON format written by [`to_json()`].
+pub const AST_JSON_VERSION: u64 = 1;
+
+/// A migration of the JSON of a program from one version of the format to the next.
+type Migration = fn(Value) -> Value;
+
+/// The migrations of the JSON of a program, where the one at index `i` migrates version `i` to version `i + 1`.
+/// Version 0 is the unversioned serialization of a [`Program`], as written by [`crate::Ast::to_json_string`].
+const MIGRATIONS: [Migration; AST_JSON_VERSION as usize] = [from_unversioned];
+
+/// Version 1 only wraps the unversioned program with its version.
+fn from_unversioned(program: Value) -> Value {
+    program
+}
+
+/// Returns `program` in the current version of the JSON format.
+pub fn to_json(program: &Program) -> Result<String> {
+    let program = serde_json::to_value(program).map_err(|e| AstError::failed_to_convert_ast_to_json_value(&e))?;
+    Ok(
+        serde_json::to_string_pretty(&json!({ "version": AST_JSON_VERSION, "program": program }))
+            .map_err(|e| AstError::failed_to_convert_ast_to_json_string(&e))?,
+    )
+}
+
+/// Returns the program of `json`, written in any version of the JSON format up to the current one,
+/// or as an unversioned program.
+pub fn from_json(json: &str) -> Result<Program> {
+    let value: Value = serde_json::from_str(json).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?;
+
+    // An unversioned program has no `version`, as `Program` has no such field.
+    let (version, mut program) = match value {
+        Value::Object(mut object) if object.contains_key("version") => {
+            let version = object.remove("version").unwrap_or_default();
+            let program = object.remove("program").unwrap_or_default();
+            match version.as_u64() {
+                Some(version) if version <= AST_JSON_VERSION => (version, program),
+                _ => return Err(AstError::unsupported_ast_json_version(version, AST_JSON_VERSION).into()),
+            }
+        }
+        program => (0, program),
+    };
+
+    for migration in MIGRATIONS[version as usize..].iter() {
+        program = migration(program);
+    }
+    // A `Value` only lends borrowed strings, which the deserialization of identifiers requires,
+    // while it is read, so the program is read back from its text.
+    Ok(serde_json::from_str(&program.to_string()).map_err(|e| AstError::failed_to_read_json_string_to_ast(&e))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alias, Identifier, IntegerType, Type};
+    use leo_span::{symbol::create_session_if_not_set_then, Symbol};
+
+    #[test]
+    fn reads_each_version() {
+        create_session_if_not_set_then(|_| {
+            let mut program = Program::new("test".to_string());
+            let name = Identifier::new(Symbol::intern("Byte"));
+            let alias = Alias {
+                name: name.clone(),
+                span: Default::default(),
+                represents: Type::IntegerType(IntegerType::U8),
+            };
+            program.aliases.insert(name, alias);
+
+            let json = to_json(&program).unwrap();
+            assert!(json.starts_with("{\n  \"version\": 1,\n  \"program\": {\n    \"name\": \"test\""));
+            assert_eq!(from_json(&json).unwrap(), program);
+
+            // The unversioned serialization is version 0.
+            let unversioned = serde_json::to_string(&program).unwrap();
+            assert_eq!(from_json(&unversioned).unwrap(), program);
+
+            let future = json.replacen("\"version\": 1", "\"version\": 2", 1);
+            assert!(from_json(&future).is_err());
+            let invalid = json.replacen("\"version\": 1", "\"version\": \"1\"", 1);
+            assert!(from_json(&invalid).is_err());
+        });
+    }
+}
@@ -55,9 +55,15 @@ pub use self::imports::*;
 pub mod input;
 pub use self::input::*;
 
+pub mod json;
+pub use self::json::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod printer;
+pub use self::printer::*;
+
 pub mod program;
 pub use self::program::*;
 
@@ -67,9 +73,15 @@ pub use self::reducer::*;
 pub mod statements;
 pub use self::statements::*;
 
+pub mod typed_ast;
+pub use self::typed_ast::*;
+
 pub mod types;
 pub use self::types::*;
 
+pub mod visitor;
+pub use self::visitor::*;
+
 mod node;
 pub use node::*;
 
@@ -0,0 +1,620 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Prints programs back to Leo source code.
+//!
+//! [`print_program()`] writes a [`Program`] in the canonical Leo style, such that parsing the output
+//! gives back the same program, up to spans. Parentheses are only written where the precedence of an
+//! operator requires them, and comments other than doc comments are not part of the AST, so they are lost.
+
+use crate::*;
+
+/// The indent for each level of nesting.
+const INDENT: &str = "    ";
+
+/// The precedence of ternary expressions, which bind the loosest.
+const TERNARY_PRECEDENCE: u8 = 0;
+/// The precedence of casts, which bind tighter than any binary operator.
+const CAST_PRECEDENCE: u8 = 12;
+/// The precedence of unary operators.
+const UNARY_PRECEDENCE: u8 = 13;
+/// The precedence of accesses and calls.
+const POSTFIX_PRECEDENCE: u8 = 14;
+/// The precedence of literals, names, and bracketed expressions, which never need parentheses.
+const PRIMARY_PRECEDENCE: u8 = 15;
+
+/// Returns `program` as Leo source code.
+pub fn print_program(program: &Program) -> String {
+    let mut printer = Printer::default();
+    printer.program(program);
+    printer.output
+}
+
+/// Returns `expression` as Leo source code.
+pub fn print_expression(expression: &Expression) -> String {
+    let mut output = String::new();
+    write_expression(&mut output, expression, TERNARY_PRECEDENCE);
+    output
+}
+
+/// Writes the items and statements of a program, tracking their nesting.
+#[derive(Default)]
+struct Printer {
+    /// The source written so far.
+    output: String,
+    /// The number of blocks containing the current line.
+    depth: usize,
+}
+
+impl Printer {
+    /// Starts a new line at the indent of the current nesting.
+    fn start_line(&mut self) {
+        for _ in 0..self.depth {
+            self.output.push_str(INDENT);
+        }
+    }
+
+    /// Writes `text` on a line of its own.
+    fn line(&mut self, text: &str) {
+        self.start_line();
+        self.output.push_str(text);
+        self.output.push('\n');
+    }
+
+    /// Writes a blank line, unless nothing is written yet.
+    fn separate(&mut self) {
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+    }
+
+    /// Writes the items of `program`, separated by blank lines.
+    fn program(&mut self, program: &Program) {
+        for annotation in program.annotations.values() {
+            self.line(&annotation_line(annotation));
+        }
+        for import in program.import_statements.iter() {
+            self.line(&format!("import {};", import_tree(&import.tree)));
+        }
+        if !program.aliases.is_empty() {
+            self.separate();
+            for alias in program.aliases.values() {
+                self.line(&format!("type {} = {};", alias.name, alias.represents.source_name()));
+            }
+        }
+        if !program.global_consts.is_empty() {
+            self.separate();
+            for definition in program.global_consts.values() {
+                for annotation in definition.annotations.values() {
+                    self.line(&annotation_line(annotation));
+                }
+                self.line(&definition_statement(definition));
+            }
+        }
+        for circuit in program.circuits.values() {
+            self.separate();
+            self.circuit(circuit);
+        }
+        for function in program.functions.values() {
+            self.separate();
+            self.function(function);
+        }
+    }
+
+    /// Writes each of `docs` as a doc comment line.
+    fn docs(&mut self, docs: &[String]) {
+        for doc in docs {
+            if doc.is_empty() {
+                self.line("///");
+            } else {
+                self.line(&format!("/// {}", doc));
+            }
+        }
+    }
+
+    fn circuit(&mut self, circuit: &Circuit) {
+        self.docs(&circuit.docs);
+        let visibility = circuit
+            .visibility
+            .map(|visibility| format!("{} ", visibility))
+            .unwrap_or_default();
+        if circuit.members.is_empty() {
+            self.line(&format!("{}circuit {} {{}}", visibility, circuit.circuit_name));
+            return;
+        }
+
+        self.line(&format!("{}circuit {} {{", visibility, circuit.circuit_name));
+        self.depth += 1;
+        for (i, member) in circuit.members.iter().enumerate() {
+            match member {
+                CircuitMember::CircuitConst(name, type_, value) => self.line(&format!(
+                    "static const {}: {} = {};",
+                    name,
+                    type_.source_name(),
+                    print_expression(value)
+                )),
+                CircuitMember::CircuitVariable(name, type_) => {
+                    self.line(&format!("{}: {},", name, type_.source_name()))
+                }
+                // Functions are separated from the members before them by a blank line.
+                CircuitMember::CircuitFunction(function) => {
+                    if i > 0 {
+                        self.output.push('\n');
+                    }
+                    self.function(function);
+                }
+            }
+        }
+        self.depth -= 1;
+        self.line("}");
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.docs(&function.docs);
+        for annotation in function.annotations.values() {
+            self.line(&annotation_line(annotation));
+        }
+        self.start_line();
+        self.output.push_str(&function.signature());
+        self.output.push(' ');
+        self.block(&function.block);
+        self.output.push('\n');
+    }
+
+    /// Writes `block`, starting on the current line and ending after its `}`.
+    fn block(&mut self, block: &Block) {
+        if block.statements.is_empty() {
+            self.output.push_str("{}");
+            return;
+        }
+
+        self.output.push_str("{\n");
+        self.depth += 1;
+        for statement in block.statements.iter() {
+            self.start_line();
+            self.statement(statement);
+            self.output.push('\n');
+        }
+        self.depth -= 1;
+        self.start_line();
+        self.output.push('}');
+    }
+
+    /// Writes `statement`, starting on the current line and ending at its last character.
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Return(statement) => {
+                self.output.push_str("return ");
+                write_expression(&mut self.output, &statement.expression, TERNARY_PRECEDENCE);
+                self.output.push(';');
+            }
+            Statement::Definition(statement) => self.output.push_str(&definition_statement(statement)),
+            Statement::Assign(statement) => {
+                self.output.push_str(&assignee(&statement.assignee));
+                self.output.push_str(&format!(" {} ", statement.operation.as_ref()));
+                write_expression(&mut self.output, &statement.value, TERNARY_PRECEDENCE);
+                self.output.push(';');
+            }
+            Statement::Conditional(statement) => self.conditional(statement),
+            Statement::Iteration(statement) => {
+                self.output.push_str(&format!("for {} in ", statement.variable));
+                write_expression(&mut self.output, &statement.start, TERNARY_PRECEDENCE);
+                self.output.push_str(if statement.inclusive { "..=" } else { ".." });
+                self.output.push_str(&block_head_expression(&statement.stop));
+                self.output.push(' ');
+                self.block(&statement.block);
+            }
+            Statement::Console(statement) => {
+                let (function, arguments) = match &statement.function {
+                    ConsoleFunction::Assert(expression) => ("assert", print_expression(expression)),
+                    ConsoleFunction::Error(arguments) => ("error", console_arguments(arguments)),
+                    ConsoleFunction::Log(arguments) => ("log", console_arguments(arguments)),
+                };
+                self.output.push_str(&format!("console.{}({});", function, arguments));
+            }
+            Statement::Expression(statement) => {
+                write_expression(&mut self.output, &statement.expression, TERNARY_PRECEDENCE);
+                self.output.push(';');
+            }
+            Statement::Block(block) => self.block(block),
+        }
+    }
+
+    /// Writes `statement` and its `else` branches.
+    fn conditional(&mut self, statement: &ConditionalStatement) {
+        self.output.push_str("if ");
+        self.output.push_str(&block_head_expression(&statement.condition));
+        self.output.push(' ');
+        self.block(&statement.block);
+        match statement.next.as_deref() {
+            None => {}
+            Some(Statement::Conditional(next)) => {
+                self.output.push_str(" else ");
+                self.conditional(next);
+            }
+            Some(Statement::Block(next)) => {
+                self.output.push_str(" else ");
+                self.block(next);
+            }
+            // Only blocks and conditionals may follow `else`, so any other statement is put in a block.
+            Some(next) => {
+                self.output.push_str(" else ");
+                self.block(&Block {
+                    statements: vec![next.clone()],
+                    span: next.span().clone(),
+                    id: NodeID::new(),
+                });
+            }
+        }
+    }
+}
+
+/// Returns `tree` as written after `import`.
+fn import_tree(tree: &ImportTree) -> String {
+    let base = tree.base.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(".");
+    match &tree.kind {
+        ImportTreeKind::Glob { .. } => format!("{}.*", base),
+        ImportTreeKind::Leaf { alias: None } => base,
+        ImportTreeKind::Leaf { alias: Some(alias) } => format!("{} as {}", base, alias),
+        ImportTreeKind::Nested { tree } => format!(
+            "{}.({})",
+            base,
+            tree.iter().map(import_tree).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+/// Returns `statement`, e.g., `let (a, b): (u8, u8) = (1u8, 2u8);`.
+fn definition_statement(statement: &DefinitionStatement) -> String {
+    let names = statement
+        .variable_names
+        .iter()
+        .map(|x| x.identifier.to_string())
+        .collect::<Vec<_>>();
+    let mut output = match names.as_slice() {
+        [name] => format!("{} {}", statement.declaration_type, name),
+        names => format!("{} ({})", statement.declaration_type, names.join(", ")),
+    };
+    if let Some(type_) = &statement.type_ {
+        output.push_str(&format!(": {}", type_.source_name()));
+    }
+    output.push_str(" = ");
+    write_expression(&mut output, &statement.value, TERNARY_PRECEDENCE);
+    output.push(';');
+    output
+}
+
+/// Returns `assignee`, e.g., `a[0].b`.
+fn assignee(assignee: &Assignee) -> String {
+    let mut output = assignee.identifier.to_string();
+    for access in assignee.accesses.iter() {
+        match access {
+            AssigneeAccess::ArrayIndex(index) => {
+                output.push('[');
+                write_expression(&mut output, index, TERNARY_PRECEDENCE);
+                output.push(']');
+            }
+            AssigneeAccess::ArrayRange(left, right) => write_range(&mut output, left.as_ref(), right.as_ref()),
+            AssigneeAccess::Tuple(index, _) => output.push_str(&format!(".{}", index)),
+            AssigneeAccess::Member(name) => output.push_str(&format!(".{}", name)),
+        }
+    }
+    output
+}
+
+/// Returns the arguments of `console.log` or `console.error`, starting with the format string.
+fn console_arguments(arguments: &ConsoleArgs) -> String {
+    let mut output = string_literal(&arguments.string);
+    for parameter in arguments.parameters.iter() {
+        output.push_str(", ");
+        write_expression(&mut output, parameter, TERNARY_PRECEDENCE);
+    }
+    output
+}
+
+/// Returns `expression` as written before the block of an `if` or `for`.
+/// There, a circuit initialization would be taken for the block, so such expressions are parenthesized.
+fn block_head_expression(expression: &Expression) -> String {
+    let mut finder = CircuitInitFinder::default();
+    finder.visit_expression(expression);
+    if finder.found {
+        format!("({})", print_expression(expression))
+    } else {
+        print_expression(expression)
+    }
+}
+
+/// Finds whether an expression contains a circuit initialization.
+#[derive(Default)]
+struct CircuitInitFinder {
+    found: bool,
+}
+
+impl ExpressionVisitor for CircuitInitFinder {
+    fn visit_circuit_init(&mut self, _init: &CircuitInitExpression) {
+        self.found = true;
+    }
+}
+
+/// Returns the precedence of `operation`, where higher precedences bind tighter,
+/// and whether it is right associative and non-associative.
+fn binary_precedence(operation: &BinaryOperation) -> (u8, bool, bool) {
+    use BinaryOperation::*;
+    match operation {
+        Or => (1, false, false),
+        And => (2, false, false),
+        Eq | Ne => (3, false, true),
+        // Written one after the other, comparisons are chained, so a comparison operand is parenthesized.
+        Lt | Le | Gt | Ge => (4, false, true),
+        BitOr => (5, false, false),
+        BitXor => (6, false, false),
+        BitAnd => (7, false, false),
+        Shl | Shr | ShrSigned => (8, false, false),
+        Add | Sub => (9, false, false),
+        Mul | Div | Mod => (10, false, false),
+        Pow => (11, true, false),
+    }
+}
+
+/// Returns `true` if `value` is a field, integer, or untyped number which is `negative`.
+fn is_number(value: &ValueExpression, negative: bool) -> bool {
+    match value {
+        ValueExpression::Field(number, _)
+        | ValueExpression::Implicit(number, _)
+        | ValueExpression::Integer(_, number, _) => number.starts_with('-') == negative,
+        _ => false,
+    }
+}
+
+/// Returns the precedence of `expression`, where higher precedences bind tighter.
+fn precedence(expression: &Expression) -> u8 {
+    match expression {
+        Expression::Ternary(_) => TERNARY_PRECEDENCE,
+        Expression::Binary(binary) => binary_precedence(&binary.op).0,
+        Expression::Cast(_) => CAST_PRECEDENCE,
+        Expression::Unary(_) => UNARY_PRECEDENCE,
+        // The `-` of a negative number is part of the literal only if nothing binds tighter than it.
+        Expression::Value(literal) if is_number(&literal.value, true) => UNARY_PRECEDENCE,
+        Expression::Access(_) | Expression::Call(_) => POSTFIX_PRECEDENCE,
+        _ => PRIMARY_PRECEDENCE,
+    }
+}
+
+/// Writes `expression` to `output`, in parentheses if it binds looser than `min_precedence`.
+fn write_expression(output: &mut String, expression: &Expression, min_precedence: u8) {
+    if precedence(expression) < min_precedence {
+        output.push('(');
+        write_expression(output, expression, TERNARY_PRECEDENCE);
+        output.push(')');
+        return;
+    }
+
+    match expression {
+        Expression::Identifier(identifier) => output.push_str(&identifier.to_string()),
+        Expression::Value(literal) => write_value(output, &literal.value),
+        Expression::Binary(binary) => {
+            let (precedence, right_associative, non_associative) = binary_precedence(&binary.op);
+            let (left, right) = if non_associative {
+                (precedence + 1, precedence + 1)
+            } else if right_associative {
+                (precedence + 1, precedence)
+            } else {
+                (precedence, precedence + 1)
+            };
+            write_expression(output, &binary.left, left);
+            output.push_str(&format!(" {} ", binary.op.as_ref()));
+            write_expression(output, &binary.right, right);
+        }
+        Expression::Unary(unary) => {
+            output.push_str(unary.op.as_ref());
+            // The negation of a number is parenthesized, so that it is not parsed as a negative literal.
+            let negates_number = matches!(
+                (&unary.op, &*unary.inner),
+                (UnaryOperation::Negate, Expression::Value(literal)) if is_number(&literal.value, false)
+            );
+            let min_precedence = if negates_number {
+                PRIMARY_PRECEDENCE + 1
+            } else {
+                UNARY_PRECEDENCE
+            };
+            write_expression(output, &unary.inner, min_precedence);
+        }
+        Expression::Ternary(ternary) => {
+            write_expression(output, &ternary.condition, TERNARY_PRECEDENCE + 1);
+            output.push_str(" ? ");
+            write_expression(output, &ternary.if_true, TERNARY_PRECEDENCE);
+            output.push_str(" : ");
+            write_expression(output, &ternary.if_false, TERNARY_PRECEDENCE);
+        }
+        Expression::Cast(cast) => {
+            write_expression(output, &cast.inner, CAST_PRECEDENCE);
+            output.push_str(&format!(" as {}", cast.target_type.source_name()));
+        }
+        Expression::Access(access) => match access {
+            AccessExpression::Array(access) => {
+                write_expression(output, &access.array, POSTFIX_PRECEDENCE);
+                output.push('[');
+                write_expression(output, &access.index, TERNARY_PRECEDENCE);
+                output.push(']');
+            }
+            AccessExpression::ArrayRange(access) => {
+                write_expression(output, &access.array, POSTFIX_PRECEDENCE);
+                write_range(output, access.left.as_deref(), access.right.as_deref());
+            }
+            AccessExpression::Member(access) => {
+                write_expression(output, &access.inner, POSTFIX_PRECEDENCE);
+                output.push_str(&format!(".{}", access.name));
+            }
+            AccessExpression::Tuple(access) => {
+                write_expression(output, &access.tuple, POSTFIX_PRECEDENCE);
+                output.push_str(&format!(".{}", access.index));
+            }
+            AccessExpression::Static(access) => {
+                write_expression(output, &access.inner, POSTFIX_PRECEDENCE);
+                output.push_str(&format!("::{}", access.name));
+            }
+        },
+        Expression::ArrayInline(array) => {
+            output.push('[');
+            for (i, element) in array.elements.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(", ");
+                }
+                match element {
+                    SpreadOrExpression::Spread(element) => {
+                        output.push_str("...");
+                        write_expression(output, element, TERNARY_PRECEDENCE);
+                    }
+                    SpreadOrExpression::Expression(element) => write_expression(output, element, TERNARY_PRECEDENCE),
+                }
+            }
+            output.push(']');
+        }
+        Expression::ArrayInit(array) => {
+            output.push('[');
+            write_expression(output, &array.element, TERNARY_PRECEDENCE);
+            output.push_str(&format!("; {}]", array.dimensions));
+        }
+        Expression::TupleInit(tuple) => {
+            output.push('(');
+            write_list(output, &tuple.elements);
+            // A single element in parentheses is not a tuple.
+            if tuple.elements.len() == 1 {
+                output.push(',');
+            }
+            output.push(')');
+        }
+        Expression::CircuitInit(init) => {
+            output.push_str(&init.name.to_string());
+            if init.members.is_empty() {
+                output.push_str(" {}");
+                return;
+            }
+            output.push_str(" { ");
+            for (i, member) in init.members.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(", ");
+                }
+                output.push_str(&member.identifier.to_string());
+                if let Some(expression) = &member.expression {
+                    output.push_str(": ");
+                    write_expression(output, expression, TERNARY_PRECEDENCE);
+                }
+            }
+            output.push_str(" }");
+        }
+        Expression::Call(call) => {
+            write_expression(output, &call.function, POSTFIX_PRECEDENCE);
+            output.push('(');
+            write_list(output, &call.arguments);
+            output.push(')');
+        }
+        Expression::Err(_) => output.push_str("error"),
+    }
+}
+
+/// Writes `expressions` separated by commas.
+fn write_list(output: &mut String, expressions: &[Expression]) {
+    for (i, expression) in expressions.iter().enumerate() {
+        if i > 0 {
+            output.push_str(", ");
+        }
+        write_expression(output, expression, TERNARY_PRECEDENCE);
+    }
+}
+
+/// Writes the range access `[left..right]`.
+fn write_range(output: &mut String, left: Option<&Expression>, right: Option<&Expression>) {
+    output.push('[');
+    if let Some(left) = left {
+        write_expression(output, left, TERNARY_PRECEDENCE);
+    }
+    output.push_str("..");
+    if let Some(right) = right {
+        write_expression(output, right, TERNARY_PRECEDENCE);
+    }
+    output.push(']');
+}
+
+fn write_value(output: &mut String, value: &ValueExpression) {
+    match value {
+        ValueExpression::Address(address, _) => output.push_str(address),
+        ValueExpression::Boolean(boolean, _) => output.push_str(boolean),
+        ValueExpression::Char(character) => {
+            output.push('\'');
+            write_char(output, &character.character, '\'');
+            output.push('\'');
+        }
+        ValueExpression::Field(field, _) => output.push_str(&format!("{}field", field)),
+        ValueExpression::Group(group) => match &**group {
+            GroupValue::Single(group, _) => output.push_str(&format!("{}group", group)),
+            GroupValue::Tuple(tuple) => output.push_str(&format!("({}, {})group", tuple.x, tuple.y)),
+        },
+        ValueExpression::Implicit(value, _) => output.push_str(value),
+        ValueExpression::Integer(type_, value, _) => output.push_str(&format!("{}{}", value, type_)),
+        ValueExpression::String(string, _) => output.push_str(&string_literal(string)),
+    }
+}
+
+/// Returns `annotation` as written before a function or global constant.
+fn annotation_line(annotation: &Annotation) -> String {
+    let arguments = annotation
+        .arguments
+        .iter()
+        .map(|x| x.to_string())
+        .chain(annotation.values.iter().map(|(name, value)| {
+            if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+                format!("{} = {}", name, value)
+            } else {
+                let value = value.chars().map(Char::Scalar).collect::<Vec<_>>();
+                format!("{} = {}", name, string_literal(&value))
+            }
+        }))
+        .collect::<Vec<_>>();
+    if arguments.is_empty() {
+        format!("@{}", annotation.name)
+    } else {
+        format!("@{}({})", annotation.name, arguments.join(", "))
+    }
+}
+
+/// Returns `string` as a string literal.
+fn string_literal(string: &[Char]) -> String {
+    let mut output = String::from("\"");
+    for character in string {
+        write_char(&mut output, character, '"');
+    }
+    output.push('"');
+    output
+}
+
+/// Writes `character` as written in a literal delimited by `quote`, escaping it if needed.
+fn write_char(output: &mut String, character: &Char, quote: char) {
+    match character {
+        Char::Scalar('\0') => output.push_str("\\0"),
+        Char::Scalar('\t') => output.push_str("\\t"),
+        Char::Scalar('\n') => output.push_str("\\n"),
+        Char::Scalar('\r') => output.push_str("\\r"),
+        Char::Scalar('\\') => output.push_str("\\\\"),
+        Char::Scalar(c) if *c == quote => {
+            output.push('\\');
+            output.push(*c);
+        }
+        Char::Scalar(c) if c.is_control() => output.push_str(&format!("\\u{{{:x}}}", *c as u32)),
+        Char::Scalar(c) => output.push(*c),
+        Char::NonScalar(c) => output.push_str(&format!("\\u{{{:x}}}", c)),
+    }
+}
@@ -17,7 +17,10 @@
 //! A Leo program consists of import, circuit, and function definitions.
 //! Each defined type consists of ast statements and expressions.
 
-use crate::{Alias, Circuit, CircuitMember, DefinitionStatement, Function, FunctionInput, Identifier, ImportStatement};
+use crate::{
+    Alias, Annotation, Circuit, CircuitMember, DefinitionStatement, Function, FunctionInput, Identifier,
+    ImportStatement,
+};
 
 use leo_span::{sym, Symbol};
 
@@ -31,6 +34,13 @@ pub struct Program {
     /// The name of the program.
     /// Empty after parsing.
     pub name: String,
+    /// Annotations on the whole program, e.g., `@program(version = 2)`.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub annotations: IndexMap<Symbol, Annotation>,
+    /// The upgradability metadata of the program, set once it is checked.
+    /// Only programs with a `@program` annotation or a constructor have one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<ProgramHeader>,
     /// Expected main function inputs.
     /// Empty after parsing.
     pub expected_input: Vec<FunctionInput>,
@@ -50,6 +60,15 @@ pub struct Program {
     pub functions: IndexMap<Identifier, Function>,
 }
 
+/// The upgradability metadata of a program, written at the start of the compiled program.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProgramHeader {
+    /// The version set by `@program(version = ...)`, or `1` if there is no such annotation.
+    pub version: u32,
+    /// Whether the program has a `constructor` function, run once when the program is deployed.
+    pub constructor: bool,
+}
+
 impl AsRef<Program> for Program {
     fn as_ref(&self) -> &Program {
         self
@@ -58,6 +77,9 @@ impl AsRef<Program> for Program {
 
 impl fmt::Display for Program {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in self.annotations.values() {
+            writeln!(f, "{}", annotation)?;
+        }
         for import in self.import_statements.iter() {
             import.fmt(f)?;
             writeln!(f,)?;
@@ -91,6 +113,8 @@ impl Program {
     pub fn new(name: String) -> Self {
         Self {
             name,
+            annotations: IndexMap::new(),
+            header: None,
             expected_input: vec![],
             import_statements: vec![],
             imports: IndexMap::new(),
@@ -44,6 +44,9 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
 
                 Type::Tuple(reduced_types)
             }
+            Type::BoundedVec(type_, capacity) => {
+                Type::BoundedVec(Box::new(self.reduce_type(type_, span)?), capacity.clone())
+            }
             Type::Identifier(identifier) => Type::Identifier(self.reduce_identifier(identifier)?),
             _ => type_.clone(),
         };
@@ -97,16 +100,21 @@ impl<R: ReconstructingReducer> ReconstructingDirector<R> {
         self.reducer.reduce_string(string, span)
     }
 
-    pub fn reduce_value(&mut self, value: &ValueExpression) -> Result<Expression> {
-        let new = match value {
-            ValueExpression::Group(group_value) => {
-                Expression::Value(ValueExpression::Group(Box::new(self.reduce_group_value(group_value)?)))
-            }
-            ValueExpression::String(string, span) => self.reduce_string(string, span)?,
-            _ => Expression::Value(value.clone()),
+    pub fn reduce_value(&mut self, literal: &LiteralExpression) -> Result<Expression> {
+        let new = match &literal.value {
+            ValueExpression::Group(group_value) => Expression::Value(LiteralExpression {
+                value: ValueExpression::Group(Box::new(self.reduce_group_value(group_value)?)),
+                id: literal.id,
+            }),
+            ValueExpression::String(string, span) => match self.reduce_string(string, span)? {
+                // A string which stays a literal is the same expression.
+                Expression::Value(new) => Expression::Value(LiteralExpression { id: literal.id, ..new }),
+                new => new,
+            },
+            _ => Expression::Value(literal.clone()),
         };
 
-        self.reducer.reduce_value(value, new)
+        self.reducer.reduce_value(&literal.value, new)
     }
 
     pub fn reduce_binary(&mut self, binary: &BinaryExpression) -> Result<BinaryExpression> {
@@ -44,6 +44,7 @@ pub trait ReconstructingReducer {
         Ok(Identifier {
             name: identifier.name,
             span: identifier.span.clone(),
+            id: identifier.id,
         })
     }
 
@@ -60,10 +61,9 @@ pub trait ReconstructingReducer {
     }
 
     fn reduce_string(&mut self, string: &[Char], span: &Span) -> Result<Expression> {
-        Ok(Expression::Value(ValueExpression::String(
-            string.to_vec(),
-            span.clone(),
-        )))
+        Ok(Expression::Value(
+            ValueExpression::String(string.to_vec(), span.clone()).into(),
+        ))
     }
 
     fn reduce_value(&mut self, _value: &ValueExpression, new: Expression) -> Result<Expression> {
@@ -82,6 +82,7 @@ pub trait ReconstructingReducer {
             right: Box::new(right),
             op,
             span: binary.span.clone(),
+            id: binary.id,
         })
     }
 
@@ -95,6 +96,7 @@ pub trait ReconstructingReducer {
             inner: Box::new(inner),
             op,
             span: unary.span.clone(),
+            id: unary.id,
         })
     }
 
@@ -110,6 +112,7 @@ pub trait ReconstructingReducer {
             if_true: Box::new(if_true),
             if_false: Box::new(if_false),
             span: ternary.span.clone(),
+            id: ternary.id,
         })
     }
 
@@ -118,6 +121,7 @@ pub trait ReconstructingReducer {
             inner: Box::new(inner),
             target_type,
             span: cast.span.clone(),
+            id: cast.id,
         })
     }
 
@@ -131,6 +135,7 @@ pub trait ReconstructingReducer {
             array: Box::new(array),
             index: Box::new(index),
             span: array_access.span.clone(),
+            id: array_access.id,
         })
     }
 
@@ -146,6 +151,7 @@ pub trait ReconstructingReducer {
             left: left.map(|expr| Box::new(expr)),
             right: right.map(|expr| Box::new(expr)),
             span: array_rage_access.span.clone(),
+            id: array_rage_access.id,
         })
     }
 
@@ -160,6 +166,7 @@ pub trait ReconstructingReducer {
             inner: Box::new(inner),
             name,
             span: member_access.span.clone(),
+            id: member_access.id,
             type_,
         })
     }
@@ -169,6 +176,7 @@ pub trait ReconstructingReducer {
             tuple: Box::new(tuple),
             index: tuple_access.index.clone(),
             span: tuple_access.span.clone(),
+            id: tuple_access.id,
         })
     }
 
@@ -184,6 +192,7 @@ pub trait ReconstructingReducer {
             name,
             type_,
             span: static_access.span.clone(),
+            id: static_access.id,
         })
     }
 
@@ -195,6 +204,7 @@ pub trait ReconstructingReducer {
         Ok(ArrayInlineExpression {
             elements,
             span: array_inline.span.clone(),
+            id: array_inline.id,
         })
     }
 
@@ -207,6 +217,7 @@ pub trait ReconstructingReducer {
             element: Box::new(element),
             dimensions: array_init.dimensions.clone(),
             span: array_init.span.clone(),
+            id: array_init.id,
         })
     }
 
@@ -218,6 +229,7 @@ pub trait ReconstructingReducer {
         Ok(TupleInitExpression {
             elements,
             span: tuple_init.span.clone(),
+            id: tuple_init.id,
         })
     }
 
@@ -240,6 +252,7 @@ pub trait ReconstructingReducer {
             name,
             members,
             span: circuit_init.span.clone(),
+            id: circuit_init.id,
         })
     }
 
@@ -253,6 +266,7 @@ pub trait ReconstructingReducer {
             function: Box::new(function),
             arguments,
             span: call.span.clone(),
+            id: call.id,
         })
     }
 
@@ -265,6 +279,7 @@ pub trait ReconstructingReducer {
         Ok(ReturnStatement {
             expression,
             span: return_statement.span.clone(),
+            id: return_statement.id,
         })
     }
 
@@ -289,6 +304,8 @@ pub trait ReconstructingReducer {
             type_,
             value,
             span: definition.span.clone(),
+            id: definition.id,
+            annotations: definition.annotations.clone(),
         })
     }
 
@@ -320,6 +337,7 @@ pub trait ReconstructingReducer {
             assignee,
             value,
             span: assign.span.clone(),
+            id: assign.id,
         })
     }
 
@@ -335,6 +353,7 @@ pub trait ReconstructingReducer {
             block,
             next: statement.map(|statement| Box::new(statement)),
             span: conditional.span.clone(),
+            id: conditional.id,
         })
     }
 
@@ -353,6 +372,7 @@ pub trait ReconstructingReducer {
             inclusive: iteration.inclusive,
             block,
             span: iteration.span.clone(),
+            id: iteration.id,
         })
     }
 
@@ -360,6 +380,7 @@ pub trait ReconstructingReducer {
         Ok(ConsoleStatement {
             function,
             span: console.span.clone(),
+            id: console.id,
         })
     }
 
@@ -371,6 +392,7 @@ pub trait ReconstructingReducer {
         Ok(ExpressionStatement {
             expression,
             span: expression_statement.span.clone(),
+            id: expression_statement.id,
         })
     }
 
@@ -378,6 +400,7 @@ pub trait ReconstructingReducer {
         Ok(Block {
             statements,
             span: block.span.clone(),
+            id: block.id,
         })
     }
 
@@ -396,6 +419,8 @@ pub trait ReconstructingReducer {
     ) -> Result<Program> {
         Ok(Program {
             name: program.name.clone(),
+            annotations: program.annotations.clone(),
+            header: program.header,
             expected_input,
             import_statements,
             imports,
@@ -414,6 +439,7 @@ pub trait ReconstructingReducer {
     ) -> Result<FunctionInputVariable> {
         Ok(FunctionInputVariable {
             identifier,
+            mode: variable.mode,
             const_: variable.const_,
             mutable: variable.mutable,
             type_,
@@ -446,11 +472,16 @@ pub trait ReconstructingReducer {
 
     fn reduce_circuit(
         &mut self,
-        _circuit: &Circuit,
+        circuit: &Circuit,
         circuit_name: Identifier,
         members: Vec<CircuitMember>,
     ) -> Result<Circuit> {
-        Ok(Circuit { circuit_name, members })
+        Ok(Circuit {
+            docs: circuit.docs.clone(),
+            visibility: circuit.visibility,
+            circuit_name,
+            members,
+        })
     }
 
     fn reduce_annotation(&mut self, annotation: &Annotation, name: Identifier) -> Result<Annotation> {
@@ -458,6 +489,7 @@ pub trait ReconstructingReducer {
             span: annotation.span.clone(),
             name,
             arguments: annotation.arguments.clone(),
+            values: annotation.values.clone(),
         })
     }
 
@@ -473,11 +505,14 @@ pub trait ReconstructingReducer {
         block: Block,
     ) -> Result<Function> {
         Ok(Function {
+            docs: function.docs.clone(),
             identifier,
             annotations,
+            visibility: function.visibility,
             input,
             const_,
             output,
+            output_mode: function.output_mode,
             block,
             core_mapping: function.core_mapping.clone(),
             span: function.span.clone(),
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node};
+use crate::{Expression, Node, NodeID};
 
 use leo_span::Span;
 
@@ -93,6 +93,9 @@ pub struct AssignStatement {
     pub value: Expression,
     /// The span, excluding the semicolon.
     pub span: Span,
+    /// The ID of the statement.
+    #[serde(skip)]
+    pub id: NodeID,
 }
 
 impl fmt::Display for AssignStatement {
@@ -14,9 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Expression, Node, Type};
-use leo_span::Span;
+use crate::{Annotation, Expression, Node, NodeID, Type};
+use leo_span::{Span, Symbol};
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -39,10 +40,19 @@ pub struct DefinitionStatement {
     pub value: Expression,
     /// The span excluding the semicolon.
     pub span: Span,
+    /// The ID of the statement.
+    #[serde(skip)]
+    pub id: NodeID,
+    /// Annotations on a global constant, e.g., `@cfg(network = "testnet")`.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub annotations: IndexMap<Symbol, Annotation>,
 }
 
 impl fmt::Display for DefinitionStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for annotation in self.annotations.values() {
+            write!(f, "{} ", annotation)?;
+        }
         write!(f, "{} ", self.declaration_type)?;
         if self.variable_names.len() == 1 {
             // mut a
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ConditionalStatement, Node};
+use crate::{ConditionalStatement, Node, NodeID};
 use leo_span::Span;
 
 use super::*;
@@ -58,6 +58,23 @@ impl fmt::Display for Statement {
     }
 }
 
+impl Statement {
+    /// Returns the ID of the statement.
+    pub fn id(&self) -> NodeID {
+        use Statement::*;
+        match &self {
+            Return(n) => n.id,
+            Definition(n) => n.id,
+            Assign(n) => n.id,
+            Conditional(n) => n.id,
+            Iteration(n) => n.id,
+            Console(n) => n.id,
+            Expression(n) => n.id,
+            Block(n) => n.id,
+        }
+    }
+}
+
 impl Node for Statement {
     fn span(&self) -> &Span {
         use Statement::*;
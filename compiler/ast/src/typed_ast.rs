@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The typed AST, the program after type checking with the type of each of its expressions.
+
+use crate::{
+    walk_expression, Ast, Expression, ExpressionVisitor, Node, NodeID, Program, ProgramVisitor, StatementVisitor, Type,
+};
+use leo_errors::{AstError, Result};
+
+use indexmap::IndexMap;
+use serde_json::{json, Value};
+
+/// The AST after type checking, where each expression of a known type carries it.
+///
+/// It is separate from the [`Ast`] of the parser, which does not store the types of expressions,
+/// so that later stages and tools outside the compiler read the types instead of deriving them again.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TypedAst {
+    ast: Ast,
+    /// The types of the expressions, keyed by their IDs.
+    types: IndexMap<NodeID, Type>,
+}
+
+impl TypedAst {
+    /// Creates a typed AST from the program of `ast` and the `types` of its expressions.
+    pub fn new(ast: Ast, types: IndexMap<NodeID, Type>) -> Self {
+        Self { ast, types }
+    }
+
+    /// Returns a reference to the inner program AST representation.
+    pub fn as_repr(&self) -> &Program {
+        self.ast.as_repr()
+    }
+
+    /// Returns the AST without the types of its expressions.
+    pub fn into_ast(self) -> Ast {
+        self.ast
+    }
+
+    /// Returns the type of `expression`, if it is known.
+    pub fn type_of(&self, expression: &Expression) -> Option<Type> {
+        self.types.get(&expression.id()).cloned()
+    }
+
+    /// Converts the typed AST into a JSON value of the form `{ "program": <program>, "types": [...] }`,
+    /// where the types list each expression of a known type, in the order of the program, by its code and span.
+    pub fn to_json_value(&self) -> Result<Value> {
+        let mut types = TypeList {
+            ast: self,
+            types: Vec::new(),
+        };
+        types.visit_program(self.as_repr());
+        Ok(json!({ "program": self.ast.to_json_value()?, "types": types.types }))
+    }
+
+    /// Serializes the typed AST into a JSON value and removes keys from object mappings before writing to a file.
+    pub fn to_json_file_without_keys(
+        &self,
+        mut path: std::path::PathBuf,
+        file_name: &str,
+        excluded_keys: &[&str],
+    ) -> Result<()> {
+        path.push(file_name);
+        let file = std::fs::File::create(&path).map_err(|e| AstError::failed_to_create_ast_json_file(&path, &e))?;
+        let writer = std::io::BufWriter::new(file);
+
+        let mut value = self.to_json_value()?;
+        for key in excluded_keys {
+            value = crate::remove_key_from_json(value, key);
+        }
+
+        Ok(serde_json::to_writer_pretty(writer, &value)
+            .map_err(|e| AstError::failed_to_write_ast_to_json_file(&path, &e))?)
+    }
+}
+
+/// Lists the expressions of a known type as JSON values, in the order they are visited.
+struct TypeList<'a> {
+    ast: &'a TypedAst,
+    types: Vec<Value>,
+}
+
+impl ExpressionVisitor for TypeList<'_> {
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Some(type_) = self.ast.type_of(expression) {
+            self.types.push(json!({
+                "expression": expression.to_string(),
+                "span": expression.span(),
+                "type": type_.to_string(),
+            }));
+        }
+        walk_expression(self, expression);
+    }
+}
+
+impl StatementVisitor for TypeList<'_> {}
+
+impl ProgramVisitor for TypeList<'_> {}
@@ -57,6 +57,34 @@ impl IntegerType {
             Self::U128 => sym::u128,
         }
     }
+
+    /// Returns the integer type named by `symbol`, if any.
+    pub fn from_symbol(symbol: Symbol) -> Option<Self> {
+        Some(match symbol {
+            sym::i8 => Self::I8,
+            sym::i16 => Self::I16,
+            sym::i32 => Self::I32,
+            sym::i64 => Self::I64,
+            sym::i128 => Self::I128,
+            sym::u8 => Self::U8,
+            sym::u16 => Self::U16,
+            sym::u32 => Self::U32,
+            sym::u64 => Self::U64,
+            sym::u128 => Self::U128,
+            _ => return None,
+        })
+    }
+
+    /// Returns the number of bits in the integer type.
+    pub fn bits(self) -> u32 {
+        match self {
+            Self::I8 | Self::U8 => 8,
+            Self::I16 | Self::U16 => 16,
+            Self::I32 | Self::U32 => 32,
+            Self::I64 | Self::U64 => 64,
+            Self::I128 | Self::U128 => 128,
+        }
+    }
 }
 
 impl fmt::Display for IntegerType {
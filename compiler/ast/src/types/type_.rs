@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ArrayDimensions, Identifier, IntegerType};
+use crate::{ArrayDimensions, Identifier, IntegerType, PositiveNumber};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -43,6 +43,10 @@ pub enum Type {
     /// A tuple type `(T_0, T_1, ...)` made up of a list of types.
     Tuple(Vec<Type>),
 
+    /// A list type `BoundedVec<element, capacity>` holding up to `capacity` elements.
+    /// It is lowered to a circuit before the program is checked.
+    BoundedVec(Box<Type>, PositiveNumber),
+
     /// A reference to either a nominal type (e.g., a `circuit`) or a type alias.
     Identifier(Identifier),
 
@@ -109,9 +113,29 @@ impl Type {
                 .iter()
                 .zip(right)
                 .all(|(left_type, right_type)| left_type.eq_flat(right_type)),
+            (Type::BoundedVec(left, left_capacity), Type::BoundedVec(right, right_capacity)) => {
+                left_capacity == right_capacity && left.eq_flat(right)
+            }
             _ => false,
         }
     }
+
+    ///
+    /// Returns the type as written in source, naming circuits without the `circuit` prefix of its `Display`.
+    ///
+    pub fn source_name(&self) -> String {
+        match self {
+            Type::Identifier(identifier) => identifier.to_string(),
+            Type::SelfType => "Self".to_string(),
+            Type::Array(element, dimensions) => format!("[{}; {}]", element.source_name(), dimensions),
+            Type::Tuple(types) => format!(
+                "({})",
+                types.iter().map(Type::source_name).collect::<Vec<_>>().join(", ")
+            ),
+            Type::BoundedVec(element, capacity) => format!("BoundedVec<{}, {}>", element.source_name(), capacity),
+            _ => self.to_string(),
+        }
+    }
 }
 
 impl fmt::Display for Type {
@@ -131,6 +155,7 @@ impl fmt::Display for Type {
 
                 write!(f, "({})", types)
             }
+            Type::BoundedVec(ref element, ref capacity) => write!(f, "BoundedVec<{}, {}>", *element, capacity),
             Type::Err => write!(f, "error"),
         }
     }
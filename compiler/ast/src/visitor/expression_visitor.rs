@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+/// A traversal of expressions.
+pub trait ExpressionVisitor {
+    /// Visits `expression` by visiting its kind of expression.
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    fn visit_value(&mut self, _value: &LiteralExpression) {}
+
+    fn visit_binary(&mut self, binary: &BinaryExpression) {
+        self.visit_expression(&binary.left);
+        self.visit_expression(&binary.right);
+    }
+
+    fn visit_unary(&mut self, unary: &UnaryExpression) {
+        self.visit_expression(&unary.inner);
+    }
+
+    fn visit_ternary(&mut self, ternary: &TernaryExpression) {
+        self.visit_expression(&ternary.condition);
+        self.visit_expression(&ternary.if_true);
+        self.visit_expression(&ternary.if_false);
+    }
+
+    fn visit_cast(&mut self, cast: &CastExpression) {
+        self.visit_expression(&cast.inner);
+    }
+
+    fn visit_access(&mut self, access: &AccessExpression) {
+        match access {
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array);
+                self.visit_expression(&access.index);
+            }
+            AccessExpression::ArrayRange(access) => {
+                self.visit_expression(&access.array);
+                access
+                    .left
+                    .iter()
+                    .chain(access.right.iter())
+                    .for_each(|bound| self.visit_expression(bound));
+            }
+            AccessExpression::Member(access) => self.visit_expression(&access.inner),
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple),
+            AccessExpression::Static(access) => self.visit_expression(&access.inner),
+        }
+    }
+
+    fn visit_array_inline(&mut self, array: &ArrayInlineExpression) {
+        for element in array.elements.iter() {
+            match element {
+                SpreadOrExpression::Spread(element) | SpreadOrExpression::Expression(element) => {
+                    self.visit_expression(element)
+                }
+            }
+        }
+    }
+
+    fn visit_array_init(&mut self, array: &ArrayInitExpression) {
+        self.visit_expression(&array.element);
+    }
+
+    fn visit_tuple_init(&mut self, tuple: &TupleInitExpression) {
+        tuple.elements.iter().for_each(|element| self.visit_expression(element));
+    }
+
+    fn visit_circuit_init(&mut self, init: &CircuitInitExpression) {
+        init.members
+            .iter()
+            .filter_map(|member| member.expression.as_ref())
+            .for_each(|expression| self.visit_expression(expression));
+    }
+
+    fn visit_call(&mut self, call: &CallExpression) {
+        self.visit_expression(&call.function);
+        call.arguments
+            .iter()
+            .for_each(|argument| self.visit_expression(argument));
+    }
+}
+
+/// Visits the kind of `expression`, as the default [`ExpressionVisitor::visit_expression`] does.
+/// For visitors which override it to do something with every expression before visiting its kind.
+pub fn walk_expression<V: ExpressionVisitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    match expression {
+        Expression::Identifier(identifier) => visitor.visit_identifier(identifier),
+        Expression::Value(value) => visitor.visit_value(value),
+        Expression::Binary(binary) => visitor.visit_binary(binary),
+        Expression::Unary(unary) => visitor.visit_unary(unary),
+        Expression::Ternary(ternary) => visitor.visit_ternary(ternary),
+        Expression::Cast(cast) => visitor.visit_cast(cast),
+        Expression::Access(access) => visitor.visit_access(access),
+        Expression::ArrayInline(array) => visitor.visit_array_inline(array),
+        Expression::ArrayInit(array) => visitor.visit_array_init(array),
+        Expression::TupleInit(tuple) => visitor.visit_tuple_init(tuple),
+        Expression::CircuitInit(init) => visitor.visit_circuit_init(init),
+        Expression::Call(call) => visitor.visit_call(call),
+        Expression::Err(_) => {}
+    }
+}
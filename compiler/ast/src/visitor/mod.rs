@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains traits to traverse the AST without reconstructing it,
+//! and a trait to rewrite the AST by reconstructing it from owned nodes.
+//! Each method has a default implementation that recurses into the children of its node,
+//! so an implementor only overrides the methods of the nodes it is interested in.
+
+pub mod expression_visitor;
+pub use expression_visitor::*;
+
+pub mod statement_visitor;
+pub use statement_visitor::*;
+
+pub mod program_visitor;
+pub use program_visitor::*;
+
+pub mod program_reconstructor;
+pub use program_reconstructor::*;
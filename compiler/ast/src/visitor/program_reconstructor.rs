@@ -0,0 +1,396 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+use leo_errors::Result;
+use leo_span::Span;
+
+/// A rewriting of a program, which takes each node by value and returns the node replacing it.
+/// By default, a node is rebuilt from its rewritten children, so a rewriting only overrides the methods
+/// of the nodes it changes. An expression or statement may be replaced by one of another kind.
+pub trait ProgramReconstructor {
+    /// Rewrites `expression` by rewriting its kind of expression.
+    fn reconstruct_expression(&mut self, expression: Expression) -> Result<Expression> {
+        match expression {
+            Expression::Identifier(identifier) => self.reconstruct_identifier(identifier),
+            Expression::Value(value) => self.reconstruct_value(value),
+            Expression::Binary(binary) => self.reconstruct_binary(binary),
+            Expression::Unary(unary) => self.reconstruct_unary(unary),
+            Expression::Ternary(ternary) => self.reconstruct_ternary(ternary),
+            Expression::Cast(cast) => self.reconstruct_cast(cast),
+            Expression::Access(access) => self.reconstruct_access(access),
+            Expression::ArrayInline(array) => self.reconstruct_array_inline(array),
+            Expression::ArrayInit(array) => self.reconstruct_array_init(array),
+            Expression::TupleInit(tuple) => self.reconstruct_tuple_init(tuple),
+            Expression::CircuitInit(init) => self.reconstruct_circuit_init(init),
+            Expression::Call(call) => self.reconstruct_call(call),
+            Expression::Err(err) => Ok(Expression::Err(err)),
+        }
+    }
+
+    fn reconstruct_identifier(&mut self, identifier: Identifier) -> Result<Expression> {
+        Ok(Expression::Identifier(identifier))
+    }
+
+    fn reconstruct_value(&mut self, value: LiteralExpression) -> Result<Expression> {
+        Ok(Expression::Value(value))
+    }
+
+    fn reconstruct_binary(&mut self, binary: BinaryExpression) -> Result<Expression> {
+        Ok(Expression::Binary(BinaryExpression {
+            left: Box::new(self.reconstruct_expression(*binary.left)?),
+            right: Box::new(self.reconstruct_expression(*binary.right)?),
+            ..binary
+        }))
+    }
+
+    fn reconstruct_unary(&mut self, unary: UnaryExpression) -> Result<Expression> {
+        Ok(Expression::Unary(UnaryExpression {
+            inner: Box::new(self.reconstruct_expression(*unary.inner)?),
+            ..unary
+        }))
+    }
+
+    fn reconstruct_ternary(&mut self, ternary: TernaryExpression) -> Result<Expression> {
+        Ok(Expression::Ternary(TernaryExpression {
+            condition: Box::new(self.reconstruct_expression(*ternary.condition)?),
+            if_true: Box::new(self.reconstruct_expression(*ternary.if_true)?),
+            if_false: Box::new(self.reconstruct_expression(*ternary.if_false)?),
+            ..ternary
+        }))
+    }
+
+    fn reconstruct_cast(&mut self, cast: CastExpression) -> Result<Expression> {
+        Ok(Expression::Cast(CastExpression {
+            inner: Box::new(self.reconstruct_expression(*cast.inner)?),
+            target_type: self.reconstruct_type(cast.target_type, &cast.span)?,
+            ..cast
+        }))
+    }
+
+    fn reconstruct_access(&mut self, access: AccessExpression) -> Result<Expression> {
+        Ok(Expression::Access(match access {
+            AccessExpression::Array(access) => AccessExpression::Array(ArrayAccess {
+                array: Box::new(self.reconstruct_expression(*access.array)?),
+                index: Box::new(self.reconstruct_expression(*access.index)?),
+                ..access
+            }),
+            AccessExpression::ArrayRange(access) => AccessExpression::ArrayRange(ArrayRangeAccess {
+                array: Box::new(self.reconstruct_expression(*access.array)?),
+                left: access
+                    .left
+                    .map(|left| self.reconstruct_expression(*left).map(Box::new))
+                    .transpose()?,
+                right: access
+                    .right
+                    .map(|right| self.reconstruct_expression(*right).map(Box::new))
+                    .transpose()?,
+                ..access
+            }),
+            AccessExpression::Member(access) => AccessExpression::Member(MemberAccess {
+                inner: Box::new(self.reconstruct_expression(*access.inner)?),
+                ..access
+            }),
+            AccessExpression::Tuple(access) => AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(self.reconstruct_expression(*access.tuple)?),
+                ..access
+            }),
+            AccessExpression::Static(access) => AccessExpression::Static(StaticAccess {
+                inner: Box::new(self.reconstruct_expression(*access.inner)?),
+                ..access
+            }),
+        }))
+    }
+
+    fn reconstruct_array_inline(&mut self, array: ArrayInlineExpression) -> Result<Expression> {
+        Ok(Expression::ArrayInline(ArrayInlineExpression {
+            elements: array
+                .elements
+                .into_iter()
+                .map(|element| {
+                    Ok(match element {
+                        SpreadOrExpression::Spread(element) => {
+                            SpreadOrExpression::Spread(self.reconstruct_expression(element)?)
+                        }
+                        SpreadOrExpression::Expression(element) => {
+                            SpreadOrExpression::Expression(self.reconstruct_expression(element)?)
+                        }
+                    })
+                })
+                .collect::<Result<_>>()?,
+            ..array
+        }))
+    }
+
+    fn reconstruct_array_init(&mut self, array: ArrayInitExpression) -> Result<Expression> {
+        Ok(Expression::ArrayInit(ArrayInitExpression {
+            element: Box::new(self.reconstruct_expression(*array.element)?),
+            ..array
+        }))
+    }
+
+    fn reconstruct_tuple_init(&mut self, tuple: TupleInitExpression) -> Result<Expression> {
+        Ok(Expression::TupleInit(TupleInitExpression {
+            elements: tuple
+                .elements
+                .into_iter()
+                .map(|element| self.reconstruct_expression(element))
+                .collect::<Result<_>>()?,
+            ..tuple
+        }))
+    }
+
+    fn reconstruct_circuit_init(&mut self, init: CircuitInitExpression) -> Result<Expression> {
+        Ok(Expression::CircuitInit(CircuitInitExpression {
+            members: init
+                .members
+                .into_iter()
+                .map(|member| {
+                    Ok(CircuitVariableInitializer {
+                        expression: member
+                            .expression
+                            .map(|expression| self.reconstruct_expression(expression))
+                            .transpose()?,
+                        ..member
+                    })
+                })
+                .collect::<Result<_>>()?,
+            ..init
+        }))
+    }
+
+    fn reconstruct_call(&mut self, call: CallExpression) -> Result<Expression> {
+        Ok(Expression::Call(CallExpression {
+            function: Box::new(self.reconstruct_expression(*call.function)?),
+            arguments: call
+                .arguments
+                .into_iter()
+                .map(|argument| self.reconstruct_expression(argument))
+                .collect::<Result<_>>()?,
+            ..call
+        }))
+    }
+
+    /// Rewrites `statement` by rewriting its kind of statement.
+    fn reconstruct_statement(&mut self, statement: Statement) -> Result<Statement> {
+        match statement {
+            Statement::Return(return_) => self.reconstruct_return(return_),
+            Statement::Definition(definition) => self.reconstruct_definition(definition),
+            Statement::Assign(assign) => self.reconstruct_assign(*assign),
+            Statement::Conditional(conditional) => self.reconstruct_conditional(conditional),
+            Statement::Iteration(iteration) => self.reconstruct_iteration(*iteration),
+            Statement::Console(console) => self.reconstruct_console(console),
+            Statement::Expression(expression) => self.reconstruct_expression_statement(expression),
+            Statement::Block(block) => Ok(Statement::Block(self.reconstruct_block(block)?)),
+        }
+    }
+
+    fn reconstruct_return(&mut self, return_: ReturnStatement) -> Result<Statement> {
+        Ok(Statement::Return(ReturnStatement {
+            expression: self.reconstruct_expression(return_.expression)?,
+            ..return_
+        }))
+    }
+
+    fn reconstruct_definition(&mut self, definition: DefinitionStatement) -> Result<Statement> {
+        Ok(Statement::Definition(DefinitionStatement {
+            type_: definition
+                .type_
+                .map(|type_| self.reconstruct_type(type_, &definition.span))
+                .transpose()?,
+            value: self.reconstruct_expression(definition.value)?,
+            ..definition
+        }))
+    }
+
+    fn reconstruct_assign(&mut self, assign: AssignStatement) -> Result<Statement> {
+        let accesses = assign
+            .assignee
+            .accesses
+            .into_iter()
+            .map(|access| {
+                Ok(match access {
+                    AssigneeAccess::ArrayIndex(index) => {
+                        AssigneeAccess::ArrayIndex(self.reconstruct_expression(index)?)
+                    }
+                    AssigneeAccess::ArrayRange(left, right) => AssigneeAccess::ArrayRange(
+                        left.map(|left| self.reconstruct_expression(left)).transpose()?,
+                        right.map(|right| self.reconstruct_expression(right)).transpose()?,
+                    ),
+                    access => access,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Statement::Assign(Box::new(AssignStatement {
+            assignee: Assignee {
+                accesses,
+                ..assign.assignee
+            },
+            value: self.reconstruct_expression(assign.value)?,
+            ..assign
+        })))
+    }
+
+    fn reconstruct_conditional(&mut self, conditional: ConditionalStatement) -> Result<Statement> {
+        Ok(Statement::Conditional(ConditionalStatement {
+            condition: self.reconstruct_expression(conditional.condition)?,
+            block: self.reconstruct_block(conditional.block)?,
+            next: conditional
+                .next
+                .map(|next| self.reconstruct_statement(*next).map(Box::new))
+                .transpose()?,
+            ..conditional
+        }))
+    }
+
+    fn reconstruct_iteration(&mut self, iteration: IterationStatement) -> Result<Statement> {
+        Ok(Statement::Iteration(Box::new(IterationStatement {
+            start: self.reconstruct_expression(iteration.start)?,
+            stop: self.reconstruct_expression(iteration.stop)?,
+            block: self.reconstruct_block(iteration.block)?,
+            ..iteration
+        })))
+    }
+
+    fn reconstruct_console(&mut self, console: ConsoleStatement) -> Result<Statement> {
+        let function = match console.function {
+            ConsoleFunction::Assert(expression) => ConsoleFunction::Assert(self.reconstruct_expression(expression)?),
+            ConsoleFunction::Error(args) => ConsoleFunction::Error(self.reconstruct_console_args(args)?),
+            ConsoleFunction::Log(args) => ConsoleFunction::Log(self.reconstruct_console_args(args)?),
+        };
+        Ok(Statement::Console(ConsoleStatement { function, ..console }))
+    }
+
+    fn reconstruct_console_args(&mut self, args: ConsoleArgs) -> Result<ConsoleArgs> {
+        Ok(ConsoleArgs {
+            parameters: args
+                .parameters
+                .into_iter()
+                .map(|parameter| self.reconstruct_expression(parameter))
+                .collect::<Result<_>>()?,
+            ..args
+        })
+    }
+
+    fn reconstruct_expression_statement(&mut self, expression: ExpressionStatement) -> Result<Statement> {
+        Ok(Statement::Expression(ExpressionStatement {
+            expression: self.reconstruct_expression(expression.expression)?,
+            ..expression
+        }))
+    }
+
+    fn reconstruct_block(&mut self, block: Block) -> Result<Block> {
+        Ok(Block {
+            statements: block
+                .statements
+                .into_iter()
+                .map(|statement| self.reconstruct_statement(statement))
+                .collect::<Result<_>>()?,
+            ..block
+        })
+    }
+
+    /// Rewrites the global constants, circuits, and functions of `program`.
+    /// The programs it imports are not rewritten.
+    fn reconstruct_program(&mut self, program: Program) -> Result<Program> {
+        Ok(Program {
+            global_consts: program
+                .global_consts
+                .into_iter()
+                .map(|(names, definition)| Ok((names, self.reconstruct_global_const(definition)?)))
+                .collect::<Result<_>>()?,
+            circuits: program
+                .circuits
+                .into_iter()
+                .map(|(name, circuit)| Ok((name, self.reconstruct_circuit(circuit)?)))
+                .collect::<Result<_>>()?,
+            functions: program
+                .functions
+                .into_iter()
+                .map(|(name, function)| Ok((name, self.reconstruct_function(function)?)))
+                .collect::<Result<_>>()?,
+            ..program
+        })
+    }
+
+    fn reconstruct_global_const(&mut self, definition: DefinitionStatement) -> Result<DefinitionStatement> {
+        Ok(DefinitionStatement {
+            type_: definition
+                .type_
+                .map(|type_| self.reconstruct_type(type_, &definition.span))
+                .transpose()?,
+            value: self.reconstruct_expression(definition.value)?,
+            ..definition
+        })
+    }
+
+    fn reconstruct_circuit(&mut self, circuit: Circuit) -> Result<Circuit> {
+        Ok(Circuit {
+            members: circuit
+                .members
+                .into_iter()
+                .map(|member| {
+                    Ok(match member {
+                        CircuitMember::CircuitConst(identifier, type_, value) => {
+                            let type_ = self.reconstruct_type(type_, &identifier.span)?;
+                            CircuitMember::CircuitConst(identifier, type_, self.reconstruct_expression(value)?)
+                        }
+                        CircuitMember::CircuitVariable(identifier, type_) => {
+                            let type_ = self.reconstruct_type(type_, &identifier.span)?;
+                            CircuitMember::CircuitVariable(identifier, type_)
+                        }
+                        CircuitMember::CircuitFunction(function) => {
+                            CircuitMember::CircuitFunction(Box::new(self.reconstruct_function(*function)?))
+                        }
+                    })
+                })
+                .collect::<Result<_>>()?,
+            ..circuit
+        })
+    }
+
+    fn reconstruct_function(&mut self, function: Function) -> Result<Function> {
+        Ok(Function {
+            input: function
+                .input
+                .into_iter()
+                .map(|input| {
+                    Ok(match input {
+                        FunctionInput::Variable(variable) => FunctionInput::Variable(FunctionInputVariable {
+                            type_: self.reconstruct_type(variable.type_, &variable.span)?,
+                            ..variable
+                        }),
+                        input => input,
+                    })
+                })
+                .collect::<Result<_>>()?,
+            output: function
+                .output
+                .map(|type_| self.reconstruct_type(type_, &function.span))
+                .transpose()?,
+            block: self.reconstruct_block(function.block)?,
+            ..function
+        })
+    }
+
+    /// Rewrites `type_`, written at `span`: the type of a definition, a cast, a circuit member,
+    /// or a function parameter or output.
+    fn reconstruct_type(&mut self, type_: Type, _span: &Span) -> Result<Type> {
+        Ok(type_)
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+/// A traversal of a program, and of the statements and expressions in it.
+pub trait ProgramVisitor: StatementVisitor {
+    /// Visits the global constants, circuits, and functions of `program`, in this order.
+    /// The programs it imports are not visited.
+    fn visit_program(&mut self, program: &Program) {
+        program
+            .global_consts
+            .values()
+            .for_each(|definition| self.visit_definition(definition));
+        program
+            .circuits
+            .values()
+            .for_each(|circuit| self.visit_circuit(circuit));
+        program
+            .functions
+            .values()
+            .for_each(|function| self.visit_function(function));
+    }
+
+    fn visit_circuit(&mut self, circuit: &Circuit) {
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitConst(_, _, value) => self.visit_expression(value),
+                CircuitMember::CircuitVariable(..) => {}
+                CircuitMember::CircuitFunction(function) => self.visit_function(function),
+            }
+        }
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        self.visit_block(&function.block);
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::*;
+
+/// A traversal of statements, and of the expressions in them.
+pub trait StatementVisitor: ExpressionVisitor {
+    /// Visits `statement` by visiting its kind of statement.
+    fn visit_statement(&mut self, statement: &Statement) {
+        walk_statement(self, statement);
+    }
+
+    fn visit_return(&mut self, return_: &ReturnStatement) {
+        self.visit_expression(&return_.expression);
+    }
+
+    fn visit_definition(&mut self, definition: &DefinitionStatement) {
+        self.visit_expression(&definition.value);
+    }
+
+    fn visit_assign(&mut self, assign: &AssignStatement) {
+        for access in assign.assignee.accesses.iter() {
+            match access {
+                AssigneeAccess::ArrayIndex(index) => self.visit_expression(index),
+                AssigneeAccess::ArrayRange(left, right) => left
+                    .iter()
+                    .chain(right.iter())
+                    .for_each(|bound| self.visit_expression(bound)),
+                AssigneeAccess::Tuple(..) | AssigneeAccess::Member(_) => {}
+            }
+        }
+        self.visit_expression(&assign.value);
+    }
+
+    fn visit_conditional(&mut self, conditional: &ConditionalStatement) {
+        self.visit_expression(&conditional.condition);
+        self.visit_block(&conditional.block);
+        if let Some(next) = &conditional.next {
+            self.visit_statement(next);
+        }
+    }
+
+    fn visit_iteration(&mut self, iteration: &IterationStatement) {
+        self.visit_expression(&iteration.start);
+        self.visit_expression(&iteration.stop);
+        self.visit_block(&iteration.block);
+    }
+
+    fn visit_console(&mut self, console: &ConsoleStatement) {
+        match &console.function {
+            ConsoleFunction::Assert(expression) => self.visit_expression(expression),
+            ConsoleFunction::Error(args) | ConsoleFunction::Log(args) => args
+                .parameters
+                .iter()
+                .for_each(|parameter| self.visit_expression(parameter)),
+        }
+    }
+
+    fn visit_expression_statement(&mut self, expression: &ExpressionStatement) {
+        self.visit_expression(&expression.expression);
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        block
+            .statements
+            .iter()
+            .for_each(|statement| self.visit_statement(statement));
+    }
+}
+
+/// Visits the kind of `statement`, as the default [`StatementVisitor::visit_statement`] does.
+/// For visitors which override it to do something with every statement before visiting its kind.
+pub fn walk_statement<V: StatementVisitor + ?Sized>(visitor: &mut V, statement: &Statement) {
+    match statement {
+        Statement::Return(return_) => visitor.visit_return(return_),
+        Statement::Definition(definition) => visitor.visit_definition(definition),
+        Statement::Assign(assign) => visitor.visit_assign(assign),
+        Statement::Conditional(conditional) => visitor.visit_conditional(conditional),
+        Statement::Iteration(iteration) => visitor.visit_iteration(iteration),
+        Statement::Console(console) => visitor.visit_console(console),
+        Statement::Expression(expression) => visitor.visit_expression_statement(expression),
+        Statement::Block(block) => visitor.visit_block(block),
+    }
+}
@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+#ifndef LEO_H
+#define LEO_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The level of a diagnostic. */
+typedef enum LeoLevel {
+    LEO_LEVEL_ERROR = 0,
+    LEO_LEVEL_WARNING = 1,
+} LeoLevel;
+
+/* An error or warning of a compiled program. Its strings are owned by the result it belongs to. */
+typedef struct LeoDiagnostic {
+    LeoLevel level;
+    /* The code of an error, e.g. "EPAR0370005", or the name of the lint of a warning. */
+    const char *code;
+    /* The message of the diagnostic, without its location. */
+    const char *message;
+    /* The diagnostic in the JSON format of `leo build --json-diagnostics`, with its spans. */
+    const char *json;
+} LeoDiagnostic;
+
+/* The result of compiling a program. */
+typedef struct LeoResult LeoResult;
+
+/* Compiles the program `source`; `path` may be NULL and only names the program in its diagnostics.
+ * Returns NULL if `source` is NULL or either string is not valid UTF-8.
+ * The result must be freed with `leo_free_result`. */
+LeoResult *leo_compile(const char *source, const char *path);
+
+/* Returns the AST of the compiled program as JSON, or NULL if it has errors. */
+const char *leo_result_ast(const LeoResult *result);
+
+/* Returns the number of diagnostics of the compiled program, errors first. */
+size_t leo_result_diagnostic_count(const LeoResult *result);
+
+/* Returns the diagnostic at `index`, or NULL if there are not that many. */
+const LeoDiagnostic *leo_result_diagnostic(const LeoResult *result, size_t index);
+
+/* Frees `result` and the strings and diagnostics it owns. Does nothing if `result` is NULL. */
+void leo_free_result(LeoResult *result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LEO_H */
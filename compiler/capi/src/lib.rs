@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! C ABI for the Leo compiler, so that it can be linked from other languages, e.g., by Python bindings or editors.
+//!
+//! [`leo_compile()`] compiles a program and returns a [`LeoResult`], whose AST and diagnostics are read with
+//! the `leo_result_*` functions, and which is freed with [`leo_free_result()`].
+//! The strings returned are owned by the result, and are valid until it is freed.
+
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::emitter::{json_diagnostic, json_warning, BufferEmitter, Handler};
+use leo_span::symbol::create_session_if_not_set_then;
+
+use std::{
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic, ptr,
+};
+
+/// The level of a diagnostic.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeoLevel {
+    Error = 0,
+    Warning = 1,
+}
+
+/// An error or warning of a compiled program.
+#[repr(C)]
+#[derive(Debug)]
+pub struct LeoDiagnostic {
+    /// Whether the diagnostic is an error or a warning.
+    pub level: LeoLevel,
+    /// The code of an error, e.g. `EPAR0370005`, or the name of the lint of a warning.
+    pub code: *const c_char,
+    /// The message of the diagnostic, without its location.
+    pub message: *const c_char,
+    /// The diagnostic in the JSON format of `leo build --json-diagnostics`, with its spans.
+    pub json: *const c_char,
+}
+
+/// The result of compiling a program, which is opaque to C.
+#[derive(Debug)]
+pub struct LeoResult {
+    /// The AST of the compiled program as JSON, or `None` if it has errors.
+    ast: Option<CString>,
+    /// The diagnostics, whose strings point into `strings`.
+    diagnostics: Vec<LeoDiagnostic>,
+    /// The strings of the diagnostics. The content of a `CString` does not move when the vector grows.
+    strings: Vec<CString>,
+}
+
+impl LeoResult {
+    /// Returns the string `string` owned by the result, with any nul byte replaced so that it is a valid C string.
+    fn c_string(&mut self, string: &str) -> *const c_char {
+        let string = CString::new(string.replace('\0', "\u{fffd}")).expect("nul bytes are replaced");
+        let pointer = string.as_ptr();
+        self.strings.push(string);
+        pointer
+    }
+
+    /// Adds the JSON diagnostic `diagnostic`, of `level`.
+    fn push_diagnostic(&mut self, level: LeoLevel, diagnostic: serde_json::Value) {
+        let code = self.c_string(diagnostic["code"]["code"].as_str().unwrap_or_default());
+        let message = self.c_string(diagnostic["message"].as_str().unwrap_or_default());
+        let json = self.c_string(&diagnostic.to_string());
+        self.diagnostics.push(LeoDiagnostic {
+            level,
+            code,
+            message,
+            json,
+        });
+    }
+}
+
+/// Compiles the program `source`, the content of the file at `path`, and returns its result,
+/// which must be freed with [`leo_free_result()`].
+/// `path` may be null, and only names the program in its diagnostics. No file is read or written.
+///
+/// Returns null if `source` is null, if it or `path` are not valid UTF-8, or if the compiler panics,
+/// as a panic must not unwind into the caller.
+///
+/// # Safety
+///
+/// `source` and `path` must be null or point to nul-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn leo_compile(source: *const c_char, path: *const c_char) -> *mut LeoResult {
+    if source.is_null() {
+        return ptr::null_mut();
+    }
+    let source = match CStr::from_ptr(source).to_str() {
+        Ok(source) => source,
+        Err(_) => return ptr::null_mut(),
+    };
+    let path = if path.is_null() {
+        "main.leo"
+    } else {
+        match CStr::from_ptr(path).to_str() {
+            Ok(path) => path,
+            Err(_) => return ptr::null_mut(),
+        }
+    };
+
+    match panic::catch_unwind(|| compile(source, path)) {
+        Ok(result) => Box::into_raw(Box::new(result)),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Compiles the program `source`, the content of the file at `path`, collecting its diagnostics.
+fn compile(source: &str, path: &str) -> LeoResult {
+    let buf = BufferEmitter::new();
+    let handler = Handler::new(Box::new(buf.clone())).with_sorted_diagnostics();
+
+    // The AST refers to the symbols of the session, so it is serialized before the session ends.
+    let ast = create_session_if_not_set_then(|_| {
+        let ast = Compiler::compile_source(&handler, path, source, &CompilerOptions::default());
+        handler
+            .extend_if_error(ast)
+            .ok()
+            .and_then(|ast| ast.to_json_string().ok())
+    });
+    handler.flush();
+
+    let mut result = LeoResult {
+        ast: ast.map(|ast| CString::new(ast).expect("JSON escapes nul bytes")),
+        diagnostics: Vec::new(),
+        strings: Vec::new(),
+    };
+    for error in buf.extract().into_inner().iter() {
+        result.push_diagnostic(LeoLevel::Error, json_diagnostic(error));
+    }
+    for warning in buf.extract_warnings().into_inner().iter() {
+        result.push_diagnostic(LeoLevel::Warning, json_warning(warning));
+    }
+    result
+}
+
+/// Returns the AST of the compiled program as JSON, or null if it has errors.
+///
+/// # Safety
+///
+/// `result` must be a result returned by [`leo_compile()`] which is not freed.
+#[no_mangle]
+pub unsafe extern "C" fn leo_result_ast(result: *const LeoResult) -> *const c_char {
+    let result = &*result;
+    match &result.ast {
+        Some(ast) => ast.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Returns the number of diagnostics of the compiled program, errors first.
+///
+/// # Safety
+///
+/// `result` must be a result returned by [`leo_compile()`] which is not freed.
+#[no_mangle]
+pub unsafe extern "C" fn leo_result_diagnostic_count(result: *const LeoResult) -> usize {
+    let result = &*result;
+    result.diagnostics.len()
+}
+
+/// Returns the diagnostic at `index` of the compiled program, or null if there are not that many.
+///
+/// # Safety
+///
+/// `result` must be a result returned by [`leo_compile()`] which is not freed.
+#[no_mangle]
+pub unsafe extern "C" fn leo_result_diagnostic(result: *const LeoResult, index: usize) -> *const LeoDiagnostic {
+    let result = &*result;
+    match result.diagnostics.get(index) {
+        Some(diagnostic) => diagnostic,
+        None => ptr::null(),
+    }
+}
+
+/// Frees `result`, and the strings and diagnostics it owns. Does nothing if `result` is null.
+///
+/// # Safety
+///
+/// `result` must be null or a result returned by [`leo_compile()`] which is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn leo_free_result(result: *mut LeoResult) {
+    if !result.is_null() {
+        drop(Box::from_raw(result));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compiles `source` through the C ABI, returning its AST and the codes of its diagnostics.
+    fn compile_through_ffi(source: &str) -> (Option<String>, Vec<(LeoLevel, String)>) {
+        let source = CString::new(source).unwrap();
+        unsafe {
+            let result = leo_compile(source.as_ptr(), ptr::null());
+            assert!(!result.is_null());
+
+            let ast = leo_result_ast(result);
+            let ast = (!ast.is_null()).then(|| CStr::from_ptr(ast).to_string_lossy().into_owned());
+            let diagnostics = (0..leo_result_diagnostic_count(result))
+                .map(|index| {
+                    let diagnostic = &*leo_result_diagnostic(result, index);
+                    (
+                        diagnostic.level,
+                        CStr::from_ptr(diagnostic.code).to_string_lossy().into_owned(),
+                    )
+                })
+                .collect();
+            assert!(leo_result_diagnostic(result, leo_result_diagnostic_count(result)).is_null());
+
+            leo_free_result(result);
+            (ast, diagnostics)
+        }
+    }
+
+    #[test]
+    fn compiles_through_ffi() {
+        let (ast, diagnostics) = compile_through_ffi("function main(a: u8) -> u8 { return a * 2u8; }");
+        assert!(ast.unwrap().contains("\"functions\""));
+        assert!(diagnostics.is_empty());
+
+        let (ast, diagnostics) = compile_through_ffi("function main( -> u8 { return 1u8; }");
+        assert!(ast.is_none());
+        assert_eq!(diagnostics[0].0, LeoLevel::Error);
+        assert!(diagnostics[0].1.starts_with("EPAR"));
+    }
+
+    #[test]
+    fn rejects_null_source() {
+        assert!(unsafe { leo_compile(ptr::null(), ptr::null()) }.is_null());
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The file systems the compiler reads Leo files from.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A file system the compiler reads Leo files from.
+pub trait FileSystem {
+    /// Returns the content of the file at `path`.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+}
+
+impl<T: FileSystem + ?Sized> FileSystem for &T {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        (**self).read_to_string(path)
+    }
+}
+
+/// The file system of the operating system, i.e., the disk.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+}
+
+/// The file system of the operating system, with relative paths resolved against `root`
+/// instead of the current directory, so programs can be compiled under paths relative to their package.
+#[derive(Clone, Debug)]
+pub struct RootedFileSystem {
+    root: PathBuf,
+}
+
+impl RootedFileSystem {
+    /// Returns the file system resolving relative paths against `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl FileSystem for RootedFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(self.root.join(path))
+    }
+}
+
+/// A file system held in memory, for embedders without a disk or with unsaved files,
+/// e.g., a playground, tests, or an editor.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFileSystem {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl MemoryFileSystem {
+    /// Returns an empty file system.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the file system with `content` as the file at `path`.
+    pub fn with_file(mut self, path: impl Into<PathBuf>, content: impl Into<String>) -> Self {
+        self.insert(path, content);
+        self
+    }
+
+    /// Writes `content` as the file at `path`, returning the previous content, if any.
+    pub fn insert(&mut self, path: impl Into<PathBuf>, content: impl Into<String>) -> Option<String> {
+        self.files.insert(path.into(), content.into())
+    }
+
+    /// Removes the file at `path`, returning its content, if any.
+    pub fn remove(&mut self, path: &Path) -> Option<String> {
+        self.files.remove(path)
+    }
+
+    /// Returns the paths and contents of the files, ordered by path.
+    pub fn files(&self) -> impl Iterator<Item = (&Path, &str)> {
+        self.files
+            .iter()
+            .map(|(path, content)| (path.as_path(), content.as_str()))
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files.get(path).cloned().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no file at `{}` in memory", path.display()),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Compiler;
+    use leo_errors::emitter::Handler;
+
+    const PROGRAM: &str = "function main(a: u8) -> u8 {\n    return a + 1u8;\n}\n";
+
+    #[test]
+    fn compiles_from_memory() {
+        let (handler, _) = Handler::new_with_buf();
+        let files = MemoryFileSystem::new().with_file("src/main.leo", PROGRAM);
+        let compiler = Compiler::new(&handler, "src/main.leo".into(), "outputs".into())
+            .with_options(crate::CompilerOptions {
+                emitted_stages: vec![],
+                ..Default::default()
+            })
+            .with_file_system(&files);
+        assert!(compiler.checksum().is_ok());
+        assert!(compiler.compile().is_ok());
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let (handler, _) = Handler::new_with_buf();
+        let files = MemoryFileSystem::new().with_file("src/main.leo", PROGRAM);
+        let compiler =
+            Compiler::new(&handler, "src/other.leo".into(), "outputs".into()).with_file_system(files.clone());
+        assert!(compiler.check().is_err());
+        assert_eq!(
+            files.read_to_string(Path::new("src/other.leo")).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn parses_programs_from_strings() {
+        let (handler, _) = Handler::new_with_buf();
+        let ast = Compiler::parse_program_from_string(&handler, "main.leo", PROGRAM).unwrap();
+        assert_eq!(ast.as_repr().functions.len(), 1);
+        assert!(Compiler::parse_program_from_string(&handler, "main.leo", "function main(").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The type of the code at a position in a program, for editors to show on hover.
+
+use crate::{SymbolKind, SymbolTable};
+use leo_ast::Type;
+use leo_ast_passes::ExpressionTypes;
+use leo_span::Span;
+
+/// The type of the code at a position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypeAt {
+    /// The span of the expression or name whose type it is.
+    pub span: Span,
+    /// The type.
+    pub type_: Type,
+    /// The span of the definition of the name at the position, if there is one.
+    pub definition: Option<Span>,
+}
+
+/// Returns the type of the code at the 1-based line and column `position` of a program,
+/// given the `types` of its expressions and its `symbols`, e.g., of the AST the checks returned.
+///
+/// This is the type of the innermost expression of a known type at `position`, or else the type of the
+/// constant, input, variable, or member whose name is there, as in `let x = ...`.
+pub fn type_at(types: &ExpressionTypes, symbols: &SymbolTable, position: (usize, usize)) -> Option<TypeAt> {
+    let name = symbols.name_at(position);
+    let (span, type_) = match (types.at(position), name) {
+        (Some((span, type_)), _) => (span.clone(), type_.clone()),
+        (None, Some((span, id))) => {
+            let definition = symbols.definition(id);
+            match definition.kind {
+                SymbolKind::Constant | SymbolKind::Input | SymbolKind::Variable | SymbolKind::Member => {
+                    (span.clone(), definition.type_.clone()?)
+                }
+                _ => return None,
+            }
+        }
+        (None, None) => return None,
+    };
+    Some(TypeAt {
+        span,
+        type_,
+        definition: name.map(|(_, id)| symbols.definition(id).span.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn finds_types_and_definitions() {
+        create_session_if_not_set_then(|_| {
+            let source = "circuit Point {\n    x: u8,\n}\nfunction main(a: u8) -> u8 {\n    \
+                          let p = Point { x: a };\n    return p.x + 1u8;\n}";
+            let (handler, _) = Handler::new_with_buf();
+            let ast = crate::Compiler::check_source(&handler, "main.leo", source).unwrap();
+            let program = ast.as_repr();
+            let (types, symbols) = (ExpressionTypes::new(program), SymbolTable::new(program));
+
+            let hover = |position| {
+                let found = type_at(&types, &symbols, position)?;
+                let definition = found.definition.map(|span| (span.line_start, span.col_start));
+                // As the language server shows it, without the `circuit` prefix of `Display`.
+                Some((found.type_.source_name(), definition))
+            };
+            // The type of `p` is inferred, and shown at its definition and its uses.
+            assert_eq!(hover((5, 9)), Some(("Point".to_string(), Some((5, 9)))));
+            assert_eq!(hover((6, 12)), Some(("Point".to_string(), Some((5, 9)))));
+            // The member `x` of `p.x`, and the sum.
+            assert_eq!(hover((6, 14)), Some(("u8".to_string(), Some((2, 5)))));
+            assert_eq!(hover((6, 16)), Some(("u8".to_string(), None)));
+            // Functions have a signature rather than a type.
+            assert_eq!(hover((4, 10)), None);
+        });
+    }
+}
@@ -22,21 +22,60 @@
 #![allow(clippy::upper_case_acronyms)]
 #![doc = include_str!("../README.md")]
 
-pub use leo_ast::Ast;
-use leo_ast::AstPass;
+pub mod file_system;
+pub use file_system::*;
+
+pub mod hover;
+pub use hover::*;
+
+pub mod options;
+pub use options::*;
+
+pub mod parse_cache;
+pub use parse_cache::*;
+
+pub mod report;
+pub use report::*;
+
+pub mod semantic_tokens;
+pub use semantic_tokens::*;
+
+pub mod span_trace;
+pub use span_trace::*;
+
+pub mod stage;
+pub use stage::*;
+
+pub mod symbol_table;
+pub use symbol_table::*;
+
+#[cfg(test)]
+mod test;
+
+pub use leo_ast::{Ast, TypedAst};
+pub use leo_ast_passes::{ExpressionTypes, OptLevel, Pass, PassManager, StandardPass, TypeInferrer};
 use leo_errors::emitter::Handler;
 use leo_errors::{CompilerError, Result};
 use leo_span::symbol::create_session_if_not_set_then;
 
 use sha2::{Digest, Sha256};
-use std::fs;
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// The primary entry point of the Leo compiler.
 pub struct Compiler<'a> {
     handler: &'a Handler,
     main_file_path: PathBuf,
     output_directory: PathBuf,
+    options: CompilerOptions,
+    /// The passes following the parser.
+    passes: PassManager,
+    /// The cache of parsed files, if any.
+    parse_cache: Option<ParseCache>,
+    /// The file system the program files are read from.
+    file_system: Box<dyn FileSystem + 'a>,
+    /// The measurements of the compilation, if they are taken.
+    report: Option<BuildReport>,
 }
 
 impl<'a> Compiler<'a> {
@@ -48,7 +87,76 @@ impl<'a> Compiler<'a> {
             handler,
             main_file_path,
             output_directory,
+            options: CompilerOptions::default(),
+            passes: CompilerOptions::default().passes(),
+            parse_cache: None,
+            file_system: Box::new(OsFileSystem),
+            report: None,
+        }
+    }
+
+    ///
+    /// Returns the compiler compiling under `options`.
+    ///
+    pub fn with_options(mut self, options: CompilerOptions) -> Self {
+        self.passes = options.passes();
+        self.options = options;
+        self
+    }
+
+    ///
+    /// Returns the compiler running `passes` after the parser instead of those of its options,
+    /// e.g., with passes of an embedder inserted.
+    ///
+    pub fn with_passes(mut self, passes: PassManager) -> Self {
+        self.passes = passes;
+        self
+    }
+
+    ///
+    /// Returns the compiler looking up parsed files in `cache` before parsing them.
+    ///
+    pub fn with_parse_cache(mut self, cache: ParseCache) -> Self {
+        self.parse_cache = Some(cache);
+        self
+    }
+
+    ///
+    /// Returns the compiler reading the program files from `file_system` instead of the disk,
+    /// e.g., from a [`MemoryFileSystem`] holding unsaved files.
+    ///
+    pub fn with_file_system(mut self, file_system: impl FileSystem + 'a) -> Self {
+        self.file_system = Box::new(file_system);
+        self
+    }
+
+    ///
+    /// Writes `ast` to the output directory, if the AST of `stage` is emitted.
+    ///
+    fn emit(&self, stage: Stage, ast: &leo_ast::Ast) -> Result<()> {
+        if !self.options.emitted_stages.contains(&stage) {
+            return Ok(());
+        }
+        if self.options.spans_enabled {
+            ast.to_json_file(self.output_directory.clone(), stage.file_name())
+        } else {
+            ast.to_json_file_without_keys(self.output_directory.clone(), stage.file_name(), &["span"])
+        }
+    }
+
+    ///
+    /// Writes the typed AST of `ast` to the output directory, if it is emitted.
+    ///
+    fn emit_typed(&self, ast: &leo_ast::Ast) -> Result<()> {
+        if !self.options.emitted_stages.contains(&Stage::Typed) {
+            return Ok(());
         }
+        let excluded_keys: &[&str] = if self.options.spans_enabled { &[] } else { &["span"] };
+        TypeInferrer::typed_ast(ast.clone()).to_json_file_without_keys(
+            self.output_directory.clone(),
+            Stage::Typed.file_name(),
+            excluded_keys,
+        )
     }
 
     ///
@@ -56,7 +164,9 @@ impl<'a> Compiler<'a> {
     ///
     pub fn checksum(&self) -> Result<String> {
         // Read in the main file as string
-        let unparsed_file = fs::read_to_string(&self.main_file_path)
+        let unparsed_file = self
+            .file_system
+            .read_to_string(&self.main_file_path)
             .map_err(|e| CompilerError::file_read_error(self.main_file_path.clone(), e))?;
 
         // Hash the file contents
@@ -70,32 +180,155 @@ impl<'a> Compiler<'a> {
     ///
     /// Runs the compiler stages.
     ///
-    fn compiler_stages(self) -> Result<leo_ast::Ast> {
+    fn compiler_stages(&mut self) -> Result<leo_ast::Ast> {
+        let ast = self.checked_ast()?;
+        let mut passes = std::mem::take(&mut self.passes);
+        let mut start = Instant::now();
+        let ast = passes.run_optimizations(self.handler, ast, |pass, ast| {
+            self.record_pass(pass, start, ast);
+            start = Instant::now();
+            Ok(())
+        })?;
+        self.passes = passes;
+        self.emit(Stage::Optimized, &ast)?;
+        if let Some(report) = &mut self.report {
+            report.record_compiled(ast.as_repr());
+        }
+        Ok(ast)
+    }
+
+    ///
+    /// Records that `pass`, started at `start`, returned `ast`, if the measurements are taken.
+    ///
+    fn record_pass(&mut self, pass: &dyn Pass, start: Instant, ast: &leo_ast::Ast) {
+        if let Some(report) = &mut self.report {
+            report.record_pass(pass.name(), start.elapsed(), ast.as_repr());
+        }
+    }
+
+    ///
+    /// Parses the program file and runs the passes which check it.
+    ///
+    fn checked_ast(&mut self) -> Result<leo_ast::Ast> {
         // Load the program file.
-        let program_string = fs::read_to_string(&self.main_file_path)
+        let program_string = self
+            .file_system
+            .read_to_string(&self.main_file_path)
             .map_err(|e| CompilerError::file_read_error(self.main_file_path.clone(), e))?;
+        let program_string = self.options.normalize_source(&program_string);
 
-        // Use the parser to construct the abstract syntax tree (ast).
-        let mut ast: leo_ast::Ast = leo_parser::parse_ast(
-            self.handler,
-            self.main_file_path.to_str().unwrap_or_default(),
-            program_string,
-        )?;
-        // Write the AST snapshot post parsing.
-        ast.to_json_file_without_keys(self.output_directory.clone(), "initial_ast.json", &["span"])?;
-
-        // Canonicalize the AST.
-        ast = leo_ast_passes::Canonicalizer::do_pass(Default::default(), ast.into_repr())?;
-        // Write the AST snapshot post parsing
-        ast.to_json_file_without_keys(self.output_directory, "canonicalization_ast.json", &["span"])?;
+        // Use the parser to construct the abstract syntax tree (ast), unless it is cached.
+        let path = self.main_file_path.to_str().unwrap_or_default();
+        let start = Instant::now();
+        let ast = match self
+            .parse_cache
+            .as_ref()
+            .and_then(|cache| cache.get(path, &program_string))
+        {
+            Some(ast) => ast,
+            None => {
+                let ast = leo_parser::parse_ast(self.handler, path, &program_string)?;
+                if let Some(cache) = &self.parse_cache {
+                    cache.insert(path, &program_string, &ast)?;
+                }
+                ast
+            }
+        };
+        if let Some(report) = &mut self.report {
+            report.parse_micros = start.elapsed().as_micros() as u64;
+            report.tokens = leo_parser::tokenize(path, &program_string).map_or(0, |tokens| tokens.len());
+            report.parsed_nodes = NodeCounter::count(ast.as_repr()).nodes();
+        }
+        self.emit(Stage::Initial, &ast)?;
 
+        let mut passes = std::mem::take(&mut self.passes);
+        let mut start = Instant::now();
+        let ast = passes.run_checks(self.handler, ast, |pass, ast| {
+            self.record_pass(pass, start, ast);
+            if pass.name() == StandardPass::Canonicalization.name() {
+                self.emit(Stage::Canonicalized, ast)?;
+            }
+            // The time to emit the AST is not the time of the next pass.
+            start = Instant::now();
+            Ok(())
+        })?;
+        self.passes = passes;
+        self.emit(Stage::Checked, &ast)?;
+        self.emit_typed(&ast)?;
         Ok(ast)
     }
 
+    ///
+    /// Parses `source`, the content of the file `name`, into an AST without reading any file.
+    ///
+    pub fn parse_program_from_string(handler: &Handler, name: &str, source: &str) -> Result<leo_ast::Ast> {
+        create_session_if_not_set_then(|_| leo_parser::parse_ast(handler, name, source))
+    }
+
+    ///
+    /// Runs the checks of the compiler stages on `source`, the content of the file at `path`, without writing any output.
+    /// This checks programs which are not saved yet, e.g., in an editor.
+    /// The AST refers to the symbols of the current session, so the caller must hold one while it uses the AST,
+    /// e.g., with `create_session_if_not_set_then`.
+    ///
+    pub fn check_source(handler: &Handler, path: &str, source: &str) -> Result<leo_ast::Ast> {
+        create_session_if_not_set_then(|_| {
+            let ast = Self::parse_program_from_string(handler, path, source)?;
+            CompilerOptions::default()
+                .passes()
+                .without_optimizations()
+                .run_checks(handler, ast, |_, _| Ok(()))
+        })
+    }
+
+    ///
+    /// Compiles `source`, the content of the file at `path`, without reading or writing any file.
+    /// This compiles programs where there is no filesystem, e.g., in a browser.
+    /// As nothing is written, the emitted stages of `options` are ignored.
+    /// As with [`Compiler::check_source()`], the caller must hold the session the AST refers to while it uses it.
+    ///
+    pub fn compile_source(
+        handler: &Handler,
+        path: &str,
+        source: &str,
+        options: &CompilerOptions,
+    ) -> Result<leo_ast::Ast> {
+        create_session_if_not_set_then(|_| {
+            let ast = Self::parse_program_from_string(handler, path, &options.normalize_source(source))?;
+            options.passes().run(handler, ast)
+        })
+    }
+
+    ///
+    /// Returns the checked Leo program, before optimizations.
+    /// Its behavior is exactly that of the source, so it is the program to interpret, e.g., in tests.
+    ///
+    pub fn check(mut self) -> Result<leo_ast::Ast> {
+        self.passes = self.passes.without_optimizations();
+        create_session_if_not_set_then(|_| self.checked_ast())
+    }
+
+    ///
+    /// Returns the checked Leo program with the type of each of its expressions, e.g., for tools analyzing it.
+    ///
+    pub fn check_typed(mut self) -> Result<TypedAst> {
+        self.passes = self.passes.without_optimizations();
+        create_session_if_not_set_then(|_| Ok(TypeInferrer::typed_ast(self.checked_ast()?)))
+    }
+
     ///
     /// Returns a compiled Leo program.
     ///
-    pub fn compile(self) -> Result<leo_ast::Ast> {
+    pub fn compile(mut self) -> Result<leo_ast::Ast> {
         create_session_if_not_set_then(|_| self.compiler_stages())
     }
+
+    ///
+    /// Returns a compiled Leo program, with the measurements of its compilation.
+    ///
+    pub fn compile_with_report(mut self) -> Result<(leo_ast::Ast, BuildReport)> {
+        self.report = Some(BuildReport::default());
+        let ast = create_session_if_not_set_then(|_| self.compiler_stages())?;
+        Ok((ast, self.report.take().unwrap_or_default()))
+    }
 }
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The options controlling the passes of the compiler and what it writes to the outputs directory.
+
+use crate::Stage;
+
+use leo_ast_passes::{Cfg, CfgPruner, OptLevel, Pass, PassManager, StandardPass};
+
+use std::borrow::Cow;
+
+/// The options of a compilation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompilerOptions {
+    /// The preset of optimizations to run.
+    pub opt_level: OptLevel,
+    /// Whether multiplications and divisions by powers of two become shifts, if the preset runs it.
+    pub strength_reduction_enabled: bool,
+    /// The stages whose AST is written to the outputs directory.
+    pub emitted_stages: Vec<Stage>,
+    /// Whether the ASTs written to the outputs directory include the spans of their nodes.
+    pub spans_enabled: bool,
+    /// The build configuration `@cfg` annotations are evaluated against, e.g., `network = "testnet"`.
+    pub cfg: Cfg,
+    /// Whether the outputs are byte-identical wherever the program is compiled.
+    /// The ASTs record the source of each node, so the line endings of the source are normalized.
+    /// They also record the path of the file as given to the compiler, which should then be relative.
+    pub deterministic: bool,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        Self {
+            opt_level: OptLevel::default(),
+            strength_reduction_enabled: true,
+            emitted_stages: vec![Stage::Initial, Stage::Canonicalized],
+            spans_enabled: false,
+            cfg: Cfg::new(),
+            deterministic: false,
+        }
+    }
+}
+
+impl CompilerOptions {
+    /// Returns the passes following the parser under these options.
+    pub fn passes(&self) -> PassManager {
+        let mut passes = PassManager::new(self.opt_level);
+        passes
+            .insert_before(
+                StandardPass::DuplicateDetection.name(),
+                CfgPruner::new(self.cfg.clone()),
+            )
+            .expect("the checks start with duplicate detection");
+        if self.strength_reduction_enabled {
+            passes
+        } else {
+            passes.without_pass(StandardPass::StrengthReduction.name())
+        }
+    }
+
+    /// Returns `source` as it is compiled under these options:
+    /// with `\r\n` line endings replaced by `\n` if the compilation is deterministic,
+    /// as they depend on the platform the file was checked out on.
+    pub fn normalize_source<'a>(&self, source: &'a str) -> Cow<'a, str> {
+        if self.deterministic && source.contains('\r') {
+            Cow::Owned(source.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(source)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Compiler, MemoryFileSystem};
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "circuit Pair {\n    a: u8,\n    b: u8,\n}\n\nfunction main(x: u8, y: u8) -> u8 {\n    \
+                           let p = Pair { b: y, a: x };\n    let z = p.a * 4u8;\n    return z + p.b;\n}\n";
+
+    /// Returns the compiled AST of `source`, as written to the outputs directory.
+    fn compiled_json(source: &str) -> String {
+        let (handler, _) = Handler::new_with_buf();
+        let options = CompilerOptions {
+            emitted_stages: vec![],
+            deterministic: true,
+            ..Default::default()
+        };
+        let ast = Compiler::new(&handler, "src/main.leo".into(), "outputs".into())
+            .with_options(options)
+            .with_file_system(MemoryFileSystem::new().with_file("src/main.leo", source))
+            .compile()
+            .unwrap();
+        leo_ast::to_json(ast.as_repr()).unwrap()
+    }
+
+    #[test]
+    fn deterministic_outputs() {
+        create_session_if_not_set_then(|_| {
+            let output = compiled_json(PROGRAM);
+            assert_eq!(output, compiled_json(PROGRAM));
+
+            // The output does not depend on the symbols interned before, e.g., by other packages.
+            compiled_json("function helper(q: u16, w: u16) -> u16 {\n    return w - q;\n}\n");
+            assert_eq!(output, compiled_json(PROGRAM));
+
+            // Nor on the line endings of the platform the source was checked out on.
+            assert_eq!(output, compiled_json(&PROGRAM.replace('\n', "\r\n")));
+        });
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Caches the AST of each parsed file in the build directory, keyed on the hash of its content,
+//! so that a rebuild only parses the files which changed.
+
+use leo_ast::Ast;
+use leo_errors::{CompilerError, Result};
+
+use sha2::{Digest, Sha256};
+use std::{fs, path::PathBuf};
+
+/// A cache of parsed ASTs, with one file per source file and content.
+///
+/// An entry is named after the hash of the source file's path and the hash of its content,
+/// so an edited file misses the cache, and its stale entry is removed when the new one is written.
+/// The version of the compiler is part of the content hash, as the AST format changes between versions.
+#[derive(Clone, Debug)]
+pub struct ParseCache {
+    directory: PathBuf,
+}
+
+impl ParseCache {
+    /// Returns the cache stored in `directory`, which is created when the first entry is written.
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Returns the AST of `source`, the content of the file at `path`, if it is cached.
+    /// An entry which cannot be read is a miss, so the file is parsed again.
+    pub fn get(&self, path: &str, source: &str) -> Option<Ast> {
+        let json = fs::read_to_string(self.entry(path, source)).ok()?;
+        leo_ast::from_json(&json).ok().map(Ast::new)
+    }
+
+    /// Caches `ast`, parsed from `source`, the content of the file at `path`,
+    /// removing the entries of previous contents of the file.
+    pub fn insert(&self, path: &str, source: &str, ast: &Ast) -> Result<()> {
+        fs::create_dir_all(&self.directory).map_err(CompilerError::output_file_io_error)?;
+
+        let prefix = Self::path_prefix(path);
+        let entry = self.entry(path, source);
+        for stale in fs::read_dir(&self.directory).map_err(CompilerError::output_file_io_error)? {
+            let stale = stale.map_err(CompilerError::output_file_io_error)?.path();
+            let is_same_file = stale
+                .file_name()
+                .and_then(|name| name.to_str())
+                .map_or(false, |name| name.starts_with(&prefix));
+            if is_same_file && stale != entry {
+                fs::remove_file(&stale).map_err(CompilerError::output_file_io_error)?;
+            }
+        }
+
+        fs::write(&entry, leo_ast::to_json(ast.as_repr())?).map_err(CompilerError::output_file_io_error)?;
+        Ok(())
+    }
+
+    /// Returns the path of the entry of `source`, the content of the file at `path`.
+    fn entry(&self, path: &str, source: &str) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update([0]);
+        hasher.update(source.as_bytes());
+        self.directory
+            .join(format!("{}{:x}.json", Self::path_prefix(path), hasher.finalize()))
+    }
+
+    /// Returns the prefix of the names of the entries of the file at `path`.
+    fn path_prefix(path: &str) -> String {
+        let hash = format!("{:x}", Sha256::digest(path.as_bytes()));
+        format!("{}-", &hash[..16])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn caches_each_content() {
+        create_session_if_not_set_then(|_| {
+            let directory = std::env::temp_dir().join(format!("leo-parse-cache-{}", std::process::id()));
+            let cache = ParseCache::new(directory.clone());
+            let handler = Handler::default();
+            let (first, second) = ("function main() {}", "function main() -> u8 { return 1u8; }");
+
+            assert_eq!(cache.get("main.leo", first), None);
+            let ast = leo_parser::parse_ast(&handler, "main.leo", first).unwrap();
+            cache.insert("main.leo", first, &ast).unwrap();
+            assert_eq!(cache.get("main.leo", first), Some(ast));
+
+            // Caching the edited file evicts its previous content.
+            let ast = leo_parser::parse_ast(&handler, "main.leo", second).unwrap();
+            cache.insert("main.leo", second, &ast).unwrap();
+            assert_eq!(cache.get("main.leo", first), None);
+            assert_eq!(cache.get("main.leo", second), Some(ast));
+
+            fs::remove_dir_all(directory).unwrap();
+        });
+    }
+}
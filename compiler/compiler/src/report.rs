@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! Measurements of a compilation, for tracking the performance of the compiler over time.
+
+use leo_ast::{
+    walk_expression, walk_statement, Expression, ExpressionVisitor, Function, Program, ProgramVisitor, Statement,
+    StatementVisitor,
+};
+
+use serde::Serialize;
+use std::time::Duration;
+
+/// The measurements of a compilation, returned by [`crate::Compiler::compile_with_report`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct BuildReport {
+    /// The number of tokens of the main file.
+    pub tokens: usize,
+    /// The time to parse the main file, or to load it from the parse cache, in microseconds.
+    pub parse_micros: u64,
+    /// The number of nodes of the AST of the main file, as parsed.
+    pub parsed_nodes: usize,
+    /// The passes, in the order they ran.
+    pub passes: Vec<PassReport>,
+    /// The number of statements of the compiled program.
+    /// There is no backend emitting instructions yet, so statements are what the program is made of.
+    pub compiled_statements: usize,
+    /// The peak resident memory of the process, in KiB, where the operating system reports it.
+    pub peak_memory_kib: Option<u64>,
+}
+
+/// The measurements of a pass of a compilation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PassReport {
+    /// The name of the pass.
+    pub name: String,
+    /// The wall time of the pass, in microseconds.
+    pub micros: u64,
+    /// The number of nodes of the AST the pass returned.
+    pub nodes: usize,
+}
+
+impl BuildReport {
+    /// Records that the pass `name` took `time` and returned `program`.
+    pub fn record_pass(&mut self, name: &str, time: Duration, program: &Program) {
+        self.passes.push(PassReport {
+            name: name.to_string(),
+            micros: time.as_micros() as u64,
+            nodes: NodeCounter::count(program).nodes(),
+        });
+    }
+
+    /// Records `program` as the compiled program, and the memory used to compile it.
+    pub fn record_compiled(&mut self, program: &Program) {
+        self.compiled_statements = NodeCounter::count(program).statements;
+        self.peak_memory_kib = peak_memory_kib();
+    }
+}
+
+/// Counts the nodes of a program: its global constants, circuits, and functions,
+/// and the statements and expressions in them.
+#[derive(Default)]
+pub(crate) struct NodeCounter {
+    items: usize,
+    statements: usize,
+    expressions: usize,
+}
+
+impl NodeCounter {
+    pub(crate) fn count(program: &Program) -> Self {
+        let mut counter = Self {
+            // The functions are counted as they are visited, the other items here.
+            items: program.global_consts.len() + program.circuits.len(),
+            ..Default::default()
+        };
+        counter.visit_program(program);
+        counter
+    }
+
+    pub(crate) fn nodes(&self) -> usize {
+        self.items + self.statements + self.expressions
+    }
+}
+
+impl ExpressionVisitor for NodeCounter {
+    fn visit_expression(&mut self, expression: &Expression) {
+        self.expressions += 1;
+        walk_expression(self, expression);
+    }
+}
+
+impl StatementVisitor for NodeCounter {
+    fn visit_statement(&mut self, statement: &Statement) {
+        self.statements += 1;
+        walk_statement(self, statement);
+    }
+}
+
+impl ProgramVisitor for NodeCounter {
+    fn visit_function(&mut self, function: &Function) {
+        self.items += 1;
+        self.visit_block(&function.block);
+    }
+}
+
+/// Returns the peak resident memory of the process in KiB, read from `/proc` on Linux.
+fn peak_memory_kib() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    line.trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Compiler, CompilerOptions, MemoryFileSystem};
+    use leo_errors::emitter::Handler;
+
+    #[test]
+    fn reports_passes() {
+        let (handler, _) = Handler::new_with_buf();
+        let files = MemoryFileSystem::new().with_file(
+            "main.leo",
+            "function main(a: u8) -> u8 {\n    let b = a * 2u8;\n    return b;\n}\n",
+        );
+        let options = CompilerOptions {
+            emitted_stages: vec![],
+            ..Default::default()
+        };
+        let pass_names = options.passes().pass_names();
+        let (_, report) = Compiler::new(&handler, "main.leo".into(), "outputs".into())
+            .with_options(options)
+            .with_file_system(files)
+            .compile_with_report()
+            .unwrap();
+
+        assert_eq!(report.tokens, 22);
+        // The function, its two statements, and the expressions `a * 2u8`, `a`, `2u8`, and `b`.
+        assert_eq!(report.parsed_nodes, 7);
+        let names: Vec<_> = report.passes.iter().map(|pass| pass.name.as_str()).collect();
+        assert_eq!(names, pass_names);
+        assert_eq!(report.compiled_statements, 2);
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The classes of the tokens of a program, for syntax highlighting.
+
+use crate::{Definition, SymbolKind, SymbolTable};
+use leo_errors::Result;
+use leo_parser::{tokenize, SpannedToken, Token};
+use leo_span::Span;
+
+use std::collections::HashMap;
+
+/// What a token is, for syntax highlighting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+    /// A keyword, such as `function`, `let`, `self`, or `true`.
+    Keyword,
+    /// A primitive type, such as `u8`, or `Self`.
+    Type,
+    /// A core module, i.e., `console`.
+    Namespace,
+    /// The name of a circuit.
+    Circuit,
+    /// The name of a type alias.
+    Alias,
+    /// The name of a function, or of a core function, such as `log` in `console.log`.
+    Function,
+    /// The name of a circuit function.
+    Method,
+    /// The name of a circuit variable or constant.
+    Member,
+    /// The name of a global constant, or of a constant of a primitive type, such as `MAX` in `u8::MAX`.
+    Constant,
+    /// The name of a function input.
+    Parameter,
+    /// The name of a variable.
+    Variable,
+    /// The `@` and the name of an annotation.
+    Annotation,
+    /// An integer, field, or group number, with its type suffix.
+    Number,
+    /// A string, character, or address literal.
+    String,
+    /// A comment.
+    Comment,
+    /// An operator, such as `+` or `&&`.
+    Operator,
+}
+
+/// Returns the classes of the tokens of `source`, in the order they appear.
+/// The names of the program are classified by what `symbols` resolves them to.
+///
+/// Punctuation has no class, and neither do names `symbols` does not resolve,
+/// such as those in imports, or the names of a program which does not parse.
+pub fn semantic_tokens(path: &str, source: &str, symbols: &SymbolTable) -> Result<Vec<(Span, TokenClass)>> {
+    // A name is both a variable and a member in `Foo { x }`, where it is classified as the variable.
+    let mut names = HashMap::new();
+    for (span, id) in symbols.names() {
+        names
+            .entry((span.line_start, span.col_start))
+            .or_insert_with(|| symbols.definition(id));
+    }
+
+    let tokens = tokenize(path, source)?;
+    let mut classes: Vec<(Span, TokenClass)> = Vec::new();
+    for (index, SpannedToken { token, span }) in tokens.iter().enumerate() {
+        let previous = |offset: usize| index.checked_sub(offset).map(|index| &tokens[index].token);
+        let class = match token {
+            Token::CommentLine(_) | Token::CommentBlock(_) => TokenClass::Comment,
+            Token::StringLit(_) | Token::CharLit(_) | Token::AddressLit(_) => TokenClass::String,
+            Token::Int(_) => TokenClass::Number,
+            Token::Ident(_) => match names.get(&(span.line_start, span.col_start)) {
+                Some(definition) => class_of(definition),
+                None => match (previous(1), previous(2)) {
+                    (Some(Token::At), _) => TokenClass::Annotation,
+                    (Some(Token::Dot), Some(Token::Console)) => TokenClass::Function,
+                    (Some(Token::DoubleColon), Some(type_)) if is_primitive_type(type_) => TokenClass::Constant,
+                    _ => continue,
+                },
+            },
+            token if is_primitive_type(token) || *token == Token::BigSelf => match classes.last_mut() {
+                // The type suffix of `1u8` is part of the number.
+                Some((number, TokenClass::Number))
+                    if number.line_stop == span.line_start && number.col_stop == span.col_start =>
+                {
+                    number.col_stop = span.col_stop;
+                    continue;
+                }
+                _ => TokenClass::Type,
+            },
+            Token::Console => TokenClass::Namespace,
+            Token::At => TokenClass::Annotation,
+            Token::Not
+            | Token::And
+            | Token::Or
+            | Token::Eq
+            | Token::NotEq
+            | Token::Lt
+            | Token::LtEq
+            | Token::Gt
+            | Token::GtEq
+            | Token::Add
+            | Token::Minus
+            | Token::Mul
+            | Token::Div
+            | Token::Exp
+            | Token::Assign
+            | Token::AddEq
+            | Token::MinusEq
+            | Token::MulEq
+            | Token::DivEq
+            | Token::ExpEq
+            | Token::Ampersand
+            | Token::Question => TokenClass::Operator,
+            token if token.is_keyword() => TokenClass::Keyword,
+            _ => continue,
+        };
+        classes.push((span.clone(), class));
+    }
+    Ok(classes)
+}
+
+/// Returns the class of the names defined by `definition`.
+fn class_of(definition: &Definition) -> TokenClass {
+    match definition.kind {
+        SymbolKind::Function if definition.circuit.is_some() => TokenClass::Method,
+        SymbolKind::Function => TokenClass::Function,
+        SymbolKind::Circuit => TokenClass::Circuit,
+        SymbolKind::Member => TokenClass::Member,
+        SymbolKind::Constant => TokenClass::Constant,
+        SymbolKind::Alias => TokenClass::Alias,
+        SymbolKind::Input => TokenClass::Parameter,
+        SymbolKind::Variable => TokenClass::Variable,
+    }
+}
+
+/// Returns `true` if `token` is a primitive type, such as `u8` or `field`.
+fn is_primitive_type(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::U8
+            | Token::U16
+            | Token::U32
+            | Token::U64
+            | Token::U128
+            | Token::I8
+            | Token::I16
+            | Token::I32
+            | Token::I64
+            | Token::I128
+            | Token::Field
+            | Token::Group
+            | Token::Bool
+            | Token::Address
+            | Token::Char
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn classifies_tokens() {
+        create_session_if_not_set_then(|_| {
+            let source = "circuit Point {\n    x: u8,\n    function get(self) -> u8 { return self.x; }\n}\n@test\n\
+                          function main(a: u8) -> u8 {\n    // Make a point.\n    let p = Point { x: a };\n    \
+                          console.log(\"{}\", u8::MAX);\n    return p.get() + 1u8;\n}";
+            let (handler, _) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "main.leo", source).unwrap();
+            let symbols = SymbolTable::new(&program);
+
+            let lines = source.lines().collect::<Vec<_>>();
+            let classes = semantic_tokens("main.leo", source, &symbols)
+                .unwrap()
+                .into_iter()
+                .filter(|(span, _)| span.line_start >= 5)
+                .map(|(span, class)| {
+                    let line = lines[span.line_start - 1];
+                    (&line[span.col_start - 1..(span.col_stop - 1).min(line.len())], class)
+                })
+                .collect::<Vec<_>>();
+            use TokenClass::*;
+            assert_eq!(
+                classes,
+                vec![
+                    ("@", Annotation),
+                    ("test", Annotation),
+                    ("function", Keyword),
+                    ("main", Function),
+                    ("a", Parameter),
+                    ("u8", Type),
+                    ("u8", Type),
+                    ("// Make a point.", Comment),
+                    ("let", Keyword),
+                    ("p", Variable),
+                    ("=", Operator),
+                    ("Point", Circuit),
+                    ("x", Member),
+                    ("a", Parameter),
+                    ("console", Namespace),
+                    ("log", Function),
+                    ("\"{}\"", String),
+                    ("u8", Type),
+                    ("MAX", Constant),
+                    ("return", Keyword),
+                    ("p", Variable),
+                    ("get", Method),
+                    ("+", Operator),
+                    ("1u8", Number),
+                ]
+            );
+        });
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A listing of the statements of a compiled program, each with the source location it was produced from.
+//! Passes which rewrite the AST keep the span of the code they replace, so the listing shows
+//! where generated statements come from, e.g., the `+=` a `=` was canonicalized from.
+
+use leo_ast::{Block, CircuitMember, Function, Node, Program, Statement};
+use leo_span::Span;
+
+use std::fmt::{self, Write};
+
+/// Returns a listing of every statement of `program`, one per line,
+/// preceded by the location of the source it was produced from.
+pub fn trace_spans(program: &Program) -> String {
+    let mut trace = String::new();
+    let circuit_functions = program.circuits.values().flat_map(|circuit| {
+        circuit.members.iter().filter_map(|member| match member {
+            CircuitMember::CircuitFunction(function) => Some(function.as_ref()),
+            CircuitMember::CircuitConst(..) | CircuitMember::CircuitVariable(..) => None,
+        })
+    });
+    for function in program.functions.values().chain(circuit_functions) {
+        // Writing to a `String` cannot fail.
+        let _ = trace_function(&mut trace, function);
+    }
+    trace
+}
+
+/// Writes the location of `span` and `text`, indented by `depth`.
+fn trace_line(trace: &mut String, span: &Span, depth: usize, text: impl fmt::Display) -> fmt::Result {
+    writeln!(
+        trace,
+        "{path}:{line}:{column}: {indent}{text}",
+        path = &*span.path,
+        line = span.line_start,
+        column = span.col_start,
+        indent = "    ".repeat(depth),
+        text = text,
+    )
+}
+
+fn trace_function(trace: &mut String, function: &Function) -> fmt::Result {
+    trace_line(
+        trace,
+        &function.span,
+        0,
+        format_args!("function {}", function.identifier),
+    )?;
+    trace_block(trace, &function.block, 1)
+}
+
+fn trace_block(trace: &mut String, block: &Block, depth: usize) -> fmt::Result {
+    block
+        .statements
+        .iter()
+        .try_for_each(|statement| trace_statement(trace, statement, depth))
+}
+
+fn trace_statement(trace: &mut String, statement: &Statement, depth: usize) -> fmt::Result {
+    match statement {
+        Statement::Conditional(conditional) => {
+            trace_line(
+                trace,
+                &conditional.span,
+                depth,
+                format_args!("if ({})", conditional.condition),
+            )?;
+            trace_block(trace, &conditional.block, depth + 1)?;
+            if let Some(next) = &conditional.next {
+                trace_line(trace, next.span(), depth, "else")?;
+                trace_statement(trace, next, depth + 1)?;
+            }
+            Ok(())
+        }
+        Statement::Iteration(iteration) => {
+            trace_line(
+                trace,
+                &iteration.span,
+                depth,
+                format_args!(
+                    "for {} in {}..{}{}",
+                    iteration.variable,
+                    iteration.start,
+                    if iteration.inclusive { "=" } else { "" },
+                    iteration.stop
+                ),
+            )?;
+            trace_block(trace, &iteration.block, depth + 1)
+        }
+        Statement::Block(block) => trace_block(trace, block, depth),
+        statement => trace_line(trace, statement.span(), depth, statement),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn traces_statements() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let program = leo_parser::parse(
+                &handler,
+                "main.leo",
+                "function main(a: u8) -> u8 {\n    let b = a;\n    if b == 1u8 {\n        return b;\n    }\n    return a;\n}",
+            )
+            .unwrap();
+
+            assert_eq!(
+                trace_spans(&program).lines().collect::<Vec<_>>(),
+                vec![
+                    "main.leo:1:1: function main",
+                    "main.leo:2:5:     let mut b = a;",
+                    "main.leo:3:5:     if (b == 1u8)",
+                    "main.leo:4:9:         return b",
+                    "main.leo:6:5:     return a",
+                ]
+            );
+        });
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The stages of the compiler whose AST can be written to the outputs directory.
+
+use std::{fmt, str::FromStr};
+
+/// A stage of the compiler, after which the AST can be written as JSON.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stage {
+    /// The AST as parsed.
+    Initial,
+    /// The AST after canonicalization.
+    Canonicalized,
+    /// The AST after all checks and type inference, before optimizations.
+    Checked,
+    /// The checked AST with the type of each expression, see [`leo_ast::TypedAst`].
+    Typed,
+    /// The AST after optimizations, as compiled.
+    Optimized,
+}
+
+impl Stage {
+    /// The stages, in the order the compiler runs them.
+    pub const ALL: [Stage; 5] = [
+        Stage::Initial,
+        Stage::Canonicalized,
+        Stage::Checked,
+        Stage::Typed,
+        Stage::Optimized,
+    ];
+
+    /// Returns the name of the file the AST of this stage is written to.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Stage::Initial => "initial_ast.json",
+            Stage::Canonicalized => "canonicalization_ast.json",
+            Stage::Checked => "checked_ast.json",
+            Stage::Typed => "typed_ast.json",
+            Stage::Optimized => "optimized_ast.json",
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(stage: &str) -> Result<Self, Self::Err> {
+        match stage {
+            "initial" | "ast" => Ok(Stage::Initial),
+            "canonicalized" => Ok(Stage::Canonicalized),
+            "checked" => Ok(Stage::Checked),
+            "typed" => Ok(Stage::Typed),
+            "optimized" => Ok(Stage::Optimized),
+            _ => Err(format!(
+                "unknown stage `{}`, expected one of {}",
+                stage,
+                Stage::ALL.map(|stage| format!("`{}`", stage)).join(", ")
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Initial => write!(f, "initial"),
+            Stage::Canonicalized => write!(f, "canonicalized"),
+            Stage::Checked => write!(f, "checked"),
+            Stage::Typed => write!(f, "typed"),
+            Stage::Optimized => write!(f, "optimized"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_stages() {
+        for stage in Stage::ALL {
+            assert_eq!(stage.to_string().parse(), Ok(stage));
+        }
+        assert_eq!("ast".parse(), Ok(Stage::Initial));
+        assert_eq!(
+            "ssa".parse::<Stage>(),
+            Err(
+                "unknown stage `ssa`, expected one of `initial`, `canonicalized`, `checked`, `typed`, `optimized`"
+                    .to_string()
+            )
+        );
+    }
+}
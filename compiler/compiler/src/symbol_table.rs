@@ -0,0 +1,886 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The names a program defines, and where each of them is used.
+//!
+//! Tools such as the language server look names up in a [`SymbolTable`]
+//! instead of resolving them on their own.
+
+use leo_ast::*;
+use leo_span::{sym, Span, Symbol};
+
+/// What a definition defines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// A function, including a circuit function.
+    Function,
+    /// A circuit.
+    Circuit,
+    /// A circuit variable or constant.
+    Member,
+    /// A global constant.
+    Constant,
+    /// A type alias.
+    Alias,
+    /// A function input.
+    Input,
+    /// A variable defined by `let` or `const` in a function, or by a `for` loop.
+    Variable,
+}
+
+/// A name defined in a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Definition {
+    /// The defined name.
+    pub name: Symbol,
+    /// What the name defines.
+    pub kind: SymbolKind,
+    /// The span of the name in the definition.
+    pub span: Span,
+    /// The circuit of a member or of a circuit function.
+    pub circuit: Option<Symbol>,
+    /// The code after the definition in which the name is visible.
+    /// `None` for functions, circuits, members, constants, and aliases, which are visible throughout the program.
+    pub scope: Option<Span>,
+    /// The signature or type of the definition, as written in Leo.
+    pub detail: String,
+    /// The type of a constant, input, variable, or member, the output type of a function,
+    /// or the type an alias stands for, with `Self` replaced by the circuit it names, if known.
+    pub type_: Option<Type>,
+}
+
+/// A replacement of the text at a span, e.g., to rename a name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextEdit {
+    /// The span of the text to replace.
+    pub span: Span,
+    /// The text to replace it with.
+    pub new_text: String,
+}
+
+/// The index of a definition in its [`SymbolTable`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefinitionId(pub usize);
+
+/// The definitions of a program, and the uses of their names.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolTable {
+    /// The definitions, in the order they appear, except that the definitions visible
+    /// throughout the program come first.
+    definitions: Vec<Definition>,
+    /// The uses of names, in the order they appear.
+    references: Vec<Reference>,
+}
+
+/// A use of a name.
+#[derive(Clone, Debug)]
+struct Reference {
+    /// The ID of the identifier using the name.
+    id: NodeID,
+    span: Span,
+    definition: DefinitionId,
+    /// Whether the name is both a member and a variable, as `x` in `Foo { x }`.
+    shorthand: bool,
+}
+
+impl SymbolTable {
+    /// Returns the symbol table of `program`, e.g., of the AST a compilation returned.
+    pub fn new(program: &Program) -> Self {
+        let mut table = Self::default();
+        table.define_items(program);
+        Resolver {
+            table: &mut table,
+            scopes: Vec::new(),
+            blocks: Vec::new(),
+            circuit: None,
+        }
+        .visit_program(program);
+        table
+    }
+
+    /// Returns the definition `id`.
+    pub fn definition(&self, id: DefinitionId) -> &Definition {
+        &self.definitions[id.0]
+    }
+
+    /// Returns all definitions.
+    pub fn definitions(&self) -> impl Iterator<Item = (DefinitionId, &Definition)> {
+        self.definitions
+            .iter()
+            .enumerate()
+            .map(|(index, definition)| (DefinitionId(index), definition))
+    }
+
+    /// Returns the definitions of `name`, e.g., of variables shadowing each other.
+    pub fn lookup(&self, name: Symbol) -> impl Iterator<Item = DefinitionId> + '_ {
+        self.definitions()
+            .filter(move |(_, definition)| definition.name == name)
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the definitions of `kind`.
+    pub fn of_kind(&self, kind: SymbolKind) -> impl Iterator<Item = DefinitionId> + '_ {
+        self.definitions()
+            .filter(move |(_, definition)| definition.kind == kind)
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the span of the name at the 1-based line and column `position`, where it is defined
+    /// or used, with its definition.
+    pub fn name_at(&self, position: (usize, usize)) -> Option<(&Span, DefinitionId)> {
+        self.names().find(|(span, _)| contains(span, position))
+    }
+
+    /// Returns the span of each definition and use of a name, with its definition, definitions first.
+    pub fn names(&self) -> impl Iterator<Item = (&Span, DefinitionId)> + '_ {
+        self.definitions().map(|(id, definition)| (&definition.span, id)).chain(
+            self.references
+                .iter()
+                .map(|reference| (&reference.span, reference.definition)),
+        )
+    }
+
+    /// Returns the definition the identifier `id` refers to, if it refers to one.
+    ///
+    /// In `Foo { x }`, `x` refers to the variable rather than to the member.
+    pub fn referent(&self, id: NodeID) -> Option<DefinitionId> {
+        self.references
+            .iter()
+            .find(|reference| reference.id == id)
+            .map(|reference| reference.definition)
+    }
+
+    /// Returns the spans of the uses of the name defined by `id`, in the order they appear.
+    pub fn references(&self, id: DefinitionId) -> impl Iterator<Item = &Span> + '_ {
+        self.references
+            .iter()
+            .filter(move |reference| reference.definition == id)
+            .map(|reference| &reference.span)
+    }
+
+    /// Returns the edits renaming the name defined by `id` to `new_name`, at its definition and
+    /// at each of its uses, in the order they appear.
+    ///
+    /// `new_name` should be an identifier which is not yet defined where the name is visible.
+    pub fn rename(&self, id: DefinitionId, new_name: &str) -> Vec<TextEdit> {
+        let definition = self.definition(id);
+        let mut edits = vec![TextEdit {
+            span: definition.span.clone(),
+            new_text: new_name.to_string(),
+        }];
+        for reference in self.references.iter().filter(|reference| reference.definition == id) {
+            // `Foo { x }` becomes `Foo { y: x }` when renaming the member, and `Foo { x: y }` when renaming the variable.
+            let new_text = match (reference.shorthand, definition.kind) {
+                (false, _) => new_name.to_string(),
+                (true, SymbolKind::Member) => format!("{}: {}", new_name, definition.name),
+                (true, _) => format!("{}: {}", definition.name, new_name),
+            };
+            if !edits.iter().any(|edit| edit.span == reference.span) {
+                edits.push(TextEdit {
+                    span: reference.span.clone(),
+                    new_text,
+                });
+            }
+        }
+        edits.sort_by_key(|edit| (edit.span.line_start, edit.span.col_start));
+        edits
+    }
+
+    /// Defines the names visible throughout `program`.
+    fn define_items(&mut self, program: &Program) {
+        for alias in program.aliases.values() {
+            self.define(
+                &alias.name,
+                SymbolKind::Alias,
+                None,
+                None,
+                format!("type {} = {}", alias.name, alias.represents.source_name()),
+                Some(alias.represents.clone()),
+            );
+        }
+        for definition in program.global_consts.values() {
+            self.define_variables(definition, SymbolKind::Constant, None);
+        }
+        for circuit in program.circuits.values() {
+            let name = &circuit.circuit_name;
+            self.define(name, SymbolKind::Circuit, None, None, format!("circuit {}", name), None);
+            for member in circuit.members.iter() {
+                match member {
+                    CircuitMember::CircuitVariable(identifier, type_) => self.define(
+                        identifier,
+                        SymbolKind::Member,
+                        Some(name.name),
+                        None,
+                        format!("{}: {}", identifier, type_.source_name()),
+                        Some(replace_self(type_, name)),
+                    ),
+                    CircuitMember::CircuitConst(identifier, type_, _) => self.define(
+                        identifier,
+                        SymbolKind::Member,
+                        Some(name.name),
+                        None,
+                        format!("const {}: {}", identifier, type_.source_name()),
+                        Some(replace_self(type_, name)),
+                    ),
+                    CircuitMember::CircuitFunction(function) => self.define(
+                        &function.identifier,
+                        SymbolKind::Function,
+                        Some(name.name),
+                        None,
+                        function.signature(),
+                        function.output.as_ref().map(|output| replace_self(output, name)),
+                    ),
+                };
+            }
+        }
+        for function in program.functions.values() {
+            self.define(
+                &function.identifier,
+                SymbolKind::Function,
+                None,
+                None,
+                function.signature(),
+                function.output.clone(),
+            );
+        }
+    }
+
+    fn define(
+        &mut self,
+        identifier: &Identifier,
+        kind: SymbolKind,
+        circuit: Option<Symbol>,
+        scope: Option<Span>,
+        detail: String,
+        type_: Option<Type>,
+    ) -> DefinitionId {
+        self.definitions.push(Definition {
+            name: identifier.name,
+            kind,
+            span: identifier.span.clone(),
+            circuit,
+            scope,
+            detail,
+            type_,
+        });
+        DefinitionId(self.definitions.len() - 1)
+    }
+
+    /// Defines the variables of `definition`, visible in `scope`.
+    fn define_variables(
+        &mut self,
+        definition: &DefinitionStatement,
+        kind: SymbolKind,
+        scope: Option<Span>,
+    ) -> Vec<(Symbol, DefinitionId)> {
+        // The type of a definition of several variables is the type of their tuple.
+        let source_name = match (&definition.type_, definition.variable_names.as_slice()) {
+            (Some(type_), [_]) => format!(": {}", type_.source_name()),
+            _ => String::new(),
+        };
+        definition
+            .variable_names
+            .iter()
+            .enumerate()
+            .map(|(index, VariableName { identifier, .. })| {
+                let detail = format!("{} {}{}", definition.declaration_type, identifier, source_name);
+                let type_ = match (&definition.type_, definition.variable_names.len()) {
+                    (Some(type_), 1) => Some(type_.clone()),
+                    (Some(Type::Tuple(types)), _) => types.get(index).cloned(),
+                    _ => None,
+                };
+                (
+                    identifier.name,
+                    self.define(identifier, kind, None, scope.clone(), detail, type_),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Resolves the names used in a program to their definitions.
+struct Resolver<'a> {
+    table: &'a mut SymbolTable,
+    /// The inputs and variables in scope, innermost scope last.
+    scopes: Vec<Vec<(Symbol, DefinitionId)>>,
+    /// The spans of the blocks being visited, innermost block last.
+    blocks: Vec<Span>,
+    /// The circuit whose members are being visited, if any.
+    circuit: Option<Symbol>,
+}
+
+impl Resolver<'_> {
+    /// Records that `identifier` refers to `definition`, if it refers to one.
+    fn refer(&mut self, identifier: &Identifier, definition: Option<DefinitionId>) {
+        self.record(identifier, definition, false);
+    }
+
+    fn record(&mut self, identifier: &Identifier, definition: Option<DefinitionId>, shorthand: bool) {
+        if let Some(definition) = definition {
+            self.table.references.push(Reference {
+                id: identifier.id,
+                span: identifier.span.clone(),
+                definition,
+                shorthand,
+            });
+        }
+    }
+
+    /// Returns the definition of the input, variable, constant, function, or circuit `identifier` names.
+    fn variable(&self, identifier: &Identifier) -> Option<DefinitionId> {
+        self.local(identifier.name).or_else(|| {
+            let name = self.circuit_name(identifier)?;
+            self.global(name, &[SymbolKind::Constant, SymbolKind::Function, SymbolKind::Circuit])
+        })
+    }
+
+    /// Returns the definition of the name `expression` consists of, or accesses last.
+    fn resolve(&self, expression: &Expression) -> Option<DefinitionId> {
+        match expression {
+            Expression::Identifier(identifier) => self.variable(identifier),
+            Expression::Access(AccessExpression::Member(access)) => self.member_of(&access.inner, access.name.name),
+            Expression::Access(AccessExpression::Static(access)) => self.static_member(access),
+            _ => None,
+        }
+    }
+
+    /// Returns the member or circuit function `access` accesses, as `f` in `Foo::f`.
+    fn static_member(&self, access: &StaticAccess) -> Option<DefinitionId> {
+        let circuit = match &*access.inner {
+            Expression::Identifier(inner) => self.circuit_name(inner),
+            _ => None,
+        };
+        self.member(circuit, access.name.name)
+    }
+
+    /// Returns the type of the value of `expression`, if it is known without inferring it.
+    fn type_of(&self, expression: &Expression) -> Option<Type> {
+        match expression {
+            Expression::Identifier(identifier) if identifier.name == sym::SelfLower => {
+                self.circuit.map(|circuit| Type::Identifier(Identifier::new(circuit)))
+            }
+            Expression::Access(AccessExpression::Array(access)) => element(self.type_of(&access.array)?),
+            Expression::Access(AccessExpression::ArrayRange(access)) => self.type_of(&access.array),
+            Expression::Access(AccessExpression::Tuple(access)) => field(self.type_of(&access.tuple)?, &access.index),
+            // The definition of a function has its output type.
+            Expression::Call(call) => self.type_of(&call.function),
+            Expression::CircuitInit(init) => {
+                let circuit = self.circuit_name(&init.name)?;
+                Some(Type::Identifier(Identifier::new(circuit)))
+            }
+            Expression::Cast(cast) => Some(cast.target_type.clone()),
+            _ => self
+                .resolve(expression)
+                .and_then(|id| self.table.definition(id).type_.clone()),
+        }
+    }
+
+    /// Returns the circuit `type_` names, directly or through aliases.
+    fn circuit_of<'a>(&'a self, mut type_: &'a Type) -> Option<Symbol> {
+        // Aliases may be defined in terms of each other, even in a cycle.
+        for _ in 0..=self.table.definitions.len() {
+            let identifier = match type_ {
+                Type::SelfType => return self.circuit,
+                Type::Identifier(identifier) => identifier,
+                _ => return None,
+            };
+            let name = self.circuit_name(identifier)?;
+            let definition = self
+                .table
+                .definition(self.global(name, &[SymbolKind::Circuit, SymbolKind::Alias])?);
+            match definition.kind {
+                SymbolKind::Circuit => return Some(name),
+                _ => type_ = definition.type_.as_ref()?,
+            }
+        }
+        None
+    }
+
+    /// Returns the member or circuit function `name` of the circuit `type_` names, or the only one
+    /// named `name` if the circuit is not known.
+    fn member_of_type(&self, type_: Option<Type>, name: Symbol) -> Option<DefinitionId> {
+        match type_.and_then(|type_| self.circuit_of(&type_)) {
+            Some(circuit) => self.member(Some(circuit), name),
+            None => self.unique_member(name),
+        }
+    }
+
+    /// Returns the member or circuit function `name` of the value of `inner`.
+    fn member_of(&self, inner: &Expression, name: Symbol) -> Option<DefinitionId> {
+        self.member_of_type(self.type_of(inner), name)
+    }
+
+    /// Returns the input or variable named `name` in scope, the innermost one if several are.
+    fn local(&self, name: Symbol) -> Option<DefinitionId> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|(local, _)| *local == name)
+            .map(|(_, id)| *id)
+    }
+
+    /// Returns the definition of one of `kinds` named `name` which is visible throughout the program.
+    fn global(&self, name: Symbol, kinds: &[SymbolKind]) -> Option<DefinitionId> {
+        self.table
+            .definitions()
+            .find(|(_, definition)| {
+                definition.name == name
+                    && definition.circuit.is_none()
+                    && definition.scope.is_none()
+                    && kinds.contains(&definition.kind)
+            })
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the name of the circuit `identifier` names, which is the current circuit for `Self`.
+    fn circuit_name(&self, identifier: &Identifier) -> Option<Symbol> {
+        if identifier.name == sym::SelfUpper {
+            self.circuit
+        } else {
+            Some(identifier.name)
+        }
+    }
+
+    /// Returns the member or circuit function `name` of `circuit`.
+    fn member(&self, circuit: Option<Symbol>, name: Symbol) -> Option<DefinitionId> {
+        let circuit = circuit?;
+        self.table
+            .definitions()
+            .find(|(_, definition)| definition.circuit == Some(circuit) && definition.name == name)
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the only member or circuit function named `name`, if a single circuit has one.
+    fn unique_member(&self, name: Symbol) -> Option<DefinitionId> {
+        let mut members = self
+            .table
+            .definitions()
+            .filter(|(_, definition)| definition.circuit.is_some() && definition.name == name);
+        match (members.next(), members.next()) {
+            (Some((id, _)), None) => Some(id),
+            _ => None,
+        }
+    }
+
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => {
+                let definition = match self.circuit_name(identifier) {
+                    Some(name) => self.global(name, &[SymbolKind::Circuit, SymbolKind::Alias]),
+                    None => None,
+                };
+                self.refer(identifier, definition);
+            }
+            Type::Array(element, _) => self.visit_type(element),
+            Type::Tuple(types) => types.iter().for_each(|type_| self.visit_type(type_)),
+            _ => {}
+        }
+    }
+
+    /// Defines `variables` in the innermost scope.
+    fn declare(&mut self, variables: Vec<(Symbol, DefinitionId)>) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.extend(variables);
+        }
+    }
+}
+
+impl ExpressionVisitor for Resolver<'_> {
+    fn visit_identifier(&mut self, identifier: &Identifier) {
+        let definition = self.variable(identifier);
+        self.refer(identifier, definition);
+    }
+
+    fn visit_cast(&mut self, cast: &CastExpression) {
+        self.visit_expression(&cast.inner);
+        self.visit_type(&cast.target_type);
+    }
+
+    fn visit_access(&mut self, access: &AccessExpression) {
+        match access {
+            AccessExpression::Member(access) => {
+                self.visit_expression(&access.inner);
+                let definition = self.member_of(&access.inner, access.name.name);
+                self.refer(&access.name, definition);
+            }
+            AccessExpression::Static(access) => {
+                self.visit_expression(&access.inner);
+                let definition = self.static_member(access);
+                self.refer(&access.name, definition);
+            }
+            AccessExpression::Array(access) => {
+                self.visit_expression(&access.array);
+                self.visit_expression(&access.index);
+            }
+            AccessExpression::ArrayRange(access) => {
+                self.visit_expression(&access.array);
+                access
+                    .left
+                    .iter()
+                    .chain(access.right.iter())
+                    .for_each(|bound| self.visit_expression(bound));
+            }
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple),
+        }
+    }
+
+    fn visit_circuit_init(&mut self, init: &CircuitInitExpression) {
+        let circuit = self.circuit_name(&init.name);
+        let definition = circuit.and_then(|name| self.global(name, &[SymbolKind::Circuit]));
+        self.refer(&init.name, definition);
+        for member in init.members.iter() {
+            // In `Foo { x }`, `x` is a variable as well as a member.
+            let shorthand = match &member.expression {
+                Some(Expression::Identifier(value)) => value.span == member.identifier.span,
+                Some(_) => false,
+                None => true,
+            };
+            match &member.expression {
+                Some(expression) if !shorthand => self.visit_expression(expression),
+                _ => {
+                    let variable = self.variable(&member.identifier);
+                    self.record(&member.identifier, variable, true);
+                }
+            }
+            let definition = self.member(circuit, member.identifier.name);
+            self.record(&member.identifier, definition, shorthand);
+        }
+    }
+}
+
+impl StatementVisitor for Resolver<'_> {
+    fn visit_definition(&mut self, definition: &DefinitionStatement) {
+        if let Some(type_) = &definition.type_ {
+            self.visit_type(type_);
+        }
+        self.visit_expression(&definition.value);
+        let scope = self.blocks.last().map(|block| span_between(&definition.span, block));
+        let variables = self.table.define_variables(definition, SymbolKind::Variable, scope);
+        self.declare(variables);
+    }
+
+    fn visit_assign(&mut self, assign: &AssignStatement) {
+        let assignee = &assign.assignee.identifier;
+        let definition = self
+            .local(assignee.name)
+            .or_else(|| self.global(assignee.name, &[SymbolKind::Constant]));
+        self.refer(assignee, definition);
+        let mut type_ = self.type_of(&Expression::Identifier(assignee.clone()));
+        for access in assign.assignee.accesses.iter() {
+            type_ = match access {
+                AssigneeAccess::ArrayIndex(index) => {
+                    self.visit_expression(index);
+                    type_.and_then(element)
+                }
+                AssigneeAccess::ArrayRange(left, right) => {
+                    left.iter()
+                        .chain(right.iter())
+                        .for_each(|bound| self.visit_expression(bound));
+                    type_
+                }
+                AssigneeAccess::Tuple(index, _) => type_.and_then(|type_| field(type_, index)),
+                AssigneeAccess::Member(name) => {
+                    let member = self.member_of_type(type_, name.name);
+                    self.refer(name, member);
+                    member.and_then(|id| self.table.definition(id).type_.clone())
+                }
+            };
+        }
+        self.visit_expression(&assign.value);
+    }
+
+    fn visit_iteration(&mut self, iteration: &IterationStatement) {
+        self.visit_expression(&iteration.start);
+        self.visit_expression(&iteration.stop);
+        let variable = self.table.define(
+            &iteration.variable,
+            SymbolKind::Variable,
+            None,
+            Some(iteration.block.span.clone()),
+            format!("for {} in {}..{}", iteration.variable, iteration.start, iteration.stop),
+            None,
+        );
+        self.scopes.push(vec![(iteration.variable.name, variable)]);
+        self.visit_block(&iteration.block);
+        self.scopes.pop();
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        self.scopes.push(Vec::new());
+        self.blocks.push(block.span.clone());
+        block
+            .statements
+            .iter()
+            .for_each(|statement| self.visit_statement(statement));
+        self.blocks.pop();
+        self.scopes.pop();
+    }
+}
+
+impl ProgramVisitor for Resolver<'_> {
+    fn visit_program(&mut self, program: &Program) {
+        for alias in program.aliases.values() {
+            self.visit_type(&alias.represents);
+        }
+        // Global constants are defined with the other items visible throughout the program.
+        for definition in program.global_consts.values() {
+            if let Some(type_) = &definition.type_ {
+                self.visit_type(type_);
+            }
+            self.visit_expression(&definition.value);
+        }
+        program
+            .circuits
+            .values()
+            .for_each(|circuit| self.visit_circuit(circuit));
+        program
+            .functions
+            .values()
+            .for_each(|function| self.visit_function(function));
+    }
+
+    fn visit_circuit(&mut self, circuit: &Circuit) {
+        self.circuit = Some(circuit.circuit_name.name);
+        for member in circuit.members.iter() {
+            match member {
+                CircuitMember::CircuitVariable(_, type_) => self.visit_type(type_),
+                CircuitMember::CircuitConst(_, type_, value) => {
+                    self.visit_type(type_);
+                    self.visit_expression(value);
+                }
+                CircuitMember::CircuitFunction(function) => self.visit_function(function),
+            }
+        }
+        self.circuit = None;
+    }
+
+    fn visit_function(&mut self, function: &Function) {
+        let mut inputs = Vec::new();
+        for input in function.input.iter() {
+            if let FunctionInput::Variable(variable) = input {
+                self.visit_type(&variable.type_);
+                let id = self.table.define(
+                    &variable.identifier,
+                    SymbolKind::Input,
+                    None,
+                    Some(function.block.span.clone()),
+                    input.signature(),
+                    Some(match self.circuit {
+                        Some(circuit) => replace_self(&variable.type_, &Identifier::new(circuit)),
+                        None => variable.type_.clone(),
+                    }),
+                );
+                inputs.push((variable.identifier.name, id));
+            }
+        }
+        if let Some(output) = &function.output {
+            self.visit_type(output);
+        }
+        self.scopes.push(inputs);
+        self.visit_block(&function.block);
+        self.scopes.pop();
+    }
+}
+
+/// Returns `type_` with `Self` replaced by `circuit`.
+fn replace_self(type_: &Type, circuit: &Identifier) -> Type {
+    match type_ {
+        Type::SelfType => Type::Identifier(circuit.clone()),
+        Type::Identifier(identifier) if identifier.name == sym::SelfUpper => Type::Identifier(circuit.clone()),
+        Type::Array(element, dimensions) => Type::Array(Box::new(replace_self(element, circuit)), dimensions.clone()),
+        Type::Tuple(types) => Type::Tuple(types.iter().map(|type_| replace_self(type_, circuit)).collect()),
+        Type::BoundedVec(element, capacity) => {
+            Type::BoundedVec(Box::new(replace_self(element, circuit)), capacity.clone())
+        }
+        _ => type_.clone(),
+    }
+}
+
+/// Returns the type of the elements of the array type `type_`.
+fn element(type_: Type) -> Option<Type> {
+    match type_ {
+        Type::Array(element, _) => Some(*element),
+        _ => None,
+    }
+}
+
+/// Returns the type of the field `index` of the tuple type `type_`.
+fn field(type_: Type, index: &PositiveNumber) -> Option<Type> {
+    match type_ {
+        Type::Tuple(mut types) => {
+            let index = index.value.parse::<usize>().ok()?;
+            (index < types.len()).then(|| types.swap_remove(index))
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `span` contains the 1-based line and column `position`, or ends at it.
+fn contains(span: &Span, (line, column): (usize, usize)) -> bool {
+    (span.line_start, span.col_start) <= (line, column) && (line, column) <= (span.line_stop, span.col_stop)
+}
+
+/// Returns the span from the end of `start` to the end of `end`.
+fn span_between(start: &Span, end: &Span) -> Span {
+    Span::new(
+        start.line_stop,
+        end.line_stop,
+        start.col_stop,
+        end.col_stop,
+        start.path.clone(),
+        String::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const PROGRAM: &str = "circuit Point {\n    x: u8,\n    function get(self) -> u8 { return self.x; }\n}\n\
+                           function main(a: u8) -> u8 {\n    let b: u8 = a;\n    if true {\n        let b = 2u8;\n        \
+                           return b;\n    }\n    let p = Point { x: b };\n    return p.get();\n}";
+
+    #[test]
+    fn resolves_names() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "main.leo", PROGRAM).unwrap();
+            let table = SymbolTable::new(&program);
+
+            let definition = |line, column| {
+                let (_, id) = table.name_at((line, column)).unwrap();
+                let definition = table.definition(id);
+                (
+                    definition.span.line_start,
+                    definition.span.col_start,
+                    definition.detail.clone(),
+                )
+            };
+            // `self.x` refers to the member.
+            assert_eq!(definition(3, 44), (2, 5, "x: u8".to_string()));
+            // `a` refers to the input.
+            assert_eq!(definition(6, 17), (5, 15, "a: u8".to_string()));
+            // The inner `b` shadows the outer one, up to the end of its block.
+            assert_eq!(definition(9, 16), (8, 13, "let b".to_string()));
+            assert_eq!(definition(11, 24), (6, 9, "let b: u8".to_string()));
+            // A circuit, its initialized member, and a method of a variable of unknown type.
+            assert_eq!(definition(11, 13).2, "circuit Point");
+            assert_eq!(definition(11, 21), (2, 5, "x: u8".to_string()));
+            assert_eq!(definition(12, 14).2, "function get(self) -> u8");
+            // The name at a definition is the definition itself.
+            assert_eq!(definition(5, 10).2, "function main(a: u8) -> u8");
+            assert!(table.name_at((6, 5)).is_none());
+        });
+    }
+
+    #[test]
+    fn looks_up_definitions_and_references() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "main.leo", PROGRAM).unwrap();
+            let table = SymbolTable::new(&program);
+
+            let b = table.lookup(Symbol::intern("b")).collect::<Vec<_>>();
+            assert_eq!(b.len(), 2);
+            let uses = table
+                .references(b[0])
+                .map(|span| (span.line_start, span.col_start))
+                .collect::<Vec<_>>();
+            assert_eq!(uses, vec![(11, 24)]);
+
+            let circuits = table
+                .of_kind(SymbolKind::Circuit)
+                .map(|id| table.definition(id).name.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(circuits, vec!["Point"]);
+            let point = table.of_kind(SymbolKind::Circuit).next().unwrap();
+            assert_eq!(table.references(point).count(), 1);
+        });
+    }
+
+    #[test]
+    fn resolves_identifiers_by_id() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "main.leo", PROGRAM).unwrap();
+            let table = SymbolTable::new(&program);
+
+            let main = program.functions.values().next().unwrap();
+            let a = match &main.block.statements[0] {
+                Statement::Definition(definition) => match &definition.value {
+                    Expression::Identifier(a) => a,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            let definition = table.definition(table.referent(a.id).unwrap());
+            assert_eq!(definition.detail, "a: u8");
+            // Another identifier at the same span is not a use of the name.
+            let other = Identifier::new_with_span(a.name, a.span.clone());
+            assert_eq!(table.referent(other.id), None);
+        });
+    }
+
+    #[test]
+    fn renames_members_by_type() {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let program = "circuit A {\n    x: u8,\n}\ncircuit B {\n    x: u8,\n    \
+                           function make(x: u8) -> Self { return Self { x }; }\n}\n\
+                           function main(b: B) -> u8 {\n    let a: A = A { x: b.x };\n    \
+                           a.x = B::make(a.x).x;\n    return a.x;\n}";
+            let program = leo_parser::parse(&handler, "main.leo", program).unwrap();
+            let table = SymbolTable::new(&program);
+
+            let edits = |position, new_name| {
+                let (_, id) = table.name_at(position).unwrap();
+                table
+                    .rename(id, new_name)
+                    .into_iter()
+                    .map(|edit| (edit.span.line_start, edit.span.col_start, edit.new_text))
+                    .collect::<Vec<_>>()
+            };
+            // The `x` of `A` is accessed through variables of type `A`, including in an assignment.
+            assert_eq!(
+                edits((2, 5), "y"),
+                vec![
+                    (2, 5, "y".to_string()),
+                    (9, 20, "y".to_string()),
+                    (10, 7, "y".to_string()),
+                    (10, 21, "y".to_string()),
+                    (11, 14, "y".to_string()),
+                ]
+            );
+            // The `x` of `B` is accessed through an input and the output of a function returning `Self`.
+            assert_eq!(
+                edits((9, 25), "y"),
+                vec![
+                    (5, 5, "y".to_string()),
+                    (6, 50, "y: x".to_string()),
+                    (9, 25, "y".to_string()),
+                    (10, 24, "y".to_string()),
+                ]
+            );
+            assert_eq!(
+                edits((6, 19), "z"),
+                vec![(6, 19, "z".to_string()), (6, 50, "x: z".to_string())]
+            );
+        });
+    }
+}
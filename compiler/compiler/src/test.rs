@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Snapshot tests of the ASTs the compiler stages produce.
+//!
+//! Each test of the `Stages` namespace is compiled, and the hashes of the ASTs of its `stages`
+//! (all of them by default) are compared to the expectations stored under `tests/expectations/compiler`.
+
+use crate::{CompilerOptions, Pass, Stage, StandardPass, TypeInferrer};
+use leo_errors::{emitter::Handler, LeoError};
+use leo_span::symbol::create_session_if_not_set_then;
+use leo_test_framework::{
+    runner::{Namespace, ParseType, Runner},
+    Test,
+};
+
+use serde_yaml::Value;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+/// Removes `key` from every object of the AST JSON `value`.
+fn remove_key_from_json(value: &mut serde_json::Value, key: &str) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove(key);
+            for value in map.values_mut() {
+                remove_key_from_json(value, key);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values.iter_mut() {
+                remove_key_from_json(value, key);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns the SHA256 hash of the AST JSON `json` without spans, so that snapshots do not change with formatting.
+fn hash_json(mut json: serde_json::Value) -> String {
+    remove_key_from_json(&mut json, "span");
+    let mut hasher = Sha256::new();
+    hasher.update(json.to_string().as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Returns the stages listed under `stages` in the configuration of `test`, or all stages if there are none.
+fn configured_stages(test: &Test) -> Vec<Stage> {
+    match test.config.get("stages") {
+        None => Stage::ALL.to_vec(),
+        Some(Value::Sequence(stages)) => stages
+            .iter()
+            .map(|stage| {
+                let stage = stage.as_str().expect("stages must be strings");
+                Stage::from_str(stage).unwrap_or_else(|error| panic!("{}", error))
+            })
+            .collect(),
+        Some(_) => panic!("`stages` must be a list of stages"),
+    }
+}
+
+struct StagesNamespace;
+
+impl Namespace for StagesNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        let stages = configured_stages(&test);
+        let mut outputs = serde_yaml::Mapping::new();
+        // The JSON of an AST is only built for the stages which are snapshot.
+        let mut reached =
+            |stage: Stage, json: &dyn Fn() -> Result<serde_json::Value, LeoError>| -> Result<(), LeoError> {
+                if stages.contains(&stage) {
+                    outputs.insert(
+                        Value::String(format!("{}_ast", stage)),
+                        Value::String(hash_json(json()?)),
+                    );
+                }
+                Ok(())
+            };
+
+        create_session_if_not_set_then(|_| {
+            Handler::with(|handler| {
+                // The stages are those of `Compiler`, which writes their ASTs instead.
+                let mut passes = CompilerOptions::default().passes();
+                let ast = leo_parser::parse_ast(handler, &test.name, &test.content)?;
+                reached(Stage::Initial, &|| ast.to_json_value())?;
+                let ast = passes.run_checks(handler, ast, |pass, ast| {
+                    if pass.name() == StandardPass::Canonicalization.name() {
+                        reached(Stage::Canonicalized, &|| ast.to_json_value())?;
+                    }
+                    Ok(())
+                })?;
+                reached(Stage::Checked, &|| ast.to_json_value())?;
+                reached(Stage::Typed, &|| TypeInferrer::typed_ast(ast.clone()).to_json_value())?;
+                let ast = passes.run_optimizations(handler, ast, |_, _| Ok(()))?;
+                reached(Stage::Optimized, &|| ast.to_json_value())
+            })
+            .map_err(|errors| errors.to_string())
+        })?;
+
+        Ok(Value::Mapping(outputs))
+    }
+}
+
+struct TestRunner;
+
+impl Runner for TestRunner {
+    fn resolve_namespace(&self, name: &str) -> Option<Box<dyn Namespace>> {
+        Some(match name {
+            "Stages" => Box::new(StagesNamespace),
+            _ => return None,
+        })
+    }
+}
+
+#[test]
+pub fn stage_tests() {
+    leo_test_framework::run_tests(&TestRunner, "compiler");
+}
@@ -0,0 +1,317 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{items, Item};
+use leo_ast::Program;
+
+use std::{fmt, str::FromStr};
+
+/// The format documentation is rendered in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocFormat {
+    Markdown,
+    Html,
+}
+
+impl DocFormat {
+    /// Returns the extension of files in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+impl FromStr for DocFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            _ => Err(format!(
+                "unknown documentation format `{}`, expected `markdown` or `html`",
+                format
+            )),
+        }
+    }
+}
+
+impl fmt::Display for DocFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Markdown => write!(f, "markdown"),
+            Self::Html => write!(f, "html"),
+        }
+    }
+}
+
+/// Returns the documentation of `program`, titled `name`, in `format`.
+pub fn document(name: &str, program: &Program, format: DocFormat) -> String {
+    let items = items(program);
+    match format {
+        DocFormat::Markdown => render(Markdown::default(), name, &items),
+        DocFormat::Html => render(Html::default(), name, &items),
+    }
+}
+
+/// The parts documentation is made of, written in one format.
+trait Writer {
+    /// Writes the title of the documentation.
+    fn title(&mut self, name: &str);
+    /// Writes a heading of `level`, which is code if `code` is set.
+    fn heading(&mut self, level: usize, text: &str, code: bool);
+    /// Writes the lines of doc comments `docs`.
+    fn docs(&mut self, docs: &[String]);
+    /// Writes a list of the code `signatures`.
+    fn list(&mut self, signatures: &[&str]);
+    /// Returns the documentation written.
+    fn finish(self) -> String;
+}
+
+fn render(mut writer: impl Writer, name: &str, items: &[Item]) -> String {
+    writer.title(name);
+    sections(&mut writer, 2, items);
+    writer.finish()
+}
+
+/// Writes `items` grouped in sections, with section headings of `level`.
+fn sections(writer: &mut impl Writer, level: usize, items: &[Item]) {
+    let mut grouped: Vec<(&str, Vec<&Item>)> = Vec::new();
+    for item in items {
+        match grouped.iter_mut().find(|(section, _)| *section == item.kind.section()) {
+            Some((_, section)) => section.push(item),
+            None => grouped.push((item.kind.section(), vec![item])),
+        }
+    }
+
+    for (section, items) in grouped {
+        writer.heading(level, section, false);
+        // Items without doc comments or members, like circuit variables, are only listed.
+        if items.iter().all(|item| item.docs.is_empty() && item.members.is_empty()) {
+            let signatures = items.iter().map(|item| item.signature.as_str()).collect::<Vec<_>>();
+            writer.list(&signatures);
+            continue;
+        }
+        for item in items {
+            writer.heading(level + 1, &item.signature, true);
+            writer.docs(&item.docs);
+            sections(writer, level + 2, &item.members);
+        }
+    }
+}
+
+/// Writes documentation in Markdown.
+#[derive(Default)]
+struct Markdown {
+    out: String,
+}
+
+impl Writer for Markdown {
+    fn title(&mut self, name: &str) {
+        self.out.push_str(&format!("# {}\n", name));
+    }
+
+    fn heading(&mut self, level: usize, text: &str, code: bool) {
+        let hashes = "#".repeat(level);
+        match code {
+            true => self.out.push_str(&format!("\n{} `{}`\n", hashes, text)),
+            false => self.out.push_str(&format!("\n{} {}\n", hashes, text)),
+        }
+    }
+
+    fn docs(&mut self, docs: &[String]) {
+        if !docs.is_empty() {
+            self.out.push('\n');
+            for line in docs {
+                self.out.push_str(line);
+                self.out.push('\n');
+            }
+        }
+    }
+
+    fn list(&mut self, signatures: &[&str]) {
+        self.out.push('\n');
+        for signature in signatures {
+            self.out.push_str(&format!("- `{}`\n", signature));
+        }
+    }
+
+    fn finish(self) -> String {
+        self.out
+    }
+}
+
+/// Writes documentation as an HTML page.
+#[derive(Default)]
+struct Html {
+    out: String,
+}
+
+impl Writer for Html {
+    fn title(&mut self, name: &str) {
+        let name = escape(name);
+        self.out
+            .push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        self.out.push_str(&format!(
+            "<title>{}</title>\n</head>\n<body>\n<h1>{}</h1>\n",
+            name, name
+        ));
+    }
+
+    fn heading(&mut self, level: usize, text: &str, code: bool) {
+        match code {
+            true => self
+                .out
+                .push_str(&format!("<h{}><code>{}</code></h{}>\n", level, escape(text), level)),
+            false => self
+                .out
+                .push_str(&format!("<h{}>{}</h{}>\n", level, escape(text), level)),
+        }
+    }
+
+    fn docs(&mut self, docs: &[String]) {
+        // Blank lines separate paragraphs.
+        for paragraph in docs.split(|line| line.trim().is_empty()) {
+            if !paragraph.is_empty() {
+                let lines = paragraph.iter().map(|line| escape(line)).collect::<Vec<_>>();
+                self.out.push_str(&format!("<p>{}</p>\n", lines.join("\n")));
+            }
+        }
+    }
+
+    fn list(&mut self, signatures: &[&str]) {
+        self.out.push_str("<ul>\n");
+        for signature in signatures {
+            self.out
+                .push_str(&format!("<li><code>{}</code></li>\n", escape(signature)));
+        }
+        self.out.push_str("</ul>\n");
+    }
+
+    fn finish(mut self) -> String {
+        self.out.push_str("</body>\n</html>\n");
+        self.out
+    }
+}
+
+/// Returns `text` with the characters which are special in HTML escaped.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    const SOURCE: &str = "
+const SIZE: u32 = 3;
+
+/// A point in the plane.
+circuit Point {
+    x: u8
+    y: u8
+
+    /// Returns the point at `x`, `y`.
+    public function new(x: u8, y: u8) -> Self {
+        return Self { x, y };
+    }
+
+    private function origin() -> Self {
+        return Self::new(0u8, 0u8);
+    }
+}
+
+private circuit Hidden {}
+
+/// Adds `a` and `b`.
+///
+/// Fails if the sum overflows.
+function add(a: u8, b: u8) -> u8 {
+    return a + b;
+}
+
+private function double(a: u8) -> u8 {
+    return add(a, a);
+}
+
+@test
+function adds() {}
+";
+
+    fn document_source(format: DocFormat) -> String {
+        create_session_if_not_set_then(|_| {
+            let ast = leo_parser::parse_ast(&Handler::default(), "test.leo", SOURCE).unwrap();
+            document("test", ast.as_repr(), format)
+        })
+    }
+
+    #[test]
+    fn documents_in_markdown() {
+        assert_eq!(
+            document_source(DocFormat::Markdown),
+            "# test
+
+## Constants
+
+- `const SIZE: u32 = 3`
+
+## Circuits
+
+### `circuit Point`
+
+A point in the plane.
+
+#### Variables
+
+- `x: u8`
+- `y: u8`
+
+#### Functions
+
+##### `public function new(x: u8, y: u8) -> Self`
+
+Returns the point at `x`, `y`.
+
+## Functions
+
+### `function add(a: u8, b: u8) -> u8`
+
+Adds `a` and `b`.
+
+Fails if the sum overflows.
+"
+        );
+    }
+
+    #[test]
+    fn documents_in_html() {
+        let html = document_source(DocFormat::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h3><code>function add(a: u8, b: u8) -&gt; u8</code></h3>\n<p>Adds `a` and `b`.</p>\n<p>Fails if the sum overflows.</p>\n"));
+        assert!(html.contains("<ul>\n<li><code>x: u8</code></li>\n<li><code>y: u8</code></li>\n</ul>\n"));
+        assert!(!html.contains("adds"));
+        assert!(!html.contains("origin") && !html.contains("Hidden") && !html.contains("double"));
+        assert_eq!("md".parse(), Ok(DocFormat::Markdown));
+        assert!("pdf".parse::<DocFormat>().is_err());
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{CircuitMember, DefinitionStatement, Function, Program};
+use leo_span::sym;
+
+/// What an item declares, which gives the section it is documented in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    /// A global constant.
+    Constant,
+    /// A type alias.
+    Alias,
+    /// A circuit.
+    Circuit,
+    /// A function, including a circuit function.
+    Function,
+    /// A circuit variable.
+    Variable,
+    /// A circuit constant.
+    CircuitConstant,
+}
+
+impl ItemKind {
+    /// Returns the heading of the section documenting items of this kind.
+    pub fn section(self) -> &'static str {
+        match self {
+            Self::Constant | Self::CircuitConstant => "Constants",
+            Self::Alias => "Type aliases",
+            Self::Circuit => "Circuits",
+            Self::Function => "Functions",
+            Self::Variable => "Variables",
+        }
+    }
+}
+
+/// A documented declaration of a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Item {
+    /// What the item declares.
+    pub kind: ItemKind,
+    /// The signature of the item as written in source, e.g., `function add(a: u8, b: u8) -> u8`.
+    pub signature: String,
+    /// The lines of the doc comments of the item.
+    pub docs: Vec<String>,
+    /// The members of a circuit.
+    pub members: Vec<Item>,
+}
+
+impl Item {
+    fn new(kind: ItemKind, signature: String) -> Self {
+        Self {
+            kind,
+            signature,
+            docs: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    fn function(function: &Function) -> Self {
+        Self {
+            docs: function.docs.clone(),
+            ..Self::new(ItemKind::Function, function.signature())
+        }
+    }
+
+    fn constant(definition: &DefinitionStatement) -> Self {
+        let names = definition
+            .variable_names
+            .iter()
+            .map(|variable| variable.identifier.to_string())
+            .collect::<Vec<_>>();
+        let mut signature = match names.as_slice() {
+            [name] => format!("const {}", name),
+            names => format!("const ({})", names.join(", ")),
+        };
+        if let Some(type_) = &definition.type_ {
+            signature.push_str(&format!(": {}", type_.source_name()));
+        }
+        signature.push_str(&format!(" = {}", definition.value));
+        Self::new(ItemKind::Constant, signature)
+    }
+}
+
+/// Returns the documented items of `program`, in the order they are declared in each section.
+/// Test functions and private functions and circuits are not part of the interface of the
+/// program, so they are not documented.
+pub fn items(program: &Program) -> Vec<Item> {
+    let mut items = Vec::new();
+    items.extend(program.global_consts.values().map(Item::constant));
+    items.extend(program.aliases.values().map(|alias| {
+        Item::new(
+            ItemKind::Alias,
+            format!("type {} = {}", alias.name, alias.represents.source_name()),
+        )
+    }));
+    for circuit in program.circuits.values().filter(|circuit| !circuit.is_private()) {
+        let members = circuit
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                CircuitMember::CircuitVariable(identifier, type_) => Some(Item::new(
+                    ItemKind::Variable,
+                    format!("{}: {}", identifier, type_.source_name()),
+                )),
+                CircuitMember::CircuitConst(identifier, type_, value) => Some(Item::new(
+                    ItemKind::CircuitConstant,
+                    format!("const {}: {} = {}", identifier, type_.source_name(), value),
+                )),
+                CircuitMember::CircuitFunction(function) if is_documented(function) => Some(Item::function(function)),
+                CircuitMember::CircuitFunction(_) => None,
+            })
+            .collect();
+        items.push(Item {
+            docs: circuit.docs.clone(),
+            members,
+            ..Item::new(ItemKind::Circuit, format!("circuit {}", circuit.circuit_name))
+        });
+    }
+    items.extend(
+        program
+            .functions
+            .values()
+            .filter(|function| is_documented(function))
+            .map(Item::function),
+    );
+    items
+}
+
+/// Returns `true` if `function` is neither annotated with `@test` nor private.
+fn is_documented(function: &Function) -> bool {
+    !function.annotations.contains_key(&sym::test) && !function.is_private()
+}
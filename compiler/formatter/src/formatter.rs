@@ -0,0 +1,300 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_errors::Result;
+use leo_parser::{tokenize_with_source, SpannedToken, Token};
+use leo_span::symbol::create_session_if_not_set_then;
+
+/// The indent for each level of nesting.
+const INDENT: &str = "    ";
+
+/// Returns `source` formatted in the canonical Leo style.
+/// Fails if `source` cannot be tokenized.
+pub fn format(path: &str, source: &str) -> Result<String> {
+    create_session_if_not_set_then(|_| {
+        let tokens = tokenize_with_source(path, source)?;
+        let mut formatter = Formatter::default();
+        for line in group_lines(&tokens) {
+            formatter.write_line(&line);
+        }
+        Ok(formatter.output)
+    })
+}
+
+/// Returns whether `source` is already formatted.
+pub fn is_formatted(path: &str, source: &str) -> Result<bool> {
+    Ok(format(path, source)? == source)
+}
+
+/// A source line: its tokens, and whether blank lines precede it.
+struct Line<'a> {
+    after_blank: bool,
+    tokens: Vec<&'a (SpannedToken, &'a str)>,
+}
+
+/// Groups `tokens` by the line they start on.
+/// A token starting on the line where a multi-line block comment ends joins the comment's line.
+fn group_lines<'a>(tokens: &'a [(SpannedToken, &'a str)]) -> Vec<Line<'a>> {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut last_line = 0;
+    for token in tokens {
+        let span = &token.0.span;
+        match lines.last_mut() {
+            Some(line) if span.line_start <= last_line => line.tokens.push(token),
+            _ => lines.push(Line {
+                after_blank: !lines.is_empty() && span.line_start > last_line + 1,
+                tokens: vec![token],
+            }),
+        }
+        last_line = span.line_stop;
+    }
+    lines
+}
+
+/// Writes formatted lines, tracking the nesting and the state needed to space tokens.
+#[derive(Default)]
+struct Formatter {
+    /// The formatted source so far.
+    output: String,
+    /// The number of open `{`, `(`, and `[`.
+    depth: usize,
+    /// Whether the last line ended with an opening delimiter.
+    opened_block: bool,
+    /// The number of `?` whose `:` has not been written yet.
+    open_ternaries: usize,
+}
+
+impl Formatter {
+    /// Writes `line` at the indent of its nesting.
+    fn write_line(&mut self, line: &Line) {
+        let leading_closers = line.tokens.iter().take_while(|(t, _)| is_closer(&t.token)).count();
+        let starts_with_closer = leading_closers > 0;
+
+        if line.after_blank && !self.opened_block && !starts_with_closer {
+            self.output.push('\n');
+        }
+        for _ in 0..self.depth.saturating_sub(leading_closers) {
+            self.output.push_str(INDENT);
+        }
+
+        let mut previous: Option<&Token> = None;
+        let mut glue_after = false;
+        for (spanned, text) in line.tokens.iter() {
+            let token = &spanned.token;
+            let glue_before = self.glue_before(previous, token);
+            if previous.is_some() && (is_comment(token) || !(glue_after || glue_before)) {
+                self.output.push(' ');
+            }
+            match token {
+                Token::CommentLine(_) => self.output.push_str(text.trim_end()),
+                _ => self.output.push_str(text),
+            }
+
+            glue_after = match token {
+                Token::LeftParen
+                | Token::LeftSquare
+                | Token::Dot
+                | Token::DoubleColon
+                | Token::DotDot
+                | Token::DotDotDot
+                | Token::At
+                | Token::Not => true,
+                // Unary operators are attached to their operand.
+                Token::Minus | Token::Ampersand => !previous.map(ends_operand).unwrap_or(false),
+                // The `=` of an inclusive range, `a..=b`.
+                Token::Assign => previous == Some(&Token::DotDot),
+                _ => false,
+            };
+            if is_opener(token) {
+                self.depth += 1;
+            } else if is_closer(token) {
+                self.depth = self.depth.saturating_sub(1);
+            }
+            previous = Some(token);
+        }
+
+        self.opened_block = previous.map(is_opener).unwrap_or(false);
+        self.output.push('\n');
+    }
+
+    /// Returns whether `next` is written right after `previous`, without a space.
+    fn glue_before(&mut self, previous: Option<&Token>, next: &Token) -> bool {
+        match next {
+            Token::Comma
+            | Token::Semicolon
+            | Token::RightParen
+            | Token::RightSquare
+            | Token::Dot
+            | Token::DoubleColon
+            | Token::DotDot => true,
+            Token::Question => {
+                self.open_ternaries += 1;
+                false
+            }
+            // A `:` is spaced when it ends the middle of a ternary expression, `a ? b : c`.
+            Token::Colon if self.open_ternaries > 0 => {
+                self.open_ternaries -= 1;
+                false
+            }
+            Token::Colon => true,
+            // Calls and accesses, `f(a)` and `a[0]`, unlike `if (a)` and `= [0]`.
+            Token::LeftParen | Token::LeftSquare => previous.map(ends_callee).unwrap_or(false),
+            Token::RightCurly => previous == Some(&Token::LeftCurly),
+            Token::Assign => previous == Some(&Token::DotDot),
+            // The type suffix of a literal, `2u8` and `(0, 1)group`.
+            Token::Group if previous == Some(&Token::RightParen) => true,
+            _ if is_type_keyword(next) => matches!(previous, Some(Token::Int(_))),
+            _ => false,
+        }
+    }
+}
+
+/// Returns whether `token` opens a nesting.
+fn is_opener(token: &Token) -> bool {
+    matches!(token, Token::LeftCurly | Token::LeftParen | Token::LeftSquare)
+}
+
+/// Returns whether `token` closes a nesting.
+fn is_closer(token: &Token) -> bool {
+    matches!(token, Token::RightCurly | Token::RightParen | Token::RightSquare)
+}
+
+/// Returns whether `token` is a comment.
+fn is_comment(token: &Token) -> bool {
+    matches!(token, Token::CommentLine(_) | Token::CommentBlock(_))
+}
+
+/// Returns whether `token` may end the name of what is called or accessed.
+fn ends_callee(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Ident(_) | Token::RightParen | Token::RightSquare | Token::LittleSelf | Token::BigSelf
+    ) || is_type_keyword(token)
+}
+
+/// Returns whether `token` may end an operand, so that a `-` or `&` after it is a binary operator.
+fn ends_operand(token: &Token) -> bool {
+    ends_callee(token)
+        || matches!(
+            token,
+            Token::Int(_)
+                | Token::True
+                | Token::False
+                | Token::AddressLit(_)
+                | Token::CharLit(_)
+                | Token::StringLit(_)
+                | Token::RightCurly
+                | Token::Input
+        )
+}
+
+/// Returns whether `token` is the keyword of a primitive type.
+fn is_type_keyword(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::U8
+            | Token::U16
+            | Token::U32
+            | Token::U64
+            | Token::U128
+            | Token::I8
+            | Token::I16
+            | Token::I32
+            | Token::I64
+            | Token::I128
+            | Token::Field
+            | Token::Group
+            | Token::Bool
+            | Token::Address
+            | Token::Char
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_parser::tokenize;
+    use std::path::{Path, PathBuf};
+
+    /// Returns the Leo files under `dir`.
+    fn leo_files(dir: &Path) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                files.extend(leo_files(&path));
+            } else if path.extension().map(|e| e == "leo").unwrap_or(false) {
+                files.push(path);
+            }
+        }
+        files.sort();
+        files
+    }
+
+    /// Returns the tokens of `source`, with line comments compared without trailing whitespace.
+    fn tokens(source: &str) -> Vec<Token> {
+        tokenize("test", source)
+            .unwrap()
+            .into_iter()
+            .map(|spanned| match spanned.token {
+                Token::CommentLine(comment) => Token::CommentLine(comment.trim_end().to_string()),
+                token => token,
+            })
+            .collect()
+    }
+
+    /// Returns the printed AST of `source`, if it parses.
+    fn parse(source: &str) -> std::result::Result<String, ()> {
+        let (handler, _) = Handler::new_with_buf();
+        leo_parser::parse(&handler, "test", source)
+            .map(|program| program.to_string())
+            .map_err(|_| ())
+    }
+
+    #[test]
+    fn formats_canonically() {
+        let source = "// A circuit.\ncircuit Foo{x:u8,\n\n\n  function bar ( self )->u8{return self.x*2u8 ;}}\n\n\n\
+                      @test\nfunction main(a : u8,b:[u8;2])->u8 {\n\n    let c=-a+b [0] ;  \n\
+                      for i in 0..=3{ c+=i; }\nreturn c>1u8?c:a;\n}";
+        let expected =
+            "// A circuit.\ncircuit Foo { x: u8,\n\n    function bar(self) -> u8 { return self.x * 2u8; } }\n\n\
+                        @test\nfunction main(a: u8, b: [u8; 2]) -> u8 {\n    let c = -a + b[0];\n    \
+                        for i in 0..=3 { c += i; }\n    return c > 1u8 ? c : a;\n}\n";
+        assert_eq!(format("test", source).unwrap(), expected);
+        assert!(is_formatted("test", expected).unwrap());
+    }
+
+    #[test]
+    fn formatting_is_idempotent_and_keeps_tokens() {
+        let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/parser");
+        create_session_if_not_set_then(|_| {
+            for path in leo_files(&corpus) {
+                let source = std::fs::read_to_string(&path).unwrap();
+                // Some tests exercise sources the tokenizer rejects.
+                let formatted = match format("test", &source) {
+                    Ok(formatted) => formatted,
+                    Err(_) => continue,
+                };
+                assert_eq!(tokens(&source), tokens(&formatted), "{}", path.display());
+                if let Ok(program) = parse(&source) {
+                    assert_eq!(Ok(program), parse(&formatted), "{}", path.display());
+                }
+                assert_eq!(format("test", &formatted).unwrap(), formatted, "{}", path.display());
+            }
+        });
+    }
+}
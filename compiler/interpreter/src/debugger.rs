@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The hooks through which a debugger follows the statements the interpreter executes.
+
+use crate::Value;
+use leo_ast::{Function, Statement};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+/// A call of a function being interpreted.
+#[derive(Clone, Debug)]
+pub struct Frame<'a> {
+    /// The function called, or `None` for the statements executed at the top level, e.g., by the REPL.
+    pub function: Option<&'a Function>,
+    /// The variables of the call, innermost scope last.
+    pub scopes: Vec<IndexMap<Symbol, Value>>,
+}
+
+impl Frame<'_> {
+    /// Returns the variables of the call in scope, where an inner variable hides an outer one of the same name.
+    pub fn variables(&self) -> IndexMap<Symbol, &Value> {
+        let mut variables = IndexMap::new();
+        for scope in self.scopes.iter() {
+            variables.extend(scope.iter().map(|(name, value)| (*name, value)));
+        }
+        variables
+    }
+}
+
+/// Follows the execution of an [`Interpreter`](crate::Interpreter), e.g., to pause it at breakpoints.
+pub trait Debugger {
+    /// Called before each statement is executed, except for blocks, whose statements are,
+    /// with the `frames` of the calls being interpreted, innermost last.
+    /// The interpreter waits for it to return, so the execution is paused until then.
+    fn before_statement(&mut self, statement: &Statement, frames: &[Frame]);
+
+    /// Called with each message of `console.log` and `console.error` as it is logged.
+    fn log(&mut self, _message: &str) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Interpreter;
+    use leo_ast::Node;
+    use leo_compiler::Compiler;
+    use leo_errors::emitter::Handler;
+    use leo_span::{sym, symbol::create_session_if_not_set_then};
+
+    /// Records the line, the called functions, and the variables of each statement executed.
+    #[derive(Default)]
+    struct Recorder(Vec<(usize, Vec<String>, String)>);
+
+    impl Debugger for Recorder {
+        fn before_statement(&mut self, statement: &Statement, frames: &[Frame]) {
+            let calls = frames
+                .iter()
+                .filter_map(|frame| Some(frame.function?.identifier.to_string()))
+                .collect();
+            let variables = frames.last().unwrap().variables();
+            let variables = variables
+                .iter()
+                .map(|(name, value)| format!("{} = {}", name, value))
+                .collect::<Vec<_>>();
+            self.0.push((statement.span().line_start, calls, variables.join(", ")));
+        }
+    }
+
+    #[test]
+    fn follows_statements() {
+        create_session_if_not_set_then(|_| {
+            let source = "function double(x: u8) -> u8 {\n    return x * 2u8;\n}\n\
+                          function main() -> u8 {\n    let a = 1u8;\n    {\n        let a = double(a);\n    }\n    \
+                          return a;\n}";
+            let handler = Handler::default();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+
+            let mut recorder = Recorder::default();
+            let mut interpreter = Interpreter::new(ast.as_repr()).unwrap().with_debugger(&mut recorder);
+            let main = interpreter.function(sym::main).unwrap();
+            let output = interpreter.call(main, Vec::new(), &main.span).unwrap();
+            drop(interpreter);
+
+            assert_eq!(output.to_string(), "1u8");
+            let step = |line, calls: &[&str], variables: &str| {
+                (
+                    line,
+                    calls.iter().map(|call| call.to_string()).collect::<Vec<_>>(),
+                    variables.to_string(),
+                )
+            };
+            assert_eq!(
+                recorder.0,
+                vec![
+                    step(5, &["main"], ""),
+                    step(7, &["main"], "a = 1u8"),
+                    step(2, &["main", "double"], "x = 1u8"),
+                    step(9, &["main"], "a = 1u8"),
+                ]
+            );
+        });
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// The modulus of the field, the order of the scalar field of BLS12-377, as little-endian 64-bit limbs.
+const MODULUS: [u64; 4] = [
+    0x0a11_8000_0000_0001,
+    0x59aa_76fe_d000_0001,
+    0x60b4_4d1e_5c37_b001,
+    0x12ab_655e_9a2c_a556,
+];
+
+/// An element of the field of `field` values, the scalar field of BLS12-377.
+///
+/// It is kept as the least non-negative residue, in little-endian 64-bit limbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Field([u64; 4]);
+
+impl Field {
+    /// The element zero.
+    pub const ZERO: Self = Self([0; 4]);
+    /// The element one.
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+
+    /// Returns the element written in decimal as `value`, which may be negative,
+    /// or `None` if it is not a number whose magnitude is less than the modulus.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+
+        let mut limbs = [0u64; 4];
+        for digit in digits.chars() {
+            let mut carry = digit.to_digit(10)? as u128;
+            for limb in limbs.iter_mut() {
+                let product = *limb as u128 * 10 + carry;
+                *limb = product as u64;
+                carry = product >> 64;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        if compare(&limbs, &MODULUS) != Ordering::Less {
+            return None;
+        }
+
+        let field = Self(limbs);
+        Some(if negative { -field } else { field })
+    }
+
+    /// Returns the element equal to the integer `value`.
+    pub fn from_u128(value: u128) -> Self {
+        Self([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    /// Returns `true` if the element is zero.
+    pub fn is_zero(self) -> bool {
+        self == Self::ZERO
+    }
+
+    /// Returns `self` to the power of the integer `exponent`, given in little-endian 64-bit limbs.
+    pub fn pow(self, exponent: &[u64]) -> Self {
+        // Square and multiply, from the most significant bit of `exponent`.
+        let mut power = Self::ONE;
+        for bit in bits(exponent) {
+            power = power * power;
+            if bit {
+                power = power * self;
+            }
+        }
+        power
+    }
+
+    /// Returns the multiplicative inverse of `self`, or `None` if it is zero.
+    pub fn inverse(self) -> Option<Self> {
+        // By Fermat's little theorem, the inverse is `self` to the power of the modulus minus two.
+        match self.is_zero() {
+            true => None,
+            false => Some(self.pow(&sub_limbs(&MODULUS, &[2, 0, 0, 0]))),
+        }
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        other.inverse().map(|inverse| self * inverse)
+    }
+
+    /// Returns the limbs of the least non-negative residue of the element.
+    pub fn limbs(self) -> [u64; 4] {
+        self.0
+    }
+}
+
+impl Add for Field {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // The modulus is less than 2^254, so the sum fits in four limbs.
+        let (mut sum, _) = add_limbs(&self.0, &other.0);
+        if compare(&sum, &MODULUS) != Ordering::Less {
+            sum = sub_limbs(&sum, &MODULUS);
+        }
+        Self(sum)
+    }
+}
+
+impl Sub for Field {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Neg for Field {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            Self(sub_limbs(&MODULUS, &self.0))
+        }
+    }
+}
+
+impl Mul for Field {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Double and add, from the most significant bit of `other`.
+        let mut product = Self::ZERO;
+        for bit in bits(&other.0) {
+            product = product + product;
+            if bit {
+                product = product + self;
+            }
+        }
+        product
+    }
+}
+
+impl PartialOrd for Field {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Field {
+    /// Orders elements by their least non-negative residues.
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.0, &other.0)
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut limbs = self.0;
+        let mut digits = Vec::new();
+        loop {
+            // Divide by ten, from the most significant limb.
+            let mut remainder = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let dividend = (remainder << 64) | *limb as u128;
+                *limb = (dividend / 10) as u64;
+                remainder = dividend % 10;
+            }
+            digits.push(char::from(b'0' + remainder as u8));
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+/// Compares the numbers with little-endian limbs `left` and `right`.
+fn compare(left: &[u64; 4], right: &[u64; 4]) -> Ordering {
+    left.iter().rev().cmp(right.iter().rev())
+}
+
+/// Returns `left + right` and whether it overflows.
+fn add_limbs(left: &[u64; 4], right: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut sum = [0u64; 4];
+    let mut carry = false;
+    for (index, limb) in sum.iter_mut().enumerate() {
+        let (partial, overflow) = left[index].overflowing_add(right[index]);
+        let (partial, carried) = partial.overflowing_add(carry as u64);
+        *limb = partial;
+        carry = overflow || carried;
+    }
+    (sum, carry)
+}
+
+/// Returns `left - right`, which must not be negative.
+fn sub_limbs(left: &[u64; 4], right: &[u64; 4]) -> [u64; 4] {
+    let mut difference = [0u64; 4];
+    let mut borrow = false;
+    for (index, limb) in difference.iter_mut().enumerate() {
+        let (partial, overflow) = left[index].overflowing_sub(right[index]);
+        let (partial, borrowed) = partial.overflowing_sub(borrow as u64);
+        *limb = partial;
+        borrow = overflow || borrowed;
+    }
+    difference
+}
+
+/// Returns the bits of the number with little-endian limbs `limbs`, most significant first.
+fn bits(limbs: &[u64]) -> impl Iterator<Item = bool> + '_ {
+    limbs
+        .iter()
+        .rev()
+        .flat_map(|limb| (0..64).rev().map(move |bit| (limb >> bit) & 1 == 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(value: &str) -> Field {
+        Field::parse(value).unwrap()
+    }
+
+    #[test]
+    fn computes_in_the_field() {
+        let modulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041";
+        assert_eq!(Field::parse(modulus), None);
+        assert_eq!(Field::parse("1a"), None);
+        assert_eq!(
+            field("-1").to_string(),
+            "8444461749428370424248824938781546531375899335154063827935233455917409239040"
+        );
+        assert_eq!(field("-1") + field("2"), field("1"));
+        assert_eq!(field("3") - field("5"), field("-2"));
+        assert_eq!(field("-1") * field("-1"), field("1"));
+        assert_eq!((field("12") * field("34")).to_string(), "408");
+        assert_eq!(field("2").pow(&[64]).to_string(), "18446744073709551616");
+        assert_eq!(field("2").pow(&[64]), Field::from_u128(1 << 64));
+        assert_eq!(field("7").checked_div(field("2")).unwrap() * field("2"), field("7"));
+        assert_eq!(field("7").checked_div(Field::ZERO), None);
+        assert!(field("-1") > field("1"));
+    }
+}
@@ -0,0 +1,1148 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Debugger, Field, Frame, Integer, Value};
+use leo_ast::*;
+use leo_errors::{AsgError, CompilerError, Result};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::IndexMap;
+use std::{cmp::Ordering, convert::TryFrom};
+
+/// What to do after a statement.
+enum Flow {
+    /// Continue with the next statement.
+    Next,
+    /// Return the value from the function.
+    Return(Value),
+}
+
+/// A step from a variable to the part of it which is assigned to.
+enum Place {
+    /// An element of an array.
+    Index(usize),
+    /// A range of elements of an array.
+    Range(Option<usize>, Option<usize>),
+    /// An element of a tuple.
+    Tuple(usize),
+    /// A variable of a circuit.
+    Member(Symbol),
+}
+
+/// Interprets the functions of a program on concrete values.
+///
+/// The program is interpreted as written, so it should be the checked AST before any optimizations.
+/// Integer operations fail on overflow and division by zero, and `console.assert` fails when its
+/// condition is false. Group elements can be compared, but not computed with.
+pub struct Interpreter<'a> {
+    /// The program whose functions are interpreted.
+    program: &'a Program,
+    /// The values of the global constants.
+    globals: IndexMap<Symbol, Value>,
+    /// The calls being interpreted, innermost last, below which are the statements executed at the top level.
+    frames: Vec<Frame<'a>>,
+    /// The messages of `console.log` and `console.error`, in order.
+    pub output: Vec<String>,
+    /// The debugger following the execution, if any.
+    debugger: Option<&'a mut dyn Debugger>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Returns an interpreter for `program`, evaluating its global constants.
+    pub fn new(program: &'a Program) -> Result<Self> {
+        let mut interpreter = Self {
+            program,
+            globals: IndexMap::new(),
+            frames: vec![Frame {
+                function: None,
+                scopes: vec![IndexMap::new()],
+            }],
+            output: Vec::new(),
+            debugger: None,
+        };
+        for definition in program.global_consts.values() {
+            interpreter.definition(definition)?;
+        }
+        interpreter.globals = interpreter.scopes().pop().unwrap_or_default();
+        Ok(interpreter)
+    }
+
+    /// Returns the interpreter, with `debugger` following the statements it executes.
+    pub fn with_debugger(mut self, debugger: &'a mut dyn Debugger) -> Self {
+        self.debugger = Some(debugger);
+        self
+    }
+
+    /// Returns the scopes of the innermost call.
+    fn scopes(&mut self) -> &mut Vec<IndexMap<Symbol, Value>> {
+        &mut self.frames.last_mut().expect("there is always a frame").scopes
+    }
+
+    /// Executes `statement` at the top level, where the variables defined by earlier statements are kept,
+    /// and returns its value if it is an expression or a `return`.
+    pub fn execute(&mut self, statement: &Statement) -> Result<Option<Value>> {
+        if self.scopes().is_empty() {
+            self.scopes().push(IndexMap::new());
+        }
+        match statement {
+            Statement::Expression(statement) => self.expression(&statement.expression).map(Some),
+            statement => match self.statement(statement)? {
+                Flow::Return(value) => Ok(Some(value)),
+                Flow::Next => Ok(None),
+            },
+        }
+    }
+
+    /// Returns the function of `program` named `name`.
+    pub fn function(&self, name: Symbol) -> Option<&'a Function> {
+        self.program
+            .functions
+            .iter()
+            .find(|(identifier, _)| identifier.name == name)
+            .map(|(_, function)| function)
+    }
+
+    /// Returns the value of calling `function`, a function of the program which is not a circuit function.
+    pub fn call(&mut self, function: &'a Function, arguments: Vec<Value>, span: &Span) -> Result<Value> {
+        self.call_with_receiver(function, None, arguments, span)
+            .map(|(output, _)| output)
+    }
+
+    /// Returns the value of calling `function` on `receiver`, if it is a circuit function,
+    /// along with the value of `self` after the call.
+    fn call_with_receiver(
+        &mut self,
+        function: &'a Function,
+        receiver: Option<Value>,
+        arguments: Vec<Value>,
+        span: &Span,
+    ) -> Result<(Value, Option<Value>)> {
+        let inputs = function.filter_self_inputs().count();
+        if inputs != arguments.len() {
+            return Err(AsgError::unexpected_call_argument_count(inputs, arguments.len(), span).into());
+        }
+
+        let mut frame = IndexMap::new();
+        if let Some(receiver) = receiver {
+            frame.insert(sym::SelfLower, receiver);
+        }
+        for (input, argument) in function.filter_self_inputs().zip(arguments) {
+            if let FunctionInput::Variable(variable) = input {
+                let argument = Self::coerce(argument, &variable.type_, span)?;
+                frame.insert(variable.identifier.name, argument);
+            }
+        }
+
+        self.frames.push(Frame {
+            function: Some(function),
+            scopes: vec![frame],
+        });
+        let flow = self.block(&function.block);
+        let frame = self
+            .frames
+            .pop()
+            .and_then(|mut frame| frame.scopes.pop())
+            .unwrap_or_default();
+
+        let output = match (flow?, &function.output) {
+            (Flow::Return(value), Some(type_)) => Self::coerce(value, type_, span)?,
+            (Flow::Return(value), None) => value,
+            (Flow::Next, Some(type_)) if !matches!(type_, Type::Tuple(types) if types.is_empty()) => {
+                return Err(CompilerError::statement_no_returns(type_, &function.span).into());
+            }
+            (Flow::Next, _) => Value::Tuple(Vec::new()),
+        };
+        Ok((output, frame.get(&sym::SelfLower).cloned()))
+    }
+
+    /// Returns the circuit of `program` named `name`.
+    fn circuit(&self, name: &Identifier) -> Result<&'a Circuit> {
+        self.program
+            .circuits
+            .iter()
+            .find(|(identifier, _)| identifier.name == name.name)
+            .map(|(_, circuit)| circuit)
+            .ok_or_else(|| AsgError::unresolved_circuit(name, &name.span).into())
+    }
+
+    /// Returns the function named `name` of the circuit `circuit`.
+    fn circuit_function(circuit: &'a Circuit, name: &Identifier) -> Result<&'a Function> {
+        circuit
+            .members
+            .iter()
+            .find_map(|member| match member {
+                CircuitMember::CircuitFunction(function) if function.name() == name.name => Some(&**function),
+                _ => None,
+            })
+            .ok_or_else(|| AsgError::unresolved_circuit_member(&circuit.circuit_name, name, &name.span).into())
+    }
+
+    /// Executes the statements of `block` in a new scope.
+    fn block(&mut self, block: &Block) -> Result<Flow> {
+        self.scopes().push(IndexMap::new());
+        let flow = self.statements(&block.statements);
+        self.scopes().pop();
+        flow
+    }
+
+    /// Executes `statements` until one of them returns.
+    fn statements(&mut self, statements: &[Statement]) -> Result<Flow> {
+        for statement in statements {
+            if let Flow::Return(value) = self.statement(statement)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Executes `statement`.
+    fn statement(&mut self, statement: &Statement) -> Result<Flow> {
+        // A block is not a step of its own, but the statements in it are.
+        if !matches!(statement, Statement::Block(_)) {
+            if let Some(debugger) = self.debugger.as_deref_mut() {
+                debugger.before_statement(statement, &self.frames);
+            }
+        }
+        match statement {
+            Statement::Return(statement) => Ok(Flow::Return(self.expression(&statement.expression)?)),
+            Statement::Definition(definition) => {
+                self.definition(definition)?;
+                Ok(Flow::Next)
+            }
+            Statement::Assign(assign) => {
+                self.assign(assign)?;
+                Ok(Flow::Next)
+            }
+            Statement::Conditional(conditional) => {
+                if self.condition(&conditional.condition)? {
+                    self.block(&conditional.block)
+                } else if let Some(next) = &conditional.next {
+                    self.statement(next)
+                } else {
+                    Ok(Flow::Next)
+                }
+            }
+            Statement::Iteration(iteration) => self.iteration(iteration),
+            Statement::Console(console) => {
+                self.console(console)?;
+                Ok(Flow::Next)
+            }
+            Statement::Expression(statement) => {
+                self.expression(&statement.expression)?;
+                Ok(Flow::Next)
+            }
+            Statement::Block(block) => self.block(block),
+        }
+    }
+
+    /// Defines the variables of `definition` in the innermost scope.
+    fn definition(&mut self, definition: &DefinitionStatement) -> Result<()> {
+        let mut value = self.expression(&definition.value)?;
+        if let Some(type_) = &definition.type_ {
+            value = Self::coerce(value, type_, &definition.span)?;
+        }
+
+        let scope = self.scopes().last_mut().expect("there is always a scope");
+        match (definition.variable_names.as_slice(), value) {
+            ([variable], value) => {
+                scope.insert(variable.identifier.name, value);
+            }
+            (variables, Value::Tuple(values)) if variables.len() == values.len() => {
+                for (variable, value) in variables.iter().zip(values) {
+                    scope.insert(variable.identifier.name, value);
+                }
+            }
+            (variables, value) => {
+                let names = variables
+                    .iter()
+                    .map(|variable| variable.identifier.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(
+                    AsgError::unexpected_type(format!("a tuple for ({})", names), value, &definition.span).into(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes `assign`.
+    fn assign(&mut self, assign: &AssignStatement) -> Result<()> {
+        let mut value = self.expression(&assign.value)?;
+        let operation = match assign.operation {
+            AssignOperation::Assign => None,
+            AssignOperation::Add => Some(BinaryOperation::Add),
+            AssignOperation::Sub => Some(BinaryOperation::Sub),
+            AssignOperation::Mul => Some(BinaryOperation::Mul),
+            AssignOperation::Div => Some(BinaryOperation::Div),
+            AssignOperation::Pow => Some(BinaryOperation::Pow),
+            AssignOperation::Or => Some(BinaryOperation::Or),
+            AssignOperation::And => Some(BinaryOperation::And),
+            AssignOperation::BitOr => Some(BinaryOperation::BitOr),
+            AssignOperation::BitAnd => Some(BinaryOperation::BitAnd),
+            AssignOperation::BitXor => Some(BinaryOperation::BitXor),
+            AssignOperation::Shr => Some(BinaryOperation::Shr),
+            AssignOperation::ShrSigned => Some(BinaryOperation::ShrSigned),
+            AssignOperation::Shl => Some(BinaryOperation::Shl),
+            AssignOperation::Mod => Some(BinaryOperation::Mod),
+        };
+        if let Some(op) = operation {
+            let current = self.read_place(&assign.assignee)?;
+            value = self.operate(op, current, value, &assign.span, &assign.to_string())?;
+        }
+
+        let places = self.places(&assign.assignee.accesses)?;
+        self.write(&assign.assignee.identifier, &places, value, &assign.span)
+    }
+
+    /// Returns the current value of `assignee`.
+    fn read_place(&mut self, assignee: &Assignee) -> Result<Value> {
+        let mut value = self.variable(&assignee.identifier)?;
+        for place in self.places(&assignee.accesses)? {
+            value = Self::access(value, &place, &assignee.span)?;
+        }
+        Ok(value)
+    }
+
+    /// Evaluates the indices in `accesses`.
+    fn places(&mut self, accesses: &[AssigneeAccess]) -> Result<Vec<Place>> {
+        accesses
+            .iter()
+            .map(|access| {
+                Ok(match access {
+                    AssigneeAccess::ArrayIndex(index) => Place::Index(self.index(index)?),
+                    AssigneeAccess::ArrayRange(left, right) => Place::Range(
+                        left.as_ref().map(|left| self.index(left)).transpose()?,
+                        right.as_ref().map(|right| self.index(right)).transpose()?,
+                    ),
+                    AssigneeAccess::Tuple(index, span) => Place::Tuple(Self::position(&index.value, span)?),
+                    AssigneeAccess::Member(name) => Place::Member(name.name),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the part of `value` at `place`.
+    fn access(value: Value, place: &Place, span: &Span) -> Result<Value> {
+        match (place, value) {
+            (Place::Index(index), Value::Array(mut elements)) => {
+                if *index >= elements.len() {
+                    return Err(AsgError::array_index_out_of_bounds(index, span).into());
+                }
+                Ok(elements.swap_remove(*index))
+            }
+            (Place::Range(left, right), Value::Array(elements)) => {
+                let (left, right) = Self::range(*left, *right, elements.len(), span)?;
+                Ok(Value::Array(elements[left..right].to_vec()))
+            }
+            (Place::Tuple(index), Value::Tuple(mut elements)) => {
+                if *index >= elements.len() {
+                    return Err(AsgError::tuple_index_out_of_bounds(index, span).into());
+                }
+                Ok(elements.swap_remove(*index))
+            }
+            (Place::Member(name), Value::Circuit(circuit, mut variables)) => variables
+                .remove(name)
+                .ok_or_else(|| AsgError::unresolved_circuit_member(circuit, name, span).into()),
+            (Place::Index(_) | Place::Range(..), value) => Err(AsgError::index_into_non_array(value, span).into()),
+            (Place::Tuple(_), value) => Err(AsgError::index_into_non_tuple(value, span).into()),
+            (Place::Member(name), value) => {
+                Err(CompilerError::expected_circuit_member(format!("{}.{}", value, name), span).into())
+            }
+        }
+    }
+
+    /// Returns the bounds of the range `left..right` of an array of length `length`.
+    fn range(left: Option<usize>, right: Option<usize>, length: usize, span: &Span) -> Result<(usize, usize)> {
+        let (left, right) = (left.unwrap_or(0), right.unwrap_or(length));
+        if right > length {
+            return Err(AsgError::array_index_out_of_bounds(right, span).into());
+        }
+        if left > right {
+            return Err(AsgError::array_index_out_of_bounds(left, span).into());
+        }
+        Ok((left, right))
+    }
+
+    /// Assigns `value` to the part at `places` of the variable `identifier`.
+    fn write(&mut self, identifier: &Identifier, places: &[Place], value: Value, span: &Span) -> Result<()> {
+        let target = match self
+            .frames
+            .last_mut()
+            .expect("there is always a frame")
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(&identifier.name))
+        {
+            Some(target) => target,
+            None if self.globals.contains_key(&identifier.name) => {
+                return Err(AsgError::immutable_assignment(identifier, span).into());
+            }
+            None => return Err(AsgError::unresolved_reference(identifier, &identifier.span).into()),
+        };
+
+        let mut target = target;
+        for place in places {
+            target = match (place, target) {
+                (Place::Index(index), Value::Array(elements)) => {
+                    let length = elements.len();
+                    elements
+                        .get_mut(*index)
+                        .ok_or_else(|| AsgError::array_index_out_of_bounds(format!("{} of {}", index, length), span))?
+                }
+                (Place::Range(left, right), Value::Array(elements)) => {
+                    let (left, right) = Self::range(*left, *right, elements.len(), span)?;
+                    return match Self::coerce_like(value, &Value::Array(elements[left..right].to_vec()), span)? {
+                        Value::Array(values) if values.len() == right - left => {
+                            elements.splice(left..right, values);
+                            Ok(())
+                        }
+                        value => {
+                            let expected = format!("an array of {} elements", right - left);
+                            Err(AsgError::unexpected_type(expected, value, span).into())
+                        }
+                    };
+                }
+                (Place::Tuple(index), Value::Tuple(elements)) => elements
+                    .get_mut(*index)
+                    .ok_or_else(|| AsgError::tuple_index_out_of_bounds(index, span))?,
+                (Place::Member(name), Value::Circuit(circuit, variables)) => {
+                    let circuit = *circuit;
+                    variables
+                        .get_mut(name)
+                        .ok_or_else(|| AsgError::unresolved_circuit_member(circuit, name, span))?
+                }
+                (Place::Index(_) | Place::Range(..), value) => {
+                    return Err(AsgError::index_into_non_array(value, span).into())
+                }
+                (Place::Tuple(_), value) => return Err(AsgError::index_into_non_tuple(value, span).into()),
+                (Place::Member(name), value) => {
+                    let member = format!("{}.{}", value, name);
+                    return Err(CompilerError::expected_circuit_member(member, span).into());
+                }
+            };
+        }
+        *target = Self::coerce_like(value, target, span)?;
+        Ok(())
+    }
+
+    /// Executes the `for` loop `iteration`.
+    fn iteration(&mut self, iteration: &IterationStatement) -> Result<Flow> {
+        let start = self.expression(&iteration.start)?;
+        let stop = self.expression(&iteration.stop)?;
+        let start = Self::coerce_like(start, &stop, iteration.start.span())?;
+        let stop = Self::coerce_like(stop, &start, iteration.stop.span())?;
+
+        let type_ = match &start {
+            Value::Integer(integer) => Some(integer.type_()),
+            _ => None,
+        };
+        let bound = |value: &Value, span: &Span| -> Result<i128> {
+            match value {
+                Value::Integer(Integer::Signed(_, value)) => Ok(*value),
+                Value::Integer(Integer::Unsigned(_, value)) => {
+                    i128::try_from(*value).map_err(|_| CompilerError::cannot_interpret("this loop bound", span).into())
+                }
+                Value::Implicit(value) => value.parse().map_err(|_| AsgError::invalid_int(value, span).into()),
+                value => Err(AsgError::unexpected_type("an integer", value, span).into()),
+            }
+        };
+        let (start, stop) = (
+            bound(&start, iteration.start.span())?,
+            bound(&stop, iteration.stop.span())?,
+        );
+
+        // Loops count down if the start is above the stop.
+        let indices: Box<dyn Iterator<Item = i128>> = match (start <= stop, iteration.inclusive) {
+            (true, false) => Box::new(start..stop),
+            (true, true) => Box::new(start..=stop),
+            (false, false) => Box::new((stop + 1..=start).rev()),
+            (false, true) => Box::new((stop..=start).rev()),
+        };
+        for index in indices {
+            let index = match type_ {
+                Some(type_) => {
+                    Value::Integer(Integer::from_i128(type_, index).expect("the index is between the bounds"))
+                }
+                None => Value::Implicit(index.to_string()),
+            };
+            let mut scope = IndexMap::new();
+            scope.insert(iteration.variable.name, index);
+            self.scopes().push(scope);
+            let flow = self.block(&iteration.block);
+            self.scopes().pop();
+            if let Flow::Return(value) = flow? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Executes the `console` statement `console`.
+    fn console(&mut self, console: &ConsoleStatement) -> Result<()> {
+        match &console.function {
+            ConsoleFunction::Assert(expression) => {
+                if !self.condition(expression)? {
+                    return Err(CompilerError::assertion_failed(expression, &console.span).into());
+                }
+            }
+            ConsoleFunction::Error(arguments) | ConsoleFunction::Log(arguments) => {
+                let message = self.format(arguments)?;
+                if let Some(debugger) = self.debugger.as_deref_mut() {
+                    debugger.log(&message);
+                }
+                self.output.push(message);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the message of a `console.log` or `console.error` with `arguments`.
+    fn format(&mut self, arguments: &ConsoleArgs) -> Result<String> {
+        let mut parameters = Vec::with_capacity(arguments.parameters.len());
+        for parameter in arguments.parameters.iter() {
+            parameters.push(self.expression(parameter)?);
+        }
+
+        let mut message = String::new();
+        let mut containers = 0;
+        let mut characters = arguments.string.iter().peekable();
+        while let Some(character) = characters.next() {
+            match character {
+                Char::Scalar('{') => match characters.next() {
+                    Some(Char::Scalar('{')) => message.push('{'),
+                    Some(Char::Scalar('}')) => {
+                        if let Some(parameter) = parameters.get(containers) {
+                            message.push_str(&parameter.to_string());
+                        }
+                        containers += 1;
+                    }
+                    _ => return Err(CompilerError::console_fmt_expected_left_or_right_brace(&arguments.span).into()),
+                },
+                Char::Scalar('}') => match characters.next() {
+                    Some(Char::Scalar('}')) => message.push('}'),
+                    _ => return Err(CompilerError::console_fmt_expected_escaped_right_brace(&arguments.span).into()),
+                },
+                character => message.push_str(&character.to_string()),
+            }
+        }
+
+        if containers != parameters.len() {
+            return Err(CompilerError::console_container_parameter_length_mismatch(
+                containers,
+                parameters.len(),
+                &arguments.span,
+            )
+            .into());
+        }
+        Ok(message)
+    }
+
+    /// Returns the value of the boolean `expression`.
+    fn condition(&mut self, expression: &Expression) -> Result<bool> {
+        match self.expression(expression)? {
+            Value::Boolean(value) => Ok(value),
+            value => Err(AsgError::unexpected_type("bool", value, expression.span()).into()),
+        }
+    }
+
+    /// Returns the value of `expression`, which must be a non-negative integer, as an index.
+    fn index(&mut self, expression: &Expression) -> Result<usize> {
+        match self.expression(expression)? {
+            Value::Integer(integer) => integer
+                .to_u128()
+                .and_then(|index| usize::try_from(index).ok())
+                .ok_or_else(|| AsgError::array_index_out_of_bounds(integer, expression.span()).into()),
+            Value::Implicit(value) => Self::position(&value, expression.span()),
+            value => Err(CompilerError::invalid_index_expression(value, expression.span()).into()),
+        }
+    }
+
+    /// Parses the position `value`, e.g., of a tuple element.
+    fn position(value: &str, span: &Span) -> Result<usize> {
+        value.parse().map_err(|_| AsgError::parse_index_error(span).into())
+    }
+
+    /// Returns the value of the variable `identifier`.
+    fn variable(&self, identifier: &Identifier) -> Result<Value> {
+        self.frames
+            .last()
+            .into_iter()
+            .flat_map(|frame| frame.scopes.iter())
+            .rev()
+            .chain(std::iter::once(&self.globals))
+            .find_map(|scope| scope.get(&identifier.name))
+            .cloned()
+            .ok_or_else(|| AsgError::unresolved_reference(identifier, &identifier.span).into())
+    }
+
+    /// Returns the value of `expression`.
+    pub fn expression(&mut self, expression: &Expression) -> Result<Value> {
+        let span = expression.span();
+        match expression {
+            Expression::Identifier(identifier) => self.variable(identifier),
+            Expression::Value(literal) => Self::literal(&literal.value),
+            Expression::Binary(binary) => {
+                let left = self.expression(&binary.left)?;
+                // `&&` and `||` only evaluate their right operand if needed.
+                match (binary.op, &left) {
+                    (BinaryOperation::And, Value::Boolean(false)) => return Ok(Value::Boolean(false)),
+                    (BinaryOperation::Or, Value::Boolean(true)) => return Ok(Value::Boolean(true)),
+                    _ => {}
+                }
+                let right = self.expression(&binary.right)?;
+                self.operate(binary.op, left, right, span, &binary.to_string())
+            }
+            Expression::Unary(unary) => match (&unary.op, self.expression(&unary.inner)?) {
+                (UnaryOperation::Not, Value::Boolean(value)) => Ok(Value::Boolean(!value)),
+                (UnaryOperation::Negate, Value::Integer(integer)) => integer
+                    .negate()
+                    .map(Value::Integer)
+                    .ok_or_else(|| CompilerError::integer_overflow(unary, span).into()),
+                (UnaryOperation::Negate, Value::Implicit(value)) => {
+                    Ok(Value::Implicit(match value.strip_prefix('-') {
+                        Some(value) => value.to_string(),
+                        None => format!("-{}", value),
+                    }))
+                }
+                (UnaryOperation::Negate, Value::Field(field)) => Ok(Value::Field(-field)),
+                (UnaryOperation::BitNot, Value::Integer(integer)) => Ok(Value::Integer(integer.bit_not())),
+                (_, value) => Err(CompilerError::cannot_evaluate_expression(
+                    format!("{}{}", unary.op.as_ref(), value),
+                    span,
+                )
+                .into()),
+            },
+            Expression::Ternary(ternary) => {
+                if self.condition(&ternary.condition)? {
+                    self.expression(&ternary.if_true)
+                } else {
+                    self.expression(&ternary.if_false)
+                }
+            }
+            Expression::Cast(cast) => {
+                let value = self.expression(&cast.inner)?;
+                let integer = match (&value, &cast.target_type) {
+                    (Value::Integer(integer), Type::IntegerType(type_)) => integer.cast(*type_),
+                    (Value::Implicit(value), Type::IntegerType(type_)) => Integer::parse(*type_, value),
+                    (value, type_) => {
+                        return Err(CompilerError::cannot_interpret(
+                            format!("the cast of `{}` to `{}`", value, type_),
+                            span,
+                        )
+                        .into())
+                    }
+                };
+                integer
+                    .map(Value::Integer)
+                    .ok_or_else(|| CompilerError::integer_overflow(cast, span).into())
+            }
+            Expression::Access(access) => self.access_expression(access),
+            Expression::ArrayInline(array) => {
+                let mut elements = Vec::new();
+                for element in array.elements.iter() {
+                    match element {
+                        SpreadOrExpression::Expression(expression) => elements.push(self.expression(expression)?),
+                        SpreadOrExpression::Spread(expression) => match self.expression(expression)? {
+                            Value::Array(values) => elements.extend(values),
+                            value => return Err(AsgError::index_into_non_array(value, expression.span()).into()),
+                        },
+                    }
+                }
+                Ok(Value::Array(elements))
+            }
+            Expression::ArrayInit(array) => {
+                let mut value = self.expression(&array.element)?;
+                for dimension in array.dimensions.0.iter().rev() {
+                    let length = Self::position(&dimension.value, span)?;
+                    value = Value::Array(vec![value; length]);
+                }
+                Ok(value)
+            }
+            Expression::TupleInit(tuple) => {
+                let mut elements = Vec::with_capacity(tuple.elements.len());
+                for element in tuple.elements.iter() {
+                    elements.push(self.expression(element)?);
+                }
+                Ok(Value::Tuple(elements))
+            }
+            Expression::CircuitInit(init) => {
+                let circuit = self.circuit(&init.name)?;
+                let mut variables = IndexMap::new();
+                for member in circuit.members.iter() {
+                    if let CircuitMember::CircuitVariable(name, type_) = member {
+                        let initializer = init
+                            .members
+                            .iter()
+                            .find(|initializer| initializer.identifier.name == name.name)
+                            .ok_or_else(|| AsgError::missing_circuit_member(&init.name, name, span))?;
+                        let value = match &initializer.expression {
+                            Some(expression) => self.expression(expression)?,
+                            None => self.variable(&initializer.identifier)?,
+                        };
+                        variables.insert(name.name, Self::coerce(value, type_, span)?);
+                    }
+                }
+                if let Some(extra) = init
+                    .members
+                    .iter()
+                    .find(|initializer| !variables.contains_key(&initializer.identifier.name))
+                {
+                    return Err(AsgError::extra_circuit_member(&init.name, &extra.identifier, span).into());
+                }
+                Ok(Value::Circuit(init.name.name, variables))
+            }
+            Expression::Call(call) => self.call_expression(call),
+            Expression::Err(_) => Err(CompilerError::cannot_interpret("an erroneous expression", span).into()),
+        }
+    }
+
+    /// Returns the value of `access`.
+    fn access_expression(&mut self, access: &AccessExpression) -> Result<Value> {
+        let span = access.span();
+        match access {
+            AccessExpression::Array(array) => {
+                let value = self.expression(&array.array)?;
+                let index = self.index(&array.index)?;
+                Self::access(value, &Place::Index(index), span)
+            }
+            AccessExpression::ArrayRange(range) => {
+                let value = self.expression(&range.array)?;
+                let left = range.left.as_ref().map(|left| self.index(left)).transpose()?;
+                let right = range.right.as_ref().map(|right| self.index(right)).transpose()?;
+                Self::access(value, &Place::Range(left, right), span)
+            }
+            AccessExpression::Member(member) => {
+                let value = self.expression(&member.inner)?;
+                Self::access(value, &Place::Member(member.name.name), span)
+            }
+            AccessExpression::Tuple(tuple) => {
+                let value = self.expression(&tuple.tuple)?;
+                Self::access(value, &Place::Tuple(Self::position(&tuple.index.value, span)?), span)
+            }
+            AccessExpression::Static(access) => {
+                let circuit = match &*access.inner {
+                    Expression::Identifier(name) => self.circuit(name)?,
+                    inner => {
+                        return Err(CompilerError::cannot_interpret(format!("the constant `{}`", inner), span).into())
+                    }
+                };
+                let constant = circuit.members.iter().find_map(|member| match member {
+                    CircuitMember::CircuitConst(name, type_, value) if name.name == access.name.name => {
+                        Some((type_, value))
+                    }
+                    _ => None,
+                });
+                match constant {
+                    Some((type_, value)) => {
+                        let value = self.expression(value)?;
+                        Self::coerce(value, type_, span)
+                    }
+                    None => Err(AsgError::unresolved_circuit_member(&circuit.circuit_name, &access.name, span).into()),
+                }
+            }
+        }
+    }
+
+    /// Returns the value of `call`.
+    fn call_expression(&mut self, call: &CallExpression) -> Result<Value> {
+        let mut arguments = Vec::with_capacity(call.arguments.len());
+        for argument in call.arguments.iter() {
+            arguments.push(self.expression(argument)?);
+        }
+
+        match &*call.function {
+            Expression::Identifier(name) => {
+                let function = self
+                    .function(name.name)
+                    .ok_or_else(|| AsgError::unresolved_function(name, &name.span))?;
+                if function.annotations.contains_key(&sym::test) {
+                    return Err(AsgError::call_test_function(&call.span).into());
+                }
+                self.call(function, arguments, &call.span)
+            }
+            Expression::Access(AccessExpression::Static(access)) => match &*access.inner {
+                Expression::Identifier(name) => {
+                    let function = Self::circuit_function(self.circuit(name)?, &access.name)?;
+                    self.call(function, arguments, &call.span)
+                }
+                inner => Err(CompilerError::cannot_interpret(format!("a call to `{}`", inner), &call.span).into()),
+            },
+            Expression::Access(AccessExpression::Member(member)) => {
+                let receiver = self.expression(&member.inner)?;
+                let circuit = match &receiver {
+                    Value::Circuit(name, _) => self.circuit(&Identifier::new(*name))?,
+                    value => {
+                        let function = format!("{}.{}", value, member.name);
+                        return Err(CompilerError::expected_circuit_member(function, &call.span).into());
+                    }
+                };
+                let function = Self::circuit_function(circuit, &member.name)?;
+                let (output, receiver) = self.call_with_receiver(function, Some(receiver), arguments, &call.span)?;
+
+                // Functions taking `&self` change the variable they are called on.
+                if function.contains_mut_self() {
+                    if let (Some((identifier, accesses)), Some(receiver)) = (assignee(&member.inner), receiver) {
+                        let places = self.places(&accesses)?;
+                        self.write(&identifier, &places, receiver, &call.span)?;
+                    }
+                }
+                Ok(output)
+            }
+            function => Err(CompilerError::cannot_interpret(format!("a call to `{}`", function), &call.span).into()),
+        }
+    }
+
+    /// Returns the value of the literal `value`.
+    fn literal(value: &ValueExpression) -> Result<Value> {
+        Ok(match value {
+            ValueExpression::Address(address, _) => Value::Address(address.clone()),
+            ValueExpression::Boolean(boolean, span) => match boolean.as_str() {
+                "true" => Value::Boolean(true),
+                "false" => Value::Boolean(false),
+                _ => return Err(AsgError::invalid_boolean(boolean, span).into()),
+            },
+            ValueExpression::Char(character) => Value::Char(character.character.clone()),
+            ValueExpression::Field(field, span) => match Field::parse(field) {
+                Some(field) => Value::Field(field),
+                None => return Err(CompilerError::invalid_field(field, span).into()),
+            },
+            ValueExpression::Group(group) => Value::Group(group.to_string()),
+            ValueExpression::Implicit(value, _) => Value::Implicit(value.clone()),
+            ValueExpression::Integer(type_, integer, span) => match Integer::parse(*type_, integer) {
+                Some(integer) => Value::Integer(integer),
+                None => return Err(AsgError::invalid_int(value, span).into()),
+            },
+            ValueExpression::String(string, _) => {
+                Value::Array(string.iter().map(|character| Value::Char(character.clone())).collect())
+            }
+        })
+    }
+
+    /// Returns `left op right`, where `operation` is the source of the operation for errors.
+    fn operate(
+        &mut self,
+        op: BinaryOperation,
+        left: Value,
+        right: Value,
+        span: &Span,
+        operation: &str,
+    ) -> Result<Value> {
+        use BinaryOperation::*;
+
+        // Literals without suffix take the type of the other operand, except for exponents and shift amounts.
+        let (left, right) = match op {
+            Pow | Shl | Shr | ShrSigned => (left, right),
+            _ => {
+                let left = Self::coerce_like(left, &right, span)?;
+                let right = Self::coerce_like(right, &left, span)?;
+                (left, right)
+            }
+        };
+
+        let mismatch = || CompilerError::cannot_evaluate_expression(operation, span);
+        match op {
+            Eq => Ok(Value::Boolean(left == right)),
+            Ne => Ok(Value::Boolean(left != right)),
+            Lt | Le | Gt | Ge => {
+                let ordering = match (&left, &right) {
+                    (Value::Integer(left), Value::Integer(right)) => left.partial_cmp(right),
+                    (Value::Char(Char::Scalar(left)), Value::Char(Char::Scalar(right))) => left.partial_cmp(right),
+                    (Value::Field(left), Value::Field(right)) => left.partial_cmp(right),
+                    (Value::Implicit(left), Value::Implicit(right)) => {
+                        match (left.parse::<i128>(), right.parse::<i128>()) {
+                            (Ok(left), Ok(right)) => left.partial_cmp(&right),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+                .ok_or_else(mismatch)?;
+                Ok(Value::Boolean(match op {
+                    Lt => ordering == Ordering::Less,
+                    Le => ordering != Ordering::Greater,
+                    Gt => ordering == Ordering::Greater,
+                    _ => ordering != Ordering::Less,
+                }))
+            }
+            And | Or => match (left, right) {
+                (Value::Boolean(left), Value::Boolean(right)) => {
+                    Ok(Value::Boolean(if op == And { left && right } else { left || right }))
+                }
+                _ => Err(mismatch().into()),
+            },
+            _ if matches!(left, Value::Field(_)) => Self::operate_fields(op, left, right, span, operation),
+            _ => {
+                // Literals without suffix on both sides are computed as `i128`s.
+                let implicit = |value: &Value| match value {
+                    Value::Implicit(value) => Integer::parse(IntegerType::I128, value),
+                    _ => None,
+                };
+                let (left, right, implicit) = match (&left, &right) {
+                    (Value::Integer(left), Value::Integer(right)) => (*left, *right, false),
+                    (Value::Integer(left), Value::Implicit(_)) => {
+                        let right = Integer::parse(IntegerType::U32, &right.to_string())
+                            .ok_or_else(|| AsgError::invalid_int(&right, span))?;
+                        (*left, right, false)
+                    }
+                    (Value::Implicit(_), _) => match (implicit(&left), implicit(&right)) {
+                        (Some(left), Some(right)) => (left, right, true),
+                        _ => return Err(mismatch().into()),
+                    },
+                    (Value::Group(_), Value::Group(_)) => {
+                        let what = format!("`{}`, as group arithmetic is not supported", operation);
+                        return Err(CompilerError::cannot_interpret(what, span).into());
+                    }
+                    _ => return Err(mismatch().into()),
+                };
+                if matches!(op, Div | Mod) && right.is_zero() {
+                    return Err(CompilerError::division_by_zero(operation, span).into());
+                }
+                let result = left
+                    .binary(op, right)
+                    .ok_or_else(|| CompilerError::integer_overflow(operation, span))?;
+                Ok(match (implicit, result) {
+                    (true, Integer::Signed(_, value)) => Value::Implicit(value.to_string()),
+                    (_, result) => Value::Integer(result),
+                })
+            }
+        }
+    }
+
+    /// Returns `left op right` for a field element `left`, where `operation` is the source of the operation for errors.
+    fn operate_fields(op: BinaryOperation, left: Value, right: Value, span: &Span, operation: &str) -> Result<Value> {
+        use BinaryOperation::*;
+
+        let mismatch = || CompilerError::cannot_evaluate_expression(operation, span);
+        let left = match left {
+            Value::Field(left) => left,
+            _ => return Err(mismatch().into()),
+        };
+        // Exponents may be field elements or unsigned integers.
+        if op == Pow {
+            let exponent = match right {
+                Value::Field(right) => right.limbs().to_vec(),
+                Value::Integer(right) => {
+                    let right = right.to_u128().ok_or_else(mismatch)?;
+                    vec![right as u64, (right >> 64) as u64]
+                }
+                Value::Implicit(right) => {
+                    let right = right.parse::<u128>().map_err(|_| AsgError::invalid_int(&right, span))?;
+                    vec![right as u64, (right >> 64) as u64]
+                }
+                _ => return Err(mismatch().into()),
+            };
+            return Ok(Value::Field(left.pow(&exponent)));
+        }
+
+        let right = match right {
+            Value::Field(right) => right,
+            _ => return Err(mismatch().into()),
+        };
+        Ok(Value::Field(match op {
+            Add => left + right,
+            Sub => left - right,
+            Mul => left * right,
+            Div => left
+                .checked_div(right)
+                .ok_or_else(|| CompilerError::division_by_zero(operation, span))?,
+            _ => return Err(mismatch().into()),
+        }))
+    }
+
+    /// Returns `value` as a value of type `type_`, giving literals without suffix that type.
+    fn coerce(value: Value, type_: &Type, span: &Span) -> Result<Value> {
+        match (value, type_) {
+            (Value::Implicit(value), Type::IntegerType(type_)) => Integer::parse(*type_, &value)
+                .map(Value::Integer)
+                .ok_or_else(|| AsgError::invalid_int(format!("{}{}", value, type_), span).into()),
+            (Value::Implicit(value), Type::Field) => Field::parse(&value)
+                .map(Value::Field)
+                .ok_or_else(|| CompilerError::invalid_field(value, span).into()),
+            (Value::Implicit(value), Type::Group) => Ok(Value::Group(value)),
+            (Value::Integer(integer), Type::IntegerType(type_)) if integer.type_() != *type_ => {
+                Err(AsgError::unexpected_type(type_, integer, span).into())
+            }
+            (Value::Array(elements), Type::Array(element, _)) => Ok(Value::Array(
+                elements
+                    .into_iter()
+                    .map(|value| Self::coerce(value, element, span))
+                    .collect::<Result<_>>()?,
+            )),
+            (Value::Tuple(elements), Type::Tuple(types)) if elements.len() == types.len() => Ok(Value::Tuple(
+                elements
+                    .into_iter()
+                    .zip(types)
+                    .map(|(value, type_)| Self::coerce(value, type_, span))
+                    .collect::<Result<_>>()?,
+            )),
+            (value, _) => Ok(value),
+        }
+    }
+
+    /// Returns `value` with the literals without suffix in it given the types of the corresponding parts of `like`.
+    fn coerce_like(value: Value, like: &Value, span: &Span) -> Result<Value> {
+        match (value, like) {
+            (Value::Implicit(value), Value::Integer(integer)) => {
+                Self::coerce(Value::Implicit(value), &Type::IntegerType(integer.type_()), span)
+            }
+            (Value::Implicit(value), Value::Field(_)) => Self::coerce(Value::Implicit(value), &Type::Field, span),
+            (Value::Implicit(value), Value::Group(_)) => Ok(Value::Group(value)),
+            (Value::Array(elements), Value::Array(likes)) if !likes.is_empty() => Ok(Value::Array(
+                elements
+                    .into_iter()
+                    .map(|value| Self::coerce_like(value, &likes[0], span))
+                    .collect::<Result<_>>()?,
+            )),
+            (Value::Tuple(elements), Value::Tuple(likes)) if elements.len() == likes.len() => Ok(Value::Tuple(
+                elements
+                    .into_iter()
+                    .zip(likes)
+                    .map(|(value, like)| Self::coerce_like(value, like, span))
+                    .collect::<Result<_>>()?,
+            )),
+            (value, _) => Ok(value),
+        }
+    }
+}
+
+/// Returns the variable and accesses of `expression`, if it is a place which can be assigned to.
+fn assignee(expression: &Expression) -> Option<(Identifier, Vec<AssigneeAccess>)> {
+    match expression {
+        Expression::Identifier(identifier) => Some((identifier.clone(), Vec::new())),
+        Expression::Access(AccessExpression::Member(member)) => {
+            let (identifier, mut accesses) = assignee(&member.inner)?;
+            accesses.push(AssigneeAccess::Member(member.name.clone()));
+            Some((identifier, accesses))
+        }
+        Expression::Access(AccessExpression::Tuple(tuple)) => {
+            let (identifier, mut accesses) = assignee(&tuple.tuple)?;
+            accesses.push(AssigneeAccess::Tuple(tuple.index.clone(), tuple.span.clone()));
+            Some((identifier, accesses))
+        }
+        Expression::Access(AccessExpression::Array(array)) => {
+            let (identifier, mut accesses) = assignee(&array.array)?;
+            accesses.push(AssigneeAccess::ArrayIndex((*array.index).clone()));
+            Some((identifier, accesses))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_compiler::Compiler;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the value returned by `main` in `source`, or the code of the error it fails with.
+    fn run(source: &str) -> String {
+        create_session_if_not_set_then(|_| {
+            let ast = Compiler::check_source(&Handler::default(), "test.leo", source).unwrap();
+            let mut interpreter = Interpreter::new(ast.as_repr()).unwrap();
+            let main = interpreter.function(sym::main).unwrap();
+            match interpreter.call(main, Vec::new(), &main.span) {
+                Ok(value) => value.to_string(),
+                Err(error) => error.error_code(),
+            }
+        })
+    }
+
+    #[test]
+    fn interprets_programs() {
+        let source = "
+const SIZE = 3u32;
+
+circuit Counter {
+    count: u8
+
+    function new() -> Self {
+        return Self { count: 0 };
+    }
+
+    function increment(&self, by: u8) {
+        self.count += by;
+    }
+}
+
+function main() -> (u8, [u8; 3], Counter, bool) {
+    let total = 0u8;
+    let values = [0u8; 3];
+    for i in 0..SIZE {
+        values[i] = (i as u8) * 2;
+        total += values[i];
+    }
+    for i in 3..=1 {
+        total += 1;
+    }
+    let counter = Counter::new();
+    counter.increment(total);
+    counter.increment(1);
+    let (a, b) = (values[1..], 'a' < 'b');
+    return (total, values, counter, b && a[0] == 2);
+}
+";
+        assert_eq!(run(source), "(9u8, [0u8, 2u8, 4u8], Counter { count: 10u8 }, true)");
+    }
+
+    #[test]
+    fn fails_on_errors() {
+        assert_eq!(run("function main() -> u8 { return 200u8 + 100u8; }"), "ECMP0376034");
+        assert_eq!(
+            run("function main() -> i8 { let a = 0i8; return 1i8 / a; }"),
+            "ECMP0376035"
+        );
+        assert_eq!(
+            run("function main() -> u8 { let a = [1u8; 2]; return a[2]; }"),
+            "EASG0373019"
+        );
+        assert_eq!(run("function main() { console.assert(1u8 == 2u8); }"), "ECMP0376033");
+    }
+
+    #[test]
+    fn evaluates_negative_literals() {
+        assert_eq!(run("function main() -> i8 { return -128i8; }"), "-128i8");
+        assert_eq!(
+            run("function main() -> i128 { let a: i128 = -170141183460469231731687303715884105728; return a; }"),
+            "-170141183460469231731687303715884105728i128"
+        );
+        // A `-` before parentheses negates the literal inside them, which overflows here.
+        assert_eq!(run("function main() -> i8 { return -(-128i8); }"), "ECMP0376034");
+    }
+
+    #[test]
+    fn executes_statements() {
+        create_session_if_not_set_then(|_| {
+            let source = "function double(a: u8) -> u8 { return a * 2; }";
+            let ast = Compiler::check_source(&Handler::default(), "test.leo", source).unwrap();
+            let mut interpreter = Interpreter::new(ast.as_repr()).unwrap();
+            let mut execute = |line: &str| {
+                let statement = leo_parser::parse_repl_statement(&Handler::default(), "repl", line).unwrap();
+                interpreter
+                    .execute(&statement)
+                    .map(|value| value.map(|value| value.to_string()))
+                    .map_err(|error| error.error_code())
+            };
+            assert_eq!(execute("let x = 3u8"), Ok(None));
+            assert_eq!(execute("x += double(x)"), Ok(None));
+            assert_eq!(execute("x"), Ok(Some("9u8".to_string())));
+            assert_eq!(execute("double(200u8)"), Err("ECMP0376034".to_string()));
+            assert_eq!(execute("if x > 5u8 { return x; }"), Ok(Some("9u8".to_string())));
+        });
+    }
+}
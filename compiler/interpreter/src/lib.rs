@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The interpreter for Leo programs.
+//!
+//! The [`Interpreter`] type runs the functions of a checked program, [`run()`] runs its `main` function on inputs,
+//! [`run_test()`] runs its `@test` functions, and [`mutants()`] changes its operators and literals to find what
+//! its tests miss. A [`Debugger`] can follow the statements an interpreter executes.
+
+#![doc = include_str!("../README.md")]
+
+pub mod debugger;
+pub use debugger::*;
+
+pub mod field;
+pub use field::*;
+
+pub mod interpreter;
+pub use interpreter::*;
+
+pub mod mutation;
+pub use mutation::*;
+
+pub mod run;
+pub use run::*;
+
+pub mod testing;
+pub use testing::*;
+
+pub mod value;
+pub use value::*;
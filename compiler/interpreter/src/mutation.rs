@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{run_test, tests};
+use leo_ast::{
+    BinaryExpression, BinaryOperation, Expression, Function, LiteralExpression, Node, Program, ProgramReconstructor,
+    ValueExpression,
+};
+use leo_errors::{emitter::Handler, Result};
+use leo_span::{sym, Span, Symbol};
+
+/// A change of one operator or literal of a program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mutation {
+    /// The span of the changed operator's expression, or of the changed literal.
+    pub span: Span,
+    /// The operator or literal before the change.
+    pub original: String,
+    /// The operator or literal after the change.
+    pub replacement: String,
+}
+
+/// A program with one mutation.
+#[derive(Clone, Debug)]
+pub struct Mutant {
+    /// The mutation made.
+    pub mutation: Mutation,
+    /// The mutated program.
+    pub program: Program,
+}
+
+impl Mutant {
+    /// Returns the name of the first of the tests named in `names` which fails on the mutant,
+    /// i.e., which kills it, or `None` if the mutant survives them.
+    pub fn killed_by(&self, names: &[Symbol]) -> Option<Symbol> {
+        // The mutations leave annotations and inputs alone, so the tests are those of the original program.
+        let tests = tests(&Handler::default(), &self.program).unwrap_or_default();
+        tests
+            .iter()
+            .filter(|test| names.contains(&test.function.name()))
+            .find(|test| !run_test(&self.program, test).passed())
+            .map(|test| test.function.name())
+    }
+}
+
+/// Returns the mutants of `program`, one for each operator and literal outside its `@test` functions.
+///
+/// An arithmetic, comparison, or logical operator is swapped with its counterpart, e.g., `+` with `-` or `<` with `<=`.
+/// An integer or field literal `n` becomes `n - 1`, or `1` if it is `0`, and a boolean literal is negated.
+/// The mutants keep the types of the program, so they need not be checked again.
+pub fn mutants(program: &Program) -> impl Iterator<Item = Mutant> + '_ {
+    // `scan` ends at the first site without a mutant, as `map_while` would.
+    (0..).scan((), move |_, site| mutate(program, site))
+}
+
+/// Returns the mutant changing the `site`-th mutable operator or literal of `program`, if there is one.
+fn mutate(program: &Program, site: usize) -> Option<Mutant> {
+    let mut mutator = Mutator {
+        site,
+        seen: 0,
+        mutation: None,
+    };
+    let program = mutator.reconstruct_program(program.clone()).ok()?;
+    let mutation = mutator.mutation?;
+    Some(Mutant { mutation, program })
+}
+
+/// Returns the operator swapped with `op`, if it is mutated.
+fn swap(op: BinaryOperation) -> Option<BinaryOperation> {
+    use BinaryOperation::*;
+    Some(match op {
+        Add => Sub,
+        Sub => Add,
+        Mul => Div,
+        Div => Mul,
+        Lt => Le,
+        Le => Lt,
+        Gt => Ge,
+        Ge => Gt,
+        Eq => Ne,
+        Ne => Eq,
+        And => Or,
+        Or => And,
+        BitAnd => BitOr,
+        BitOr => BitAnd,
+        _ => return None,
+    })
+}
+
+/// Returns the literal replacing `value`, if it is mutated.
+fn replace(value: &ValueExpression) -> Option<ValueExpression> {
+    let step = |number: &str| {
+        let number = number.parse::<u128>().ok()?;
+        Some(if number == 0 { 1 } else { number - 1 }.to_string())
+    };
+    match value {
+        ValueExpression::Boolean(boolean, span) => {
+            Some(ValueExpression::Boolean((boolean != "true").to_string(), span.clone()))
+        }
+        ValueExpression::Field(field, span) => Some(ValueExpression::Field(step(field)?, span.clone())),
+        ValueExpression::Integer(type_, integer, span) => {
+            Some(ValueExpression::Integer(*type_, step(integer)?, span.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Mutates the `site`-th operator or literal it visits, counting in the order of the program's text.
+struct Mutator {
+    site: usize,
+    seen: usize,
+    mutation: Option<Mutation>,
+}
+
+impl Mutator {
+    /// Returns whether the next mutable operator or literal is the one to mutate.
+    fn is_site(&mut self) -> bool {
+        self.seen += 1;
+        self.seen - 1 == self.site
+    }
+}
+
+impl ProgramReconstructor for Mutator {
+    fn reconstruct_value(&mut self, value: LiteralExpression) -> Result<Expression> {
+        match replace(&value.value) {
+            Some(replacement) if self.is_site() => {
+                self.mutation = Some(Mutation {
+                    span: value.span().clone(),
+                    original: value.to_string(),
+                    replacement: replacement.to_string(),
+                });
+                Ok(Expression::Value(LiteralExpression {
+                    value: replacement,
+                    id: value.id,
+                }))
+            }
+            _ => Ok(Expression::Value(value)),
+        }
+    }
+
+    fn reconstruct_binary(&mut self, binary: BinaryExpression) -> Result<Expression> {
+        let left = Box::new(self.reconstruct_expression(*binary.left)?);
+        let op = match swap(binary.op) {
+            Some(op) if self.is_site() => {
+                self.mutation = Some(Mutation {
+                    span: binary.span.clone(),
+                    original: binary.op.as_ref().to_string(),
+                    replacement: op.as_ref().to_string(),
+                });
+                op
+            }
+            _ => binary.op,
+        };
+        let right = Box::new(self.reconstruct_expression(*binary.right)?);
+        Ok(Expression::Binary(BinaryExpression {
+            left,
+            right,
+            op,
+            ..binary
+        }))
+    }
+
+    fn reconstruct_function(&mut self, function: Function) -> Result<Function> {
+        // Tests are what judges the mutants, so they are left alone.
+        if function.annotations.contains_key(&sym::test) {
+            return Ok(function);
+        }
+        // Only the body has operators or literals to mutate.
+        Ok(Function {
+            block: self.reconstruct_block(function.block)?,
+            ..function
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_compiler::Compiler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn reports_surviving_mutants() {
+        create_session_if_not_set_then(|_| {
+            let source = "
+function clamp(a: u8, max: u8) -> u8 {
+    return a > max ? max : a + 0u8;
+}
+
+@test
+function clamps() {
+    console.assert(clamp(9u8, 5u8) == 5u8);
+    console.assert(clamp(2u8, 5u8) == 2u8);
+}
+";
+            let handler = Handler::default();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+            let program = ast.as_repr();
+            let names = vec![Symbol::intern("clamps")];
+
+            let mutants = mutants(program).collect::<Vec<_>>();
+            let mutations = mutants
+                .iter()
+                .map(|mutant| (mutant.mutation.original.as_str(), mutant.mutation.replacement.as_str()))
+                .collect::<Vec<_>>();
+            assert_eq!(mutations, vec![(">", ">="), ("+", "-"), ("0u8", "1u8")]);
+            assert_eq!(mutants[0].mutation.span.line_start, 3);
+
+            let survivors = mutants
+                .iter()
+                .filter(|mutant| mutant.killed_by(&names).is_none())
+                .map(|mutant| mutant.mutation.original.as_str())
+                .collect::<Vec<_>>();
+            // No test clamps a value equal to its maximum, nor tells `a + 0u8` from `a - 0u8`.
+            assert_eq!(survivors, vec![">", "+"]);
+        });
+    }
+}
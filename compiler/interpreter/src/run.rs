@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Field, Integer, Interpreter, Value};
+use leo_ast::{FunctionInput, InputValue, Program, ProgramInput};
+use leo_errors::{AsgError, CompilerError, Result};
+use leo_span::{sym, Span};
+
+/// The result of running the `main` function of a program.
+#[derive(Debug)]
+pub struct Execution {
+    /// The value returned by `main`.
+    pub value: Value,
+    /// The messages logged while running.
+    pub output: Vec<String>,
+}
+
+/// Runs the `main` function of `program` on `input`.
+///
+/// Constant inputs of `main` are read from the `[constants]` section of `input`, and the others from `[main]`.
+/// The `constructor` of the program, if any, runs first, as when the program is deployed.
+pub fn run(program: &Program, input: &ProgramInput) -> Result<Execution> {
+    run_in(Interpreter::new(program)?, input)
+}
+
+/// Runs the `main` function of the program of `interpreter` on `input`, as [`run()`] does,
+/// e.g., with a debugger following it.
+pub fn run_in(mut interpreter: Interpreter, input: &ProgramInput) -> Result<Execution> {
+    if let Some(constructor) = interpreter.function(sym::constructor) {
+        interpreter.call(constructor, Vec::new(), &constructor.span)?;
+    }
+    let main = interpreter
+        .function(sym::main)
+        .ok_or_else(CompilerError::no_main_function)?;
+
+    let mut arguments = Vec::new();
+    for parameter in main.filter_self_inputs() {
+        if let FunctionInput::Variable(variable) = parameter {
+            let section = if variable.is_constant() {
+                &input.constants
+            } else {
+                &input.main
+            };
+            let value = section.get(&variable.identifier.name).ok_or_else(|| {
+                CompilerError::function_input_not_found(sym::main, &variable.identifier, &variable.span)
+            })?;
+            arguments.push(input_value(value, &variable.span)?);
+        }
+    }
+
+    let value = interpreter.call(main, arguments, &main.span)?;
+    Ok(Execution {
+        value,
+        output: interpreter.output,
+    })
+}
+
+/// Returns the value of the input `value`, where `span` is the span of the input of `main` it is passed as.
+fn input_value(value: &InputValue, span: &Span) -> Result<Value> {
+    Ok(match value {
+        InputValue::Address(address) => Value::Address(address.clone()),
+        InputValue::Boolean(boolean) => Value::Boolean(*boolean),
+        InputValue::Char(character) => Value::Char(character.character.clone()),
+        InputValue::Field(field) => {
+            Value::Field(Field::parse(field).ok_or_else(|| CompilerError::invalid_field(field, span))?)
+        }
+        InputValue::Group(group) => Value::Group(group.to_string()),
+        InputValue::Integer(type_, integer) => Value::Integer(
+            Integer::parse(*type_, integer)
+                .ok_or_else(|| AsgError::invalid_int(format!("{}{}", integer, type_), span))?,
+        ),
+        InputValue::Array(elements) => Value::Array(
+            elements
+                .iter()
+                .map(|element| input_value(element, span))
+                .collect::<Result<_>>()?,
+        ),
+        InputValue::Tuple(elements) => Value::Tuple(
+            elements
+                .iter()
+                .map(|element| input_value(element, span))
+                .collect::<Result<_>>()?,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_ast::ProgramHeader;
+    use leo_compiler::Compiler;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn runs_main_on_inputs() {
+        create_session_if_not_set_then(|_| {
+            let source = "
+function main(a: field, b: [u8; 2], const c: bool) -> (field, u8) {
+    console.log(\"{}\", c);
+    return (a * a - 1field / 2field, b[0] + b[1]);
+}
+";
+            let handler = Handler::default();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+            let program = ast.as_repr();
+
+            let input = "
+[main]
+a: field = 3;
+b: [u8; 2] = [1, 2];
+
+[constants]
+c: bool = true;
+";
+            let input = leo_parser::parse_program_inputs(&handler, input, "test.in").unwrap();
+            let execution = run(program, &input.program_input).unwrap();
+            assert_eq!(
+                execution.value.to_string(),
+                "(4222230874714185212124412469390773265687949667577031913967616727958704619529field, 3u8)"
+            );
+            assert_eq!(execution.output, vec!["true".to_string()]);
+
+            let mut input = input.program_input;
+            input.constants.clear();
+            assert_eq!(run(program, &input).unwrap_err().error_code(), "ECMP0376016");
+        });
+    }
+
+    #[test]
+    fn runs_constructor_before_main() {
+        create_session_if_not_set_then(|_| {
+            let source = "
+@program(version = 2)
+
+function constructor() {
+    console.log(\"deployed\");
+}
+
+function main() {
+    console.log(\"called\");
+}
+";
+            let handler = Handler::default();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+            let program = ast.as_repr();
+            assert_eq!(
+                program.header,
+                Some(ProgramHeader {
+                    version: 2,
+                    constructor: true
+                })
+            );
+
+            let execution = run(program, &ProgramInput::default()).unwrap();
+            assert_eq!(execution.output, vec!["deployed".to_string(), "called".to_string()]);
+        });
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Interpreter;
+use leo_ast::{CircuitMember, Function, Program};
+use leo_errors::{emitter::Handler, AsgError, CompilerError, LeoError, Result};
+use leo_span::sym;
+
+/// A function annotated with `@test`.
+#[derive(Debug)]
+pub struct Test<'a> {
+    /// The test function.
+    pub function: &'a Function,
+    /// Whether the function is annotated with `@should_fail`, so the test passes if the function fails.
+    pub should_fail: bool,
+}
+
+/// The result of running a test.
+#[derive(Debug)]
+pub struct TestOutcome {
+    /// The messages logged by the test.
+    pub output: Vec<String>,
+    /// The reason the test failed, or `None` if it passed.
+    pub error: Option<LeoError>,
+}
+
+impl TestOutcome {
+    /// Returns `true` if the test passed.
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Returns the tests of `program`.
+///
+/// Test functions in circuits, test functions with inputs, and `@should_fail` on functions which are not tests
+/// are errors. All errors but the last are emitted to `handler`, and the last is returned.
+pub fn tests<'a>(handler: &Handler, program: &'a Program) -> Result<Vec<Test<'a>>> {
+    let mut errors: Vec<LeoError> = Vec::new();
+    for circuit in program.circuits.values() {
+        for member in circuit.members.iter() {
+            if let CircuitMember::CircuitFunction(function) = member {
+                if let Some(annotation) = function.annotations.get(&sym::test) {
+                    errors.push(AsgError::circuit_test_function(&annotation.span).into());
+                }
+            }
+        }
+    }
+
+    let mut tests = Vec::new();
+    for function in program.functions.values() {
+        let should_fail = function.annotations.get(&sym::should_fail);
+        if !function.annotations.contains_key(&sym::test) {
+            if let Some(annotation) = should_fail {
+                errors.push(CompilerError::should_fail_without_test(&function.identifier, &annotation.span).into());
+            }
+        } else if !function.input.is_empty() {
+            errors.push(CompilerError::test_function_with_inputs(&function.identifier, &function.span).into());
+        } else {
+            tests.push(Test {
+                function,
+                should_fail: should_fail.is_some(),
+            });
+        }
+    }
+
+    match errors.pop() {
+        Some(last) => {
+            for error in errors {
+                handler.emit_err(error);
+            }
+            Err(last)
+        }
+        None => Ok(tests),
+    }
+}
+
+/// Runs `test`, a test of `program`.
+pub fn run_test(program: &Program, test: &Test) -> TestOutcome {
+    let mut output = Vec::new();
+    let result = Interpreter::new(program).and_then(|mut interpreter| {
+        let result = interpreter.call(test.function, Vec::new(), &test.function.span);
+        output = std::mem::take(&mut interpreter.output);
+        result
+    });
+
+    let error = match (result, test.should_fail) {
+        (Ok(_), false) | (Err(_), true) => None,
+        (Ok(_), true) => {
+            let function = &test.function.identifier;
+            Some(CompilerError::test_did_not_fail(function, &function.span).into())
+        }
+        (Err(error), false) => Some(error),
+    };
+    TestOutcome { output, error }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_compiler::Compiler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn runs_tests() {
+        create_session_if_not_set_then(|_| {
+            let source = "
+function add(a: u8, b: u8) -> u8 {
+    return a + b;
+}
+
+@test
+function adds() {
+    console.log(\"{} + {}\", 1u8, 2u8);
+    console.assert(add(1u8, 2u8) == 3u8);
+}
+
+@test
+function asserts() {
+    console.assert(add(1u8, 2u8) == 4u8);
+}
+
+@test
+@should_fail
+function overflows() {
+    add(255u8, 1u8);
+}
+
+@test
+@should_fail
+function does_not_overflow() {
+    add(254u8, 1u8);
+}
+
+@test
+function has_input(a: u8) {}
+
+@should_fail
+function not_a_test() {}
+";
+            let (handler, buf) = Handler::new_with_buf();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+            let program = ast.as_repr();
+            let error = tests(&handler, program).unwrap_err();
+            let errors = buf.extract().into_inner();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].error_code(), "ECMP0376037");
+            assert_eq!(error.error_code(), "ECMP0376038");
+
+            let source = source.split("@test\nfunction has_input").next().unwrap();
+            let ast = Compiler::check_source(&handler, "test.leo", source).unwrap();
+            let program = ast.as_repr();
+            let tests = tests(&handler, program).unwrap();
+
+            let outcomes = tests
+                .iter()
+                .map(|test| (test.function.name().to_string(), run_test(program, test)))
+                .collect::<Vec<_>>();
+            let results = outcomes
+                .iter()
+                .map(|(name, outcome)| (name.as_str(), outcome.passed()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                results,
+                vec![
+                    ("adds", true),
+                    ("asserts", false),
+                    ("overflows", true),
+                    ("does_not_overflow", false)
+                ]
+            );
+            assert_eq!(outcomes[0].1.output, vec!["1u8 + 2u8".to_string()]);
+
+            let error = outcomes[1].1.error.as_ref().unwrap();
+            assert_eq!(error.error_code(), "ECMP0376033");
+            assert_eq!(error.span().unwrap().line_start, 14);
+        });
+    }
+}
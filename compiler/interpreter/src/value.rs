@@ -0,0 +1,419 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Field;
+use leo_ast::{BinaryOperation, Char, IntegerType};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use std::{cmp::Ordering, convert::TryFrom, fmt};
+
+/// A value of one of the integer types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Integer {
+    /// A value of a signed integer type.
+    Signed(IntegerType, i128),
+    /// A value of an unsigned integer type.
+    Unsigned(IntegerType, u128),
+}
+
+impl Integer {
+    /// Returns `value` as an integer of type `type_`, or `None` if it is out of the range of the type.
+    pub fn from_i128(type_: IntegerType, value: i128) -> Option<Self> {
+        if type_.is_signed() {
+            let shift = 128 - type_.bits();
+            ((i128::MIN >> shift) <= value && value <= (i128::MAX >> shift)).then(|| Self::Signed(type_, value))
+        } else {
+            Self::from_u128(type_, u128::try_from(value).ok()?)
+        }
+    }
+
+    /// Returns `value` as an integer of type `type_`, or `None` if it is out of the range of the type.
+    pub fn from_u128(type_: IntegerType, value: u128) -> Option<Self> {
+        if type_.is_signed() {
+            Self::from_i128(type_, i128::try_from(value).ok()?)
+        } else {
+            (value <= u128::MAX >> (128 - type_.bits())).then(|| Self::Unsigned(type_, value))
+        }
+    }
+
+    /// Parses the decimal `value`, possibly negative, as an integer of type `type_`.
+    pub fn parse(type_: IntegerType, value: &str) -> Option<Self> {
+        match value.strip_prefix('-') {
+            Some(_) => Self::from_i128(type_, value.parse().ok()?),
+            None => Self::from_u128(type_, value.parse().ok()?),
+        }
+    }
+
+    /// Returns the type of the integer.
+    pub fn type_(self) -> IntegerType {
+        match self {
+            Self::Signed(type_, _) | Self::Unsigned(type_, _) => type_,
+        }
+    }
+
+    /// Returns the integer as a `u128`, if it is not negative.
+    pub fn to_u128(self) -> Option<u128> {
+        match self {
+            Self::Signed(_, value) => u128::try_from(value).ok(),
+            Self::Unsigned(_, value) => Some(value),
+        }
+    }
+
+    /// Returns `true` if the integer is zero.
+    pub fn is_zero(self) -> bool {
+        self.to_u128() == Some(0)
+    }
+
+    /// Returns the integer converted to type `type_`, or `None` if it is out of the range of that type.
+    pub fn cast(self, type_: IntegerType) -> Option<Self> {
+        match self {
+            Self::Signed(_, value) => Self::from_i128(type_, value),
+            Self::Unsigned(_, value) => Self::from_u128(type_, value),
+        }
+    }
+
+    /// Returns the bits of the integer in two's complement, in the low bits of a `u128`.
+    fn bits(self) -> u128 {
+        match self {
+            Self::Signed(type_, value) => value as u128 & (u128::MAX >> (128 - type_.bits())),
+            Self::Unsigned(_, value) => value,
+        }
+    }
+
+    /// Returns the integer of type `type_` with the two's complement `bits`, which are truncated to its width.
+    fn from_bits(type_: IntegerType, bits: u128) -> Self {
+        let shift = 128 - type_.bits();
+        if type_.is_signed() {
+            // Shifting the sign bit of the type into the sign bit of `i128` and back extends the sign.
+            Self::Signed(type_, ((bits << shift) as i128) >> shift)
+        } else {
+            Self::Unsigned(type_, (bits << shift) >> shift)
+        }
+    }
+
+    /// Returns `self op other` for an arithmetic, bitwise, or shift operation `op`,
+    /// or `None` if the result overflows or `other` is zero for a division or remainder.
+    ///
+    /// Except for the exponent of `**` and the amount of a shift, which may be of any integer type,
+    /// both operands must be of the same type.
+    pub fn binary(self, op: BinaryOperation, other: Self) -> Option<Self> {
+        use BinaryOperation::*;
+
+        let type_ = self.type_();
+        match op {
+            Pow | Shl | Shr | ShrSigned => {
+                let amount = u32::try_from(other.to_u128()?).ok()?;
+                match (op, self) {
+                    (Pow, Self::Signed(_, value)) => Self::from_i128(type_, value.checked_pow(amount)?),
+                    (Pow, Self::Unsigned(_, value)) => Self::from_u128(type_, value.checked_pow(amount)?),
+                    // Shifts by the width of the type or more are errors, as in Rust.
+                    _ if amount >= type_.bits() => None,
+                    (Shl, _) => Some(Self::from_bits(type_, self.bits() << amount)),
+                    (Shr, Self::Signed(_, value)) => Some(Self::Signed(type_, value >> amount)),
+                    (Shr, Self::Unsigned(_, value)) => Some(Self::Unsigned(type_, value >> amount)),
+                    _ => Some(Self::from_bits(type_, self.bits() >> amount)),
+                }
+            }
+            _ => match (self, other) {
+                (Self::Signed(_, left), Self::Signed(right_type, right)) if right_type == type_ => {
+                    let result = match op {
+                        Add => left.checked_add(right)?,
+                        Sub => left.checked_sub(right)?,
+                        Mul => left.checked_mul(right)?,
+                        Div => left.checked_div(right)?,
+                        Mod => left.checked_rem(right)?,
+                        BitOr => left | right,
+                        BitAnd => left & right,
+                        BitXor => left ^ right,
+                        _ => return None,
+                    };
+                    Self::from_i128(type_, result)
+                }
+                (Self::Unsigned(_, left), Self::Unsigned(right_type, right)) if right_type == type_ => {
+                    let result = match op {
+                        Add => left.checked_add(right)?,
+                        Sub => left.checked_sub(right)?,
+                        Mul => left.checked_mul(right)?,
+                        Div => left.checked_div(right)?,
+                        Mod => left.checked_rem(right)?,
+                        BitOr => left | right,
+                        BitAnd => left & right,
+                        BitXor => left ^ right,
+                        _ => return None,
+                    };
+                    Self::from_u128(type_, result)
+                }
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns `-self`, or `None` if it overflows or the integer is unsigned.
+    pub fn negate(self) -> Option<Self> {
+        match self {
+            Self::Signed(type_, value) => Self::from_i128(type_, value.checked_neg()?),
+            Self::Unsigned(..) => None,
+        }
+    }
+
+    /// Returns `!self`, the bitwise complement.
+    pub fn bit_not(self) -> Self {
+        Self::from_bits(self.type_(), !self.bits())
+    }
+}
+
+impl PartialOrd for Integer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Signed(left_type, left), Self::Signed(right_type, right)) if left_type == right_type => {
+                left.partial_cmp(right)
+            }
+            (Self::Unsigned(left_type, left), Self::Unsigned(right_type, right)) if left_type == right_type => {
+                left.partial_cmp(right)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Signed(type_, value) => write!(f, "{}{}", value, type_),
+            Self::Unsigned(type_, value) => write!(f, "{}{}", value, type_),
+        }
+    }
+}
+
+/// A value computed by the interpreter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// An address, e.g., `aleo1...`.
+    Address(String),
+    /// A boolean.
+    Boolean(bool),
+    /// A character.
+    Char(Char),
+    /// A field element.
+    Field(Field),
+    /// A group element, kept as the literal it was written as.
+    Group(String),
+    /// An integer of one of the integer types.
+    Integer(Integer),
+    /// An integer literal without a type suffix, whose type is given by where it is used.
+    Implicit(String),
+    /// An array.
+    Array(Vec<Value>),
+    /// A tuple, or the empty tuple returned by functions without output.
+    Tuple(Vec<Value>),
+    /// A circuit with its variables.
+    Circuit(Symbol, IndexMap<Symbol, Value>),
+}
+
+impl Value {
+    /// Returns the name of the type of the value, as it is written in Leo, e.g., `[u8; 3]`.
+    /// Integer literals without a type suffix have the type `integer`.
+    pub fn type_name(&self) -> String {
+        match self {
+            Self::Address(_) => "address".to_string(),
+            Self::Boolean(_) => "bool".to_string(),
+            Self::Char(_) => "char".to_string(),
+            Self::Field(_) => "field".to_string(),
+            Self::Group(_) => "group".to_string(),
+            Self::Integer(integer) => integer.type_().to_string(),
+            Self::Implicit(_) => "integer".to_string(),
+            Self::Array(elements) => {
+                let element = elements.first().map(Self::type_name).unwrap_or_else(|| "_".to_string());
+                format!("[{}; {}]", element, elements.len())
+            }
+            Self::Tuple(elements) => {
+                let elements = elements.iter().map(Self::type_name).collect::<Vec<_>>();
+                format!("({})", elements.join(", "))
+            }
+            Self::Circuit(name, _) => name.to_string(),
+        }
+    }
+
+    /// Returns the value as it is written in Leo, with each variable of a circuit on its own line, e.g.,
+    /// for the output of a program.
+    pub fn to_pretty_string(&self) -> String {
+        self.pretty(0)
+    }
+
+    /// Returns the value as [`Value::to_pretty_string`] does, for a value nested `indent` circuits deep.
+    fn pretty(&self, indent: usize) -> String {
+        let list = |values: &[Value]| {
+            values
+                .iter()
+                .map(|value| value.pretty(indent))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Self::Array(elements) => format!("[{}]", list(elements)),
+            Self::Tuple(elements) => format!("({})", list(elements)),
+            Self::Circuit(name, variables) if !variables.is_empty() => {
+                let mut pretty = format!("{} {{\n", name);
+                for (variable, value) in variables.iter() {
+                    pretty += &format!(
+                        "{}{}: {},\n",
+                        "    ".repeat(indent + 1),
+                        variable,
+                        value.pretty(indent + 1)
+                    );
+                }
+                pretty + &"    ".repeat(indent) + "}"
+            }
+            value => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        /// Writes `values` separated by commas.
+        fn list(f: &mut fmt::Formatter, values: &[Value]) -> fmt::Result {
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                value.fmt(f)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Self::Address(address) => write!(f, "{}", address),
+            Self::Boolean(boolean) => write!(f, "{}", boolean),
+            Self::Char(character) => write!(f, "'{}'", character),
+            Self::Field(field) => write!(f, "{}field", field),
+            Self::Group(group) => write!(f, "{}group", group),
+            Self::Integer(integer) => integer.fmt(f),
+            Self::Implicit(value) => write!(f, "{}", value),
+            Self::Array(elements) => {
+                write!(f, "[")?;
+                list(f, elements)?;
+                write!(f, "]")
+            }
+            Self::Tuple(elements) => {
+                write!(f, "(")?;
+                list(f, elements)?;
+                write!(f, ")")
+            }
+            Self::Circuit(name, variables) => {
+                write!(f, "{} {{", name)?;
+                for (index, (variable, value)) in variables.iter().enumerate() {
+                    write!(f, "{} {}: {}", if index > 0 { "," } else { "" }, variable, value)?;
+                }
+                write!(f, " }}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+    use BinaryOperation::*;
+
+    fn int(value: &str, type_: IntegerType) -> Integer {
+        Integer::parse(type_, value).unwrap()
+    }
+
+    #[test]
+    fn checks_integer_ranges() {
+        assert_eq!(
+            Integer::parse(IntegerType::U8, "255"),
+            Some(Integer::Unsigned(IntegerType::U8, 255))
+        );
+        assert_eq!(Integer::parse(IntegerType::U8, "256"), None);
+        assert_eq!(
+            Integer::parse(IntegerType::I8, "-128"),
+            Some(Integer::Signed(IntegerType::I8, -128))
+        );
+        assert_eq!(Integer::parse(IntegerType::I8, "128"), None);
+        assert_eq!(
+            Integer::parse(IntegerType::U128, &u128::MAX.to_string())
+                .unwrap()
+                .to_u128(),
+            Some(u128::MAX)
+        );
+        assert_eq!(int("-1", IntegerType::I16).cast(IntegerType::U16), None);
+        assert_eq!(
+            int("300", IntegerType::U16).cast(IntegerType::I32),
+            Some(int("300", IntegerType::I32))
+        );
+    }
+
+    #[test]
+    fn computes_operations() {
+        let u8 = |value| int(value, IntegerType::U8);
+        let i8 = |value| int(value, IntegerType::I8);
+        assert_eq!(u8("200").binary(Add, u8("55")), Some(u8("255")));
+        assert_eq!(u8("200").binary(Add, u8("56")), None);
+        assert_eq!(u8("2").binary(Sub, u8("3")), None);
+        assert_eq!(u8("7").binary(Div, u8("0")), None);
+        assert_eq!(i8("-7").binary(Div, i8("2")), Some(i8("-3")));
+        assert_eq!(i8("-128").binary(Div, i8("-1")), None);
+        assert_eq!(u8("2").binary(Pow, int("7", IntegerType::U32)), Some(u8("128")));
+        assert_eq!(u8("2").binary(Pow, int("8", IntegerType::U32)), None);
+        assert_eq!(u8("129").binary(Shl, u8("1")), Some(u8("2")));
+        assert_eq!(u8("1").binary(Shl, u8("8")), None);
+        assert_eq!(i8("-8").binary(Shr, u8("1")), Some(i8("-4")));
+        assert_eq!(i8("-8").binary(ShrSigned, u8("1")), Some(i8("124")));
+        assert_eq!(i8("-128").negate(), None);
+        assert_eq!(i8("5").bit_not(), i8("-6"));
+        assert_eq!(u8("5").bit_not(), u8("250"));
+        assert_eq!(u8("1").binary(Add, i8("1")), None);
+    }
+
+    #[test]
+    fn names_types() {
+        let array = Value::Array(vec![Value::Integer(int("1", IntegerType::U8)); 3]);
+        let tuple = Value::Tuple(vec![array, Value::Boolean(true), Value::Implicit("2".to_string())]);
+        assert_eq!(tuple.type_name(), "([u8; 3], bool, integer)");
+        assert_eq!(Value::Array(Vec::new()).type_name(), "[_; 0]");
+        assert_eq!(Value::Tuple(Vec::new()).type_name(), "()");
+    }
+
+    #[test]
+    fn pretty_prints_circuits() {
+        create_session_if_not_set_then(|_| {
+            let amount = Value::Integer(int("5", IntegerType::U64));
+            let owner = Value::Circuit(
+                Symbol::intern("Owner"),
+                [(Symbol::intern("id"), Value::Boolean(true))].into_iter().collect(),
+            );
+            let token = Value::Circuit(
+                Symbol::intern("Token"),
+                [(Symbol::intern("owner"), owner), (Symbol::intern("amount"), amount)]
+                    .into_iter()
+                    .collect(),
+            );
+            let output = Value::Tuple(vec![token, Value::Field(Field::parse("1").unwrap())]);
+            assert_eq!(
+                output.to_pretty_string(),
+                "(Token {\n    owner: Owner {\n        id: true,\n    },\n    amount: 5u64,\n}, 1field)"
+            );
+            assert_eq!(
+                Value::Circuit(Symbol::intern("Empty"), IndexMap::new()).to_pretty_string(),
+                "Empty { }"
+            );
+        });
+    }
+}
@@ -30,11 +30,11 @@ fn parse_ast(path: &str, input: &str) -> Ast {
 macro_rules! bench {
     ($func_name:ident, $file_name:expr) => {
         fn $func_name(c: &mut Criterion) {
-            let ast = parse_ast(
-                concat!("./", $file_name, ".leo"),
-                include_str!(concat!("./", $file_name, ".leo"),),
-            );
-            c.bench_function(concat!("Ast::", $file_name), |b| b.iter(|| &ast));
+            let path = concat!("./", $file_name, ".leo");
+            let source = include_str!(concat!("./", $file_name, ".leo"));
+            c.bench_function(concat!("Ast::", $file_name), |b| {
+                b.iter(|| parse_ast(path, source))
+            });
         }
     };
 }
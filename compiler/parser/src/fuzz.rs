@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Entry points for fuzzing the tokenizer and the parser, used by the targets in `fuzz/`.
+//!
+//! Each function accepts arbitrary bytes and must return without panicking.
+//! Invalid input is reported through diagnostics, which are discarded.
+
+use leo_errors::emitter::Handler;
+use leo_span::symbol::create_session_if_not_set_then;
+
+/// The path the fuzzed source code is attributed to.
+const FUZZ_PATH: &str = "fuzz.leo";
+
+/// Tokenizes `data`, if it is valid UTF-8.
+pub fn tokenize_fuzz(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        create_session_if_not_set_then(|_| {
+            let _ = crate::tokenize(FUZZ_PATH, source);
+        });
+    }
+}
+
+/// Parses `data` as a program, if it is valid UTF-8.
+pub fn parse_program_fuzz(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let _ = crate::parse(&handler, FUZZ_PATH, source);
+        });
+    }
+}
+
+/// Parses `data` as an input file, if it is valid UTF-8.
+pub fn parse_input_fuzz(data: &[u8]) {
+    if let Ok(source) = std::str::from_utf8(data) {
+        create_session_if_not_set_then(|_| {
+            let (handler, _) = Handler::new_with_buf();
+            let _ = crate::parse_input(&handler, FUZZ_PATH, source);
+        });
+    }
+}
@@ -23,12 +23,15 @@
 #![doc = include_str!("../README.md")]
 
 pub(crate) mod tokenizer;
-pub use tokenizer::KEYWORD_TOKENS;
-pub(crate) use tokenizer::*;
+pub use tokenizer::{
+    lexer::SpannedToken, token::Token, tokenize, tokenize_lossless, tokenize_with_source, KEYWORD_TOKENS,
+};
 
 pub mod parser;
 pub use parser::*;
 
+pub mod fuzz;
+
 use leo_ast::{Ast, Input, ProgramInput, ProgramState};
 use leo_errors::emitter::Handler;
 use leo_errors::Result;
@@ -36,6 +39,9 @@ use leo_errors::Result;
 #[cfg(test)]
 mod test;
 
+#[cfg(test)]
+mod round_trip;
+
 /// Creates a new AST from a given file path and source code text.
 pub fn parse_ast<T: AsRef<str>, Y: AsRef<str>>(handler: &Handler, path: T, source: Y) -> Result<Ast> {
     Ok(Ast::new(parser::parse(handler, path.as_ref(), source.as_ref())?))
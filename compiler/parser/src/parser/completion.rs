@@ -0,0 +1,448 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! Finds what may be written at a position of an incomplete program, for editors to complete it.
+
+use super::*;
+
+/// What may be written at a position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompletionContext {
+    /// A top-level item, such as a function or a circuit.
+    Item,
+    /// A member of the circuit being defined.
+    CircuitMember,
+    /// A statement, or an expression starting one.
+    Statement,
+    /// An expression.
+    Expression,
+    /// A type, as after `let x:` or `->`.
+    Type,
+    /// A member or circuit function of the value of the expression, as after `p.`.
+    Member(Expression),
+    /// A constant or function of the circuit or primitive type, as after `Point::` or `u8::`.
+    StaticMember(Identifier),
+    /// A variable of the circuit being initialized, as after `Point { x: 1, `.
+    CircuitInitMember(Identifier),
+    /// A core function of `console`, as after `console.`.
+    ConsoleFunction,
+    /// Nothing to complete, as in a comment, or where a new name is defined.
+    None,
+}
+
+/// What may be written at a position, and the start of the name written there so far.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Completion {
+    /// What may be written.
+    pub context: CompletionContext,
+    /// The start of the name written so far, e.g., `le` of `let`. Empty if none is.
+    pub prefix: String,
+}
+
+/// A `{` opening a block, a circuit definition, or a circuit initialization.
+enum Curly {
+    Block,
+    Circuit,
+    CircuitInit(Identifier),
+}
+
+/// Returns what may be written at the 1-based line and byte column `cursor` of `source`,
+/// which may be an incomplete program, given only the code before `cursor`.
+/// Must be called within a symbol session.
+pub fn completion_context(path: &str, source: &str, cursor: (usize, usize)) -> Completion {
+    let (line, column) = cursor;
+    let offset = source
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column.saturating_sub(1);
+    let mut end = offset.min(source.len());
+    while !source.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    // The code before the cursor does not lex inside a string or a block comment.
+    let mut tokens = match crate::tokenize_with_source(path, &source[..end]) {
+        Ok(tokens) => tokens,
+        Err(_) => return none(),
+    };
+    let mut prefix = String::new();
+    if let Some((last, text)) = tokens.last() {
+        let at_cursor = (last.span.line_stop, last.span.col_stop) == cursor;
+        match &last.token {
+            Token::Ident(_) | Token::LittleSelf | Token::BigSelf if at_cursor => prefix = text.to_string(),
+            token if at_cursor && token.is_keyword() && *token != Token::Ampersand => prefix = text.to_string(),
+            // A literal or a comment is being written.
+            Token::CommentLine(_)
+            | Token::CommentBlock(_)
+            | Token::Int(_)
+            | Token::StringLit(_)
+            | Token::CharLit(_)
+            | Token::AddressLit(_)
+                if at_cursor =>
+            {
+                return none()
+            }
+            _ => {}
+        }
+    }
+    if !prefix.is_empty() {
+        tokens.pop();
+    }
+    let tokens = tokens
+        .into_iter()
+        .map(|(token, _)| token)
+        .filter(|token| !matches!(token.token, Token::CommentLine(_) | Token::CommentBlock(_)))
+        .collect::<Vec<_>>();
+
+    Completion {
+        context: context(&tokens),
+        prefix,
+    }
+}
+
+fn none() -> Completion {
+    Completion {
+        context: CompletionContext::None,
+        prefix: String::new(),
+    }
+}
+
+/// Returns what may follow `tokens`.
+fn context(tokens: &[SpannedToken]) -> CompletionContext {
+    let last = match tokens.last() {
+        Some(last) => &last.token,
+        None => return CompletionContext::Item,
+    };
+    let before = tokens.len().checked_sub(2).map(|index| &tokens[index].token);
+    let innermost = open_brackets(tokens).pop();
+    let curly = innermost.and_then(|index| curly(tokens, index));
+
+    match last {
+        Token::Dot if before == Some(&Token::Console) => CompletionContext::ConsoleFunction,
+        Token::Dot => match receiver(tokens, tokens.len() - 1) {
+            Some(receiver) => CompletionContext::Member(receiver),
+            None => CompletionContext::None,
+        },
+        Token::DoubleColon => {
+            let receiver = &tokens[tokens.len() - 2];
+            let name = match &receiver.token {
+                Token::Ident(name) => *name,
+                token => match token.keyword_to_symbol() {
+                    Some(name) if *token == Token::BigSelf || is_primitive_type(token) => name,
+                    _ => return CompletionContext::None,
+                },
+            };
+            CompletionContext::StaticMember(Identifier::new_with_span(name, receiver.span.clone()))
+        }
+        Token::Arrow | Token::As => CompletionContext::Type,
+        // The `:` of a ternary expression or of a circuit initialization is followed by an expression.
+        Token::Colon => match curly {
+            Some(Curly::CircuitInit(_)) => CompletionContext::Expression,
+            _ if in_ternary(tokens) => CompletionContext::Expression,
+            _ => CompletionContext::Type,
+        },
+        // A new name is defined.
+        Token::Let
+        | Token::Const
+        | Token::Function
+        | Token::Circuit
+        | Token::Type
+        | Token::For
+        | Token::Import
+        | Token::Mut
+        | Token::At
+        | Token::Console
+        | Token::Else => CompletionContext::None,
+        Token::LeftCurly | Token::RightCurly | Token::Semicolon => match (innermost, curly) {
+            (None, _) => CompletionContext::Item,
+            (_, Some(Curly::Circuit)) => CompletionContext::CircuitMember,
+            (_, Some(Curly::Block)) => CompletionContext::Statement,
+            (_, Some(Curly::CircuitInit(circuit))) if *last == Token::LeftCurly => {
+                CompletionContext::CircuitInitMember(circuit)
+            }
+            _ => CompletionContext::Expression,
+        },
+        Token::Comma | Token::LeftParen => match curly {
+            Some(Curly::CircuitInit(circuit)) => CompletionContext::CircuitInitMember(circuit),
+            Some(Curly::Circuit) => CompletionContext::CircuitMember,
+            // The inputs of a function are new names.
+            _ if innermost.map_or(false, |index| {
+                segment(tokens, index)
+                    .iter()
+                    .any(|token| token.token == Token::Function)
+            }) =>
+            {
+                CompletionContext::None
+            }
+            _ => CompletionContext::Expression,
+        },
+        // An operand is complete, and may be followed by an operator.
+        Token::Ident(_)
+        | Token::Int(_)
+        | Token::True
+        | Token::False
+        | Token::StringLit(_)
+        | Token::CharLit(_)
+        | Token::AddressLit(_)
+        | Token::LittleSelf
+        | Token::BigSelf
+        | Token::Input
+        | Token::RightParen
+        | Token::RightSquare => CompletionContext::None,
+        token if is_primitive_type(token) => CompletionContext::None,
+        _ => CompletionContext::Expression,
+    }
+}
+
+/// Returns the indices of the brackets `tokens` leave open, innermost last.
+fn open_brackets(tokens: &[SpannedToken]) -> Vec<usize> {
+    let mut open = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        match token.token {
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly => open.push(index),
+            Token::RightParen | Token::RightSquare | Token::RightCurly => {
+                open.pop();
+            }
+            _ => {}
+        }
+    }
+    open
+}
+
+/// Returns the tokens of the statement or item up to the token at `end`, from the last `;`, `{`, or `}` before it.
+fn segment(tokens: &[SpannedToken], end: usize) -> &[SpannedToken] {
+    let start = tokens[..end]
+        .iter()
+        .rposition(|token| matches!(token.token, Token::Semicolon | Token::LeftCurly | Token::RightCurly))
+        .map_or(0, |index| index + 1);
+    &tokens[start..end]
+}
+
+/// Returns what the bracket at `index` opens, if it is a `{`.
+fn curly(tokens: &[SpannedToken], index: usize) -> Option<Curly> {
+    if tokens[index].token != Token::LeftCurly {
+        return None;
+    }
+    let segment = segment(tokens, index);
+    let defines = |keyword: &[Token]| segment.iter().any(|token| keyword.contains(&token.token));
+    Some(if defines(&[Token::Circuit]) {
+        Curly::Circuit
+    } else if defines(&[Token::Function, Token::If, Token::Else, Token::For]) {
+        Curly::Block
+    } else {
+        match segment.last().map(|token| (&token.token, &token.span)) {
+            Some((Token::Ident(name), span)) => Curly::CircuitInit(Identifier::new_with_span(*name, span.clone())),
+            Some((Token::BigSelf, span)) => {
+                Curly::CircuitInit(Identifier::new_with_span(leo_span::sym::SelfUpper, span.clone()))
+            }
+            _ => Curly::Block,
+        }
+    })
+}
+
+/// Returns `true` if the `:` ending `tokens` is that of a ternary expression.
+fn in_ternary(tokens: &[SpannedToken]) -> bool {
+    let mut depth = 0usize;
+    for token in tokens[..tokens.len() - 1].iter().rev() {
+        match token.token {
+            Token::RightParen | Token::RightSquare | Token::RightCurly => depth += 1,
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly if depth == 0 => return false,
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly => depth -= 1,
+            Token::Question if depth == 0 => return true,
+            Token::Semicolon | Token::Comma if depth == 0 => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns the expression before the `.` at `dot`, e.g., `a.b(c)` in `a.b(c).`.
+fn receiver(tokens: &[SpannedToken], dot: usize) -> Option<Expression> {
+    let mut start = dot;
+    loop {
+        let last = start.checked_sub(1)?;
+        start = match tokens[last].token {
+            Token::RightParen | Token::RightSquare => matching_open(tokens, last)?,
+            Token::Ident(_) | Token::LittleSelf | Token::BigSelf | Token::Int(_) | Token::Input => last,
+            _ => return None,
+        };
+        match start.checked_sub(1).map(|index| &tokens[index].token) {
+            Some(Token::Dot | Token::DoubleColon) => start -= 1,
+            // A call or an index continues with the callee or the array.
+            Some(Token::Ident(_) | Token::RightParen | Token::RightSquare | Token::LittleSelf | Token::BigSelf)
+                if matches!(tokens[start].token, Token::LeftParen | Token::LeftSquare) => {}
+            _ => break,
+        }
+    }
+
+    let (handler, _) = Handler::new_with_buf();
+    let mut parser = ParserContext::new(&handler, tokens[start..dot].to_vec());
+    let expression = parser.parse_expression().ok()?;
+    (!parser.has_next() && handler.err_count() == 0).then(|| expression)
+}
+
+/// Returns the index of the bracket opening the one at `close`.
+fn matching_open(tokens: &[SpannedToken], close: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for index in (0..=close).rev() {
+        match tokens[index].token {
+            Token::RightParen | Token::RightSquare | Token::RightCurly => depth += 1,
+            Token::LeftParen | Token::LeftSquare | Token::LeftCurly => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Returns `true` if `token` is a primitive type, such as `u8` or `field`.
+fn is_primitive_type(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::U8
+            | Token::U16
+            | Token::U32
+            | Token::U64
+            | Token::U128
+            | Token::I8
+            | Token::I16
+            | Token::I32
+            | Token::I64
+            | Token::I128
+            | Token::Field
+            | Token::Group
+            | Token::Bool
+            | Token::Address
+            | Token::Char
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    /// Returns the context and prefix at the `$` in `source`.
+    fn complete(source: &str) -> (CompletionContext, String) {
+        let offset = source.find('$').unwrap();
+        let line = source[..offset].matches('\n').count() + 1;
+        let column = offset - source[..offset].rfind('\n').map_or(0, |index| index + 1) + 1;
+        let completion = completion_context("test", &source.replacen('$', "", 1), (line, column));
+        (completion.context, completion.prefix)
+    }
+
+    fn context(source: &str) -> CompletionContext {
+        complete(source).0
+    }
+
+    #[test]
+    fn completes_items_statements_and_types() {
+        create_session_if_not_set_then(|_| {
+            use CompletionContext::*;
+            assert_eq!(complete("$"), (Item, String::new()));
+            assert_eq!(complete("func$"), (Item, "func".to_string()));
+            assert_eq!(complete("function main() {\n    le$"), (Statement, "le".to_string()));
+            // The code after the cursor is ignored.
+            assert_eq!(context("function main() {\n    let x = 1u8;\n    $\n}"), Statement);
+            assert_eq!(context("function main(a: u8) {\n    let x = a + $"), Expression);
+            assert_eq!(
+                context("function main(a: u8) {\n    return a > 1u8 ? a : $"),
+                Expression
+            );
+            assert_eq!(context("function main() {\n    let x: $"), Type);
+            assert_eq!(context("function main() -> $"), Type);
+            assert_eq!(context("circuit Point {\n    $"), CircuitMember);
+            assert_eq!(context("circuit Point {\n    x: $"), Type);
+            assert_eq!(
+                context("circuit Point {\n    x: u8,\n    function get(self) {\n        $"),
+                Statement
+            );
+        });
+    }
+
+    #[test]
+    fn completes_members() {
+        create_session_if_not_set_then(|_| {
+            let member = |source| match context(source) {
+                CompletionContext::Member(receiver) => receiver.to_string(),
+                context => panic!("unexpected context {:?}", context),
+            };
+            assert_eq!(member("function main(p: Point) {\n    let x = p.$"), "p");
+            assert_eq!(
+                member("function main(p: Point) {\n    let x = p.get(1u8).$"),
+                "p.get(1u8)"
+            );
+            assert_eq!(
+                member("function main() {\n    return self.points[0].$"),
+                "self.points[0]"
+            );
+            assert_eq!(member("function main(p: Point) {\n    let x = p.ge$"), "p");
+            assert_eq!(complete("function main(p: Point) {\n    let x = p.ge$").1, "ge");
+        });
+    }
+
+    #[test]
+    fn completes_core_functions_and_circuit_initializations() {
+        create_session_if_not_set_then(|_| {
+            use CompletionContext::*;
+            let name = |context| match context {
+                StaticMember(name) | CircuitInitMember(name) => name.name.to_string(),
+                context => panic!("unexpected context {:?}", context),
+            };
+            assert_eq!(
+                complete("function main() {\n    console.lo$"),
+                (ConsoleFunction, "lo".to_string())
+            );
+            assert_eq!(name(context("function main() {\n    let m = u8::$")), "u8");
+            assert_eq!(name(context("function main() {\n    let p = Point::new$")), "Point");
+            assert_eq!(name(context("function main() {\n    let p = Point { $")), "Point");
+            assert_eq!(
+                name(context("function main() {\n    let p = Point { x: 1u8, $")),
+                "Point"
+            );
+            assert_eq!(context("function main() {\n    let p = Point { x: $"), Expression);
+        });
+    }
+
+    #[test]
+    fn completes_nothing_in_new_names_literals_and_comments() {
+        create_session_if_not_set_then(|_| {
+            for source in [
+                "function main(a$",
+                "function main(a: u8, $",
+                "function main() {\n    let $",
+                "function main() {\n    let x$",
+                "function main() {\n    // Comment $",
+                "function main() {\n    /* Comment $",
+                "function main() {\n    console.log(\"$",
+                "function main() {\n    let x = 12$",
+                "function main() {\n    let x = a $",
+            ] {
+                assert_eq!(context(source), CompletionContext::None, "{}", source);
+            }
+            // A complete comment is not in the way.
+            assert_eq!(
+                context("function main() {\n    // Comment\n    $"),
+                CompletionContext::Statement
+            );
+        });
+    }
+}
@@ -18,20 +18,36 @@ use crate::{assert_no_whitespace, tokenizer::*, Token, KEYWORD_TOKENS};
 
 use leo_ast::*;
 use leo_errors::emitter::Handler;
-use leo_errors::{LeoError, ParserError, Result};
-use leo_span::{Span, Symbol};
+use leo_errors::{LeoError, LeoWarning, LintName, ParserError, Result};
+use leo_span::{sym, Span, Symbol};
 
+use indexmap::IndexMap;
 use std::{borrow::Cow, unreachable};
 
+/// The deepest nesting of expressions, statements and types the parser accepts.
+/// Deeper nesting is reported as an error rather than overflowing the stack.
+pub const MAX_NESTING_DEPTH: usize = 64;
+
 /// Stores a program in tokenized format plus additional context.
 /// May be converted into a [`Program`] AST by parsing all tokens.
 pub struct ParserContext<'a> {
     #[allow(dead_code)]
     pub(crate) handler: &'a Handler,
-    tokens: Vec<SpannedToken>,
+    /// The tokens which are not parsed yet, in source order.
+    tokens: std::vec::IntoIter<SpannedToken>,
+    /// Doc comments keyed by the start of the token they precede.
+    doc_comments: IndexMap<(usize, usize), Vec<String>>,
     end_span: Span,
     // true if parsing an expression for if and loop statements -- means circuit inits are not legal
     pub(crate) disallow_circuit_construction: bool,
+    // true if parsing a REPL line -- means the final semicolon may be left out
+    pub(crate) allow_missing_final_semicolon: bool,
+    // the number of expressions, statements and types being parsed that contain the current token
+    nesting_depth: usize,
+    // the lints allowed with `@allow` on the function being parsed
+    pub(crate) allowed_lints: Vec<LintName>,
+    // the number of syntax errors skipped over with `recover`
+    pub(crate) recovered_errors: usize,
 }
 
 impl Iterator for ParserContext<'_> {
@@ -47,27 +63,71 @@ impl<'a> ParserContext<'a> {
     /// Returns a new [`ParserContext`] type given a vector of tokens.
     ///
     pub fn new(handler: &'a Handler, mut tokens: Vec<SpannedToken>) -> Self {
-        tokens.reverse();
-        // todo: performance optimization here: drain filter
-        tokens = tokens
-            .into_iter()
-            .filter(|x| !matches!(x.token, Token::CommentLine(_) | Token::CommentBlock(_)))
-            .collect();
+        // Drop comments in place, but attach doc comments to the token that follows them.
+        let mut doc_comments = IndexMap::new();
+        let mut pending = Vec::new();
+        tokens.retain(|x| match &x.token {
+            Token::CommentLine(comment) => {
+                pending.extend(doc_comment_line(comment));
+                false
+            }
+            Token::CommentBlock(_) => false,
+            _ => {
+                if !pending.is_empty() {
+                    doc_comments.insert((x.span.line_start, x.span.col_start), std::mem::take(&mut pending));
+                }
+                true
+            }
+        });
         Self {
             handler,
             end_span: tokens
                 .iter()
+                .rev()
                 .find(|x| !x.span.content.trim().is_empty())
                 .map(|x| x.span.clone())
                 .unwrap_or_default(),
-            tokens,
+            tokens: tokens.into_iter(),
+            doc_comments,
             disallow_circuit_construction: false,
+            allow_missing_final_semicolon: false,
+            nesting_depth: 0,
+            allowed_lints: Vec::new(),
+            recovered_errors: 0,
+        }
+    }
+
+    ///
+    /// Parses with `parse` one level of nesting deeper,
+    /// or returns an error if the nesting is deeper than [`MAX_NESTING_DEPTH`].
+    ///
+    pub(crate) fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        if self.nesting_depth >= MAX_NESTING_DEPTH {
+            let span = self.peek_option().map(|x| &x.span).unwrap_or(&self.end_span);
+            return Err(ParserError::nesting_too_deep(MAX_NESTING_DEPTH, span).into());
         }
+        self.nesting_depth += 1;
+        let result = parse(self);
+        self.nesting_depth -= 1;
+        result
+    }
+
+    /// Removes and returns the doc comments attached to the current token.
+    pub fn take_doc_comments(&mut self) -> Vec<String> {
+        self.peek_option()
+            .map(|x| (x.span.line_start, x.span.col_start))
+            .and_then(|key| self.doc_comments.remove(&key))
+            .unwrap_or_default()
     }
 
     /// Returns the current token if there is one.
     pub fn peek_option(&self) -> Option<&SpannedToken> {
-        self.tokens.last()
+        self.look_ahead(0)
+    }
+
+    /// Returns the token `n` tokens after the current token, if there is one.
+    fn look_ahead(&self, n: usize) -> Option<&SpannedToken> {
+        self.tokens.as_slice().get(n)
     }
 
     /// Emit the error `err`.
@@ -75,6 +135,13 @@ impl<'a> ParserContext<'a> {
         self.handler.emit_err(err.into());
     }
 
+    /// Emit the warning `warning`, unless its lint is allowed on the function being parsed.
+    pub(crate) fn emit_warning(&self, warning: LeoWarning) {
+        if !self.allowed_lints.contains(&warning.lint) {
+            self.handler.emit_warning(warning);
+        }
+    }
+
     ///
     /// Returns an unexpected end of function [`SyntaxError`].
     ///
@@ -86,14 +153,14 @@ impl<'a> ParserContext<'a> {
     /// Returns a reference to the next SpannedToken or error if it does not exist.
     ///
     pub fn peek_next(&self) -> Result<&SpannedToken> {
-        self.tokens.get(self.tokens.len() - 2).ok_or_else(|| self.eof())
+        self.look_ahead(1).ok_or_else(|| self.eof())
     }
 
     ///
     /// Returns a reference to the current SpannedToken or error if it does not exist.
     ///
     pub fn peek(&self) -> Result<&SpannedToken> {
-        self.tokens.last().ok_or_else(|| self.eof())
+        self.peek_option().ok_or_else(|| self.eof())
     }
 
     ///
@@ -110,12 +177,12 @@ impl<'a> ParserContext<'a> {
     /// Returns true if the next token exists.
     ///
     pub fn has_next(&self) -> bool {
-        !self.tokens.is_empty()
+        !self.tokens.as_slice().is_empty()
     }
 
     /// Advances the current token.
     pub fn bump(&mut self) -> Option<SpannedToken> {
-        self.tokens.pop()
+        self.tokens.next()
     }
 
     ///
@@ -131,13 +198,6 @@ impl<'a> ParserContext<'a> {
         None
     }
 
-    ///
-    /// Appends a token to the back of the vector.
-    ///
-    pub fn backtrack(&mut self, token: SpannedToken) {
-        self.tokens.push(token);
-    }
-
     ///
     /// Removes the next token if it is a [`Token::Ident(_)`] and returns it, or [None] if
     /// the next token is not a [`Token::Ident(_)`] or if the next token does not exist.
@@ -152,7 +212,7 @@ impl<'a> ParserContext<'a> {
                 span,
             } = self.bump().unwrap()
             {
-                return Some(Identifier { name, span });
+                return Some(Identifier::new_with_span(name, span));
             } else {
                 unreachable!("eat_identifier_ shouldn't produce this")
             }
@@ -161,20 +221,20 @@ impl<'a> ParserContext<'a> {
     }
 
     ///
-    /// Returns a reference to the next token if it is a [`GroupCoordinate`], or [None] if
-    /// the next token is not a [`GroupCoordinate`].
+    /// Returns the [`GroupCoordinate`] starting `i` tokens after the current token, advancing `i` past it,
+    /// or [None] if the tokens there are not a [`GroupCoordinate`].
     ///
     fn peek_group_coordinate(&self, i: &mut usize) -> Option<GroupCoordinate> {
-        *i = i.checked_sub(1)?;
-        let token = self.tokens.get(*i)?;
+        let token = self.look_ahead(*i)?;
+        *i += 1;
         Some(match &token.token {
             Token::Add => GroupCoordinate::SignHigh,
-            Token::Minus => match self.tokens.get(i.checked_sub(1)?) {
+            Token::Minus => match self.look_ahead(*i) {
                 Some(SpannedToken {
                     token: Token::Int(value),
                     span,
                 }) => {
-                    *i -= 1;
+                    *i += 1;
                     GroupCoordinate::Number(format!("-{}", value), span.clone())
                 }
                 _ => GroupCoordinate::SignLow,
@@ -185,18 +245,20 @@ impl<'a> ParserContext<'a> {
         })
     }
 
-    /// Returns `true` if the next token is Function or if it is a Const followed by Function.
+    /// Returns `true` if the next token is Function or if it is a Const followed by Function,
+    /// either of them possibly preceded by a `public` or `private` modifier.
     /// Returns `false` otherwise.
     pub fn peek_is_function(&self) -> Result<bool> {
         let first = &self.peek()?.token;
-        let next = if self.tokens.len() >= 2 {
-            &self.peek_next()?.token
-        } else {
-            return Ok(false);
+        let next = match self.look_ahead(1) {
+            Some(next) => &next.token,
+            None => return Ok(false),
         };
         Ok(matches!(
             (first, next),
-            (Token::Function | Token::At, _) | (Token::Const, Token::Function)
+            (Token::Function | Token::At, _)
+                | (Token::Const, Token::Function)
+                | (Token::Ident(sym::public | sym::private), Token::Function | Token::Const)
         ))
     }
 
@@ -205,12 +267,11 @@ impl<'a> ParserContext<'a> {
     /// or [None] if the next token is not a [`GroupCoordinate`].
     ///
     pub fn eat_group_partial(&mut self) -> Option<Result<(GroupCoordinate, GroupCoordinate, Span)>> {
-        let mut i = self.tokens.len();
-        let start_span = self.tokens.get(i.checked_sub(1)?)?.span.clone();
+        let start_span = self.peek_option()?.span.clone();
+        let mut i = 0;
         let first = self.peek_group_coordinate(&mut i)?;
-        i = i.checked_sub(1)?;
         if !matches!(
-            self.tokens.get(i),
+            self.look_ahead(i),
             Some(SpannedToken {
                 token: Token::Comma,
                 ..
@@ -218,31 +279,32 @@ impl<'a> ParserContext<'a> {
         ) {
             return None;
         }
+        i += 1;
 
         let second = self.peek_group_coordinate(&mut i)?;
-        i = i.checked_sub(1)?;
         let right_paren_span = if let Some(SpannedToken {
             token: Token::RightParen,
             span,
-        }) = self.tokens.get(i)
+        }) = self.look_ahead(i)
         {
             span.clone()
         } else {
             return None;
         };
 
-        i = i.checked_sub(1)?;
+        i += 1;
         let end_span = if let Some(SpannedToken {
             token: Token::Group,
             span,
-        }) = self.tokens.get(i)
+        }) = self.look_ahead(i)
         {
             span.clone()
         } else {
             return None;
         };
 
-        self.tokens.drain(i..);
+        // Consume the tokens up to and including `group`.
+        self.tokens.nth(i);
         if let Err(e) = assert_no_whitespace(
             &right_paren_span,
             &end_span,
@@ -304,6 +366,70 @@ impl<'a> ParserContext<'a> {
         }
     }
 
+    ///
+    /// Reports `error` and skips to a point where parsing can resume, so that
+    /// one run reports as many syntax errors as possible.
+    ///
+    /// Tokens are skipped up to the next token for which `is_start` holds. Inside a block,
+    /// skipping also stops after the next `;` or before the `}` closing the block.
+    /// Tokens nested in braces are skipped over, and at least one token is always skipped,
+    /// except for the `}` closing the block, which the block consumes.
+    /// Returns `error` if the end of the input is reached, as there is nothing left to parse.
+    ///
+    pub(crate) fn recover(&mut self, error: LeoError, in_block: bool, is_start: fn(&Self) -> bool) -> Result<()> {
+        if !self.has_next() {
+            return Err(error);
+        }
+        self.handler.emit_err(error);
+        self.recovered_errors += 1;
+
+        let mut depth = 0usize;
+        let mut first = true;
+        while let Some(token) = self.peek_option() {
+            match token.token {
+                Token::Semicolon if in_block && depth == 0 => {
+                    self.bump();
+                    return Ok(());
+                }
+                // The block's own `}` is left for the block to consume, even if it is the token in error.
+                Token::RightCurly if in_block && depth == 0 => return Ok(()),
+                Token::RightCurly => depth = depth.saturating_sub(1),
+                Token::LeftCurly => depth += 1,
+                _ if depth == 0 && !first && is_start(self) => return Ok(()),
+                _ => {}
+            }
+            self.bump();
+            first = false;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the span of the next token if it is a semicolon, or an error
+    /// suggesting to insert one after `previous`, the span of what precedes it.
+    ///
+    pub fn expect_semicolon_after(&mut self, previous: &Span) -> Result<Span> {
+        match self.peek_option() {
+            Some(SpannedToken {
+                token: Token::Semicolon,
+                ..
+            }) => Ok(self.bump().unwrap().span),
+            Some(SpannedToken { token, span }) => {
+                let end = Span {
+                    line_start: previous.line_stop,
+                    col_start: previous.col_stop,
+                    content: previous.content.lines().last().unwrap_or_default().into(),
+                    ..previous.clone()
+                };
+                Err(ParserError::unexpected(token, Token::Semicolon, span)
+                    .with_suggestion("try adding a semicolon", ";", &end)
+                    .into())
+            }
+            None if self.allow_missing_final_semicolon => Ok(previous.clone()),
+            None => Err(self.eof()),
+        }
+    }
+
     ///
     /// Returns the span of the next token if it is equal to one of the given [`Token`]s, or error.
     ///
@@ -330,14 +456,14 @@ impl<'a> ParserContext<'a> {
     ///
     pub fn expect_loose_identifier(&mut self) -> Result<Identifier> {
         if let Some(token) = self.eat_any(KEYWORD_TOKENS) {
-            return Ok(Identifier {
-                name: token.token.keyword_to_symbol().unwrap(),
-                span: token.span,
-            });
+            return Ok(Identifier::new_with_span(
+                token.token.keyword_to_symbol().unwrap(),
+                token.span,
+            ));
         }
         if let Some((int, span)) = self.eat_int() {
             let name = Symbol::intern(&int.value);
-            return Ok(Identifier { name, span });
+            return Ok(Identifier::new_with_span(name, span));
         }
         self.expect_ident()
     }
@@ -351,7 +477,7 @@ impl<'a> ParserContext<'a> {
                     span,
                 } = self.bump().unwrap()
                 {
-                    Ok(Identifier { name, span })
+                    Ok(Identifier::new_with_span(name, span))
                 } else {
                     unreachable!("expect_ident_ shouldn't produce this")
                 }
@@ -367,11 +493,7 @@ impl<'a> ParserContext<'a> {
     /// Returns the next token if it exists or return end of function.
     ///
     pub fn expect_any(&mut self) -> Result<SpannedToken> {
-        if let Some(x) = self.tokens.pop() {
-            Ok(x)
-        } else {
-            Err(self.eof())
-        }
+        self.bump().ok_or_else(|| self.eof())
     }
 
     /// Parses a list of `T`s using `inner`
@@ -425,3 +547,13 @@ impl<'a> ParserContext<'a> {
         matches!(self.peek_option().map(|t| &t.token), Some(Token::LeftParen))
     }
 }
+
+/// Returns the text of `comment` if it is a doc comment (`///`), without the marker.
+fn doc_comment_line(comment: &str) -> Option<String> {
+    let text = comment.strip_prefix("///")?;
+    if text.starts_with('/') {
+        return None;
+    }
+    let text = text.trim_end_matches(|c| c == '\n' || c == '\r');
+    Some(text.strip_prefix(' ').unwrap_or(text).to_string())
+}
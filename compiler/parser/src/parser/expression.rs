@@ -16,7 +16,7 @@
 
 use super::*;
 
-use leo_errors::{ParserError, Result};
+use leo_errors::{LeoWarning, ParserError, Result};
 use leo_span::sym;
 
 const INT_TYPES: &[Token] = &[
@@ -34,6 +34,19 @@ const INT_TYPES: &[Token] = &[
     Token::Group,
 ];
 
+/// How a sequence of binary operators of the same precedence is grouped.
+#[derive(Clone, Copy, PartialEq)]
+enum Associativity {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a ** b ** c` is `a ** (b ** c)`.
+    Right,
+    /// `a == b == c` is not allowed.
+    NonAssociative,
+    /// `a < b < c` is `a < b && b < c`.
+    Chained,
+}
+
 impl ParserContext<'_> {
     ///
     /// Returns an [`Expression`] AST node if the next token is an expression.
@@ -47,7 +60,7 @@ impl ParserContext<'_> {
         self.disallow_circuit_construction = false;
 
         // Parse expression.
-        let result = self.parse_conditional_expression();
+        let result = self.nested(Self::parse_conditional_expression);
 
         // Restore prior parser state.
         self.disallow_circuit_construction = prior_fuzzy_state;
@@ -59,19 +72,20 @@ impl ParserContext<'_> {
     /// Returns an [`Expression`] AST node if the next tokens represent
     /// a ternary expression. May or may not include circuit init expressions.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_disjunctive_expression`].
+    /// Otherwise, tries to parse the next token using [`parse_binary_expression`].
     ///
     pub fn parse_conditional_expression(&mut self) -> Result<Expression> {
-        // Try to parse the next expression. Try BinaryOperation::Or.
-        let mut expr = self.parse_disjunctive_expression()?;
+        // Try to parse the condition, or the whole expression if there is no `?`.
+        let mut expr = self.parse_binary_expression(0)?;
 
         // Parse the rest of the ternary expression.
         if self.eat(Token::Question).is_some() {
             let if_true = self.parse_expression()?;
             self.expect(Token::Colon)?;
-            let if_false = self.parse_conditional_expression()?;
+            let if_false = self.nested(Self::parse_conditional_expression)?;
             expr = Expression::Ternary(TernaryExpression {
                 span: expr.span() + if_false.span(),
+                id: NodeID::new(),
                 condition: Box::new(expr),
                 if_true: Box::new(if_true),
                 if_false: Box::new(if_false),
@@ -84,130 +98,121 @@ impl ParserContext<'_> {
     fn bin_expr(left: Expression, right: Expression, op: BinaryOperation) -> Expression {
         Expression::Binary(BinaryExpression {
             span: left.span() + right.span(),
+            id: NodeID::new(),
             op,
             left: Box::new(left),
             right: Box::new(right),
         })
     }
 
-    /// Parses a left-associative binary expression `<left> token <right>` using `f` for left/right.
-    /// The `token` is translated to `op` in the AST.
-    fn parse_bin_expr(
-        &mut self,
-        token: Token,
-        op: BinaryOperation,
-        mut f: impl FnMut(&mut Self) -> Result<Expression>,
-    ) -> Result<Expression> {
-        let mut expr = f(self)?;
-        while self.eat(token.clone()).is_some() {
-            expr = Self::bin_expr(expr, f(self)?, op);
-        }
-        Ok(expr)
-    }
-
-    /// Returns an [`Expression`] AST node if the next tokens represent
-    /// a binary or expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_conjunctive_expression`].
-    pub fn parse_disjunctive_expression(&mut self) -> Result<Expression> {
-        self.parse_bin_expr(Token::Or, BinaryOperation::Or, Self::parse_conjunctive_expression)
-    }
-
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary and expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_equality_expression`].
-    pub fn parse_conjunctive_expression(&mut self) -> Result<Expression> {
-        self.parse_bin_expr(Token::And, BinaryOperation::And, Self::parse_equality_expression)
+    /// Returns the binary operation `token` stands for, with its precedence and associativity.
+    /// Operators with a higher precedence bind tighter.
+    fn binary_operator(token: &Token) -> Option<(BinaryOperation, u8, Associativity)> {
+        use Associativity::*;
+        Some(match token {
+            Token::Or => (BinaryOperation::Or, 1, Left),
+            Token::And => (BinaryOperation::And, 2, Left),
+            Token::Eq => (BinaryOperation::Eq, 3, NonAssociative),
+            Token::NotEq => (BinaryOperation::Ne, 3, NonAssociative),
+            Token::Lt => (BinaryOperation::Lt, 4, Chained),
+            Token::LtEq => (BinaryOperation::Le, 4, Chained),
+            Token::Gt => (BinaryOperation::Gt, 4, Chained),
+            Token::GtEq => (BinaryOperation::Ge, 4, Chained),
+            Token::Add => (BinaryOperation::Add, 5, Left),
+            Token::Minus => (BinaryOperation::Sub, 5, Left),
+            Token::Mul => (BinaryOperation::Mul, 6, Left),
+            Token::Div => (BinaryOperation::Div, 6, Left),
+            Token::Exp => (BinaryOperation::Pow, 7, Right),
+            _ => return None,
+        })
     }
 
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary equals or not equals expression.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_ordering_expression`].
-    pub fn parse_equality_expression(&mut self) -> Result<Expression> {
-        let mut expr = self.parse_ordering_expression()?;
-        if let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Eq, Token::NotEq]) {
-            let right = self.parse_ordering_expression()?;
-            let op = match op {
-                Token::Eq => BinaryOperation::Eq,
-                Token::NotEq => BinaryOperation::Ne,
-                _ => unreachable!("parse_equality_expression_ shouldn't produce this"),
-            };
-            expr = Self::bin_expr(expr, right, op);
+    /// Returns `true` if `expr` only reads values, so it may be evaluated twice
+    /// as the middle operand of chained comparisons.
+    fn is_repeatable(expr: &Expression) -> bool {
+        match expr {
+            Expression::Identifier(_) | Expression::Value(_) => true,
+            Expression::Access(AccessExpression::Member(access)) => Self::is_repeatable(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => Self::is_repeatable(&access.tuple),
+            Expression::Access(AccessExpression::Static(access)) => Self::is_repeatable(&access.inner),
+            Expression::Access(AccessExpression::Array(access)) => {
+                Self::is_repeatable(&access.array) && Self::is_repeatable(&access.index)
+            }
+            Expression::Unary(unary) => Self::is_repeatable(&unary.inner),
+            Expression::Cast(cast) => Self::is_repeatable(&cast.inner),
+            Expression::Binary(binary) => Self::is_repeatable(&binary.left) && Self::is_repeatable(&binary.right),
+            _ => false,
         }
-        Ok(expr)
     }
 
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary relational expression: less than, less than or equals, greater than, greater than or equals.
-    ///
-    /// Otherwise, tries to parse the next token using [`parse_shift_expression`].
-    pub fn parse_ordering_expression(&mut self) -> Result<Expression> {
-        let mut expr = self.parse_additive_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Lt, Token::LtEq, Token::Gt, Token::GtEq])
-        {
-            let right = self.parse_additive_expression()?;
-            let op = match op {
-                Token::Lt => BinaryOperation::Lt,
-                Token::LtEq => BinaryOperation::Le,
-                Token::Gt => BinaryOperation::Gt,
-                Token::GtEq => BinaryOperation::Ge,
-                _ => unreachable!("parse_ordering_expression_ shouldn't produce this"),
-            };
-            expr = Self::bin_expr(expr, right, op);
+    /// Returns the warning for the comparison `middle op right` chained to `expr`.
+    /// Operators are printed without the parentheses they may need,
+    /// so the fix is only suggested for a middle operand which is a variable, literal, or access.
+    fn chained_comparison_warning(expr: &Expression, middle: &Expression, right: &Expression) -> LeoWarning {
+        let warning = LeoWarning::chained_comparison(middle, &(expr.span() + right.span()));
+        match middle {
+            Expression::Identifier(_) | Expression::Value(_) | Expression::Access(_) => warning.with_suggestion(
+                "join the comparisons with `&&`",
+                format!("{} && {}", middle, middle),
+                middle.span(),
+            ),
+            _ => warning,
         }
-        Ok(expr)
     }
 
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary addition or subtraction expression.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_mul_div_pow_expression`].
-    pub fn parse_additive_expression(&mut self) -> Result<Expression> {
-        let mut expr = self.parse_multiplicative_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Add, Token::Minus]) {
-            let right = self.parse_multiplicative_expression()?;
-            let op = match op {
-                Token::Add => BinaryOperation::Add,
-                Token::Minus => BinaryOperation::Sub,
-                _ => unreachable!("parse_additive_expression_ shouldn't produce this"),
-            };
-            expr = Self::bin_expr(expr, right, op);
-        }
-        Ok(expr)
-    }
-
     /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary multiplication, division, or modulus expression.
+    /// binary expression whose operators all have at least `min_precedence`.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_exponential_expression`].
-    pub fn parse_multiplicative_expression(&mut self) -> Result<Expression> {
-        let mut expr = self.parse_exponential_expression()?;
-        while let Some(SpannedToken { token: op, .. }) = self.eat_any(&[Token::Mul, Token::Div]) {
-            let right = self.parse_exponential_expression()?;
-            let op = match op {
-                Token::Mul => BinaryOperation::Mul,
-                Token::Div => BinaryOperation::Div,
-                _ => unreachable!("parse_multiplicative_expression_ shouldn't produce this"),
-            };
-            expr = Self::bin_expr(expr, right, op);
-        }
-        Ok(expr)
-    }
-
-    /// Returns an [`Expression`] AST node if the next tokens represent a
-    /// binary exponentiation expression.
+    /// Operands are parsed using [`parse_cast_expression`], so casts bind tighter than any binary operator.
+    /// A non-associative operator may not directly follow another of the same precedence,
+    /// so `a == b == c` stops after `a == b`.
+    /// Chained comparisons are desugared with a warning, so `a < b < c` is `a < b && b < c`.
     ///
-    /// Otherwise, tries to parse the next token using [`parse_cast_expression`].
-    pub fn parse_exponential_expression(&mut self) -> Result<Expression> {
+    pub fn parse_binary_expression(&mut self, min_precedence: u8) -> Result<Expression> {
         let mut expr = self.parse_cast_expression()?;
+        // The highest precedence the next operator may have to extend `expr`.
+        let mut max_precedence = u8::MAX;
+        // The right operand of the comparison `expr` ends with, if the next operator may chain to it.
+        let mut chained_operand: Option<Expression> = None;
 
-        if self.eat(Token::Exp).is_some() {
-            let right = self.parse_exponential_expression()?;
-            expr = Self::bin_expr(expr, right, BinaryOperation::Pow);
-        }
+        while let Some((op, precedence, associativity)) =
+            self.peek_option().and_then(|token| Self::binary_operator(&token.token))
+        {
+            if precedence < min_precedence || precedence > max_precedence {
+                break;
+            }
+            self.bump();
 
+            let right = match associativity {
+                Associativity::Right => self.nested(|p| p.parse_binary_expression(precedence))?,
+                Associativity::Left | Associativity::NonAssociative | Associativity::Chained => {
+                    self.parse_binary_expression(precedence + 1)?
+                }
+            };
+            // A comparison may be chained to the next one through its right operand.
+            let next_chained_operand = (associativity == Associativity::Chained).then(|| right.clone());
+            expr = match chained_operand
+                .take()
+                .filter(|_| associativity == Associativity::Chained)
+            {
+                Some(middle) => {
+                    if !Self::is_repeatable(&middle) {
+                        return Err(ParserError::chained_comparison(&middle, middle.span()).into());
+                    }
+                    self.emit_warning(Self::chained_comparison_warning(&expr, &middle, &right));
+                    let comparison = Self::bin_expr(middle, right, op);
+                    Self::bin_expr(expr, comparison, BinaryOperation::And)
+                }
+                None => Self::bin_expr(expr, right, op),
+            };
+            chained_operand = next_chained_operand;
+
+            max_precedence = match associativity {
+                Associativity::NonAssociative => precedence - 1,
+                Associativity::Left | Associativity::Right | Associativity::Chained => precedence,
+            };
+        }
         Ok(expr)
     }
 
@@ -223,6 +228,7 @@ impl ParserContext<'_> {
             let (type_, type_span) = self.parse_type()?;
             expr = Expression::Cast(CastExpression {
                 span: expr.span() + &type_span,
+                id: NodeID::new(),
                 inner: Box::new(expr),
                 target_type: type_,
             })
@@ -230,9 +236,26 @@ impl ParserContext<'_> {
         Ok(expr)
     }
 
+    /// Makes the number `value` negative, extending its span to the `-` at `minus`.
+    /// Returns `false` if `value` is not a field, integer, or untyped number.
+    fn negate_literal(value: &mut ValueExpression, minus: &Span) -> bool {
+        match value {
+            ValueExpression::Field(number, span)
+            | ValueExpression::Implicit(number, span)
+            | ValueExpression::Integer(_, number, span) => {
+                *number = format!("-{}", number);
+                *span = minus + &*span;
+                true
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Returns an [`Expression`] AST node if the next tokens represent a
     /// unary not, negate, or bitwise not expression.
+    /// A number directly following `-`, without whitespace in between, is parsed as a negative literal
+    /// instead of a negation.
     ///
     /// Otherwise, tries to parse the next token using [`parse_postfix_expression`].
     ///
@@ -241,7 +264,28 @@ impl ParserContext<'_> {
         while let Some(token) = self.eat_any(&[Token::Not, Token::Minus]) {
             ops.push(token);
         }
+        // A `-` directly before a number is the sign of the literal, so that `-128i8` is in range.
+        let sign = match (ops.last(), self.peek_option()) {
+            (
+                Some(SpannedToken {
+                    token: Token::Minus,
+                    span: minus,
+                }),
+                Some(SpannedToken {
+                    token: Token::Int(_),
+                    span: number,
+                }),
+            ) if minus.line_stop == number.line_start && minus.col_stop == number.col_start => Some(minus.clone()),
+            _ => None,
+        };
         let mut inner = self.parse_postfix_expression()?;
+        let signed = match (sign, &mut inner) {
+            (Some(minus), Expression::Value(literal)) => Self::negate_literal(&mut literal.value, &minus),
+            _ => false,
+        };
+        if signed {
+            ops.pop();
+        }
         for op in ops.into_iter().rev() {
             let operation = match op.token {
                 Token::Not => UnaryOperation::Not,
@@ -250,6 +294,7 @@ impl ParserContext<'_> {
             };
             inner = Expression::Unary(UnaryExpression {
                 span: &op.span + inner.span(),
+                id: NodeID::new(),
                 op: operation,
                 inner: Box::new(inner),
             });
@@ -281,6 +326,7 @@ impl ParserContext<'_> {
                         let end = self.expect(Token::RightSquare)?;
                         expr = Expression::Access(AccessExpression::ArrayRange(ArrayRangeAccess {
                             span: expr.span() + &end,
+                            id: NodeID::new(),
                             array: Box::new(expr),
                             left: None,
                             right,
@@ -299,6 +345,7 @@ impl ParserContext<'_> {
                         let end = self.expect(Token::RightSquare)?;
                         expr = Expression::Access(AccessExpression::ArrayRange(ArrayRangeAccess {
                             span: expr.span() + &end,
+                            id: NodeID::new(),
                             array: Box::new(expr),
                             left: Some(Box::new(left)),
                             right,
@@ -307,6 +354,7 @@ impl ParserContext<'_> {
                         let end = self.expect(Token::RightSquare)?;
                         expr = Expression::Access(AccessExpression::Array(ArrayAccess {
                             span: expr.span() + &end,
+                            id: NodeID::new(),
                             array: Box::new(expr),
                             index: Box::new(left),
                         }));
@@ -316,6 +364,7 @@ impl ParserContext<'_> {
                     if let Some(ident) = self.eat_identifier() {
                         expr = Expression::Access(AccessExpression::Member(MemberAccess {
                             span: expr.span() + &ident.span,
+                            id: NodeID::new(),
                             inner: Box::new(expr),
                             name: ident,
                             type_: None,
@@ -323,6 +372,7 @@ impl ParserContext<'_> {
                     } else if let Some((num, span)) = self.eat_int() {
                         expr = Expression::Access(AccessExpression::Tuple(TupleAccess {
                             span: expr.span() + &span,
+                            id: NodeID::new(),
                             tuple: Box::new(expr),
                             index: num,
                         }));
@@ -347,6 +397,7 @@ impl ParserContext<'_> {
                     }
                     expr = Expression::Call(CallExpression {
                         span: expr.span() + &end_span,
+                        id: NodeID::new(),
                         function: Box::new(expr),
                         arguments,
                     });
@@ -355,6 +406,7 @@ impl ParserContext<'_> {
                     let ident = self.expect_ident()?;
                     expr = Expression::Access(AccessExpression::Static(StaticAccess {
                         span: expr.span() + &ident.span,
+                        id: NodeID::new(),
                         inner: Box::new(expr),
                         type_: None,
                         name: ident,
@@ -391,6 +443,7 @@ impl ParserContext<'_> {
         })?;
         Ok(Expression::CircuitInit(CircuitInitExpression {
             span: &identifier.span + &span,
+            id: NodeID::new(),
             name: identifier,
             members,
         }))
@@ -402,13 +455,14 @@ impl ParserContext<'_> {
     ///
     pub fn parse_tuple_expression(&mut self, span: &Span) -> Result<Expression> {
         if let Some((left, right, span)) = self.eat_group_partial().transpose()? {
-            return Ok(Expression::Value(ValueExpression::Group(Box::new(GroupValue::Tuple(
-                GroupTuple {
+            return Ok(Expression::Value(
+                ValueExpression::Group(Box::new(GroupValue::Tuple(GroupTuple {
                     span,
                     x: left,
                     y: right,
-                },
-            )))));
+                })))
+                .into(),
+            ));
         }
         let mut args = Vec::new();
         let end_span;
@@ -430,6 +484,7 @@ impl ParserContext<'_> {
         } else {
             Ok(Expression::TupleInit(TupleInitExpression {
                 span: span + &end_span,
+                id: NodeID::new(),
                 elements: args,
             }))
         }
@@ -444,6 +499,7 @@ impl ParserContext<'_> {
             return Ok(Expression::ArrayInline(ArrayInlineExpression {
                 elements: Vec::new(),
                 span: span + &end.span,
+                id: NodeID::new(),
             }));
         }
         let first = self.parse_spread_or_expression()?;
@@ -461,6 +517,7 @@ impl ParserContext<'_> {
             };
             Ok(Expression::ArrayInit(ArrayInitExpression {
                 span: span + &end,
+                id: NodeID::new(),
                 element: Box::new(first),
                 dimensions,
             }))
@@ -488,6 +545,7 @@ impl ParserContext<'_> {
             Ok(Expression::ArrayInline(ArrayInlineExpression {
                 elements,
                 span: span + &end_span,
+                id: NodeID::new(),
             }))
         }
     }
@@ -512,41 +570,46 @@ impl ParserContext<'_> {
                         span: type_span,
                     }) => {
                         assert_no_whitespace(&span, &type_span, &value, "field")?;
-                        Expression::Value(ValueExpression::Field(value, span + type_span))
+                        Expression::Value(ValueExpression::Field(value, span + type_span).into())
                     }
                     Some(SpannedToken {
                         token: Token::Group,
                         span: type_span,
                     }) => {
                         assert_no_whitespace(&span, &type_span, &value, "group")?;
-                        Expression::Value(ValueExpression::Group(Box::new(GroupValue::Single(
-                            value,
-                            span + type_span,
-                        ))))
+                        Expression::Value(
+                            ValueExpression::Group(Box::new(GroupValue::Single(value, span + type_span))).into(),
+                        )
                     }
                     Some(SpannedToken { token, span: type_span }) => {
                         assert_no_whitespace(&span, &type_span, &value, &token.to_string())?;
-                        Expression::Value(ValueExpression::Integer(
-                            Self::token_to_int_type(token).expect("unknown int type token"),
-                            value,
-                            span + type_span,
-                        ))
+                        Expression::Value(
+                            ValueExpression::Integer(
+                                Self::token_to_int_type(token).expect("unknown int type token"),
+                                value,
+                                span + type_span,
+                            )
+                            .into(),
+                        )
                     }
-                    None => Expression::Value(ValueExpression::Implicit(value, span)),
+                    None => Expression::Value(ValueExpression::Implicit(value, span).into()),
                 }
             }
-            Token::True => Expression::Value(ValueExpression::Boolean("true".into(), span)),
-            Token::False => Expression::Value(ValueExpression::Boolean("false".into(), span)),
-            Token::AddressLit(value) => Expression::Value(ValueExpression::Address(value, span)),
-            Token::CharLit(value) => Expression::Value(ValueExpression::Char(CharValue {
-                character: value.into(),
-                span,
-            })),
-            Token::StringLit(value) => Expression::Value(ValueExpression::String(value, span)),
+            Token::True => Expression::Value(ValueExpression::Boolean("true".into(), span).into()),
+            Token::False => Expression::Value(ValueExpression::Boolean("false".into(), span).into()),
+            Token::AddressLit(value) => Expression::Value(ValueExpression::Address(value, span).into()),
+            Token::CharLit(value) => Expression::Value(
+                ValueExpression::Char(CharValue {
+                    character: value.into(),
+                    span,
+                })
+                .into(),
+            ),
+            Token::StringLit(value) => Expression::Value(ValueExpression::String(value, span).into()),
             Token::LeftParen => self.parse_tuple_expression(&span)?,
             Token::LeftSquare => self.parse_array_expression(&span)?,
             Token::Ident(name) => {
-                let ident = Identifier { name, span };
+                let ident = Identifier::new_with_span(name, span);
                 if !self.disallow_circuit_construction && self.peek_token().as_ref() == &Token::LeftCurly {
                     self.parse_circuit_expression(ident)?
                 } else {
@@ -554,25 +617,18 @@ impl ParserContext<'_> {
                 }
             }
             Token::BigSelf => {
-                let ident = Identifier {
-                    name: sym::SelfUpper,
-                    span,
-                };
+                let ident = Identifier::new_with_span(sym::SelfUpper, span);
                 if !self.disallow_circuit_construction && self.peek_token().as_ref() == &Token::LeftCurly {
                     self.parse_circuit_expression(ident)?
                 } else {
                     Expression::Identifier(ident)
                 }
             }
-            Token::LittleSelf => Expression::Identifier(Identifier {
-                name: sym::SelfLower,
-                span,
-            }),
-            Token::Input => Expression::Identifier(Identifier { name: sym::input, span }),
-            t if crate::type_::TYPE_TOKENS.contains(&t) => Expression::Identifier(Identifier {
-                name: t.keyword_to_symbol().unwrap(),
-                span,
-            }),
+            Token::LittleSelf => Expression::Identifier(Identifier::new_with_span(sym::SelfLower, span)),
+            Token::Input => Expression::Identifier(Identifier::new_with_span(sym::input, span)),
+            t if crate::type_::TYPE_TOKENS.contains(&t) => {
+                Expression::Identifier(Identifier::new_with_span(t.keyword_to_symbol().unwrap(), span))
+            }
             token => {
                 return Err(ParserError::unexpected_str(token, "expression", &span).into());
             }
@@ -17,7 +17,7 @@
 use super::*;
 use crate::KEYWORD_TOKENS;
 
-use leo_errors::{ParserError, Result};
+use leo_errors::{LintName, ParserError, Result};
 use leo_span::sym;
 
 impl ParserContext<'_> {
@@ -25,64 +25,114 @@ impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     ///
     pub fn parse_program(&mut self) -> Result<Program> {
-        let mut import_statements = Vec::new();
-        let mut aliases = IndexMap::new();
-        let mut circuits = IndexMap::new();
-        let mut functions = IndexMap::new();
-        let mut global_consts = IndexMap::new();
-        // let mut tests = IndexMap::new();
+        let mut program = Program::new(String::new());
 
         while self.has_next() {
-            let token = self.peek()?;
-            match &token.token {
-                Token::Import => {
-                    import_statements.push(self.parse_import_statement()?);
-                }
-                Token::Circuit => {
+            if let Err(error) = self.parse_program_item(&mut program) {
+                self.recover(error, false, Self::peek_is_item_start)?;
+            }
+        }
+        Ok(program)
+    }
+
+    /// Parses the next import, circuit, function, global constant, or type alias into `program`.
+    fn parse_program_item(&mut self, program: &mut Program) -> Result<()> {
+        let token = self.peek()?;
+        match &token.token {
+            Token::Import => {
+                program.import_statements.push(self.parse_import_statement()?);
+            }
+            Token::Circuit => {
+                let docs = self.take_doc_comments();
+                self.expect(Token::Circuit)?;
+                let (id, mut circuit) = self.parse_circuit()?;
+                circuit.docs = docs;
+                program.circuits.insert(id, circuit);
+            }
+            Token::Ident(ident) => match *ident {
+                sym::test => return Err(ParserError::test_function(&token.span).into()),
+                sym::public | sym::private if matches!(self.peek_next(), Ok(next) if next.token == Token::Circuit) => {
+                    let docs = self.take_doc_comments();
+                    let visibility = self.eat_visibility();
                     self.expect(Token::Circuit)?;
-                    let (id, circuit) = self.parse_circuit()?;
-                    circuits.insert(id, circuit);
+                    let (id, mut circuit) = self.parse_circuit()?;
+                    circuit.docs = docs;
+                    circuit.visibility = visibility;
+                    program.circuits.insert(id, circuit);
                 }
-                Token::Ident(ident) => match *ident {
-                    sym::test => return Err(ParserError::test_function(&token.span).into()),
-                    kw @ (sym::Struct | sym::Class) => {
-                        self.emit_err(ParserError::unexpected(kw, "circuit", &token.span));
-                        self.bump().unwrap();
-                        let (id, circuit) = self.parse_circuit()?;
-                        circuits.insert(id, circuit);
-                    }
-                    _ => return Err(Self::unexpected_item(token).into()),
-                },
-                // Const functions share the first token with the global Const.
-                Token::Const if self.peek_is_function()? => {
+                sym::public | sym::private if self.peek_is_function()? => {
                     let (id, function) = self.parse_function_declaration()?;
-                    functions.insert(id, function);
+                    program.functions.insert(id, function);
                 }
-                Token::Const => {
-                    let (name, global_const) = self.parse_global_const_declaration()?;
-                    global_consts.insert(name, global_const);
+                kw @ (sym::Struct | sym::Class) => {
+                    self.emit_err(ParserError::unexpected(kw, "circuit", &token.span));
+                    let docs = self.take_doc_comments();
+                    self.bump().unwrap();
+                    let (id, mut circuit) = self.parse_circuit()?;
+                    circuit.docs = docs;
+                    program.circuits.insert(id, circuit);
                 }
-                Token::Function | Token::At => {
-                    let (id, function) = self.parse_function_declaration()?;
-                    functions.insert(id, function);
+                _ => return Err(Self::unexpected_item(token).into()),
+            },
+            // Const functions share the first token with the global Const.
+            Token::Const if self.peek_is_function()? => {
+                let (id, function) = self.parse_function_declaration()?;
+                program.functions.insert(id, function);
+            }
+            Token::Const => {
+                let (name, global_const) = self.parse_global_const_declaration()?;
+                program.global_consts.insert(name, global_const);
+            }
+            Token::Function => {
+                let (id, function) = self.parse_function_declaration()?;
+                program.functions.insert(id, function);
+            }
+            // Both functions and global constants may be annotated, and `@program` annotates the whole program.
+            Token::At => {
+                let docs = self.take_doc_comments();
+                let mut annotations = self.parse_annotations()?;
+                if let Some(annotation) = annotations.shift_remove(&sym::program) {
+                    program.annotations.insert(sym::program, annotation);
+                    let next = self.peek_token();
+                    if annotations.is_empty()
+                        && !matches!(
+                            next.as_ref(),
+                            Token::Function | Token::Const | Token::Ident(sym::public | sym::private)
+                        )
+                    {
+                        return Ok(());
+                    }
                 }
-                Token::Type => {
-                    let (name, alias) = self.parse_type_alias()?;
-                    aliases.insert(name, alias);
+                if self.peek_token().as_ref() == &Token::Const && !self.peek_is_function()? {
+                    let (name, mut global_const) = self.parse_global_const_declaration()?;
+                    global_const.annotations = annotations;
+                    program.global_consts.insert(name, global_const);
+                } else {
+                    let (id, function) = self.parse_annotated_function(docs, annotations)?;
+                    program.functions.insert(id, function);
                 }
-                _ => return Err(Self::unexpected_item(token).into()),
             }
+            Token::Type => {
+                let (name, alias) = self.parse_type_alias()?;
+                program.aliases.insert(name, alias);
+            }
+            _ => return Err(Self::unexpected_item(token).into()),
         }
-        Ok(Program {
-            name: String::new(),
-            expected_input: Vec::new(),
-            import_statements,
-            imports: IndexMap::new(),
-            aliases,
-            circuits,
-            functions,
-            global_consts,
-        })
+        Ok(())
+    }
+
+    /// Returns `true` if the next token may start a program item.
+    fn peek_is_item_start(&self) -> bool {
+        matches!(
+            self.peek_token().as_ref(),
+            Token::Import
+                | Token::Circuit
+                | Token::Function
+                | Token::At
+                | Token::Const
+                | Token::Type
+                | Token::Ident(sym::public | sym::private)
+        )
     }
 
     fn unexpected_item(token: &SpannedToken) -> ParserError {
@@ -110,9 +160,27 @@ impl ParserContext<'_> {
 
         assert_no_whitespace(&start, &name.span, &name.name.as_str(), "@")?;
 
+        let mut values = Vec::new();
         let (end_span, arguments) = if self.peek_is_left_par() {
             let (args, _, span) = self.parse_paren_comma_list(|p| {
                 Ok(if let Some(ident) = p.eat_identifier() {
+                    // Parse `ident = "value"` or `ident = 1`.
+                    if p.eat(Token::Assign).is_some() {
+                        let token = p.expect_any()?;
+                        match token.token {
+                            Token::StringLit(chars) => values.push((ident.name, Chars(chars).to_string())),
+                            Token::Int(value) => values.push((ident.name, value)),
+                            other => p.emit_err(ParserError::unexpected_str(&other, "string or integer", &token.span)),
+                        }
+                        return Ok(None);
+                    }
+                    if name.name == sym::allow && LintName::from_name(&ident.name.as_str()).is_none() {
+                        p.emit_err(ParserError::unknown_lint(ident.name, &ident.span).with_similar_name(
+                            &ident.name.as_str(),
+                            LintName::ALL.iter().map(|lint| lint.name()),
+                            &ident.span,
+                        ));
+                    }
                     Some(ident.name)
                 } else {
                     let token = p.expect_any()?;
@@ -124,15 +192,37 @@ impl ParserContext<'_> {
         } else {
             (name.span.clone(), Vec::new())
         };
+        let span = start + end_span;
+
+        if name.name == sym::cfg && (!arguments.is_empty() || values.is_empty()) {
+            self.emit_err(ParserError::invalid_cfg(&span));
+        }
+
         Ok(Annotation {
             name,
             arguments,
-            span: start + end_span,
+            values,
+            span,
         })
     }
 
+    /// Returns the annotations `@name (arguments)?` preceding a function or global constant, keyed by name.
+    pub fn parse_annotations(&mut self) -> Result<IndexMap<Symbol, Annotation>> {
+        let mut annotations = IndexMap::new();
+        while self.peek_token().as_ref() == &Token::At {
+            let annotation = self.parse_annotation()?;
+            annotations.insert(annotation.name.name, annotation);
+        }
+        Ok(annotations)
+    }
+
     /// Parses `foo` in an annotation `@foo . That is, the name of the annotation.
     fn parse_annotation_name(&mut self) -> Result<Identifier> {
+        // `@const` is named by a keyword.
+        if let Some(token) = self.eat(Token::Const) {
+            return Ok(Identifier::new_with_span(sym::Const, token.span));
+        }
+
         let mut name = self.expect_ident()?;
 
         // Recover `context` instead of `test`.
@@ -347,6 +437,8 @@ impl ParserContext<'_> {
         Ok((
             name.clone(),
             Circuit {
+                docs: Vec::new(),
+                visibility: None,
                 circuit_name: name,
                 members,
             },
@@ -357,14 +449,12 @@ impl ParserContext<'_> {
     /// Returns a [`FunctionInput`] AST node if the next tokens represent a function parameter.
     ///
     pub fn parse_function_parameters(&mut self, first: bool) -> Result<FunctionInput> {
+        let mode = self.eat_mode(|next| matches!(next, Token::Ident(_) | Token::Const | Token::Mut));
         let const_ = self.eat(Token::Const);
         let mutable = self.eat(Token::Mut);
         let reference = self.eat(Token::Ampersand);
         let mut name = if let Some(token) = self.eat(Token::LittleSelf) {
-            Identifier {
-                name: sym::SelfLower,
-                span: token.span,
-            }
+            Identifier::new_with_span(sym::SelfLower, token.span)
         } else {
             self.expect_ident()?
         };
@@ -394,6 +484,7 @@ impl ParserContext<'_> {
         self.expect(Token::Colon)?;
         let type_ = self.parse_type()?.0;
         Ok(FunctionInput::Variable(FunctionInputVariable {
+            mode,
             const_: const_.is_some(),
             mutable: const_.is_none(),
             type_,
@@ -413,14 +504,21 @@ impl ParserContext<'_> {
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
     /// and function definition.
     pub fn parse_function_declaration(&mut self) -> Result<(Identifier, Function)> {
-        // Parse any annotations.
-        let mut annotations = IndexMap::new();
-        while self.peek_token().as_ref() == &Token::At {
-            let annotation = self.parse_annotation()?;
-            annotations.insert(annotation.name.name, annotation);
-        }
+        // Doc comments precede any annotations.
+        let docs = self.take_doc_comments();
+        let annotations = self.parse_annotations()?;
+        self.parse_annotated_function(docs, annotations)
+    }
 
-        // Parse optional const modifier.
+    /// Returns an [`(Identifier, Function)`] AST node for the function following the already parsed
+    /// `docs` and `annotations`.
+    fn parse_annotated_function(
+        &mut self,
+        docs: Vec<String>,
+        annotations: IndexMap<Symbol, Annotation>,
+    ) -> Result<(Identifier, Function)> {
+        // Parse optional visibility and const modifiers.
+        let visibility = self.eat_visibility();
         let const_ = self.eat(Token::Const).is_some();
 
         // Parse `function IDENT`.
@@ -435,24 +533,37 @@ impl ParserContext<'_> {
             param
         })?;
 
-        // Parse return type.
-        let output = if self.eat(Token::Arrow).is_some() {
-            Some(self.parse_type()?.0)
+        // Parse return mode and type.
+        let (output_mode, output) = if self.eat(Token::Arrow).is_some() {
+            let mode = self.eat_mode(|next| !matches!(next, Token::LeftCurly));
+            (mode, Some(self.parse_type()?.0))
         } else {
-            None
+            (None, None)
         };
 
-        // Parse the function body.
-        let block = self.parse_block()?;
+        // Parse the function body, with the lints allowed on the function.
+        self.allowed_lints = annotations.get(&sym::allow).map_or_else(Vec::new, |allow| {
+            allow
+                .arguments
+                .iter()
+                .filter_map(|arg| LintName::from_name(&arg.as_str()))
+                .collect()
+        });
+        let block = self.parse_block();
+        self.allowed_lints.clear();
+        let block = block?;
 
         Ok((
             name.clone(),
             Function {
+                docs,
                 annotations,
+                visibility,
                 identifier: name,
                 input: inputs,
                 const_,
                 output,
+                output_mode,
                 span: start + block.span.clone(),
                 block,
                 core_mapping: <_>::default(),
@@ -460,6 +571,39 @@ impl ParserContext<'_> {
         ))
     }
 
+    /// Eats a `public` or `private` modifier, if it is followed by a function or circuit.
+    fn eat_visibility(&mut self) -> Option<Visibility> {
+        let visibility = match self.peek_token().as_ref() {
+            Token::Ident(sym::public) => Visibility::Public,
+            Token::Ident(sym::private) => Visibility::Private,
+            _ => return None,
+        };
+        if !matches!(
+            self.peek_next().map(|next| &next.token),
+            Ok(Token::Function | Token::Const | Token::Circuit)
+        ) {
+            return None;
+        }
+        self.bump();
+        Some(visibility)
+    }
+
+    /// Eats a `constant`, `private` or `public` mode, if the token following it satisfies `follows`.
+    /// Otherwise, the identifier is left to be parsed as a name or type.
+    fn eat_mode(&mut self, follows: impl Fn(&Token) -> bool) -> Option<Mode> {
+        let mode = match self.peek_token().as_ref() {
+            Token::Ident(sym::constant) => Mode::Constant,
+            Token::Ident(sym::private) => Mode::Private,
+            Token::Ident(sym::public) => Mode::Public,
+            _ => return None,
+        };
+        if !self.peek_next().map_or(false, |next| follows(&next.token)) {
+            return None;
+        }
+        self.bump();
+        Some(mode)
+    }
+
     ///
     /// Returns an [`(String, DefinitionStatement)`] AST node if the next tokens represent a global
     /// constant declaration.
@@ -29,6 +29,9 @@ use leo_span::{Span, Symbol};
 use indexmap::IndexMap;
 use std::unreachable;
 
+mod completion;
+pub use completion::*;
+
 mod context;
 pub use context::*;
 
@@ -53,7 +56,25 @@ pub(crate) fn assert_no_whitespace(left_span: &Span, right_span: &Span, left: &s
 pub fn parse(handler: &Handler, path: &str, source: &str) -> Result<Program> {
     let mut tokens = ParserContext::new(handler, crate::tokenize(path, source)?);
 
-    tokens.parse_program()
+    let program = tokens.parse_program()?;
+
+    // Errors the parser recovered from have been emitted, but the program is not valid.
+    match tokens.recovered_errors {
+        0 => Ok(program),
+        count => Err(ParserError::aborted_due_to_errors(count).into()),
+    }
+}
+
+/// Parses a statement entered on a REPL line, given a `path` naming the line and its `source` code text.
+pub fn parse_repl_statement(handler: &Handler, path: &str, source: &str) -> Result<Statement> {
+    let mut tokens = ParserContext::new(handler, crate::tokenize(path, source)?);
+
+    let statement = tokens.parse_repl_statement()?;
+
+    match tokens.recovered_errors {
+        0 => Ok(statement),
+        count => Err(ParserError::aborted_due_to_errors(count).into()),
+    }
 }
 
 /// Parses an input file at the given file `path` and `source` code text.
@@ -62,3 +83,30 @@ pub fn parse_input(handler: &Handler, path: &str, source: &str) -> Result<Parsed
 
     tokens.parse_input()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn keeps_programs_with_errors_not_recovered_from() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let program = parse(&handler, "test", "struct Foo { x: u8 }").unwrap();
+            assert_eq!(handler.err_count(), 1);
+            assert_eq!(program.circuits.len(), 1);
+        });
+    }
+
+    #[test]
+    fn aborts_after_recovering_from_errors() {
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let source = "function a() {\n    let x = 1u8 +;\n}\nfunction b() {\n    let y = 2u8 -;\n}";
+            let error = parse(&handler, "test", source).unwrap_err();
+            assert_eq!(error.to_string(), ParserError::aborted_due_to_errors(2).to_string());
+            assert_eq!(handler.err_count(), 2);
+        });
+    }
+}
@@ -84,14 +84,28 @@ impl ParserContext<'_> {
     /// Returns a [`Statement`] AST node if the next tokens represent a statement.
     ///
     pub fn parse_statement(&mut self) -> Result<Statement> {
-        match &self.peek()?.token {
-            Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
-            Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
-            Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
-            Token::Console => Ok(Statement::Console(self.parse_console_statement()?)),
-            Token::Let | Token::Const => Ok(Statement::Definition(self.parse_definition_statement()?)),
-            Token::LeftCurly => Ok(Statement::Block(self.parse_block()?)),
-            _ => Ok(self.parse_assign_statement()?),
+        self.nested(|p| match &p.peek()?.token {
+            Token::Return => Ok(Statement::Return(p.parse_return_statement()?)),
+            Token::If => Ok(Statement::Conditional(p.parse_conditional_statement()?)),
+            Token::For => Ok(Statement::Iteration(Box::new(p.parse_loop_statement()?))),
+            Token::Console => Ok(Statement::Console(p.parse_console_statement()?)),
+            Token::Let | Token::Const => Ok(Statement::Definition(p.parse_definition_statement()?)),
+            Token::LeftCurly => Ok(Statement::Block(p.parse_block()?)),
+            _ => Ok(p.parse_assign_statement()?),
+        })
+    }
+
+    ///
+    /// Returns a [`Statement`] AST node if all of the remaining tokens represent a statement entered
+    /// on a REPL line. The final semicolon may be left out.
+    ///
+    pub fn parse_repl_statement(&mut self) -> Result<Statement> {
+        self.allow_missing_final_semicolon = true;
+        let statement = self.parse_statement()?;
+
+        match self.peek_option() {
+            Some(SpannedToken { token, span }) => Err(ParserError::unexpected(token, "end of input", span).into()),
+            None => Ok(statement),
         }
     }
 
@@ -104,9 +118,10 @@ impl ParserContext<'_> {
         if let Some(operator) = self.eat_any(ASSIGN_TOKENS) {
             let value = self.parse_expression()?;
             let assignee = Self::construct_assignee(expr)?;
-            self.expect(Token::Semicolon)?;
+            self.expect_semicolon_after(value.span())?;
             Ok(Statement::Assign(Box::new(AssignStatement {
                 span: &assignee.span + value.span(),
+                id: NodeID::new(),
                 assignee,
                 operation: match operator.token {
                     Token::Assign => AssignOperation::Assign,
@@ -120,9 +135,10 @@ impl ParserContext<'_> {
                 value,
             })))
         } else {
-            self.expect(Token::Semicolon)?;
+            self.expect_semicolon_after(expr.span())?;
             Ok(Statement::Expression(ExpressionStatement {
                 span: expr.span().clone(),
+                id: NodeID::new(),
                 expression: expr,
             }))
         }
@@ -137,12 +153,14 @@ impl ParserContext<'_> {
         let mut statements = Vec::new();
         loop {
             match self.eat(Token::RightCurly) {
-                None => {
-                    statements.push(self.parse_statement()?);
-                }
+                None => match self.parse_statement() {
+                    Ok(statement) => statements.push(statement),
+                    Err(error) => self.recover(error, true, Self::peek_is_statement_start)?,
+                },
                 Some(end) => {
                     return Ok(Block {
                         span: start + end.span,
+                        id: NodeID::new(),
                         statements,
                     });
                 }
@@ -150,16 +168,25 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Returns `true` if the next token may start a statement other than an assignment or expression.
+    pub(crate) fn peek_is_statement_start(&self) -> bool {
+        matches!(
+            self.peek_token().as_ref(),
+            Token::Return | Token::If | Token::For | Token::Console | Token::Let | Token::Const
+        )
+    }
+
     ///
     /// Returns a [`ReturnStatement`] AST node if the next tokens represent a return statement.
     ///
     pub fn parse_return_statement(&mut self) -> Result<ReturnStatement> {
         let start = self.expect(Token::Return)?;
         let expr = self.parse_expression()?;
-        self.expect(Token::Semicolon)?;
+        self.expect_semicolon_after(expr.span())?;
 
         Ok(ReturnStatement {
             span: &start + expr.span(),
+            id: NodeID::new(),
             expression: expr,
         })
     }
@@ -170,6 +197,18 @@ impl ParserContext<'_> {
         self.disallow_circuit_construction = true;
         let expr = self.parse_conditional_expression()?;
         self.disallow_circuit_construction = false;
+
+        // Catch `if a = b { ... }`, which most likely meant `a == b`.
+        if let Some(SpannedToken {
+            token: Token::Assign,
+            span,
+        }) = self.peek_option()
+        {
+            return Err(ParserError::unexpected(Token::Assign, Token::LeftCurly, span)
+                .with_suggestion("try comparing for equality", "==", span)
+                .into());
+        }
+
         let body = self.parse_block()?;
         let next = if self.eat(Token::Else).is_some() {
             let s = self.parse_statement()?;
@@ -183,6 +222,7 @@ impl ParserContext<'_> {
 
         Ok(ConditionalStatement {
             span: &start + next.as_ref().map(|x| x.span()).unwrap_or(&body.span),
+            id: NodeID::new(),
             condition: expr,
             block: body,
             next,
@@ -207,6 +247,7 @@ impl ParserContext<'_> {
 
         Ok(IterationStatement {
             span: start_span + block.span.clone(),
+            id: NodeID::new(),
             variable: ident,
             start,
             stop,
@@ -265,10 +306,11 @@ impl ParserContext<'_> {
                 ConsoleFunction::Log(self.parse_console_args()?)
             }
         };
-        self.expect(Token::Semicolon)?;
+        self.expect_semicolon_after(function.span())?;
 
         Ok(ConsoleStatement {
             span: &keyword + function.span(),
+            id: NodeID::new(),
             function,
         })
     }
@@ -317,10 +359,11 @@ impl ParserContext<'_> {
 
         self.expect(Token::Assign)?;
         let expr = self.parse_expression()?;
-        self.expect(Token::Semicolon)?;
+        self.expect_semicolon_after(expr.span())?;
 
         Ok(DefinitionStatement {
             span: &declare.span + expr.span(),
+            id: NodeID::new(),
             declaration_type: match declare.token {
                 Token::Let => Declare::Let,
                 Token::Const => Declare::Const,
@@ -329,6 +372,7 @@ impl ParserContext<'_> {
             variable_names,
             type_,
             value: expr,
+            annotations: IndexMap::new(),
         })
     }
 }
@@ -16,6 +16,7 @@
 
 use super::*;
 use leo_errors::{ParserError, Result};
+use leo_span::sym;
 
 use smallvec::smallvec;
 
@@ -82,19 +83,37 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns the type `BoundedVec<element, capacity>` following `BoundedVec<`, spanning from `start`.
+    fn parse_bounded_vec(&mut self, start: Span) -> Result<(Type, Span)> {
+        let (element, _) = self.nested(Self::parse_type)?;
+        self.expect(Token::Comma)?;
+        let capacity = match self.eat_int() {
+            Some((capacity, _)) => capacity,
+            None => {
+                let token = self.expect_any()?;
+                return Err(ParserError::unexpected_str(&token.token, "int", &token.span).into());
+            }
+        };
+        let end = self.expect(Token::Gt)?;
+        Ok((Type::BoundedVec(Box::new(element), capacity), start + end))
+    }
+
     /// Returns a [`(Type, Span)`] tuple of AST nodes if the next token represents a type.
     /// Also returns the span of the parsed token.
     pub fn parse_type(&mut self) -> Result<(Type, Span)> {
         Ok(if let Some(token) = self.eat(Token::BigSelf) {
             (Type::SelfType, token.span)
         } else if let Some(ident) = self.eat_identifier() {
+            if ident.name == sym::BoundedVec && self.eat(Token::Lt).is_some() {
+                return self.parse_bounded_vec(ident.span);
+            }
             let span = ident.span.clone();
             (Type::Identifier(ident), span)
         } else if self.peek_is_left_par() {
-            let (types, _, span) = self.parse_paren_comma_list(|p| p.parse_type().map(|t| Some(t.0)))?;
+            let (types, _, span) = self.parse_paren_comma_list(|p| p.nested(Self::parse_type).map(|t| Some(t.0)))?;
             (Type::Tuple(types), span)
         } else if let Some(token) = self.eat(Token::LeftSquare) {
-            let (inner, _) = self.parse_type()?;
+            let (inner, _) = self.nested(Self::parse_type)?;
             self.expect(Token::Semicolon)?;
             let dimensions = self.parse_array_dimensions()?;
             let end_span = self.expect(Token::RightSquare)?;
@@ -0,0 +1,703 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Property tests checking that printed programs parse back to the programs printed.
+//!
+//! Programs are generated from the AST, restricted to what the parser accepts,
+//! printed with [`print_program()`], and parsed again. The program parsed must equal
+//! the one generated up to spans, and must print to the same source.
+
+use leo_ast::*;
+use leo_errors::emitter::Handler;
+use leo_span::{sym, symbol::create_session_if_not_set_then, Span, Symbol};
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+use serde::Serialize;
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// The names of variables, functions, and members.
+const NAMES: &[&str] = &["a", "b", "foo", "bar_1"];
+/// The names of circuits and aliases.
+const TYPE_NAMES: &[&str] = &["Foo", "Bar"];
+
+/// The binary operations the parser supports.
+const BINARY_OPERATIONS: &[BinaryOperation] = &[
+    BinaryOperation::Or,
+    BinaryOperation::And,
+    BinaryOperation::Eq,
+    BinaryOperation::Ne,
+    BinaryOperation::Lt,
+    BinaryOperation::Le,
+    BinaryOperation::Gt,
+    BinaryOperation::Ge,
+    BinaryOperation::Add,
+    BinaryOperation::Sub,
+    BinaryOperation::Mul,
+    BinaryOperation::Div,
+    BinaryOperation::Pow,
+];
+
+/// The assignment operations the parser supports.
+const ASSIGN_OPERATIONS: &[AssignOperation] = &[
+    AssignOperation::Assign,
+    AssignOperation::Add,
+    AssignOperation::Sub,
+    AssignOperation::Mul,
+    AssignOperation::Div,
+    AssignOperation::Pow,
+];
+
+fn identifier(names: &'static [&'static str]) -> impl Strategy<Value = Identifier> + Clone {
+    select(names).prop_map(|name| Identifier::new(Symbol::intern(name)))
+}
+
+fn number() -> impl Strategy<Value = String> {
+    (0u32..1000).prop_map(|number| number.to_string())
+}
+
+/// A number which may be negative, as numeric literals carry their sign.
+fn signed_number() -> impl Strategy<Value = String> {
+    (-1000i32..1000).prop_map(|number| number.to_string())
+}
+
+fn integer_type() -> impl Strategy<Value = IntegerType> {
+    use IntegerType::*;
+    select(&[U8, U16, U32, U64, U128, I8, I16, I32, I64, I128][..])
+}
+
+fn dimensions() -> impl Strategy<Value = ArrayDimensions> {
+    prop_oneof![number().prop_map(|number| vec![number]), vec(number(), 2..4)]
+        .prop_map(|values| ArrayDimensions(values.into_iter().map(|value| PositiveNumber { value }).collect()))
+}
+
+fn type_() -> impl Strategy<Value = Type> {
+    let leaf = prop_oneof![
+        Just(Type::Address),
+        Just(Type::Boolean),
+        Just(Type::Char),
+        Just(Type::Field),
+        Just(Type::Group),
+        Just(Type::SelfType),
+        integer_type().prop_map(Type::IntegerType),
+        identifier(TYPE_NAMES).prop_map(Type::Identifier),
+    ];
+    leaf.prop_recursive(3, 8, 3, |inner| {
+        prop_oneof![
+            (inner.clone(), dimensions()).prop_map(|(element, dimensions)| Type::Array(Box::new(element), dimensions)),
+            vec(inner.clone(), 0..3).prop_map(Type::Tuple),
+            (inner, number())
+                .prop_map(|(element, value)| Type::BoundedVec(Box::new(element), PositiveNumber { value })),
+        ]
+    })
+}
+
+/// Characters which are escaped in literals, or are in neither ASCII nor the Unicode scalar values.
+fn character() -> impl Strategy<Value = Char> {
+    prop_oneof![
+        select(&['a', 'é', '{', '}', '\'', '"', '\\', '\n', '\t', '\0', '\u{7f}'][..]).prop_map(Char::Scalar),
+        Just(Char::NonScalar(0xD800)),
+    ]
+}
+
+fn group_coordinate() -> impl Strategy<Value = GroupCoordinate> {
+    prop_oneof![
+        number().prop_map(|number| GroupCoordinate::Number(number, Span::default())),
+        number().prop_map(|number| GroupCoordinate::Number(format!("-{}", number), Span::default())),
+        Just(GroupCoordinate::SignHigh),
+        Just(GroupCoordinate::SignLow),
+        Just(GroupCoordinate::Inferred),
+    ]
+}
+
+fn value() -> impl Strategy<Value = ValueExpression> {
+    prop_oneof![
+        signed_number().prop_map(|number| ValueExpression::Implicit(number, Span::default())),
+        (integer_type(), signed_number()).prop_map(|(type_, number)| ValueExpression::Integer(
+            type_,
+            number,
+            Span::default()
+        )),
+        signed_number().prop_map(|number| ValueExpression::Field(number, Span::default())),
+        number().prop_map(|number| ValueExpression::Group(Box::new(GroupValue::Single(number, Span::default())))),
+        (group_coordinate(), group_coordinate()).prop_map(|(x, y)| {
+            ValueExpression::Group(Box::new(GroupValue::Tuple(GroupTuple {
+                x,
+                y,
+                span: Span::default(),
+            })))
+        }),
+        any::<bool>().prop_map(|boolean| ValueExpression::Boolean(boolean.to_string(), Span::default())),
+        character().prop_map(|character| ValueExpression::Char(CharValue {
+            character,
+            span: Span::default(),
+        })),
+        vec(character(), 0..4).prop_map(|string| ValueExpression::String(string, Span::default())),
+    ]
+}
+
+fn expression() -> impl Strategy<Value = Expression> {
+    let leaf = prop_oneof![
+        select(&["a", "b", "foo", "self", "input"][..])
+            .prop_map(|name| Expression::Identifier(Identifier::new(Symbol::intern(name)))),
+        value().prop_map(|value| Expression::Value(value.into())),
+    ];
+    leaf.prop_recursive(4, 32, 4, |inner| {
+        let access = prop_oneof![
+            (inner.clone(), inner.clone()).prop_map(|(array, index)| AccessExpression::Array(ArrayAccess {
+                array: Box::new(array),
+                index: Box::new(index),
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            (
+                inner.clone(),
+                proptest::option::of(inner.clone()),
+                proptest::option::of(inner.clone())
+            )
+                .prop_map(|(array, left, right)| AccessExpression::ArrayRange(ArrayRangeAccess {
+                    array: Box::new(array),
+                    left: left.map(Box::new),
+                    right: right.map(Box::new),
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })),
+            (inner.clone(), identifier(NAMES)).prop_map(|(inner, name)| AccessExpression::Member(MemberAccess {
+                inner: Box::new(inner),
+                name,
+                span: Span::default(),
+                id: NodeID::new(),
+                type_: None,
+            })),
+            (inner.clone(), number()).prop_map(|(tuple, value)| AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(tuple),
+                index: PositiveNumber { value },
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            (inner.clone(), identifier(NAMES)).prop_map(|(inner, name)| AccessExpression::Static(StaticAccess {
+                inner: Box::new(inner),
+                name,
+                type_: None,
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+        ];
+        let element = prop_oneof![
+            inner.clone().prop_map(SpreadOrExpression::Spread),
+            inner.clone().prop_map(SpreadOrExpression::Expression),
+        ];
+        // A single expression in parentheses is not a tuple, so tuples have no or at least two elements.
+        let tuple = prop_oneof![Just(Vec::new()), vec(inner.clone(), 2..4)];
+        let member = (identifier(NAMES), proptest::option::of(inner.clone()))
+            .prop_map(|(identifier, expression)| CircuitVariableInitializer { identifier, expression });
+
+        prop_oneof![
+            (inner.clone(), select(BINARY_OPERATIONS), inner.clone()).prop_map(|(left, op, right)| {
+                Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op,
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+            (
+                select(&[UnaryOperation::Not, UnaryOperation::Negate][..]),
+                inner.clone()
+            )
+                .prop_map(|(op, inner)| Expression::Unary(UnaryExpression {
+                    inner: Box::new(inner),
+                    op,
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })),
+            (inner.clone(), inner.clone(), inner.clone()).prop_map(|(condition, if_true, if_false)| {
+                Expression::Ternary(TernaryExpression {
+                    condition: Box::new(condition),
+                    if_true: Box::new(if_true),
+                    if_false: Box::new(if_false),
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+            (inner.clone(), type_()).prop_map(|(inner, target_type)| Expression::Cast(CastExpression {
+                inner: Box::new(inner),
+                target_type,
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            access.prop_map(Expression::Access),
+            (inner.clone(), vec(inner.clone(), 0..3)).prop_map(|(function, arguments)| {
+                Expression::Call(CallExpression {
+                    function: Box::new(function),
+                    arguments,
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+            vec(element, 0..3).prop_map(|elements| Expression::ArrayInline(ArrayInlineExpression {
+                elements,
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            (inner, dimensions()).prop_map(|(element, dimensions)| Expression::ArrayInit(ArrayInitExpression {
+                element: Box::new(element),
+                dimensions,
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            tuple.prop_map(|elements| Expression::TupleInit(TupleInitExpression {
+                elements,
+                span: Span::default(),
+                id: NodeID::new(),
+            })),
+            (identifier(TYPE_NAMES), vec(member, 0..3)).prop_map(|(name, members)| {
+                Expression::CircuitInit(CircuitInitExpression {
+                    name,
+                    members,
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+        ]
+    })
+}
+
+fn block(statements: Vec<Statement>) -> Block {
+    Block {
+        statements,
+        span: Span::default(),
+        id: NodeID::new(),
+    }
+}
+
+fn definition(declarations: &'static [Declare]) -> impl Strategy<Value = DefinitionStatement> {
+    (
+        select(declarations),
+        vec(identifier(NAMES), 1..3),
+        proptest::option::of(type_()),
+        expression(),
+    )
+        .prop_map(|(declaration_type, names, type_, value)| DefinitionStatement {
+            variable_names: names
+                .into_iter()
+                .map(|identifier| VariableName {
+                    mutable: declaration_type == Declare::Let,
+                    identifier,
+                    span: Span::default(),
+                })
+                .collect(),
+            declaration_type,
+            type_,
+            value,
+            span: Span::default(),
+            id: NodeID::new(),
+            annotations: Default::default(),
+        })
+}
+
+fn assignee() -> impl Strategy<Value = Assignee> {
+    let access = prop_oneof![
+        expression().prop_map(AssigneeAccess::ArrayIndex),
+        (proptest::option::of(expression()), proptest::option::of(expression()))
+            .prop_map(|(left, right)| AssigneeAccess::ArrayRange(left, right)),
+        number().prop_map(|value| AssigneeAccess::Tuple(PositiveNumber { value }, Span::default())),
+        identifier(NAMES).prop_map(AssigneeAccess::Member),
+    ];
+    (identifier(NAMES), vec(access, 0..3)).prop_map(|(identifier, accesses)| Assignee {
+        identifier,
+        accesses,
+        span: Span::default(),
+    })
+}
+
+fn console_args() -> impl Strategy<Value = ConsoleArgs> {
+    (vec(character(), 0..4), vec(expression(), 0..3)).prop_map(|(string, parameters)| ConsoleArgs {
+        string,
+        parameters,
+        span: Span::default(),
+    })
+}
+
+fn statement() -> impl Strategy<Value = Statement> {
+    let console = prop_oneof![
+        expression().prop_map(ConsoleFunction::Assert),
+        console_args().prop_map(ConsoleFunction::Error),
+        console_args().prop_map(ConsoleFunction::Log),
+    ];
+    let leaf = prop_oneof![
+        expression().prop_map(|expression| Statement::Return(ReturnStatement {
+            expression,
+            span: Span::default(),
+            id: NodeID::new(),
+        })),
+        definition(&[Declare::Let, Declare::Const]).prop_map(Statement::Definition),
+        (assignee(), select(ASSIGN_OPERATIONS), expression()).prop_map(|(assignee, operation, value)| {
+            Statement::Assign(Box::new(AssignStatement {
+                operation,
+                assignee,
+                value,
+                span: Span::default(),
+                id: NodeID::new(),
+            }))
+        }),
+        console.prop_map(|function| Statement::Console(ConsoleStatement {
+            function,
+            span: Span::default(),
+            id: NodeID::new(),
+        })),
+        expression().prop_map(|expression| Statement::Expression(ExpressionStatement {
+            expression,
+            span: Span::default(),
+            id: NodeID::new(),
+        })),
+    ];
+    leaf.prop_recursive(3, 16, 3, |inner| {
+        let body = vec(inner, 0..3).prop_map(block);
+        // Only blocks and other conditionals may follow `else`.
+        let next = prop_oneof![
+            body.clone().prop_map(Statement::Block),
+            (expression(), body.clone()).prop_map(|(condition, block)| {
+                Statement::Conditional(ConditionalStatement {
+                    condition,
+                    block,
+                    next: None,
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+        ];
+        prop_oneof![
+            body.clone().prop_map(Statement::Block),
+            (expression(), body.clone(), proptest::option::of(next)).prop_map(|(condition, block, next)| {
+                Statement::Conditional(ConditionalStatement {
+                    condition,
+                    block,
+                    next: next.map(Box::new),
+                    span: Span::default(),
+                    id: NodeID::new(),
+                })
+            }),
+            (identifier(NAMES), expression(), any::<bool>(), expression(), body).prop_map(
+                |(variable, start, inclusive, stop, block)| {
+                    Statement::Iteration(Box::new(IterationStatement {
+                        variable,
+                        start,
+                        stop,
+                        inclusive,
+                        block,
+                        span: Span::default(),
+                        id: NodeID::new(),
+                    }))
+                }
+            ),
+        ]
+    })
+}
+
+fn self_input() -> impl Strategy<Value = FunctionInput> {
+    prop_oneof![
+        Just(FunctionInput::SelfKeyword(SelfKeyword {
+            identifier: Identifier::new(sym::SelfLower),
+        })),
+        Just(FunctionInput::ConstSelfKeyword(ConstSelfKeyword {
+            identifier: Identifier::new(Symbol::intern("const self")),
+        })),
+        Just(FunctionInput::RefSelfKeyword(RefSelfKeyword {
+            identifier: Identifier::new(Symbol::intern("&self")),
+        })),
+    ]
+}
+
+fn input_variable() -> impl Strategy<Value = FunctionInput> {
+    (identifier(NAMES), mode(), any::<bool>(), type_()).prop_map(|(identifier, mode, const_, type_)| {
+        FunctionInput::Variable(FunctionInputVariable {
+            identifier,
+            mode,
+            const_,
+            mutable: !const_,
+            type_,
+            span: Span::default(),
+        })
+    })
+}
+
+/// Generates the visibility modifier of a function or circuit, if any.
+fn visibility() -> impl Strategy<Value = Option<Visibility>> {
+    proptest::option::of(select(&[Visibility::Public, Visibility::Private][..]))
+}
+
+/// Generates the mode of an input or output, if any.
+fn mode() -> impl Strategy<Value = Option<Mode>> {
+    proptest::option::of(select(&[Mode::Constant, Mode::Private, Mode::Public][..]))
+}
+
+/// Generates a function, which takes a `self` parameter if `with_self` is `true`.
+fn function(with_self: bool) -> impl Strategy<Value = Function> {
+    let docs = vec(
+        select(&["Returns a value.", "", "  Indented."][..]).prop_map(String::from),
+        0..3,
+    );
+    let annotation = (
+        select(&["test", "inline", "foo"][..]),
+        vec(select(NAMES), 0..3),
+        vec((select(NAMES), "[a-z ]{0,5}"), 0..2),
+    )
+        .prop_map(|(name, arguments, values)| Annotation {
+            span: Span::default(),
+            name: Identifier::new(Symbol::intern(name)),
+            arguments: arguments.into_iter().map(Symbol::intern).collect(),
+            values: values
+                .into_iter()
+                .map(|(name, value)| (Symbol::intern(name), value))
+                .collect(),
+        });
+    let receiver = if with_self {
+        proptest::option::of(self_input()).boxed()
+    } else {
+        Just(None).boxed()
+    };
+    (
+        docs,
+        vec(annotation, 0..3),
+        visibility(),
+        identifier(NAMES),
+        receiver,
+        vec(input_variable(), 0..3),
+        any::<bool>(),
+        proptest::option::of((mode(), type_())),
+        vec(statement(), 0..4),
+    )
+        .prop_map(
+            |(docs, annotations, visibility, identifier, receiver, inputs, const_, output, statements)| Function {
+                docs,
+                annotations: annotations
+                    .into_iter()
+                    .map(|annotation| (annotation.name.name, annotation))
+                    .collect(),
+                visibility,
+                identifier,
+                input: receiver.into_iter().chain(inputs).collect(),
+                const_,
+                output_mode: output.as_ref().and_then(|(mode, _)| *mode),
+                output: output.map(|(_, type_)| type_),
+                core_mapping: Default::default(),
+                block: block(statements),
+                span: Span::default(),
+            },
+        )
+}
+
+fn circuit() -> impl Strategy<Value = Circuit> {
+    let docs = vec(select(&["A circuit.", ""][..]).prop_map(String::from), 0..2);
+    let consts = vec((identifier(NAMES), type_(), expression()), 0..2);
+    let variables = vec((identifier(NAMES), type_()), 0..3);
+    let functions = vec(function(true), 0..2);
+    // Members are ordered constants, variables, and then functions, as the parser requires.
+    (docs, visibility(), identifier(TYPE_NAMES), consts, variables, functions).prop_map(
+        |(docs, visibility, circuit_name, consts, variables, functions)| Circuit {
+            docs,
+            visibility,
+            circuit_name,
+            members: consts
+                .into_iter()
+                .map(|(name, type_, value)| CircuitMember::CircuitConst(name, type_, value))
+                .chain(
+                    variables
+                        .into_iter()
+                        .map(|(name, type_)| CircuitMember::CircuitVariable(name, type_)),
+                )
+                .chain(
+                    functions
+                        .into_iter()
+                        .map(|function| CircuitMember::CircuitFunction(Box::new(function))),
+                )
+                .collect(),
+        },
+    )
+}
+
+fn import_tree() -> impl Strategy<Value = ImportTree> {
+    let base = vec(identifier(NAMES), 1..3);
+    let leaf = prop_oneof![
+        base.clone().prop_map(|base| ImportTree {
+            base,
+            kind: ImportTreeKind::Glob { span: Span::default() },
+            span: Span::default(),
+        }),
+        (base.clone(), proptest::option::of(identifier(NAMES))).prop_map(|(base, alias)| ImportTree {
+            base,
+            kind: ImportTreeKind::Leaf { alias },
+            span: Span::default(),
+        }),
+    ];
+    leaf.prop_recursive(2, 8, 3, move |inner| {
+        (base.clone(), vec(inner, 1..3)).prop_map(|(base, tree)| ImportTree {
+            base,
+            kind: ImportTreeKind::Nested { tree },
+            span: Span::default(),
+        })
+    })
+}
+
+fn program() -> impl Strategy<Value = Program> {
+    let imports = vec(
+        import_tree().prop_map(|tree| ImportStatement {
+            tree,
+            span: Span::default(),
+        }),
+        0..2,
+    );
+    let aliases = vec(
+        (identifier(TYPE_NAMES), type_()).prop_map(|(name, represents)| Alias {
+            name,
+            span: Span::default(),
+            represents,
+        }),
+        0..2,
+    );
+    let global_consts = vec(definition(&[Declare::Const]), 0..2);
+    (
+        imports,
+        aliases,
+        global_consts,
+        vec(circuit(), 0..2),
+        vec(function(false), 1..3),
+    )
+        .prop_map(|(imports, aliases, global_consts, circuits, functions)| {
+            let mut program = Program::new(String::new());
+            program.import_statements = imports;
+            program.aliases = aliases.into_iter().map(|alias| (alias.name.clone(), alias)).collect();
+            program.global_consts = global_consts
+                .into_iter()
+                .map(|definition| {
+                    let names = definition
+                        .variable_names
+                        .iter()
+                        .map(|name| name.identifier.clone())
+                        .collect::<Vec<_>>();
+                    (names, definition)
+                })
+                .collect();
+            program.circuits = circuits
+                .into_iter()
+                .map(|circuit| (circuit.circuit_name.clone(), circuit))
+                .collect();
+            program.functions = functions
+                .into_iter()
+                .map(|function| (function.identifier.clone(), function))
+                .collect();
+            program
+        })
+}
+
+/// Returns the JSON of `node` without spans, and with identifiers replaced by their names.
+fn without_spans(node: &impl Serialize) -> Value {
+    fn strip(value: Value) -> Value {
+        match value {
+            Value::Object(object) if object.contains_key("line_start") => Value::Null,
+            Value::Object(object) => Value::Object(
+                object
+                    .into_iter()
+                    .map(|(key, value)| (identifier_name(key), strip(value)))
+                    .collect(),
+            ),
+            Value::Array(values) => Value::Array(values.into_iter().map(strip).collect()),
+            Value::String(string) => Value::String(identifier_name(string)),
+            value => value,
+        }
+    }
+
+    /// Identifiers serialize to a string of JSON with their name and span.
+    fn identifier_name(string: String) -> String {
+        match serde_json::from_str::<Value>(&string) {
+            Ok(Value::Object(object)) if object.contains_key("span") => match object.get("name") {
+                Some(Value::String(name)) => name.clone(),
+                _ => string,
+            },
+            _ => string,
+        }
+    }
+
+    strip(serde_json::to_value(node).unwrap())
+}
+
+/// Prints `program`, and checks that parsing the output gives back `program`, which prints the same.
+fn round_trips(program: &Program) -> Result<(), TestCaseError> {
+    let source = print_program(program);
+    let (handler, buffer) = Handler::new_with_buf();
+    let parsed = crate::parse(&handler, "round_trip", &source)
+        .map_err(|error| TestCaseError::fail(format!("{}\n{}\nwhen parsing:\n{}", error, buffer.extract(), source)))?;
+
+    prop_assert_eq!(
+        without_spans(&parsed),
+        without_spans(program),
+        "when parsing:\n{}",
+        source
+    );
+    prop_assert_eq!(print_program(&parsed), source);
+    Ok(())
+}
+
+#[test]
+fn printed_programs_parse_back() {
+    create_session_if_not_set_then(|_| {
+        let mut runner = TestRunner::new(Config {
+            cases: 256,
+            failure_persistence: None,
+            ..Config::default()
+        });
+        runner.run(&program(), |program| round_trips(&program)).unwrap();
+    });
+}
+
+/// Prints each program of the compiler tests which parses, and checks that it parses back.
+#[test]
+fn printed_compiler_tests_parse_back() {
+    fn visit(path: &Path, programs: &mut usize) {
+        for entry in fs::read_dir(path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, programs);
+            } else if path.extension().map_or(false, |extension| extension == "leo") {
+                let source = fs::read_to_string(&path).unwrap();
+                let (handler, _) = Handler::new_with_buf();
+                let program = match crate::parse(&handler, &path.to_string_lossy(), &source) {
+                    Ok(program) => program,
+                    Err(_) => continue,
+                };
+
+                if let Err(error) = round_trips(&program) {
+                    panic!("{:?} does not round trip: {}", path, error);
+                }
+                *programs += 1;
+            }
+        }
+    }
+
+    create_session_if_not_set_then(|_| {
+        let mut programs = 0;
+        visit(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/compiler"),
+            &mut programs,
+        );
+        assert!(programs > 0);
+    });
+}
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{tokenizer, ParserContext, SpannedToken};
-use leo_ast::{Expression, ExpressionStatement, Statement, ValueExpression};
+use leo_ast::{Expression, ExpressionStatement, NodeID, Statement, ValueExpression};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{symbol::create_session_if_not_set_then, Span};
 use leo_test_framework::{
@@ -24,6 +24,7 @@ use leo_test_framework::{
 };
 use serde::Serialize;
 use serde_yaml::Value;
+use std::{fs, path::Path};
 use tokenizer::Token;
 
 struct TokenNamespace;
@@ -76,7 +77,7 @@ fn with_handler<T>(
 }
 
 fn implicit_value_expr() -> Expression {
-    Expression::Value(ValueExpression::Implicit("".into(), Span::default()))
+    Expression::Value(ValueExpression::Implicit("".into(), Span::default()).into())
 }
 
 fn tokenize(test: Test) -> Result<Vec<SpannedToken>, String> {
@@ -125,6 +126,7 @@ impl Namespace for ParseImportNamespace {
                 return Ok(yaml_or_fail(Statement::Expression(ExpressionStatement {
                     expression: implicit_value_expr(),
                     span: Span::default(),
+                    id: NodeID::new(),
                 })));
             }
             with_handler(tokenizer, |p| p.parse_import_statement()).map(yaml_or_fail)
@@ -146,6 +148,7 @@ impl Namespace for ParseStatementNamespace {
                 return Ok(yaml_or_fail(Statement::Expression(ExpressionStatement {
                     expression: implicit_value_expr(),
                     span: Span::default(),
+                    id: NodeID::new(),
                 })));
             }
             with_handler(tokenizer, |p| p.parse_statement()).map(yaml_or_fail)
@@ -153,6 +156,20 @@ impl Namespace for ParseStatementNamespace {
     }
 }
 
+struct ParseReplStatementNamespace;
+
+impl Namespace for ParseReplStatementNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Line
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|_| {
+            with_handler(tokenize(test)?, |p| p.parse_repl_statement()).map(yaml_or_fail)
+        })
+    }
+}
+
 struct ParseNamespace;
 
 impl Namespace for ParseNamespace {
@@ -251,6 +268,7 @@ impl Runner for TestRunner {
             "ParseImport" => Box::new(ParseImportNamespace),
             "ParseExpression" => Box::new(ParseExpressionNamespace),
             "ParseStatement" => Box::new(ParseStatementNamespace),
+            "ParseReplStatement" => Box::new(ParseReplStatementNamespace),
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
             "Token" => Box::new(TokenNamespace),
@@ -263,3 +281,159 @@ impl Runner for TestRunner {
 pub fn parser_tests() {
     leo_test_framework::run_tests(&TestRunner, "parser");
 }
+
+/// Parses each program of the compiler tests which parses, and checks that the program read back
+/// from its versioned JSON writes exactly the same JSON, so that the format is canonical.
+#[test]
+pub fn ast_json_round_trips() {
+    fn visit(path: &Path, programs: &mut usize) {
+        for entry in fs::read_dir(path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                visit(&path, programs);
+            } else if path.extension().map_or(false, |extension| extension == "leo") {
+                let source = fs::read_to_string(&path).unwrap();
+                let (handler, _) = Handler::new_with_buf();
+                let program = match crate::parse_ast(&handler, path.to_string_lossy(), source) {
+                    Ok(ast) => ast.into_repr(),
+                    Err(_) => continue,
+                };
+
+                let json = leo_ast::to_json(&program).unwrap();
+                let read = leo_ast::from_json(&json).unwrap();
+                assert_eq!(leo_ast::to_json(&read).unwrap(), json, "{:?} is not canonical", path);
+                *programs += 1;
+            }
+        }
+    }
+
+    create_session_if_not_set_then(|_| {
+        let mut programs = 0;
+        visit(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/compiler"),
+            &mut programs,
+        );
+        assert!(programs > 0);
+    });
+}
+
+#[test]
+pub fn rejects_deep_nesting() {
+    // Run on a thread with a large stack, as unoptimized parsing uses a lot of stack per nesting level.
+    std::thread::Builder::new()
+        .stack_size(64 << 20)
+        .spawn(|| {
+            create_session_if_not_set_then(|_| {
+                let cases = [
+                    ("", "(", "1u8", ")", ";"),
+                    ("", "{ ", "", "} ", ""),
+                    ("let x: ", "[", "u8", "; 1]", " = 0;"),
+                    ("return ", "1u8 ** ", "1u8", "", ";"),
+                    ("return ", "true ? 1u8 : ", "1u8", "", ";"),
+                ];
+                for (prefix, open, inner, close, suffix) in cases {
+                    let source = |depth: usize| {
+                        format!(
+                            "function main() {{ {}{}{}{}{} }}",
+                            prefix,
+                            open.repeat(depth),
+                            inner,
+                            close.repeat(depth),
+                            suffix
+                        )
+                    };
+                    // The errors of parsing `source` at `depth`, including those the parser recovered from.
+                    let errors = |depth: usize| {
+                        let (handler, buffer) = Handler::new_with_buf();
+                        let result = crate::parse(&handler, "test", &source(depth));
+                        let mut errors: Vec<String> =
+                            buffer.extract().into_inner().iter().map(|e| e.to_string()).collect();
+                        errors.extend(result.err().map(|e| e.to_string()));
+                        errors
+                    };
+                    let too_deep = |errors: &[String]| errors.iter().any(|e| e.contains("nested more than"));
+
+                    assert!(!too_deep(&errors(crate::MAX_NESTING_DEPTH / 2)));
+                    let errors = errors(crate::MAX_NESTING_DEPTH * 4);
+                    assert!(too_deep(&errors), "{:?}", errors);
+                }
+            })
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+pub fn fuzz_entry_points_do_not_panic() {
+    let inputs: &[&[u8]] = &[
+        b"",
+        b"\xff\xfe",
+        b"function",
+        b"function main(",
+        b"'\\u{110000}'",
+        b"\"\\x",
+        b"/*",
+        b"circuit A { function f(self) { return self.",
+        b"[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[",
+        b"[main]\na: [[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[[u8",
+    ];
+    for input in inputs {
+        crate::fuzz::tokenize_fuzz(input);
+        crate::fuzz::parse_program_fuzz(input);
+        crate::fuzz::parse_input_fuzz(input);
+    }
+}
+
+#[test]
+pub fn desugars_chained_comparisons() {
+    create_session_if_not_set_then(|_| {
+        // The program returning `body`, or the errors emitted while parsing it,
+        // with the messages of the warnings raised while parsing it.
+        let parse = |annotation: &str, body: &str| {
+            let (handler, buffer) = Handler::new_with_buf();
+            let source = format!(
+                "{}\nfunction main(a: u8, b: u8, c: u8) -> bool {{ return {}; }}",
+                annotation, body
+            );
+            let program = crate::parse(&handler, "test", &source).map_err(|_| buffer.extract().to_string());
+            let warnings: Vec<String> = buffer
+                .extract_warnings()
+                .into_inner()
+                .into_iter()
+                .map(|w| w.message)
+                .collect();
+            (program, warnings)
+        };
+        // The expression returned by `main`.
+        let returned = |program: leo_ast::Program| {
+            let main = program.functions.values().next().unwrap();
+            match &main.block.statements[0] {
+                Statement::Return(statement) => statement.expression.to_string(),
+                statement => panic!("unexpected statement {}", statement),
+            }
+        };
+
+        let (program, warnings) = parse("", "a < b <= c");
+        assert_eq!(returned(program.unwrap()), "a < b && b <= c");
+        assert_eq!(warnings, ["comparisons are chained"]);
+
+        let (program, warnings) = parse("", "0u8 < a + 1u8 > b < c");
+        assert_eq!(returned(program.unwrap()), "0u8 < a + 1u8 && a + 1u8 > b && b < c");
+        assert_eq!(warnings.len(), 2);
+
+        // Comparisons of different precedences are not chained.
+        let (program, warnings) = parse("", "a < b == b > c");
+        assert_eq!(returned(program.unwrap()), "a < b == b > c");
+        assert!(warnings.is_empty());
+
+        let (program, warnings) = parse("@allow(chained_comparisons)", "a < b < c");
+        assert_eq!(returned(program.unwrap()), "a < b && b < c");
+        assert!(warnings.is_empty());
+
+        let (program, _) = parse("", "a < main(a, b, c) < c");
+        assert!(program
+            .unwrap_err()
+            .contains("comparisons cannot be chained through `main(a, b, c)`"));
+    })
+}
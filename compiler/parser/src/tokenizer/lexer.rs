@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::tokenizer::{Char, Token};
+use leo_ast::IntegerType;
 use leo_errors::{ParserError, Result};
 use leo_span::{Span, Symbol};
 
@@ -22,6 +23,56 @@ use serde::{Deserialize, Serialize};
 
 use std::{fmt, iter::Peekable};
 
+/// Returns the integer type named `name`, if any.
+fn integer_type(name: &str) -> Option<IntegerType> {
+    Some(match name {
+        "u8" => IntegerType::U8,
+        "u16" => IntegerType::U16,
+        "u32" => IntegerType::U32,
+        "u64" => IntegerType::U64,
+        "u128" => IntegerType::U128,
+        "i8" => IntegerType::I8,
+        "i16" => IntegerType::I16,
+        "i32" => IntegerType::I32,
+        "i64" => IntegerType::I64,
+        "i128" => IntegerType::I128,
+        _ => return None,
+    })
+}
+
+/// Returns `true` if the `input` following a hexadecimal digit starts with a type suffix rather than more digits,
+/// e.g., `field` in `0x1Ffield`.
+fn starts_type_suffix(input: impl Iterator<Item = char>) -> bool {
+    let word: String = input.take_while(|c| c.is_ascii_alphanumeric()).collect();
+    integer_type(&word).is_some() || word == "field" || word == "group"
+}
+
+/// Returns the decimal digits of the number whose `digits` are in base `radix`.
+/// The number may be larger than any integer type, e.g., a field element.
+fn to_decimal(digits: &str, radix: u32) -> String {
+    const LIMB: u64 = 1_000_000_000;
+
+    // The number in base `LIMB`, least significant limb first.
+    let mut limbs = vec![0u64];
+    for digit in digits.chars().filter_map(|c| c.to_digit(radix)) {
+        let mut carry = digit as u64;
+        for limb in limbs.iter_mut() {
+            let value = *limb * radix as u64 + carry;
+            *limb = value % LIMB;
+            carry = value / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+    }
+
+    let mut decimal = limbs.pop().unwrap_or_default().to_string();
+    for limb in limbs.iter().rev() {
+        decimal.push_str(&format!("{:09}", limb));
+    }
+    decimal
+}
+
 ///
 /// Returns a new `StrTendril` string if an identifier can be eaten, otherwise returns [`None`].
 /// An identifier can be eaten if its bytes are at the front of the given `input_tendril` string.
@@ -156,23 +207,68 @@ impl Token {
     /// Returns a tuple: [(integer length, integer token)] if an integer can be eaten, otherwise returns [`None`].
     /// An integer can be eaten if its bytes are at the front of the given `input_tendril` string.
     ///
-    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char>>) -> Result<(usize, Token)> {
-        if input.peek().is_none() {
-            return Err(ParserError::lexer_empty_input_tendril().into());
+    /// Binary, octal, and hexadecimal integers, e.g., `0b1010`, `0o17`, and `0x1F`, and integers with digits
+    /// separated by underscores, e.g., `1_000_000`, are turned into the decimal digits of their value.
+    ///
+    fn eat_integer(input: &mut Peekable<impl Iterator<Item = char> + Clone>) -> Result<(usize, Token)> {
+        let first = match input.next() {
+            Some(first) => first,
+            None => return Err(ParserError::lexer_empty_input_tendril().into()),
+        };
+
+        let mut int = first.to_string();
+        let (radix, base) = match (first, input.peek()) {
+            ('0', Some('b')) => (2, "binary"),
+            ('0', Some('o')) => (8, "octal"),
+            ('0', Some('x')) => (16, "hexadecimal"),
+            _ => (10, "decimal"),
+        };
+        if radix != 10 {
+            int.push(input.next().unwrap());
         }
 
-        let mut int = String::new();
-        while let Some(c) = input.next_if(|c| c.is_ascii_digit()) {
-            if c == '0' && matches!(input.peek(), Some('x')) {
-                int.push(c);
-                int.push(input.next().unwrap());
-                return Err(ParserError::lexer_hex_number_provided(int).into());
+        // Hexadecimal digits include letters, so the suffix of a hexadecimal integer starts at the first other letter,
+        // or at a letter which starts a type suffix.
+        loop {
+            match input.peek().copied() {
+                Some(c) if c == '_' || c.is_ascii_digit() => {}
+                Some(c) if radix == 16 && c.is_ascii_hexdigit() && !starts_type_suffix(input.clone()) => {}
+                _ => break,
             }
+            int.push(input.next().unwrap());
+        }
+
+        let prefix_len = if radix == 10 { 0 } else { 2 };
+        let digits: String = int[prefix_len..].chars().filter(|c| c != &'_').collect();
+        if digits.is_empty() {
+            return Err(ParserError::lexer_missing_digits(int).into());
+        }
+        let written = &int[prefix_len..];
+        if written.starts_with('_') || written.ends_with('_') || written.contains("__") {
+            return Err(ParserError::lexer_misplaced_underscore(int).into());
+        }
+        if let Some(digit) = digits.chars().find(|c| !c.is_digit(radix)) {
+            return Err(ParserError::lexer_invalid_digit(digit, base, int).into());
+        }
+        if radix == 10 {
+            return Ok((int.len(), Token::Int(digits)));
+        }
 
-            int.push(c);
+        // The value of a decimal integer is checked when it is evaluated, but the error would then show
+        // the decimal value instead of the integer as written, so the others are checked against their suffix here.
+        let suffix: String = input.take_while(|c| c.is_ascii_alphanumeric() || c == &'_').collect();
+        if let Some(type_) = integer_type(&suffix) {
+            let max = match type_.is_signed() {
+                // The magnitude of the minimum, as the integer may be negated.
+                true => 1u128 << (type_.bits() - 1),
+                false => u128::MAX >> (128 - type_.bits()),
+            };
+            if !matches!(u128::from_str_radix(&digits, radix), Ok(value) if value <= max) {
+                return Err(ParserError::lexer_integer_out_of_range(int, type_).into());
+            }
         }
 
-        Ok((int.len(), Token::Int(int)))
+        Ok((int.len(), Token::Int(to_decimal(&digits, radix))))
     }
 
     ///
@@ -456,6 +552,7 @@ impl Token {
     }
 }
 
+/// A token with the span of the source it was lexed from.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SpannedToken {
     pub token: Token,
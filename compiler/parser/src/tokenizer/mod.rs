@@ -32,21 +32,91 @@ use leo_errors::{ParserError, Result};
 use leo_span::Span;
 
 /// Creates a new vector of spanned tokens from a given file path and source code text.
-pub(crate) fn tokenize(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
+pub fn tokenize(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
+    Ok(tokenize_with_source(path, input)?
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect())
+}
+
+/// Creates a new vector of spanned tokens from a given file path and source code text,
+/// each with the source text it was lexed from.
+/// Comments are kept as tokens, while whitespace is dropped.
+pub fn tokenize_with_source<'a>(path: &str, input: &'a str) -> Result<Vec<(SpannedToken, &'a str)>> {
+    lex(path, input, false)
+}
+
+/// Creates a new vector of spanned tokens from a given file path and source code text,
+/// each with the source text it was lexed from, for tools such as formatters and highlighters.
+/// Comments are kept as tokens, and each run of whitespace is a [`Token::WhiteSpace`],
+/// so the source texts of the tokens add up to `input`.
+pub fn tokenize_lossless<'a>(path: &str, input: &'a str) -> Result<Vec<(SpannedToken, &'a str)>> {
+    lex(path, input, true)
+}
+
+/// Returns the content of the line of `input` starting at `line_start`,
+/// reusing the content in `line` if it is for the same line.
+fn line_content(input: &str, line_start: usize, line: &mut Option<(usize, Arc<str>)>) -> Arc<str> {
+    match line {
+        Some((start, content)) if *start == line_start => content.clone(),
+        _ => {
+            let line_stop = input[line_start..]
+                .find('\n')
+                .map(|i| i + line_start)
+                .unwrap_or(input.len());
+            let content: Arc<str> = input[line_start..line_stop].into();
+            *line = Some((line_start, content.clone()));
+            content
+        }
+    }
+}
+
+/// Lexes `input` into spanned tokens with their source text, keeping runs of whitespace if `keep_whitespace`.
+fn lex<'a>(path: &str, input: &'a str, keep_whitespace: bool) -> Result<Vec<(SpannedToken, &'a str)>> {
     let path = Arc::new(path.to_string());
     let mut tokens = vec![];
     let mut index = 0usize;
     let mut line_no = 1usize;
     let mut line_start = 0usize;
-    while input.len() > index {
+    // The content of the current line, shared by the spans of its tokens, and the index it starts at.
+    let mut line: Option<(usize, Arc<str>)> = None;
+    // The index, line number, and line start of the run of whitespace being lexed, if any.
+    let mut whitespace: Option<(usize, usize, usize)> = None;
+    loop {
+        if let Some((start, start_line_no, start_line_start)) = whitespace {
+            if input.len() <= index || !input[index..].starts_with(|c: char| c.is_ascii_whitespace()) {
+                let span = Span::new(
+                    start_line_no,
+                    line_no,
+                    start - start_line_start + 1,
+                    index - line_start + 1,
+                    path.clone(),
+                    line_content(input, start_line_start, &mut line),
+                );
+                tokens.push((
+                    SpannedToken {
+                        token: Token::WhiteSpace,
+                        span,
+                    },
+                    &input[start..index],
+                ));
+                whitespace = None;
+            }
+        }
+        if input.len() <= index {
+            break;
+        }
         match Token::eat(&input[index..])? {
             (token_len, Token::WhiteSpace) => {
+                if keep_whitespace && whitespace.is_none() {
+                    whitespace = Some((index, line_no, line_start));
+                }
                 let bytes = input.as_bytes();
                 if bytes[index] == 0x000D && matches!(bytes.get(index + 1), Some(0x000A)) {
                     // Check carriage return followed by newline.
                     line_no += 1;
-                    line_start = index + token_len;
                     index += token_len;
+                    line_start = index + token_len;
                 } else if matches!(bytes[index], 0x000A | 0x000D) {
                     // Check new-line or carriage-return
                     line_no += 1;
@@ -55,18 +125,14 @@ pub(crate) fn tokenize(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
                 index += token_len;
             }
             (token_len, token) => {
+                let content = line_content(input, line_start, &mut line);
                 let mut span = Span::new(
                     line_no,
                     line_no,
                     index - line_start + 1,
                     index - line_start + token_len + 1,
                     path.clone(),
-                    input[line_start
-                        ..input[line_start..]
-                            .find('\n')
-                            .map(|i| i + line_start)
-                            .unwrap_or(input.len())]
-                        .to_string(),
+                    content,
                 );
                 match &token {
                     Token::CommentLine(_) => {
@@ -90,7 +156,7 @@ pub(crate) fn tokenize(path: &str, input: &str) -> Result<Vec<SpannedToken>> {
                     }
                     _ => (),
                 }
-                tokens.push(SpannedToken { token, span });
+                tokens.push((SpannedToken { token, span }, &input[index..index + token_len]));
                 index += token_len;
             }
         }
@@ -244,4 +310,86 @@ ppp            test
             }
         })
     }
+
+    #[test]
+    fn test_lossless() {
+        create_session_if_not_set_then(|_| {
+            let source = "function main() {\n    // test\n    return 1u8; /* test\n test */ \r\n}\n";
+            let tokens = tokenize_lossless("test_path", source).unwrap();
+            assert_eq!(tokens.iter().map(|(_, raw)| *raw).collect::<String>(), source);
+
+            let whitespace: Vec<_> = tokens
+                .iter()
+                .filter(|(token, _)| token.token == Token::WhiteSpace)
+                .map(|(SpannedToken { span, .. }, raw)| {
+                    (*raw, span.line_start, span.col_start, span.line_stop, span.col_stop)
+                })
+                .collect();
+            assert_eq!(
+                whitespace,
+                vec![
+                    (" ", 1, 9, 1, 10),
+                    (" ", 1, 16, 1, 17),
+                    ("\n    ", 1, 18, 2, 5),
+                    ("    ", 3, 1, 3, 5),
+                    (" ", 3, 11, 3, 12),
+                    (" ", 3, 16, 3, 17),
+                    (" \r\n", 4, 9, 5, 1),
+                    ("\n", 5, 2, 6, 1),
+                ]
+            );
+
+            // Without whitespace, the tokens and their spans are the same.
+            let without_whitespace = |tokens: &[(SpannedToken, &str)]| -> Vec<String> {
+                tokens
+                    .iter()
+                    .filter(|(token, _)| token.token != Token::WhiteSpace)
+                    .map(|(token, raw)| format!("{:?} {:?}", token, raw))
+                    .collect()
+            };
+            assert_eq!(
+                without_whitespace(&tokens),
+                without_whitespace(&tokenize_with_source("test_path", source).unwrap())
+            );
+        })
+    }
+
+    #[test]
+    fn test_integers() {
+        create_session_if_not_set_then(|_| {
+            let tokens = tokenize_with_source(
+                "test_path",
+                "1_000_000u64 0x1Fu8 0o17 0b1010i8 0x1_0000_0000_0000_0000_0000_0000_0000_0000",
+            )
+            .unwrap();
+            let output: Vec<String> = tokens
+                .iter()
+                .map(|(SpannedToken { token, .. }, raw)| format!("{} {}", raw, token))
+                .collect();
+            assert_eq!(
+                output,
+                vec![
+                    "1_000_000 1000000",
+                    "u64 u64",
+                    "0x1F 31",
+                    "u8 u8",
+                    "0o17 15",
+                    "0b1010 10",
+                    "i8 i8",
+                    "0x1_0000_0000_0000_0000_0000_0000_0000_0000 340282366920938463463374607431768211456",
+                ]
+            );
+
+            for (source, error) in [
+                ("0x", "The number `0x` has no digits after its prefix."),
+                ("0b102", "`2` is not a digit of the binary number `0b102`."),
+                ("0x100u8", "The number `0x100` does not fit in the type `u8`."),
+                ("0x81i8", "The number `0x81` does not fit in the type `i8`."),
+            ] {
+                let message = tokenize("test_path", source.into()).unwrap_err().to_string();
+                assert!(message.contains(error), "{}", message);
+            }
+            assert!(tokenize("test_path", "0x80i8 0xffu8".into()).is_ok());
+        })
+    }
 }
@@ -14,20 +14,60 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-// Currently does not support crate `leo-compiler` because it has a dependency in its tree
-// which is not wasm compatible. All compiler passes (such as TypeInference)
+//! WASM bindings for the Leo compiler.
+//!
+//! The compiler runs on the source of a program, without reading or writing any file,
+//! so that it can be embedded in browsers, e.g., in the playground.
 
-use leo_ast::AstPass;
-use leo_errors::LeoError;
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_errors::{
+    emitter::{json_diagnostic, json_warning, BufferEmitter, Handler},
+    LeoError,
+};
+use leo_span::symbol::create_session_if_not_set_then;
 
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(typescript_custom_section)]
 const TS_APPEND_CONTENT: &'static str = r#"
 export interface LeoError { text: string, code: string, exitCode: number }
+export interface CompileOptions { path?: string, denyWarnings?: boolean, optimize?: boolean }
+export interface CompileResult { ast: object | null, diagnostics: object[] }
 "#;
 
+/// The options of [`compile()`], all of which may be omitted.
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CompileOptions {
+    /// The path of the program in the spans of its diagnostics.
+    pub path: String,
+    /// Whether warnings are reported as errors.
+    pub deny_warnings: bool,
+    /// Whether the optimizations are run. If not, the program is only checked.
+    pub optimize: bool,
+}
+
+impl Default for CompileOptions {
+    fn default() -> Self {
+        Self {
+            path: "main.leo".to_string(),
+            deny_warnings: false,
+            optimize: true,
+        }
+    }
+}
+
+/// The result of [`compile()`].
+#[derive(Debug, Serialize)]
+pub struct CompileResult {
+    /// The AST of the compiled program, or `None` if it has errors.
+    pub ast: Option<serde_json::Value>,
+    /// The errors and warnings of the program, as JSON diagnostics in the format of `leo build --json-diagnostics`.
+    pub diagnostics: Vec<serde_json::Value>,
+}
+
 /// Publicly accessible method.
 /// Parse the code and return an AST as JSON or an error object.
 #[wasm_bindgen(method, catch)]
@@ -35,14 +75,59 @@ pub fn parse(program: &str) -> Result<String, JsValue> {
     parse_program(program).map_err(error_to_value)
 }
 
-/// Parse the program and pass the Canonicalization phase;
-/// Asg is useless without compiler passes, so we need to add them once the compatibility problem in
-/// snarkvm is solved.
+/// Publicly accessible method.
+/// Compile the code with `options`, a `CompileOptions` object or `undefined`, and return a `CompileResult` object.
+#[wasm_bindgen]
+pub fn compile(source: &str, options: JsValue) -> Result<JsValue, JsValue> {
+    let options = if options.is_undefined() || options.is_null() {
+        CompileOptions::default()
+    } else {
+        options
+            .into_serde()
+            .map_err(|e| JsValue::from_str(&format!("invalid compile options: {}", e)))?
+    };
+    JsValue::from_serde(&compile_program(source, &options)).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Parse the program into its AST, as JSON.
 fn parse_program(program: &str) -> leo_errors::Result<String> {
-    let ast = leo_parser::parse_ast("", program)?;
-    let ast = leo_ast_passes::Canonicalizer::do_pass(ast.into_repr())?.to_json_string()?;
+    create_session_if_not_set_then(|_| {
+        Handler::with(|handler| leo_parser::parse_ast(handler, "", program)?.to_json_string())
+            .map_err(|errors| errors.into_inner().remove(0))
+    })
+}
+
+/// Compiles the program `source` with `options`, collecting its diagnostics instead of printing them.
+pub fn compile_program(source: &str, options: &CompileOptions) -> CompileResult {
+    let buf = BufferEmitter::new();
+    let handler = Handler::new(Box::new(buf.clone()))
+        .with_deny_warnings(options.deny_warnings)
+        .with_sorted_diagnostics();
 
-    Ok(ast)
+    // The AST refers to the symbols of the session, so it is converted to JSON before the session ends.
+    let ast = create_session_if_not_set_then(|_| {
+        let ast = if options.optimize {
+            Compiler::compile_source(&handler, &options.path, source, &CompilerOptions::default())
+        } else {
+            Compiler::check_source(&handler, &options.path, source)
+        };
+        handler
+            .extend_if_error(ast)
+            .ok()
+            .and_then(|ast| ast.to_json_value().ok())
+    });
+    handler.flush();
+
+    let errors = buf.extract().into_inner();
+    let warnings = buf.extract_warnings().into_inner();
+    CompileResult {
+        ast,
+        diagnostics: errors
+            .iter()
+            .map(json_diagnostic)
+            .chain(warnings.iter().map(json_warning))
+            .collect(),
+    }
 }
 
 /// Make a pretty-print JS object for the thrown error.
@@ -54,3 +139,41 @@ fn error_to_value(err: LeoError) -> JsValue {
     }))
     .expect("Unable to create an error object from JSON")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiles_programs() {
+        let source = "function main(a: u8) -> u8 { let b = 1u8; return a * 2u8; }";
+        let result = compile_program(source, &CompileOptions::default());
+        let ast = result.ast.unwrap();
+        assert!(ast["functions"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .any(|name| name.contains("main")));
+        // The unused variable `b` is reported, but does not fail the compilation.
+        assert_eq!(result.diagnostics.len(), 1);
+
+        let options = CompileOptions {
+            deny_warnings: true,
+            optimize: false,
+            ..CompileOptions::default()
+        };
+        let result = compile_program(source, &options);
+        assert!(result.ast.is_none());
+        assert!(!result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reports_syntax_errors() {
+        let result = compile_program("function main( -> u8 { return 1u8; }", &CompileOptions::default());
+        assert!(result.ast.is_none());
+        assert!(result.diagnostics[0]["code"]["code"]
+            .as_str()
+            .unwrap()
+            .starts_with("EPAR"));
+    }
+}
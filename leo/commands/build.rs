@@ -14,29 +14,70 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{commands::Command, context::Context};
-use leo_compiler::{Ast, Compiler};
-use leo_errors::{CliError, Result};
+use crate::{api::Fetch, commands::Command, context::Context};
+use leo_compiler::{trace_spans, Ast, Compiler, CompilerOptions, OptLevel, ParseCache, RootedFileSystem, Stage};
+use leo_errors::{
+    emitter::{compact_diagnostic, compact_warning, BufferEmitter, Handler, JsonEmitter},
+    CliError, Result,
+};
 use leo_package::{
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
     // inputs::*,
     // outputs::CircuitFile
-    outputs::{ChecksumFile, OutputsDirectory, OUTPUTS_DIRECTORY_NAME},
+    outputs::{BuildArtifacts, ChecksumFile, CompiledAstFile, PARSE_CACHE_DIRECTORY_NAME},
+    root::{lock_file::Package, Dependency, LockFile, Workspace},
     source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
 };
 
+use indexmap::IndexMap;
+use notify::{watcher, DebouncedEvent, RecursiveMode, Watcher};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::mpsc::channel,
+    time::Duration,
+};
 use structopt::StructOpt;
 use tracing::span::Span;
 
+/// The delay in milliseconds between a change to the source directory and the rebuild,
+/// so that an editor saving several files triggers a single rebuild.
+const WATCH_DELAY: u64 = 200;
+
 /// Compiler Options wrapper for Build command. Also used by other commands which
 /// require Build command output as their input.
 #[derive(StructOpt, Clone, Debug, Default)]
 pub struct BuildOptions {
-    #[structopt(long, help = "Disable constant folding compiler optimization")]
-    pub disable_constant_folding: bool,
-    #[structopt(long, help = "Disable dead code elimination compiler optimization")]
-    pub disable_code_elimination: bool,
+    #[structopt(long, help = "Disable strength reduction compiler optimization")]
+    pub disable_strength_reduction: bool,
     #[structopt(long, help = "Disable all compiler optimizations")]
     pub disable_all_optimizations: bool,
+    #[structopt(
+        long,
+        default_value = "1",
+        help = "The optimizations to run: `0` for none, `1` for those which never make the program larger."
+    )]
+    pub opt_level: OptLevel,
+    #[structopt(
+        long,
+        help = "Emit errors as JSON diagnostics on the standard output, one per line, instead of logging progress."
+    )]
+    pub json_diagnostics: bool,
+    #[structopt(long, help = "Report warnings as errors, failing the build.")]
+    pub deny_warnings: bool,
+    #[structopt(
+        long,
+        help = "Print each statement of the compiled program with the source location it comes from."
+    )]
+    pub trace_span: bool,
+    #[structopt(
+        long,
+        use_delimiter = true,
+        help = "Write the AST after each of these stages to the outputs directory, as JSON: \
+                `initial` (or `ast`), `canonicalized`, `checked`, `typed`, `optimized`."
+    )]
+    pub emit: Vec<Stage>,
     #[structopt(long, help = "Enable spans in AST snapshots.")]
     pub enable_spans: bool,
     #[structopt(long, help = "Writes all AST snapshots for the different compiler phases.")]
@@ -49,77 +90,289 @@ pub struct BuildOptions {
     pub enable_canonicalized_ast_snapshot: bool,
     #[structopt(long, help = "Writes AST snapshot after the type inference phase.")]
     pub enable_type_inferenced_ast_snapshot: bool,
+    #[structopt(
+        long,
+        number_of_values = 1,
+        parse(try_from_str = parse_cfg),
+        help = "Set a name of the build configuration, e.g., `--cfg network=testnet`, \
+                keeping the items annotated with a matching `@cfg`."
+    )]
+    pub cfg: Vec<(String, String)>,
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "Write a JSON report of the build to this file: the wall time and AST size after each pass, \
+                the token count, the size of the compiled program, and the peak memory. Always compiles the package."
+    )]
+    pub json_report: Option<PathBuf>,
+    #[structopt(
+        long,
+        help = "Write outputs which are byte-identical wherever the package is built, \
+                recording its files by their path in the package and with normalized line endings."
+    )]
+    pub deterministic: bool,
+}
+
+/// Parses a `--cfg` argument of the form `name=value`.
+fn parse_cfg(argument: &str) -> Result<(String, String), String> {
+    match argument.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("expected `name=value`, found `{}`", argument)),
+    }
 }
 
-// impl From<BuildOptions> for CompilerOptions {
-//     fn from(options: BuildOptions) -> Self {
-//         if options.disable_all_optimizations {
-//             CompilerOptions {
-//                 constant_folding_enabled: false,
-//                 dead_code_elimination_enabled: false,
-//             }
-//         } else {
-//             CompilerOptions {
-//                 constant_folding_enabled: !options.disable_constant_folding,
-//                 dead_code_elimination_enabled: !options.disable_code_elimination,
-//             }
-//         }
-//     }
-// }
-
-// impl From<BuildOptions> for AstSnapshotOptions {
-//     fn from(options: BuildOptions) -> Self {
-//         if options.enable_all_ast_snapshots {
-//             AstSnapshotOptions {
-//                 spans_enabled: options.enable_spans,
-//                 initial: true,
-//                 imports_resolved: true,
-//                 canonicalized: true,
-//                 type_inferenced: true,
-//             }
-//         } else {
-//             AstSnapshotOptions {
-//                 spans_enabled: options.enable_spans,
-//                 initial: options.enable_initial_ast_snapshot,
-//                 imports_resolved: options.enable_imports_resolved_ast_snapshot,
-//                 canonicalized: options.enable_canonicalized_ast_snapshot,
-//                 type_inferenced: options.enable_type_inferenced_ast_snapshot,
-//             }
-//         }
-//     }
-// }
+impl From<&BuildOptions> for CompilerOptions {
+    fn from(options: &BuildOptions) -> Self {
+        let mut emitted_stages = options.emit.clone();
+        if options.enable_all_ast_snapshots {
+            emitted_stages.extend(Stage::ALL);
+        }
+        // Imports are not resolved yet, so the AST after their resolution is the initial AST.
+        if options.enable_initial_ast_snapshot || options.enable_imports_resolved_ast_snapshot {
+            emitted_stages.push(Stage::Initial);
+        }
+        if options.enable_canonicalized_ast_snapshot {
+            emitted_stages.push(Stage::Canonicalized);
+        }
+        if options.enable_type_inferenced_ast_snapshot {
+            emitted_stages.push(Stage::Checked);
+        }
+
+        let default = CompilerOptions::default();
+        CompilerOptions {
+            opt_level: if options.disable_all_optimizations {
+                OptLevel::O0
+            } else {
+                options.opt_level
+            },
+            strength_reduction_enabled: !options.disable_strength_reduction,
+            emitted_stages: if emitted_stages.is_empty() {
+                default.emitted_stages
+            } else {
+                emitted_stages
+            },
+            spans_enabled: options.enable_spans,
+            cfg: options.cfg.iter().cloned().collect(),
+            deterministic: options.deterministic,
+        }
+    }
+}
 
 /// Compile and build program command.
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
 pub struct Build {
-    #[allow(dead_code)]
     #[structopt(flatten)]
     pub(crate) compiler_options: BuildOptions,
+    #[structopt(
+        long,
+        help = "Rebuild on each change to the source directory, printing the diagnostics which appeared (+) or were fixed (-)."
+    )]
+    pub(crate) watch: bool,
 }
 
-impl Command for Build {
-    type Input = ();
-    type Output = (Ast, bool);
+impl Build {
+    ///
+    /// Rebuilds the program at `main_file_path` on each change to the source directory of the package,
+    /// printing the diagnostics which differ from the previous build.
+    /// Only returns if the source directory cannot be watched.
+    ///
+    fn watch(&self, package_path: &Path, main_file_path: PathBuf, output_directory: PathBuf) -> Result<(Ast, bool)> {
+        let (tx, rx) = channel();
+        let mut watcher = watcher(tx, Duration::from_millis(WATCH_DELAY)).map_err(CliError::unable_to_watch)?;
+        watcher
+            .watch(package_path.join(SOURCE_DIRECTORY_NAME), RecursiveMode::Recursive)
+            .map_err(CliError::unable_to_watch)?;
+
+        tracing::info!("Watching Leo source code");
+
+        let mut checksum = None;
+        let mut diagnostics = Vec::new();
+        loop {
+            // Editors also write files they did not change, and only the main file is compiled,
+            // so the program is rebuilt only if the main file changed.
+            let handler = Handler::default();
+            let current = Compiler::new(&handler, main_file_path.clone(), output_directory.clone())
+                .checksum()
+                .ok();
+            if checksum.is_none() || current != checksum {
+                // The rebuild misses the parse cache for the changed file, and replaces its stale entry.
+                checksum = current;
+                let rebuilt = self.diagnostics(main_file_path.clone(), output_directory.clone());
+                for fixed in diagnostics.iter().filter(|d| !rebuilt.contains(d)) {
+                    tracing::info!("- {}", fixed);
+                }
+                for new in rebuilt.iter().filter(|d| !diagnostics.contains(d)) {
+                    tracing::info!("+ {}", new);
+                }
+                diagnostics = rebuilt;
+                tracing::info!("Rebuilt with {} diagnostic(s)", diagnostics.len());
+            }
+
+            // Wait for the next change to the source directory.
+            loop {
+                match rx.recv() {
+                    Ok(DebouncedEvent::Create(_))
+                    | Ok(DebouncedEvent::Write(_))
+                    | Ok(DebouncedEvent::Remove(_))
+                    | Ok(DebouncedEvent::Rename(_, _)) => break,
+                    Ok(DebouncedEvent::Error(e, _)) => return Err(CliError::unable_to_watch(e).into()),
+                    Ok(_) => {}
+                    Err(e) => return Err(CliError::unable_to_watch(e).into()),
+                }
+            }
+        }
+    }
 
-    fn log_span(&self) -> Span {
-        tracing::span!(tracing::Level::INFO, "Build")
+    ///
+    /// Compiles the program at `main_file_path`, returning its errors and warnings in their compact form.
+    ///
+    fn diagnostics(&self, main_file_path: PathBuf, output_directory: PathBuf) -> Vec<String> {
+        let buf = BufferEmitter::new();
+        let handler = Handler::new(Box::new(buf.clone()))
+            .with_deny_warnings(self.compiler_options.deny_warnings)
+            .with_sorted_diagnostics();
+
+        let parse_cache = ParseCache::new(output_directory.join(PARSE_CACHE_DIRECTORY_NAME));
+        let program = Compiler::new(&handler, main_file_path, output_directory)
+            .with_options((&self.compiler_options).into())
+            .with_parse_cache(parse_cache);
+        let _ = handler.extend_if_error(program.compile());
+        handler.flush();
+
+        let errors = buf.extract().into_inner();
+        let warnings = buf.extract_warnings().into_inner();
+        errors
+            .iter()
+            .map(compact_diagnostic)
+            .chain(warnings.iter().map(compact_warning))
+            .collect()
     }
 
-    fn prelude(&self, _: Context) -> Result<Self::Input> {
+    ///
+    /// Fetches the `dependencies` of the package at `package_path` into its imports directory,
+    /// at the versions of its lock file. If the lock file is missing or does not list every dependency,
+    /// the dependencies are resolved again and the lock file is rewritten.
+    ///
+    fn fetch_dependencies(
+        context: &Context,
+        package_path: &Path,
+        dependencies: &IndexMap<String, Dependency>,
+    ) -> Result<()> {
+        let mut lock_file = match context.lock_file_exists()? {
+            true => context.lock_file()?,
+            false => LockFile::new(),
+        };
+        let locked = lock_file.to_import_map();
+        if !dependencies.keys().all(|name| locked.contains_key(name)) {
+            lock_file = LockFile::resolve(dependencies)?;
+        }
+
+        ImportsDirectory::create(package_path)?;
+        let imports_directory = package_path.join(IMPORTS_DIRECTORY_NAME);
+        for package in lock_file.package.iter_mut() {
+            if imports_directory.join(package.to_string()).exists() {
+                continue;
+            }
+
+            tracing::info!("Fetching `{}`", package);
+            match package.source.clone() {
+                Some(source) => Self::clone_package(package, source.trim_start_matches("git+"), &imports_directory)?,
+                None => Self::download_package(context, package, &imports_directory)?,
+            }
+        }
+
+        lock_file.write_to(package_path)
+    }
+
+    ///
+    /// Clones the git repository at `url` into the imports directory,
+    /// pinning the version of `package` to the commit it is checked out at.
+    ///
+    fn clone_package(package: &mut Package, url: &str, imports_directory: &Path) -> Result<()> {
+        let name = package.name.clone();
+        let git = |args: &[&str]| -> Result<String> {
+            let output = process::Command::new("git")
+                .args(args)
+                .output()
+                .map_err(|error| CliError::failed_to_fetch_dependency(&name, error))?;
+            if !output.status.success() {
+                let error = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                return Err(CliError::failed_to_fetch_dependency(&name, error).into());
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        // Clone under a temporary name, as the commit of the default branch is only known once it is cloned.
+        let clone_path = imports_directory.join(format!(".{}", package));
+        let clone = clone_path.to_string_lossy().to_string();
+        if clone_path.exists() {
+            fs::remove_dir_all(&clone_path).map_err(CliError::cli_io_error)?;
+        }
+        git(&["clone", "--quiet", "--", url, &clone])?;
+        if package.version != "HEAD" {
+            git(&["-C", &clone, "checkout", "--quiet", &package.version])?;
+        }
+        package.version = git(&["-C", &clone, "rev-parse", "HEAD"])?;
+
+        let package_path = imports_directory.join(package.to_string());
+        if package_path.exists() {
+            fs::remove_dir_all(&clone_path).map_err(CliError::cli_io_error)?;
+        } else {
+            fs::rename(&clone_path, &package_path).map_err(CliError::cli_io_error)?;
+        }
+
         Ok(())
     }
 
-    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+    ///
+    /// Downloads `package` from the Aleo Package Manager and unpacks it into the imports directory.
+    ///
+    fn download_package(context: &Context, package: &Package, imports_directory: &Path) -> Result<()> {
+        let fetch = Fetch {
+            author: package.author.clone(),
+            package_name: package.name.clone(),
+            version: Some(package.version.clone()),
+        };
+        let bytes = context
+            .api
+            .run_route(fetch)?
+            .bytes()
+            .map_err(CliError::cli_bytes_conversion_error)?;
+
+        let package_path = imports_directory.join(package.to_string());
+        let mut zip_archive = zip::ZipArchive::new(io::Cursor::new(bytes)).map_err(CliError::cli_zip_error)?;
+        for i in 0..zip_archive.len() {
+            let mut file = zip_archive.by_index(i).map_err(CliError::cli_zip_error)?;
+
+            // Skip the entries which would be unpacked outside of the package directory.
+            let file_path = match file.enclosed_name() {
+                Some(name) => package_path.join(name),
+                None => continue,
+            };
+
+            if file.is_dir() {
+                fs::create_dir_all(&file_path).map_err(CliError::cli_io_error)?;
+            } else {
+                if let Some(parent_directory) = file_path.parent() {
+                    fs::create_dir_all(parent_directory).map_err(CliError::cli_io_error)?;
+                }
+                let mut created = fs::File::create(&file_path).map_err(CliError::cli_io_error)?;
+                io::copy(&mut file, &mut created).map_err(CliError::cli_io_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Builds the package of `context`, whose dependencies on the packages `workspace_dependencies`
+    /// are other packages of its workspace.
+    ///
+    fn build_package(self, context: Context, workspace_dependencies: &[String]) -> Result<(Ast, bool)> {
         let path = context.dir()?;
         let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
         let package_name = manifest.get_package_name();
-        let imports_map = manifest.get_imports_map().unwrap_or_default();
-
-        // Error out if there are dependencies but no lock file found.
-        if !imports_map.is_empty() && !context.lock_file_exists()? {
-            return Err(CliError::dependencies_are_not_installed().into());
-        }
 
         // Sanitize the package path to the root directory.
         let mut package_path = path.clone();
@@ -127,9 +380,17 @@ impl Command for Build {
             package_path.pop();
         }
 
-        // Construct the path to the output directory.
-        let mut output_directory = package_path.clone();
-        output_directory.push(OUTPUTS_DIRECTORY_NAME);
+        // Fetch the dependencies which are not installed yet.
+        // The dependencies on other packages of the workspace are built, not installed.
+        let installed: IndexMap<String, Dependency> = manifest
+            .get_package_dependencies()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, dependency)| !workspace_dependencies.contains(&dependency.package))
+            .collect();
+        if !installed.is_empty() {
+            Self::fetch_dependencies(&context, &package_path, &installed)?;
+        }
 
         tracing::info!("Starting...");
 
@@ -138,14 +399,39 @@ impl Command for Build {
             return Err(CliError::package_main_file_not_found().into());
         }
 
-        // Create the output directory
-        OutputsDirectory::create(&package_path)?;
+        // Create the output directory, removing the artifacts of other compiler versions.
+        let artifacts = BuildArtifacts::new(&package_path);
+        let output_directory = artifacts.prepare(env!("CARGO_PKG_VERSION"))?;
 
         // Construct the path to the main file in the source directory
         let mut main_file_path = package_path.clone();
         main_file_path.push(SOURCE_DIRECTORY_NAME);
         main_file_path.push(MAIN_FILENAME);
 
+        if self.watch {
+            return self.watch(&package_path, main_file_path, output_directory);
+        }
+
+        // Skip the compilation if neither the package nor the options changed since the last build,
+        // unless it is measured.
+        let fingerprint = leo_package::package::Package::fingerprint(
+            &package_path,
+            &format!("{} {:?}", env!("CARGO_PKG_VERSION"), self.compiler_options),
+        )?;
+        let checksum_file = ChecksumFile::new(&package_name);
+        let compiled_ast_file = CompiledAstFile::new(&package_name);
+        let fresh_ast = match self.compiler_options.json_report {
+            Some(_) => None,
+            None => Self::fresh_ast(&package_path, &fingerprint, &checksum_file, &compiled_ast_file),
+        };
+        if let Some(ast) = fresh_ast {
+            tracing::info!("Fresh");
+            if self.compiler_options.trace_span {
+                print!("{}", trace_spans(ast.as_repr()));
+            }
+            return Ok((ast, false));
+        }
+
         // Load the input file at `package_name.in`
         // let (input_string, input_path) = InputFile::new(&package_name).read_from(&path)?;
 
@@ -177,15 +463,49 @@ impl Command for Build {
         // )?;
 
         // Initialize error handler
-        let handler = leo_errors::emitter::Handler::default();
-
-        let program = Compiler::new(&handler, main_file_path, output_directory);
+        let handler = if self.compiler_options.json_diagnostics {
+            Handler::new(Box::new(JsonEmitter::stdout()))
+        } else {
+            Handler::default()
+        }
+        .with_deny_warnings(self.compiler_options.deny_warnings)
+        .with_sorted_diagnostics();
+
+        // The ASTs record the path of the main file, so a deterministic build names it by its path in the package.
+        let compiler = if self.compiler_options.deterministic {
+            let relative_path = PathBuf::from(format!("{}{}", SOURCE_DIRECTORY_NAME, MAIN_FILENAME));
+            Compiler::new(&handler, relative_path, output_directory)
+                .with_file_system(RootedFileSystem::new(package_path.clone()))
+        } else {
+            Compiler::new(&handler, main_file_path, output_directory)
+        };
+        let parse_cache = ParseCache::new(artifacts.parse_cache_directory());
+        let program = compiler
+            .with_options((&self.compiler_options).into())
+            .with_parse_cache(parse_cache);
+
+        // Compile the program, measuring the compilation if it is reported.
+        let compiled = match &self.compiler_options.json_report {
+            Some(_) => program.compile_with_report().map(|(ast, report)| (ast, Some(report))),
+            None => program.compile().map(|ast| (ast, None)),
+        };
+        let (program_compiled, report) = match compiled {
+            Ok(compiled) => compiled,
+            // Report the error through the handler so it is emitted as JSON as well.
+            Err(err) if self.compiler_options.json_diagnostics => handler.fatal_err(err),
+            Err(err) => return Err(err),
+        };
 
-        // Compute the current program checksum
-        let program_checksum = program.checksum()?;
+        if self.compiler_options.trace_span {
+            print!("{}", trace_spans(program_compiled.as_repr()));
+        }
 
-        // Compile the program
-        let program_compiled = program.compile()?;
+        if let (Some(path), Some(report)) = (&self.compiler_options.json_report, report) {
+            let json = serde_json::to_string_pretty(&report)
+                .map_err(|error| CliError::failed_to_write_build_report(path, error))?;
+            fs::write(path, json).map_err(|error| CliError::failed_to_write_build_report(path, error))?;
+            tracing::info!("Build report written to {:?}", path);
+        }
 
         // Generate the program on the constraint system and verify correctness
         {
@@ -219,26 +539,75 @@ impl Command for Build {
             // println!("deserialized {:?}", circuit_synthesizer.num_constraints());
         }
 
-        // If a checksum file exists, check if it differs from the new checksum
-        let checksum_file = ChecksumFile::new(&package_name);
-        let checksum_differs = if checksum_file.exists_at(&package_path) {
-            let previous_checksum = checksum_file.read_from(&package_path)?;
-            program_checksum != previous_checksum
-        } else {
-            // By default, the checksum differs if there is no checksum to compare against
-            true
-        };
+        if let Some(header) = &program_compiled.as_repr().header {
+            let constructor = if header.constructor { ", with a constructor" } else { "" };
+            tracing::info!("Program version {}{}", header.version, constructor);
+        }
 
-        // If checksum differs, compile the program
-        if checksum_differs {
-            // Write the new checksum to the output directory
-            checksum_file.write_to(&path, program_checksum)?;
+        // Save the compiled program with the fingerprint it was compiled from, for the next build to reuse.
+        compiled_ast_file.write_to(&package_path, leo_ast::to_json(program_compiled.as_repr())?)?;
+        checksum_file.write_to(&package_path, fingerprint)?;
+        tracing::debug!("Checksum saved ({:?})", package_path);
+
+        tracing::info!("Complete");
 
-            tracing::debug!("Checksum saved ({:?})", path);
+        Ok((program_compiled, true))
+    }
+
+    ///
+    /// Returns the program compiled by the last build, if it was compiled from the same `fingerprint`.
+    ///
+    fn fresh_ast(
+        package_path: &Path,
+        fingerprint: &str,
+        checksum_file: &ChecksumFile,
+        compiled_ast_file: &CompiledAstFile,
+    ) -> Option<Ast> {
+        if !checksum_file.exists_at(package_path) || !compiled_ast_file.exists_at(package_path) {
+            return None;
+        }
+        if checksum_file.read_from(package_path).ok()? != fingerprint {
+            return None;
         }
+        // A compiled AST which cannot be read, e.g., written by an older format, is compiled again.
+        let json = compiled_ast_file.read_from(package_path).ok()?;
+        leo_ast::from_json(&json).ok().map(Ast::new)
+    }
+}
 
-        tracing::info!("Complete");
+impl Command for Build {
+    type Input = ();
+    type Output = (Ast, bool);
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Build")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let path = context.dir()?;
+
+        // Build the packages of a workspace one after the other, each after its dependencies.
+        if let Some(workspace) = Workspace::open(&path)? {
+            let mut output = None;
+            for member in workspace.build_order()? {
+                tracing::info!("Building workspace member `{}`", member.name);
+                let context = Context {
+                    api: context.api.clone(),
+                    path: Some(member.path.clone()),
+                };
+                let build = Build {
+                    compiler_options: self.compiler_options.clone(),
+                    watch: false,
+                };
+                output = Some(build.build_package(context, &member.dependencies)?);
+            }
+            return output.ok_or_else(|| CliError::empty_workspace().into());
+        }
 
-        Ok((program_compiled, checksum_differs))
+        self.build_package(context, &[])
     }
 }
@@ -16,28 +16,44 @@
 
 use crate::{commands::Command, context::Context};
 use leo_errors::Result;
+use leo_package::{outputs::BuildArtifacts, root::Workspace};
 
 use structopt::StructOpt;
 use tracing::span::Span;
 
-/// Lint Leo code command
+/// Remove the build artifacts of the package, or of every package of the workspace.
 #[derive(StructOpt, Debug)]
 #[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
-pub struct Lint {}
+pub struct Clean {}
 
-impl Command for Lint {
+impl Command for Clean {
     type Input = ();
     type Output = ();
 
     fn log_span(&self) -> Span {
-        tracing::span!(tracing::Level::INFO, "Linting")
+        tracing::span!(tracing::Level::INFO, "Cleaning")
     }
 
     fn prelude(&self, _: Context) -> Result<Self::Input> {
         Ok(())
     }
 
-    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
-        unimplemented!("Lint command has not been implemented yet");
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut path = context.dir()?;
+        if path.is_file() {
+            path.pop();
+        }
+
+        let package_paths = match Workspace::open(&path)? {
+            Some(workspace) => workspace.members.into_iter().map(|member| member.path).collect(),
+            None => vec![path],
+        };
+        for package_path in package_paths {
+            if BuildArtifacts::new(&package_path).clean()? {
+                tracing::info!("Removed the build artifacts of {:?}", package_path);
+            }
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,542 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::{
+        run::{describe_output, read_inputs},
+        Command,
+    },
+    context::Context,
+};
+use leo_ast::{Node, Program, ProgramInput, Statement};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, CliError, Result};
+use leo_interpreter::{Debugger, Execution, Frame, Interpreter};
+use leo_package::{
+    outputs::BuildArtifacts,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use indexmap::{IndexMap, IndexSet};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    io::{self, BufRead, BufReader, Write},
+    net::TcpListener,
+    path::PathBuf,
+};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// The ID of the only thread of a program, as the Debug Adapter Protocol names threads.
+const THREAD_ID: u64 = 1;
+
+/// Run the `main` function of the package in the interpreter, under the control of a debugger.
+///
+/// The debugger is a client of the Debug Adapter Protocol, e.g., an editor, which connects to the given port.
+/// It sets breakpoints on the lines of the program, steps through its statements, into and out of calls,
+/// and inspects the variables of each call. The inputs of `main` are read as by `leo run`.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Debug {
+    #[structopt(long, default_value = "4711", help = "The port to listen on for the debugger")]
+    pub(crate) port: u16,
+    #[structopt(
+        short,
+        long = "input",
+        number_of_values = 1,
+        help = "Pass `value` as the input `name` of `main`, as `name=value`"
+    )]
+    pub(crate) inputs: Vec<String>,
+}
+
+impl Command for Debug {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Debug")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+        let package_name = manifest.get_package_name();
+
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(CliError::package_main_file_not_found().into());
+        }
+
+        let output_directory = BuildArtifacts::new(&package_path).prepare(env!("CARGO_PKG_VERSION"))?;
+
+        let mut main_file_path = package_path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let handler = Handler::default().with_sorted_diagnostics();
+
+        // The program is interpreted as written, before optimizations.
+        let ast = Compiler::new(&handler, main_file_path, output_directory).check()?;
+        let input = read_inputs(&handler, ast.as_repr(), &package_path, &package_name, &self.inputs)?;
+        handler.flush();
+
+        let listener = TcpListener::bind(("127.0.0.1", self.port)).map_err(CliError::cli_io_error)?;
+        tracing::info!("Waiting for a debugger on port {}", self.port);
+        let (stream, _) = listener.accept().map_err(CliError::cli_io_error)?;
+        let reader = BufReader::new(stream.try_clone().map_err(CliError::cli_io_error)?);
+        debug(&mut Session::new(reader, stream), ast.as_repr(), &input).map_err(CliError::cli_io_error)?;
+        Ok(())
+    }
+}
+
+/// Runs the `main` function of `program` on `input` under the control of the client of `session`.
+fn debug<R: BufRead, W: Write>(session: &mut Session<R, W>, program: &Program, input: &ProgramInput) -> io::Result<()> {
+    if session.configure()? {
+        session.resume = Resume::Entry;
+    }
+    let execution = Interpreter::new(program)
+        .and_then(|interpreter| leo_interpreter::run_in(interpreter.with_debugger(session), input));
+    session.finish(program, execution)
+}
+
+/// When the execution pauses next, besides at breakpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Resume {
+    /// At the first statement, when the execution starts.
+    Entry,
+    /// Only at breakpoints.
+    Continue,
+    /// At the next statement.
+    StepIn,
+    /// At the next statement of a call at most as deep as this one, stepping over deeper calls.
+    Next(usize),
+    /// At the next statement of a call less deep than this one, once it returns.
+    StepOut(usize),
+}
+
+/// A session of the Debug Adapter Protocol, in which the client reading from `writer` and writing to `reader`
+/// controls the interpreter.
+struct Session<R, W> {
+    reader: R,
+    writer: W,
+    /// The sequence number of the next message to the client.
+    seq: u64,
+    /// The lines with a breakpoint in each file.
+    breakpoints: IndexMap<PathBuf, IndexSet<usize>>,
+    /// The canonical path of each file of a span, as the client names the files.
+    paths: IndexMap<String, PathBuf>,
+    /// When to pause next.
+    resume: Resume,
+    /// The location of the statement being executed in each call, innermost last.
+    locations: Vec<leo_span::Span>,
+    /// Whether the client is gone, after which the program runs to the end.
+    disconnected: bool,
+}
+
+impl<R: BufRead, W: Write> Session<R, W> {
+    fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            seq: 1,
+            breakpoints: IndexMap::new(),
+            paths: IndexMap::new(),
+            resume: Resume::Continue,
+            locations: Vec::new(),
+            disconnected: false,
+        }
+    }
+
+    /// Reads the next message of the client, or `None` once it closes the connection.
+    /// A message is a JSON value preceded by a `Content-Length` header and an empty line.
+    fn read(&mut self) -> io::Result<Option<Value>> {
+        let mut length = None;
+        loop {
+            let mut header = String::new();
+            if self.reader.read_line(&mut header)? == 0 {
+                return Ok(None);
+            }
+            match header.trim().split_once(':') {
+                Some(("Content-Length", value)) => length = value.trim().parse::<usize>().ok(),
+                Some(_) => {}
+                None if length.is_some() => break,
+                None => return Err(io::Error::new(io::ErrorKind::InvalidData, "expected `Content-Length`")),
+            }
+        }
+        let mut content = vec![0; length.unwrap_or_default()];
+        self.reader.read_exact(&mut content)?;
+        serde_json::from_slice(&content)
+            .map(Some)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    /// Sends `message` to the client.
+    fn send(&mut self, mut message: Value) -> io::Result<()> {
+        message["seq"] = json!(self.seq);
+        self.seq += 1;
+        let content = message.to_string();
+        write!(self.writer, "Content-Length: {}\r\n\r\n{}", content.len(), content)?;
+        self.writer.flush()
+    }
+
+    /// Sends the event `event` with `body` to the client.
+    fn event(&mut self, event: &str, body: Value) -> io::Result<()> {
+        self.send(json!({ "type": "event", "event": event, "body": body }))
+    }
+
+    /// Answers the requests of the client until it is done configuring the session,
+    /// and returns whether the execution pauses at its first statement.
+    fn configure(&mut self) -> io::Result<bool> {
+        let mut stop_on_entry = false;
+        while let Some(request) = self.read()? {
+            match request["command"].as_str() {
+                Some("initialize") => {
+                    self.respond(&request, json!({ "supportsConfigurationDoneRequest": true }))?;
+                    self.event("initialized", json!({}))?;
+                }
+                Some("launch") | Some("attach") => {
+                    stop_on_entry = request["arguments"]["stopOnEntry"].as_bool().unwrap_or(false);
+                    self.respond(&request, json!({}))?;
+                }
+                Some("configurationDone") => {
+                    self.respond(&request, json!({}))?;
+                    return Ok(stop_on_entry);
+                }
+                _ => {
+                    self.answer(&request, &[])?;
+                    if self.disconnected {
+                        break;
+                    }
+                }
+            }
+        }
+        self.disconnected = true;
+        Ok(false)
+    }
+
+    /// Sends a successful response to `request` with `body`.
+    fn respond(&mut self, request: &Value, body: Value) -> io::Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": true,
+            "body": body,
+        }))
+    }
+
+    /// Answers `request`, made while the calls `frames` are interpreted,
+    /// and returns when to pause next if it resumes the execution.
+    fn answer(&mut self, request: &Value, frames: &[Frame]) -> io::Result<Option<Resume>> {
+        let arguments = &request["arguments"];
+        // The calls, innermost first, as a stack trace lists them.
+        let calls = frames.iter().rev().filter(|frame| frame.function.is_some());
+        let depth = self.locations.len();
+        let (body, resume) = match request["command"].as_str().unwrap_or_default() {
+            "setBreakpoints" => {
+                let path = self.canonical_path(arguments["source"]["path"].as_str().unwrap_or_default());
+                let lines = arguments["breakpoints"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|breakpoint| breakpoint["line"].as_u64())
+                    .map(|line| line as usize)
+                    .collect::<IndexSet<_>>();
+                let breakpoints = lines
+                    .iter()
+                    .map(|line| json!({ "verified": true, "line": line }))
+                    .collect::<Vec<_>>();
+                self.breakpoints.insert(path, lines);
+                (json!({ "breakpoints": breakpoints }), None)
+            }
+            "threads" => (json!({ "threads": [{ "id": THREAD_ID, "name": "main" }] }), None),
+            "stackTrace" => {
+                let stack_frames = calls
+                    .zip(self.locations.iter().rev())
+                    .enumerate()
+                    .map(|(id, (frame, location))| {
+                        json!({
+                            "id": id,
+                            "name": frame.function.map(|function| function.identifier.to_string()),
+                            "source": { "path": &*location.path },
+                            "line": location.line_start,
+                            "column": location.col_start,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                (json!({ "stackFrames": stack_frames, "totalFrames": depth }), None)
+            }
+            // Each call has one scope of variables, referred to by one more than the ID of its frame.
+            "scopes" => {
+                let reference = arguments["frameId"].as_u64().unwrap_or_default() + 1;
+                (
+                    json!({ "scopes": [{ "name": "Locals", "variablesReference": reference, "expensive": false }] }),
+                    None,
+                )
+            }
+            "variables" => {
+                let frame = (arguments["variablesReference"].as_u64().unwrap_or_default() as usize)
+                    .checked_sub(1)
+                    .and_then(|id| calls.clone().nth(id));
+                let variables = frame
+                    .map(|frame| frame.variables())
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|(name, value)| {
+                        json!({
+                            "name": name.to_string(),
+                            "value": value.to_string(),
+                            "type": value.type_name(),
+                            "variablesReference": 0,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                (json!({ "variables": variables }), None)
+            }
+            "continue" => (json!({ "allThreadsContinued": true }), Some(Resume::Continue)),
+            "next" => (json!({}), Some(Resume::Next(depth))),
+            "stepIn" => (json!({}), Some(Resume::StepIn)),
+            "stepOut" => (json!({}), Some(Resume::StepOut(depth))),
+            "disconnect" => {
+                self.disconnected = true;
+                (json!({}), Some(Resume::Continue))
+            }
+            command => {
+                self.send(json!({
+                    "type": "response",
+                    "request_seq": request["seq"],
+                    "command": command,
+                    "success": false,
+                    "message": format!("unsupported request `{}`", command),
+                }))?;
+                return Ok(None);
+            }
+        };
+        self.respond(request, body)?;
+        Ok(resume)
+    }
+
+    /// Returns the canonical form of `path`, the path of a file of the program, by which breakpoints are set.
+    fn canonical_path(&mut self, path: &str) -> PathBuf {
+        self.paths
+            .entry(path.to_string())
+            .or_insert_with(|| fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from(path)))
+            .clone()
+    }
+
+    /// Pauses the execution of the calls `frames` for `reason`, answering the requests of the client until it resumes.
+    fn pause(&mut self, reason: &str, frames: &[Frame]) -> io::Result<()> {
+        self.event(
+            "stopped",
+            json!({ "reason": reason, "threadId": THREAD_ID, "allThreadsStopped": true }),
+        )?;
+        while let Some(request) = self.read()? {
+            if let Some(resume) = self.answer(&request, frames)? {
+                self.resume = resume;
+                return Ok(());
+            }
+        }
+        self.disconnected = true;
+        Ok(())
+    }
+
+    /// Reports the end of the execution of `program`, and answers the requests of the client until it disconnects.
+    fn finish(&mut self, program: &Program, execution: Result<Execution>) -> io::Result<()> {
+        if self.disconnected {
+            return Ok(());
+        }
+        let (category, output, exit_code) = match execution {
+            Ok(execution) => (
+                "stdout",
+                format!("Output: {}\n", describe_output(program, &execution.value)),
+                0,
+            ),
+            Err(error) => ("stderr", format!("{}\n", error), 1),
+        };
+        self.event("output", json!({ "category": category, "output": output }))?;
+        self.event("exited", json!({ "exitCode": exit_code }))?;
+        self.event("terminated", json!({}))?;
+        while let Some(request) = self.read()? {
+            self.answer(&request, &[])?;
+            if self.disconnected {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead, W: Write> Debugger for Session<R, W> {
+    fn before_statement(&mut self, statement: &Statement, frames: &[Frame]) {
+        let depth = frames.iter().filter(|frame| frame.function.is_some()).count();
+        let span = statement.span();
+        self.locations.resize(depth, span.clone());
+        if let Some(location) = self.locations.last_mut() {
+            *location = span.clone();
+        }
+        if self.disconnected {
+            return;
+        }
+
+        let path = self.canonical_path(&span.path);
+        let at_breakpoint = self
+            .breakpoints
+            .get(&path)
+            .map_or(false, |lines| lines.contains(&span.line_start));
+        let reason = match self.resume {
+            _ if at_breakpoint => "breakpoint",
+            Resume::Entry => "entry",
+            Resume::StepIn => "step",
+            Resume::Next(next) if depth <= next => "step",
+            Resume::StepOut(out) if depth < out => "step",
+            Resume::Continue | Resume::Next(_) | Resume::StepOut(_) => return,
+        };
+        // A client which cannot be reached is gone.
+        if self.pause(reason, frames).is_err() {
+            self.disconnected = true;
+        }
+    }
+
+    fn log(&mut self, message: &str) {
+        if self.disconnected {
+            return;
+        }
+        let output = json!({ "category": "stdout", "output": format!("{}\n", message) });
+        if self.event("output", output).is_err() {
+            self.disconnected = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+    use std::io::Cursor;
+
+    /// Returns the requests `commands`, each with its arguments, as a client sends them.
+    fn requests(commands: &[(&str, Value)]) -> Vec<u8> {
+        let mut requests = Vec::new();
+        for (seq, (command, arguments)) in commands.iter().enumerate() {
+            let request = json!({ "seq": seq + 1, "type": "request", "command": command, "arguments": arguments });
+            let request = request.to_string();
+            write!(requests, "Content-Length: {}\r\n\r\n{}", request.len(), request).unwrap();
+        }
+        requests
+    }
+
+    #[test]
+    fn steps_through_calls() {
+        create_session_if_not_set_then(|_| {
+            let source = "function double(x: u8) -> u8 {\n    return x * 2u8;\n}\n\
+                          function main(a: u8) -> u8 {\n    let b = double(a);\n    console.log(\"{}\", b);\n    \
+                          return b + 1u8;\n}";
+            let handler = Handler::default();
+            let ast = Compiler::check_source(&handler, "main.leo", source).unwrap();
+            let input = leo_parser::parse_program_inputs(&handler, "[main]\na: u8 = 3;\n", "main.in")
+                .unwrap()
+                .program_input;
+
+            let requests = requests(&[
+                ("initialize", json!({ "adapterID": "leo" })),
+                ("launch", json!({})),
+                (
+                    "setBreakpoints",
+                    json!({ "source": { "path": "main.leo" }, "breakpoints": [{ "line": 2 }] }),
+                ),
+                ("configurationDone", json!({})),
+                ("stackTrace", json!({ "threadId": 1 })),
+                ("variables", json!({ "variablesReference": 1 })),
+                ("stepOut", json!({ "threadId": 1 })),
+                ("variables", json!({ "variablesReference": 1 })),
+                ("next", json!({ "threadId": 1 })),
+                ("continue", json!({ "threadId": 1 })),
+                ("disconnect", json!({})),
+            ]);
+            let mut session = Session::new(Cursor::new(requests), Vec::new());
+            debug(&mut session, ast.as_repr(), &input).unwrap();
+
+            let mut client = Session::new(Cursor::new(session.writer), Vec::new());
+            let mut messages = Vec::new();
+            while let Some(message) = client.read().unwrap() {
+                messages.push(message);
+            }
+            let events = messages
+                .iter()
+                .filter(|message| message["type"] == "event")
+                .map(|event| match event["event"].as_str().unwrap() {
+                    "stopped" => format!("stopped: {}", event["body"]["reason"].as_str().unwrap()),
+                    "output" => format!("output: {}", event["body"]["output"].as_str().unwrap().trim()),
+                    event => event.to_string(),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                events,
+                [
+                    "initialized",
+                    "stopped: breakpoint",
+                    "stopped: step",
+                    "output: 6u8",
+                    "stopped: step",
+                    "output: Output: 7u8: u8",
+                    "exited",
+                    "terminated",
+                ]
+            );
+
+            let body = |command: &str| {
+                messages
+                    .iter()
+                    .filter(|message| message["type"] == "response" && message["command"] == command)
+                    .map(|response| response["body"].clone())
+                    .collect::<Vec<_>>()
+            };
+            let frames = body("stackTrace")[0]["stackFrames"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|frame| format!("{} {}", frame["name"].as_str().unwrap(), frame["line"]))
+                .collect::<Vec<_>>();
+            assert_eq!(frames, ["double 2", "main 5"]);
+            let variables = body("variables")
+                .iter()
+                .map(|body| {
+                    body["variables"]
+                        .as_array()
+                        .unwrap()
+                        .iter()
+                        .map(|variable| {
+                            format!(
+                                "{} = {}",
+                                variable["name"].as_str().unwrap(),
+                                variable["value"].as_str().unwrap()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(variables, [vec!["x = 3u8"], vec!["a = 3u8", "b = 6u8"]]);
+        });
+    }
+}
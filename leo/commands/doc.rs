@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_doc::DocFormat;
+use leo_errors::{emitter::Handler, CliError, Result};
+use leo_package::{outputs::BuildArtifacts, source::SourceDirectory};
+
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Generate documentation for the Leo files of the package.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Doc {
+    #[structopt(
+        long,
+        default_value = "markdown",
+        help = "The format of the documentation, `markdown` or `html`."
+    )]
+    pub(crate) format: DocFormat,
+}
+
+impl Command for Doc {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Documenting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let artifacts = BuildArtifacts::new(&package_path);
+        artifacts.prepare(env!("CARGO_PKG_VERSION"))?;
+        let doc_directory = artifacts.doc_directory();
+        fs::create_dir_all(&doc_directory).map_err(CliError::cli_io_error)?;
+
+        let handler = Handler::default();
+        for file_path in SourceDirectory::files(&package_path)? {
+            let source = fs::read_to_string(&file_path).map_err(CliError::cli_io_error)?;
+            let ast = leo_parser::parse_ast(&handler, file_path.to_string_lossy(), &source)?;
+
+            let name = file_path.file_stem().unwrap_or_default().to_string_lossy();
+            let documentation = leo_doc::document(&name, ast.as_repr(), self.format);
+            let doc_path = doc_directory.join(format!("{}.{}", name, self.format.extension()));
+            fs::write(&doc_path, documentation).map_err(CliError::cli_io_error)?;
+            tracing::info!("Documented {} in {}", file_path.display(), doc_path.display());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_errors::{CliError, Result};
+use leo_package::source::SourceDirectory;
+
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Format the Leo files of the package.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Fmt {
+    #[structopt(
+        long,
+        help = "List the files which are not formatted without changing them, failing if there are any."
+    )]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Formatting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        let mut unformatted = 0;
+        for file_path in SourceDirectory::files(&package_path)? {
+            let source = fs::read_to_string(&file_path).map_err(CliError::cli_io_error)?;
+            let formatted = leo_formatter::format(&file_path.to_string_lossy(), &source)?;
+            if formatted == source {
+                continue;
+            }
+
+            if self.check {
+                tracing::info!("Not formatted: {}", file_path.display());
+                unformatted += 1;
+            } else {
+                fs::write(&file_path, formatted).map_err(CliError::cli_io_error)?;
+                tracing::info!("Formatted {}", file_path.display());
+            }
+        }
+
+        if unformatted > 0 {
+            return Err(CliError::unformatted_files(unformatted).into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::Compiler;
+use leo_errors::{
+    apply_suggestions,
+    emitter::{Emitter, Handler, StderrEmitter},
+    CliError, Result,
+};
+use leo_package::source::SourceDirectory;
+
+use std::fs;
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Lint the Leo files of the package, and apply the fixes the warnings suggest.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Lint {
+    #[structopt(long, help = "Apply the fixes suggested by the warnings to the files.")]
+    pub(crate) fix: bool,
+
+    #[structopt(
+        long,
+        requires = "fix",
+        help = "Print the changes `--fix` would make as a diff, without changing the files."
+    )]
+    pub(crate) dry_run: bool,
+
+    #[structopt(long, help = "Only report the warnings of security lints, which have a severity.")]
+    pub(crate) security: bool,
+}
+
+impl Command for Lint {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Linting")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        for file_path in SourceDirectory::files(&package_path)? {
+            let source = fs::read_to_string(&file_path).map_err(CliError::cli_io_error)?;
+            let path = file_path.to_string_lossy();
+
+            let (handler, buf) = Handler::new_with_buf();
+            let result = Compiler::check_source(&handler, &path, &source);
+            buf.extract()
+                .into_inner()
+                .into_iter()
+                .for_each(|error| StderrEmitter.emit_err(error));
+            result?;
+
+            // Without `--fix`, every warning is reported. With it, only those left after the fixes are.
+            let (fixable, unfixable): (Vec<_>, Vec<_>) = buf
+                .extract_warnings()
+                .into_inner()
+                .into_iter()
+                .filter(|warning| !self.security || warning.lint.severity().is_some())
+                .partition(|warning| self.fix && warning.suggestion.is_some());
+            unfixable
+                .into_iter()
+                .for_each(|warning| StderrEmitter.emit_warning(warning));
+            if fixable.is_empty() {
+                continue;
+            }
+
+            let (fixed, applied) = apply_suggestions(
+                &source,
+                fixable.iter().filter_map(|warning| warning.suggestion.as_deref()),
+            );
+            if self.dry_run {
+                print!("{}", diff(&path, &source, &fixed));
+            } else {
+                fs::write(&file_path, fixed).map_err(CliError::cli_io_error)?;
+                tracing::info!("Fixed {} warning(s) in {}", applied, file_path.display());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the changes from `before` to `after`, the content of the file at `path`,
+/// as a unified diff without context lines.
+fn diff(path: &str, before: &str, after: &str) -> String {
+    let old: Vec<&str> = before.split_inclusive('\n').collect();
+    let new: Vec<&str> = after.split_inclusive('\n').collect();
+
+    // Fixes change few lines, so the lines around them are skipped before comparing the rest.
+    let prefix = old.iter().zip(new.iter()).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_middle, new_middle) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    // The length of the longest common subsequence of the lines from each pair of positions on.
+    let mut common = vec![vec![0usize; new_middle.len() + 1]; old_middle.len() + 1];
+    for i in (0..old_middle.len()).rev() {
+        for j in (0..new_middle.len()).rev() {
+            common[i][j] = if old_middle[i] == new_middle[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", path, path);
+    let (mut i, mut j) = (0, 0);
+    while i < old_middle.len() || j < new_middle.len() {
+        if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        // Collect the lines removed and added up to the next common line.
+        let (old_start, new_start) = (i, j);
+        while i < old_middle.len() || j < new_middle.len() {
+            if i < old_middle.len() && j < new_middle.len() && old_middle[i] == new_middle[j] {
+                break;
+            }
+            if j == new_middle.len() || (i < old_middle.len() && common[i + 1][j] >= common[i][j + 1]) {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        // An empty range starts at the line before it.
+        let start = |start: usize, count: usize| prefix + start + (count > 0) as usize;
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start(old_start, i - old_start),
+            i - old_start,
+            start(new_start, j - new_start),
+            j - new_start
+        ));
+        let removed = old_middle[old_start..i].iter().map(|line| ('-', line));
+        let added = new_middle[new_start..j].iter().map(|line| ('+', line));
+        for (sign, line) in removed.chain(added) {
+            output.push(sign);
+            output.push_str(line);
+            if !line.ends_with('\n') {
+                output.push_str("\n\\ No newline at end of file\n");
+            }
+        }
+    }
+    output
+}
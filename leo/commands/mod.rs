@@ -23,33 +23,48 @@ use tracing::span::Span;
 // local program commands
 pub mod build;
 pub use build::Build;
-//
-// pub mod clean;
-// pub use clean::Clean;
+
+pub mod clean;
+pub use clean::Clean;
+
+pub mod debug;
+pub use debug::Debug;
+
+pub mod doc;
+pub use doc::Doc;
+
+pub mod fmt;
+pub use fmt::Fmt;
 //
 // pub mod deploy;
 // pub use deploy::Deploy;
 //
 // pub mod init;
 // pub use init::Init;
-//
-// pub mod lint;
-// pub use lint::Lint;
+
+pub mod lint;
+pub use lint::Lint;
+
+pub mod mutate;
+pub use mutate::Mutate;
 //
 // pub mod new;
 // pub use new::New;
 //
 // pub mod prove;
 // pub use prove::Prove;
-//
-// pub mod run;
-// pub use run::Run;
+
+pub mod repl;
+pub use repl::Repl;
+
+pub mod run;
+pub use run::Run;
 //
 // pub mod setup;
 // pub use setup::Setup;
-//
-// pub mod test;
-// pub use test::Test;
+
+pub mod test;
+pub use test::Test;
 //
 // pub mod update;
 // pub use update::{Automatic as UpdateAutomatic, Update};
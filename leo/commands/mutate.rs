@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, CliError, Result};
+use leo_package::{
+    outputs::BuildArtifacts,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Change the operators and literals of the package one at a time, reporting the changes its tests do not catch.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Mutate {
+    #[structopt(help = "Run only the tests whose name contains this string.")]
+    pub(crate) filter: Option<String>,
+}
+
+impl Command for Mutate {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Mutate")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(CliError::package_main_file_not_found().into());
+        }
+
+        let output_directory = BuildArtifacts::new(&package_path).prepare(env!("CARGO_PKG_VERSION"))?;
+
+        let mut main_file_path = package_path;
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let handler = Handler::default().with_sorted_diagnostics();
+
+        // Mutants are interpreted as tests are, so they are made from the checked program.
+        let ast = Compiler::new(&handler, main_file_path, output_directory).check()?;
+        let program = ast.as_repr();
+        let tests = leo_interpreter::tests(&handler, program)?;
+        handler.flush();
+
+        let selected = |name: String| {
+            self.filter
+                .as_ref()
+                .map_or(true, |filter| name.contains(filter.as_str()))
+        };
+        let tests = tests
+            .iter()
+            .filter(|test| selected(test.function.name().to_string()))
+            .collect::<Vec<_>>();
+
+        // A mutant is only caught by tests which pass on the program itself.
+        let failed = tests
+            .iter()
+            .filter(|test| !leo_interpreter::run_test(program, test).passed())
+            .count();
+        if failed > 0 {
+            return Err(CliError::failed_tests(failed).into());
+        }
+        let names = tests.iter().map(|test| test.function.name()).collect::<Vec<_>>();
+
+        let (mut killed, mut survived) = (0, 0);
+        for mutant in leo_interpreter::mutants(program) {
+            let mutation = &mutant.mutation;
+            let span = &mutation.span;
+            match mutant.killed_by(&names) {
+                Some(test) => {
+                    tracing::debug!(
+                        "{}:{}:{}: `{}` -> `{}` killed by {}",
+                        span.path,
+                        span.line_start,
+                        span.col_start,
+                        mutation.original,
+                        mutation.replacement,
+                        test
+                    );
+                    killed += 1;
+                }
+                None => {
+                    tracing::warn!(
+                        "{}:{}:{}: `{}` -> `{}` survived",
+                        span.path,
+                        span.line_start,
+                        span.col_start,
+                        mutation.original,
+                        mutation.replacement
+                    );
+                    survived += 1;
+                }
+            }
+        }
+
+        tracing::info!("{} mutants killed; {} survived", killed, survived);
+        Ok(())
+    }
+}
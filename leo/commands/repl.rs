@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, explain::error_name, CliError, Result};
+use leo_interpreter::{Interpreter, Value};
+use leo_package::{
+    outputs::BuildArtifacts,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use std::io::{self, BufRead, Write};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// The name given to the lines read by the REPL in error messages.
+const REPL_PATH: &str = "repl";
+
+/// Evaluate expressions and statements against the package, one line at a time.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Repl {}
+
+impl Command for Repl {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Repl")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(CliError::package_main_file_not_found().into());
+        }
+
+        let output_directory = BuildArtifacts::new(&package_path).prepare(env!("CARGO_PKG_VERSION"))?;
+
+        let mut main_file_path = package_path;
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let handler = Handler::default().with_sorted_diagnostics();
+
+        // Lines are interpreted against the checked program, so they behave as the program is written.
+        let ast = Compiler::new(&handler, main_file_path, output_directory).check()?;
+        handler.flush();
+        let mut interpreter = Interpreter::new(ast.as_repr())?;
+
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+        let mut input = String::new();
+        loop {
+            print!("{}", if input.is_empty() { "leo> " } else { "...> " });
+            io::stdout().flush().map_err(CliError::cli_io_error)?;
+
+            let line = match lines.next() {
+                Some(line) => line.map_err(CliError::cli_io_error)?,
+                None => break,
+            };
+            if input.is_empty() && line.trim().is_empty() {
+                continue;
+            }
+            if input.is_empty() && matches!(line.trim(), ":quit" | ":q") {
+                break;
+            }
+            input.push_str(&line);
+            input.push('\n');
+
+            // Each line gets its own handler, as the handler reports an error at the same location only once.
+            let handler = Handler::default();
+            let statement = match leo_parser::parse_repl_statement(&handler, REPL_PATH, &input) {
+                // A statement which is not finished continues on the next line, until an empty line.
+                Err(error) if error_name(&error.error_code()) == Some("unexpected_eof") && !line.trim().is_empty() => {
+                    continue;
+                }
+                Err(error) => {
+                    handler.emit_err(error);
+                    input.clear();
+                    continue;
+                }
+                Ok(statement) => statement,
+            };
+            input.clear();
+
+            let result = interpreter.execute(&statement);
+            for message in interpreter.output.drain(..) {
+                println!("{}", message);
+            }
+            match result {
+                Ok(Some(value)) if value != Value::Tuple(Vec::new()) => println!("{}: {}", value, value.type_name()),
+                Ok(_) => {}
+                Err(error) => handler.emit_err(error),
+            }
+        }
+        Ok(())
+    }
+}
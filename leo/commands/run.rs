@@ -0,0 +1,244 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_ast::{FunctionInput, Program, ProgramInput};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, CliError, Result};
+use leo_interpreter::Value;
+use leo_package::{
+    inputs::InputFile,
+    outputs::BuildArtifacts,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+use leo_span::sym;
+
+use indexmap::IndexMap;
+use std::{env, fs, path::Path};
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// The prefix of the environment variables holding inputs of `main`, e.g. `LEO_INPUT_AMOUNT` for its input `amount`.
+pub const INPUT_VARIABLE_PREFIX: &str = "LEO_INPUT_";
+
+/// The file of the package whose variables are read as if they were set in the environment.
+pub const ENV_FILENAME: &str = ".env";
+
+/// Run the `main` function of the package on the inputs in its input file.
+///
+/// An input can also be set in the environment or the `.env` file of the package, as `LEO_INPUT_<NAME>`,
+/// or passed on the command line, as `--input name=value`. The command line takes precedence over the environment,
+/// the environment over the `.env` file, and all of them over the input file.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Run {
+    #[structopt(long, help = "Interpret the program directly, without building its circuit")]
+    pub(crate) interpret: bool,
+    #[structopt(
+        short,
+        long = "input",
+        number_of_values = 1,
+        help = "Pass `value` as the input `name` of `main`, as `name=value`"
+    )]
+    pub(crate) inputs: Vec<String>,
+}
+
+/// Returns the inputs of `main` in `program`, read from the input file of the package `package_name` at `package_path`,
+/// if it has one, and overridden by the `inputs` of the command line and the environment.
+pub(crate) fn read_inputs(
+    handler: &Handler,
+    program: &Program,
+    package_path: &Path,
+    package_name: &str,
+    inputs: &[String],
+) -> Result<ProgramInput> {
+    // A program whose `main` has no inputs, or gets them all from elsewhere, does not need an input file.
+    let input_file = InputFile::new(package_name);
+    let mut input = if input_file.exists_at(package_path) {
+        let (input_string, input_path) = input_file.read_from(package_path)?;
+        leo_parser::parse_program_inputs(handler, input_string, input_path.to_string_lossy())?.program_input
+    } else {
+        Default::default()
+    };
+    resolve_inputs(handler, program, package_path, inputs, &mut input)?;
+    Ok(input)
+}
+
+///
+/// Sets the inputs of `main` in `input` which are passed in `inputs`, as `name=value`, or set in the environment,
+/// parsing each as the type it has in the signature of `main`.
+///
+fn resolve_inputs(
+    handler: &Handler,
+    program: &Program,
+    package_path: &Path,
+    inputs: &[String],
+    input: &mut ProgramInput,
+) -> Result<()> {
+    let main = match program
+        .functions
+        .values()
+        .find(|function| function.identifier.name == sym::main)
+    {
+        Some(main) => main,
+        // Running the program reports the missing `main`.
+        None => return Ok(()),
+    };
+
+    let mut arguments = IndexMap::new();
+    for argument in inputs.iter() {
+        let (name, value) = argument
+            .split_once('=')
+            .ok_or_else(|| CliError::invalid_input_argument(argument))?;
+        arguments.insert(name.trim().to_string(), value.trim().to_string());
+    }
+
+    let env_path = package_path.join(ENV_FILENAME);
+    let env_file = if env_path.exists() {
+        parse_env_file(&fs::read_to_string(&env_path).map_err(CliError::cli_io_error)?)
+    } else {
+        Default::default()
+    };
+
+    for parameter in main.filter_self_inputs() {
+        if let FunctionInput::Variable(variable) = parameter {
+            let name = variable.identifier.name.to_string();
+            let env_name = format!("{}{}", INPUT_VARIABLE_PREFIX, name.to_uppercase());
+            let (value, origin) = match arguments.remove(&name) {
+                Some(value) => (value, format!("--input {}", name)),
+                None => match env::var(&env_name).ok().or_else(|| env_file.get(&env_name).cloned()) {
+                    Some(value) => (value, env_name),
+                    None => continue,
+                },
+            };
+
+            // The value is parsed as the definition of an input file, which checks it against its type.
+            let section = if variable.is_constant() { "constants" } else { "main" };
+            let definition = format!("[{}]\n{}: {} = {};\n", section, name, variable.type_, value);
+            let parsed = leo_parser::parse_program_inputs(handler, definition, origin)?.program_input;
+            input.main.extend(parsed.main);
+            input.constants.extend(parsed.constants);
+        }
+    }
+
+    // The inputs left on the command line are not inputs of `main`.
+    match arguments.keys().next() {
+        Some(name) => Err(CliError::unknown_main_input(name, main.signature()).into()),
+        None => Ok(()),
+    }
+}
+
+/// Returns `output`, the value returned by `main` in `program`, with its type, as `value: type`.
+pub(crate) fn describe_output(program: &Program, output: &Value) -> String {
+    // The output is shown with the type `main` declares, rather than one derived from the value,
+    // which cannot tell the type of the elements of an empty array.
+    let type_ = program
+        .functions
+        .values()
+        .find(|function| function.identifier.name == sym::main)
+        .and_then(|main| main.output.as_ref())
+        .map_or_else(|| output.type_name(), |type_| type_.to_string());
+    format!("{}: {}", output.to_pretty_string(), type_)
+}
+
+/// Returns the variables set in the contents `env` of a `.env` file, of the form `NAME=value`, one per line.
+/// Empty lines and comments starting with `#` are skipped, and a value may be enclosed in quotes.
+fn parse_env_file(env: &str) -> IndexMap<String, String> {
+    env.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.trim_start_matches("export ").split_once('='))
+        .map(|(name, value)| {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            (name.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+impl Command for Run {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Run")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        if !self.interpret {
+            return Err(CliError::circuit_execution_unsupported().into());
+        }
+
+        let manifest = context.manifest().map_err(|_| CliError::manifest_file_not_found())?;
+        let package_name = manifest.get_package_name();
+
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(CliError::package_main_file_not_found().into());
+        }
+
+        let output_directory = BuildArtifacts::new(&package_path).prepare(env!("CARGO_PKG_VERSION"))?;
+
+        let mut main_file_path = package_path.clone();
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let handler = Handler::default().with_sorted_diagnostics();
+
+        // The program is interpreted as written, before optimizations.
+        let ast = Compiler::new(&handler, main_file_path, output_directory).check()?;
+
+        let input = read_inputs(&handler, ast.as_repr(), &package_path, &package_name, &self.inputs)?;
+        handler.flush();
+
+        let execution = leo_interpreter::run(ast.as_repr(), &input)?;
+        for message in execution.output.iter() {
+            tracing::info!("{}", message);
+        }
+        tracing::info!("Output: {}", describe_output(ast.as_repr(), &execution.value));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_env_file() {
+        let env = "
+# The inputs of `main`.
+LEO_INPUT_A=3field
+export LEO_INPUT_B = \"[1, 2]\"
+LEO_INPUT_C
+";
+        let variables = parse_env_file(env);
+        assert_eq!(variables.len(), 2);
+        assert_eq!(variables["LEO_INPUT_A"], "3field");
+        assert_eq!(variables["LEO_INPUT_B"], "[1, 2]");
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{commands::Command, context::Context};
+use leo_compiler::Compiler;
+use leo_errors::{emitter::Handler, CliError, Result};
+use leo_package::{
+    outputs::BuildArtifacts,
+    source::{MainFile, MAIN_FILENAME, SOURCE_DIRECTORY_NAME},
+};
+
+use structopt::StructOpt;
+use tracing::span::Span;
+
+/// Run the `@test` functions of the package.
+#[derive(StructOpt, Debug)]
+#[structopt(setting = structopt::clap::AppSettings::ColoredHelp)]
+pub struct Test {
+    #[structopt(help = "Run only the tests whose name contains this string.")]
+    pub(crate) filter: Option<String>,
+}
+
+impl Command for Test {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Test")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let mut package_path = context.dir()?;
+        if package_path.is_file() {
+            package_path.pop();
+        }
+
+        if !MainFile::exists_at(&package_path) {
+            return Err(CliError::package_main_file_not_found().into());
+        }
+
+        let output_directory = BuildArtifacts::new(&package_path).prepare(env!("CARGO_PKG_VERSION"))?;
+
+        let mut main_file_path = package_path;
+        main_file_path.push(SOURCE_DIRECTORY_NAME);
+        main_file_path.push(MAIN_FILENAME);
+
+        let handler = Handler::default().with_sorted_diagnostics();
+
+        // Tests run on the checked program, as optimizations may change how it fails.
+        let ast = Compiler::new(&handler, main_file_path, output_directory).check()?;
+        let program = ast.as_repr();
+        let tests = leo_interpreter::tests(&handler, program)?;
+        // Report the warnings of the program before the results of the tests.
+        handler.flush();
+
+        let (mut passed, mut failed) = (0, 0);
+        for test in tests.iter() {
+            let name = test.function.name().to_string();
+            if matches!(&self.filter, Some(filter) if !name.contains(filter.as_str())) {
+                continue;
+            }
+
+            let outcome = leo_interpreter::run_test(program, test);
+            for message in outcome.output.iter() {
+                tracing::info!("{}", message);
+            }
+            match outcome.error {
+                None => {
+                    tracing::info!("{} ... ok", name);
+                    passed += 1;
+                }
+                Some(error) => {
+                    tracing::error!("{} ... FAILED", name);
+                    handler.emit_err(error);
+                    handler.flush();
+                    failed += 1;
+                }
+            }
+        }
+
+        tracing::info!("{} passed; {} failed", passed, failed);
+        if failed > 0 {
+            return Err(CliError::failed_tests(failed).into());
+        }
+        Ok(())
+    }
+}
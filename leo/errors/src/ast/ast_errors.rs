@@ -138,4 +138,164 @@ create_errors!(
         msg: "tuples of 1 element are not allowed",
         help: None,
     }
+
+    /// For when a function calls itself, directly or through other functions.
+    @formatted
+    recursive_function_call {
+        args: (name: impl Display, cycle: impl Display),
+        msg: format!("function `{}` is recursive: {}", name, cycle),
+        help: Some("Recursive calls cannot be unrolled into a fixed size circuit. Rewrite the function using a loop.".to_string()),
+    }
+
+    /// For when a primitive type is accessed for a constant it does not define.
+    @formatted
+    unknown_intrinsic_constant {
+        args: (type_: impl Display, name: impl Display),
+        msg: format!("type `{}` has no associated constant `{}`", type_, name),
+        help: Some("Integer types define `MIN` and `MAX`, and `field` defines `MAX`. `field` has no `MODULUS`, as the modulus is not itself a field element; `field::MAX` is the modulus minus one.".to_string()),
+    }
+
+    /// For when a group coordinate is not an element of the base field.
+    @formatted
+    group_coordinate_out_of_range {
+        args: (coordinate: impl Display),
+        msg: format!("group coordinate `{}` is not an element of the base field", coordinate),
+        help: None,
+    }
+
+    /// For when the coordinates of a group literal do not describe a point on the curve.
+    @formatted
+    group_point_not_on_curve {
+        args: (x: impl Display, y: impl Display),
+        msg: format!("`({}, {})group` is not a point on the curve", x, y),
+        help: Some("The coordinates of a group element must satisfy `-x^2 + y^2 = 1 + 3021 * x^2 * y^2`.".to_string()),
+    }
+
+    /// For when warnings are denied and linting reported some.
+    @backtraced
+    denied_warnings {
+        args: (count: impl Display),
+        msg: format!("aborting due to {} denied warning(s)", count),
+        help: None,
+    }
+
+    /// For when a pass runs before a pass establishing a property of the AST it requires.
+    @backtraced
+    pass_requirement_not_met {
+        args: (pass: impl Display, property: impl Display),
+        msg: format!("pass `{}` requires {}, which no pass before it provides", pass, property),
+        help: None,
+    }
+
+    /// For when a pass is inserted next to a pass which is not in the pipeline.
+    @backtraced
+    unknown_pass {
+        args: (name: impl Display),
+        msg: format!("no pass named `{}` in the pipeline", name),
+        help: None,
+    }
+
+    /// For when `@program` has an argument other than `version = <integer>`, or a version below 1.
+    @formatted
+    invalid_program_annotation {
+        args: (),
+        msg: "`@program` expects a single argument `version = <integer>`, with a version of at least 1",
+        help: Some("for example, `@program(version = 2)`".to_string()),
+    }
+
+    /// For when the constructor of a program is const, or has inputs or an output.
+    @formatted
+    invalid_constructor {
+        args: (),
+        msg: "the constructor of a program must be a non-const function without inputs or output",
+        help: Some("the constructor runs once, when the program is deployed".to_string()),
+    }
+
+    /// For when a field literal is not less than the modulus of the field.
+    @formatted
+    field_out_of_range {
+        args: (value: impl Display),
+        msg: format!("field literal `{}` is not an element of the field", value),
+        help: Some("field elements must be less than the modulus 8444461749428370424248824938781546531375899335154063827935233455917409239041".to_string()),
+    }
+
+    /// For when a private circuit function is called from outside its circuit.
+    @formatted
+    private_circuit_function {
+        args: (circuit: impl Display, name: impl Display),
+        msg: format!("function `{}::{}` is private", circuit, name),
+        help: Some(format!("it can only be called by the functions of circuit `{}`", circuit)),
+    }
+
+    /// For when an import names a private function or circuit of the imported package.
+    @formatted
+    private_import {
+        args: (name: impl Display, package: impl Display),
+        msg: format!("`{}` is private to package `{}`", name, package),
+        help: Some("private functions and circuits can only be used by the program defining them".to_string()),
+    }
+
+    /// For when an input or the output of a function other than `main` has a mode.
+    @formatted
+    mode_outside_main {
+        args: (function: impl Display),
+        msg: format!("function `{}` cannot declare input or output modes", function),
+        help: Some("only the inputs and output of `main` can be `constant`, `private` or `public`".to_string()),
+    }
+
+    /// For when an input is declared both `const` and with a mode.
+    @formatted
+    const_input_with_mode {
+        args: (name: impl Display, mode: impl Display),
+        msg: format!("input `{}` is both `const` and `{}`", name, mode),
+        help: Some(format!("remove `const`; `constant {}` declares a constant input", name)),
+    }
+
+    /// For when the output of `main` is declared `constant`.
+    @formatted
+    constant_output {
+        args: (),
+        msg: "the output of `main` cannot be `constant`",
+        help: Some("the output is computed by the proof, so it is either `private` or `public`".to_string()),
+    }
+
+    /// For when a `@const` function uses an operation which cannot be evaluated at compile time.
+    @formatted
+    non_const_operation {
+        args: (function: impl Display, operation: impl Display),
+        msg: format!("`@const` function `{}` cannot use {}", function, operation),
+        help: Some("`@const` functions are evaluated at compile time, so they may only compute with their inputs, constants and other `@const` functions".to_string()),
+    }
+
+    /// For when the capacity of a `BoundedVec` is zero or does not fit in a `u32`.
+    @formatted
+    invalid_bounded_vec_capacity {
+        args: (capacity: impl Display),
+        msg: format!("invalid `BoundedVec` capacity `{}`", capacity),
+        help: Some("the capacity must be between 1 and 4294967295".to_string()),
+    }
+
+    /// For when the elements of a `BoundedVec` have no default value.
+    @formatted
+    bounded_vec_without_default {
+        args: (element: impl Display),
+        msg: format!("a `BoundedVec` cannot hold `{}`", element),
+        help: Some("the free slots of a `BoundedVec` hold a default value, which addresses do not have".to_string()),
+    }
+
+    /// For when a `BoundedVec` is created where its element type and capacity are not known.
+    @formatted
+    bounded_vec_without_type {
+        args: (),
+        msg: "cannot infer the element type and capacity of `BoundedVec`",
+        help: Some("annotate the variable it is assigned to, e.g., `let list: BoundedVec<u8, 4> = BoundedVec::new();`".to_string()),
+    }
+
+    /// For when the JSON of a program has a version of the format this compiler cannot read.
+    @backtraced
+    unsupported_ast_json_version {
+        args: (version: impl Display, supported: impl Display),
+        msg: format!("the AST JSON has version {}, which is not one of the versions 0 to {} this compiler reads", version, supported),
+        help: None,
+    }
 );
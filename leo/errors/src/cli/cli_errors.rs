@@ -400,6 +400,78 @@ create_errors!(
         msg: "unable to parse imported dependency's manifest",
         help: None,
     }
+
+    /// For when `--explain` is given a code which is not a Leo error code.
+    @backtraced
+    unknown_error_code {
+        args: (code: impl Display),
+        msg: format!("`{}` is not a Leo error code", code),
+        help: Some("Error codes look like `EPAR0370005`, as printed in brackets after `Error`.".to_string()),
+    }
+
+    /// For when `leo fmt --check` finds files which are not formatted.
+    @backtraced
+    unformatted_files {
+        args: (count: impl Display),
+        msg: format!("{} file(s) are not formatted", count),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    /// For when `leo test` runs tests which fail.
+    @backtraced
+    failed_tests {
+        args: (count: impl Display),
+        msg: format!("{} test(s) failed", count),
+        help: None,
+    }
+
+    /// For when `leo run` is asked to execute the circuit of a program.
+    @backtraced
+    circuit_execution_unsupported {
+        args: (),
+        msg: "executing the circuit of a program is not supported yet",
+        help: Some("Run `leo run --interpret` to interpret the program instead.".to_string()),
+    }
+
+    /// For when a workspace is built which has no members.
+    @backtraced
+    empty_workspace {
+        args: (),
+        msg: "the workspace has no members to build",
+        help: Some("List the directories of its packages in the `members` of the `[workspace]` table.".to_string()),
+    }
+
+    /// For when a dependency of the package cannot be fetched.
+    @backtraced
+    failed_to_fetch_dependency {
+        args: (name: impl Display, error: impl Display),
+        msg: format!("failed to fetch dependency `{}`: {}", name, error),
+        help: None,
+    }
+
+    /// For when an input passed to `leo run` is not of the form `name=value`.
+    @backtraced
+    invalid_input_argument {
+        args: (argument: impl Display),
+        msg: format!("invalid input `{}`", argument),
+        help: Some("Pass inputs as `--input name=value`.".to_string()),
+    }
+
+    /// For when an input passed to `leo run` is not an input of `main`.
+    @backtraced
+    unknown_main_input {
+        args: (name: impl Display, signature: impl Display),
+        msg: format!("`main` has no input `{}`", name),
+        help: Some(format!("The signature of `main` is `{}`.", signature)),
+    }
+
+    /// For when the report of a build cannot be written.
+    @backtraced
+    failed_to_write_build_report {
+        args: (path: impl Debug, error: impl Display),
+        msg: format!("failed to write the build report to {:?}: {}", path, error),
+        help: None,
+    }
 );
 
 impl CliError {
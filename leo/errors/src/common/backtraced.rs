@@ -14,11 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{common::formatted::write_note, paint};
+
 use std::fmt;
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
-use colored::Colorize;
+use colored::Color;
 use derivative::Derivative;
 
 /// The indent for an error message.
@@ -104,26 +106,10 @@ impl fmt::Display for BacktracedError {
             message = self.message,
         );
 
-        // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK")
-            .unwrap_or_default()
-            .trim()
-            .to_owned()
-            .is_empty()
-        {
-            write!(f, "{}", error_message.bold().red())?;
-        } else {
-            write!(f, "{}", error_message)?;
-        };
+        write!(f, "{}", paint(&error_message, Color::Red))?;
 
         if let Some(help) = &self.help {
-            write!(
-                f,
-                "\n{indent     } |\n\
-            {indent     } = {help}",
-                indent = INDENT,
-                help = help
-            )?;
+            write_note(f, help)?;
         }
 
         let leo_backtrace = std::env::var("LEO_BACKTRACE").unwrap_or_default().trim().to_owned();
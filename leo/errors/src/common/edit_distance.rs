@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Finds the names closest to a misspelled name, for "did you mean" suggestions.
+
+/// Returns the Levenshtein distance between `a` and `b`, i.e., the least number of
+/// single character insertions, deletions, and substitutions turning `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // The distances from the prefix of `a` seen so far to each prefix of `b`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + (a_char != *b_char) as usize;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Returns the candidate most likely meant by the misspelled `name`, if any is close enough.
+///
+/// A candidate equal to `name` up to case is preferred. Otherwise, the candidate with the
+/// least edit distance is chosen, provided the distance is at most a third of the length of `name`.
+pub fn find_best_match<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let candidates: Vec<&str> = candidates.into_iter().filter(|candidate| *candidate != name).collect();
+
+    if let Some(candidate) = candidates.iter().find(|candidate| candidate.eq_ignore_ascii_case(name)) {
+        return Some(candidate);
+    }
+
+    let max_distance = std::cmp::max(name.chars().count(), 3) / 3;
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("MAX", "MAX"), 0);
+    }
+
+    #[test]
+    fn finds_best_match() {
+        let names = ["balance", "owner", "total_supply"];
+        assert_eq!(find_best_match("blance", names), Some("balance"));
+        assert_eq!(find_best_match("OWNER", names), Some("owner"));
+        assert_eq!(find_best_match("total_suply", names), Some("total_supply"));
+        assert_eq!(find_best_match("amount", names), None);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Applies suggestions to the source code they were made for, e.g., for `leo lint --fix`.
+
+use crate::Suggestion;
+
+use leo_span::Span;
+
+/// Returns the byte offset in `source` of the line and column at which `span` starts or stops.
+/// A position past the end of a line or of the source is clamped to it.
+fn offset(line_starts: &[usize], source: &str, line: usize, col: usize) -> usize {
+    match line_starts.get(line.saturating_sub(1)) {
+        Some(start) => {
+            let end = line_starts.get(line).copied().unwrap_or(source.len());
+            (start + col.saturating_sub(1)).min(end)
+        }
+        None => source.len(),
+    }
+}
+
+/// Returns the byte offsets in `source` where `span` starts and stops.
+fn range(line_starts: &[usize], source: &str, span: &Span) -> (usize, usize) {
+    (
+        offset(line_starts, source, span.line_start, span.col_start),
+        offset(line_starts, source, span.line_stop, span.col_stop),
+    )
+}
+
+/// Applies `suggestions` to `source`, returning the fixed source and the number of suggestions applied.
+///
+/// The spans of the suggestions refer to `source`, so they are applied all at once.
+/// A suggestion overlapping one that comes before it is skipped, as its span no longer denotes
+/// the code it was made for; running the check again on the fixed source suggests it anew if it still applies.
+pub fn apply_suggestions<'a>(source: &str, suggestions: impl IntoIterator<Item = &'a Suggestion>) -> (String, usize) {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+
+    let mut edits: Vec<(usize, usize, &str)> = suggestions
+        .into_iter()
+        .map(|suggestion| {
+            let (start, stop) = range(&line_starts, source, &suggestion.span);
+            (start, stop.max(start), suggestion.replacement.as_str())
+        })
+        .collect();
+    edits.sort_by_key(|(start, stop, _)| (*start, *stop));
+
+    let mut fixed = String::with_capacity(source.len());
+    let mut applied = 0;
+    let mut copied = 0;
+    for (start, stop, replacement) in edits {
+        if start < copied || !source.is_char_boundary(start) || !source.is_char_boundary(stop) {
+            continue;
+        }
+        fixed.push_str(&source[copied..start]);
+        fixed.push_str(replacement);
+        copied = stop;
+        applied += 1;
+    }
+    fixed.push_str(&source[copied..]);
+    (fixed, applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(
+        replacement: &str,
+        line_start: usize,
+        col_start: usize,
+        line_stop: usize,
+        col_stop: usize,
+    ) -> Suggestion {
+        Suggestion {
+            message: String::new(),
+            replacement: replacement.to_string(),
+            span: Span::new(line_start, line_stop, col_start, col_stop, Default::default(), ""),
+        }
+    }
+
+    #[test]
+    fn applies_suggestions() {
+        let source = "let a = 1u8;\nlet b = 2;\nreturn a;\n";
+        let suggestions = vec![
+            suggestion("u8", 2, 10, 2, 10),
+            suggestion("", 1, 1, 2, 1),
+            suggestion("b", 3, 8, 3, 9),
+        ];
+        let (fixed, applied) = apply_suggestions(source, &suggestions);
+        assert_eq!(fixed, "let b = 2u8;\nreturn b;\n");
+        assert_eq!(applied, 3);
+    }
+
+    #[test]
+    fn skips_overlapping_suggestions() {
+        let source = "let a = b;";
+        let suggestions = vec![suggestion("", 1, 1, 1, 11), suggestion("c", 1, 9, 1, 10)];
+        let (fixed, applied) = apply_suggestions(source, &suggestions);
+        assert_eq!(fixed, "");
+        assert_eq!(applied, 1);
+    }
+
+    #[test]
+    fn clamps_positions_past_the_end() {
+        let (fixed, _) = apply_suggestions("let a = 1u8;", &[suggestion("", 1, 1, 2, 1)]);
+        assert_eq!(fixed, "");
+    }
+}
@@ -14,13 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BacktracedError, INDENT};
+use crate::{paint, BacktracedError, GUTTER, INDENT};
 
 use leo_span::Span;
 
 use backtrace::Backtrace;
 use color_backtrace::{BacktracePrinter, Verbosity};
-use colored::Colorize;
+use colored::Color;
 use std::fmt;
 
 /// Formatted compiler error type
@@ -38,6 +38,31 @@ pub struct FormattedError {
     pub span: Span,
     /// The backtrace to track where the Leo error originated.
     pub backtrace: BacktracedError,
+    /// A fix for the error that tools may apply automatically.
+    pub suggestion: Option<Box<Suggestion>>,
+    /// Other code related to the error, such as an earlier definition.
+    pub labels: Vec<Label>,
+}
+
+/// A secondary span of an error, with a message explaining how it relates to the error.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Label {
+    /// Describes the span, e.g., `first defined here`.
+    pub message: String,
+    /// The related source.
+    pub span: Span,
+}
+
+/// A machine-applicable fix: replace the source at `span` with `replacement`.
+/// An empty span, where the start equals the stop, inserts the replacement.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct Suggestion {
+    /// Describes the fix, e.g., `try adding a semicolon`.
+    pub message: String,
+    /// The text to put in place of `span`.
+    pub replacement: String,
+    /// The source to replace.
+    pub span: Span,
 }
 
 impl FormattedError {
@@ -64,9 +89,30 @@ impl FormattedError {
                 error_type,
                 backtrace,
             ),
+            suggestion: None,
+            labels: Vec::new(),
         }
     }
 
+    /// Attaches a suggestion to replace the source at `span` with `replacement`.
+    pub fn with_suggestion(mut self, message: impl ToString, replacement: impl ToString, span: &Span) -> Self {
+        self.suggestion = Some(Box::new(Suggestion {
+            message: message.to_string(),
+            replacement: replacement.to_string(),
+            span: span.clone(),
+        }));
+        self
+    }
+
+    /// Attaches the related source at `span`, described by `message`.
+    pub fn with_label(mut self, message: impl ToString, span: &Span) -> Self {
+        self.labels.push(Label {
+            message: message.to_string(),
+            span: span.clone(),
+        });
+        self
+    }
+
     /// Calls the backtraces error code.
     pub fn exit_code(&self) -> i32 {
         self.backtrace.exit_code()
@@ -78,81 +124,97 @@ impl FormattedError {
     }
 }
 
-impl fmt::Display for FormattedError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let underline = |mut start: usize, mut end: usize| -> String {
-            if start > end {
-                std::mem::swap(&mut start, &mut end)
-            }
+/// Writes the location of `span` followed by its source lines, with the span underlined in `color`.
+pub(crate) fn write_snippet(f: &mut fmt::Formatter, span: &Span, color: Color) -> fmt::Result {
+    let underline = |mut start: usize, mut end: usize| -> String {
+        if start > end {
+            std::mem::swap(&mut start, &mut end)
+        }
 
-            let mut underline = String::new();
+        let mut underline = String::new();
 
-            for _ in 0..start {
-                underline.push(' ');
-                end -= 1;
-            }
+        for _ in 0..start {
+            underline.push(' ');
+            end -= 1;
+        }
 
-            for _ in 0..end {
-                underline.push('^');
-            }
+        for _ in 0..end {
+            underline.push('^');
+        }
+
+        underline
+    };
+
+    let underlined = underline(span.col_start, span.col_stop);
+    let margin = paint(&format!("{} |", INDENT), GUTTER);
+
+    write!(
+        f,
+        "\n{arrow}{path}:{line_start}:{start}\n\
+        {margin}\n",
+        arrow = paint(&format!("{}--> ", INDENT), GUTTER),
+        path = &*span.path,
+        line_start = span.line_start,
+        start = span.col_start,
+        margin = margin,
+    )?;
+
+    for (line_no, line) in span.content.lines().enumerate() {
+        writeln!(
+            f,
+            "{line_no} {text}",
+            line_no = paint(
+                &format!("{:width$} |", span.line_start + line_no, width = INDENT.len()),
+                GUTTER
+            ),
+            text = line,
+        )?;
+    }
 
-            underline
-        };
+    write!(
+        f,
+        "{margin}{underlined}",
+        margin = margin,
+        underlined = paint(&underlined, color),
+    )
+}
 
-        let underlined = underline(self.span.col_start, self.span.col_stop);
+/// Writes a `= note` or `= help` line after a blank margin line.
+pub(crate) fn write_note(f: &mut fmt::Formatter, note: impl fmt::Display) -> fmt::Result {
+    write!(
+        f,
+        "\n{margin}\n{equals} {note}",
+        margin = paint(&format!("{} |", INDENT), GUTTER),
+        equals = paint(&format!("{} =", INDENT), GUTTER),
+        note = note,
+    )
+}
 
+impl fmt::Display for FormattedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let error_message = format!(
             "Error [{error_code}]: {message}",
             error_code = self.error_code(),
             message = self.backtrace.message,
         );
 
-        // To avoid the color enabling characters for comparison with test expectations.
-        if std::env::var("LEO_TESTFRAMEWORK")
-            .unwrap_or_default()
-            .trim()
-            .to_owned()
-            .is_empty()
-        {
-            write!(f, "{}", error_message.bold().red())?;
-        } else {
-            write!(f, "{}", error_message)?;
-        };
-
-        write!(
-            f,
-            "\n{indent     }--> {path}:{line_start}:{start}\n\
-            {indent     } |\n",
-            indent = INDENT,
-            path = &*self.span.path,
-            line_start = self.span.line_start,
-            start = self.span.col_start,
-        )?;
+        write!(f, "{}", paint(&error_message, Color::Red))?;
 
-        for (line_no, line) in self.span.content.lines().enumerate() {
-            writeln!(
-                f,
-                "{line_no:width$} | {text}",
-                width = INDENT.len(),
-                line_no = self.span.line_start + line_no,
-                text = line,
-            )?;
+        write_snippet(f, &self.span, Color::Red)?;
+
+        if let Some(help) = &self.backtrace.help {
+            write_note(f, help)?;
         }
 
-        write!(
-            f,
-            "{indent     } |{underlined}",
-            indent = INDENT,
-            underlined = underlined,
-        )?;
+        for label in self.labels.iter() {
+            write_note(f, format_args!("note: {}", label.message))?;
+            write_snippet(f, &label.span, GUTTER)?;
+        }
 
-        if let Some(help) = &self.backtrace.help {
-            write!(
+        if let Some(suggestion) = &self.suggestion {
+            write_note(
                 f,
-                "\n{indent     } |\n\
-            {indent     } = {help}",
-                indent = INDENT,
-                help = help
+                format_args!("help: {}: `{}`", suggestion.message, suggestion.replacement),
             )?;
         }
 
@@ -73,6 +73,84 @@ macro_rules! create_errors {
         }
 
 
+        impl $error_type {
+            /// Returns the message, help, and codes of the error.
+            pub fn backtraced(&self) -> &BacktracedError {
+                match self {
+                    Self::FormattedError(formatted) => &formatted.backtrace,
+                    Self::BacktracedError(backtraced) => backtraced,
+                }
+            }
+
+            /// Returns the span the error points at, if any.
+            pub fn span(&self) -> Option<&leo_span::Span> {
+                match self {
+                    Self::FormattedError(formatted) => Some(&formatted.span),
+                    Self::BacktracedError(_) => None,
+                }
+            }
+
+            /// Returns the suggested fix for the error, if any.
+            pub fn suggestion(&self) -> Option<&crate::Suggestion> {
+                match self {
+                    Self::FormattedError(formatted) => formatted.suggestion.as_deref(),
+                    Self::BacktracedError(_) => None,
+                }
+            }
+
+            /// Returns the related spans of the error, if any.
+            pub fn labels(&self) -> &[crate::Label] {
+                match self {
+                    Self::FormattedError(formatted) => &formatted.labels,
+                    Self::BacktracedError(_) => &[],
+                }
+            }
+
+            /// Attaches the related source at `span`, described by `message`.
+            /// Errors without a span cannot carry labels and are returned unchanged.
+            pub fn with_label(self, message: impl ToString, span: &leo_span::Span) -> Self {
+                match self {
+                    Self::FormattedError(formatted) => Self::FormattedError(formatted.with_label(message, span)),
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Attaches a suggestion to replace the source at `span` with `replacement`.
+            /// Errors without a span cannot carry a suggestion and are returned unchanged.
+            pub fn with_suggestion(self, message: impl ToString, replacement: impl ToString, span: &leo_span::Span) -> Self {
+                match self {
+                    Self::FormattedError(formatted) => Self::FormattedError(formatted.with_suggestion(message, replacement, span)),
+                    backtraced => backtraced,
+                }
+            }
+
+            /// Attaches a suggestion to replace the misspelled `name` at `span` with the most similar
+            /// of `candidates`, if any is similar enough.
+            pub fn with_similar_name<'a>(self, name: &str, candidates: impl IntoIterator<Item = &'a str>, span: &leo_span::Span) -> Self {
+                match crate::find_best_match(name, candidates) {
+                    Some(candidate) => self.with_suggestion("a similar name exists", candidate, span),
+                    None => self,
+                }
+            }
+        }
+
+        impl $error_type {
+            /// Returns the names of the errors, in the order of their codes.
+            pub fn error_names() -> &'static [&'static str] {
+                &[$(stringify!($names)),*]
+            }
+
+            /// Returns the code of the error with the given index in `error_names`.
+            pub fn error_code_of(index: usize) -> String {
+                format!(
+                    "E{error_type}{code_identifier:0>3}{exit_code:0>4}",
+                    error_type = Self::error_type(),
+                    code_identifier = Self::code_identifier(),
+                    exit_code = Self::exit_code_mask() + index as i32,
+                )
+            }
+        }
+
         // Steps over the list of functions with an initial error code of 0.
         impl $error_type {
             create_errors!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
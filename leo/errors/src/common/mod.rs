@@ -18,6 +18,14 @@
 pub mod backtraced;
 pub use self::backtraced::*;
 
+/// This module contains the edit distance used to suggest similar names.
+pub mod edit_distance;
+pub use self::edit_distance::*;
+
+/// This module contains the application of suggestions to source code.
+pub mod fix;
+pub use self::fix::*;
+
 /// This module contains a formatted error and its methods.
 pub mod formatted;
 pub use self::formatted::*;
@@ -27,6 +35,10 @@ pub use self::formatted::*;
 pub mod macros;
 pub use self::macros::*;
 
+/// This module contains the colors used to render diagnostics.
+pub mod style;
+pub use self::style::*;
+
 /// This module contains traits for making errors easily.
 pub mod traits;
 pub use self::traits::*;
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use colored::{Color, ColoredString, Colorize};
+use std::cell::Cell;
+
+thread_local! {
+    static COLORS: Cell<bool> = Cell::new(true);
+}
+
+/// The color of the line numbers and margin of source snippets.
+pub(crate) const GUTTER: Color = Color::Blue;
+
+/// Returns whether diagnostics are rendered with colors.
+/// Colors are never used in the test framework, so that output can be compared with expectations.
+pub fn colors_enabled() -> bool {
+    COLORS.with(Cell::get) && std::env::var("LEO_TESTFRAMEWORK").unwrap_or_default().trim().is_empty()
+}
+
+/// Runs `f`, rendering any diagnostics it displays without colors.
+pub fn without_colors<T>(f: impl FnOnce() -> T) -> T {
+    let previous = COLORS.with(|colors| colors.replace(false));
+    let result = f();
+    COLORS.with(|colors| colors.set(previous));
+    result
+}
+
+/// Renders `text` in bold `color`, or as is when colors are disabled.
+pub(crate) fn paint(text: &str, color: Color) -> ColoredString {
+    if colors_enabled() {
+        text.color(color).bold()
+    } else {
+        text.normal()
+    }
+}
@@ -312,4 +312,76 @@ create_errors!(
         msg: format!("Tried to assign to static member `{}`", member),
         help: None,
     }
+
+    /// For when a lint warning is reported while warnings are denied.
+    @formatted
+    denied_warning {
+        args: (message: impl Display, lint: impl Display),
+        msg: message,
+        help: Some(format!("`{}` is denied by `--deny-warnings`; add `@allow({})` to the function to allow it", lint, lint)),
+    }
+
+    /// For when a `console.assert` fails while a program is interpreted.
+    @formatted
+    assertion_failed {
+        args: (expression: impl Display),
+        msg: format!("assertion failed: `{}`", expression),
+        help: None,
+    }
+
+    /// For when an integer operation overflows while a program is interpreted.
+    @formatted
+    integer_overflow {
+        args: (operation: impl Display),
+        msg: format!("the operation `{}` overflows", operation),
+        help: None,
+    }
+
+    /// For when a division or remainder by zero is interpreted.
+    @formatted
+    division_by_zero {
+        args: (operation: impl Display),
+        msg: format!("the operation `{}` divides by zero", operation),
+        help: None,
+    }
+
+    /// For when the interpreter does not support evaluating an expression.
+    @formatted
+    cannot_interpret {
+        args: (what: impl Display),
+        msg: format!("the interpreter cannot evaluate {}", what),
+        help: None,
+    }
+
+    /// For when a test function has inputs.
+    @formatted
+    test_function_with_inputs {
+        args: (name: impl Display),
+        msg: format!("test function `{}` cannot have inputs", name),
+        help: None,
+    }
+
+    /// For when `@should_fail` is put on a function which is not a test.
+    @formatted
+    should_fail_without_test {
+        args: (name: impl Display),
+        msg: format!("`@should_fail` is only allowed on test functions, but `{}` is not annotated with `@test`", name),
+        help: None,
+    }
+
+    /// For when a test annotated with `@should_fail` passes.
+    @formatted
+    test_did_not_fail {
+        args: (name: impl Display),
+        msg: format!("test `{}` was expected to fail, but passed", name),
+        help: None,
+    }
+
+    /// For when a field element is not less than the modulus of the field.
+    @formatted
+    invalid_field {
+        args: (value: impl Display),
+        msg: format!("failed to parse field value '{}'", value),
+        help: Some("field elements must be less than the modulus 8444461749428370424248824938781546531375899335154063827935233455917409239041".to_string()),
+    }
 );
@@ -14,24 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::LeoError;
+use super::{without_colors, LeoError, LeoWarning};
 use core::default::Default;
 use core::fmt;
+use leo_span::Span;
+use serde_json::json;
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::{self, Write};
 use std::rc::Rc;
 
 /// Types that are sinks for compiler errors.
 pub trait Emitter {
     /// Emit the error `err`.
     fn emit_err(&mut self, err: LeoError);
+
+    /// Emit the warning `warning`.
+    fn emit_warning(&mut self, warning: LeoWarning);
 }
 
 /// A trivial `Emitter` using the standard error.
 pub struct StderrEmitter;
 
+impl StderrEmitter {
+    /// Prints `diagnostic`, with colors only if the standard error is a terminal.
+    fn print(diagnostic: impl fmt::Display) {
+        if atty::is(atty::Stream::Stderr) {
+            eprintln!("{}", diagnostic);
+        } else {
+            without_colors(|| eprintln!("{}", diagnostic));
+        }
+    }
+}
+
 impl Emitter for StderrEmitter {
     fn emit_err(&mut self, err: LeoError) {
-        eprintln!("{}", err);
+        Self::print(err);
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        Self::print(warning);
     }
 }
 
@@ -73,26 +96,231 @@ impl<T: fmt::Display> fmt::Display for Buffer<T> {
 /// A buffer of `LeoError`s.
 pub type ErrBuffer = Buffer<LeoError>;
 
+/// A buffer of `LeoWarning`s.
+pub type WarningBuffer = Buffer<LeoWarning>;
+
 /// An `Emitter` that collects into a list.
 #[derive(Default, Clone)]
-pub struct BufferEmitter(Rc<RefCell<ErrBuffer>>);
+pub struct BufferEmitter(Rc<RefCell<ErrBuffer>>, Rc<RefCell<WarningBuffer>>);
 
 impl BufferEmitter {
     /// Returns a new buffered emitter.
     pub fn new() -> Self {
-        BufferEmitter(<_>::default())
+        BufferEmitter(<_>::default(), <_>::default())
     }
 
     /// Extracts all the errors collected in this emitter.
     pub fn extract(&self) -> ErrBuffer {
         self.0.take()
     }
+
+    /// Extracts all the warnings collected in this emitter.
+    pub fn extract_warnings(&self) -> WarningBuffer {
+        self.1.take()
+    }
 }
 
 impl Emitter for BufferEmitter {
     fn emit_err(&mut self, err: LeoError) {
         self.0.borrow_mut().push(err);
     }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        self.1.borrow_mut().push(warning);
+    }
+}
+
+/// An `Emitter` writing each error as one line of JSON, for consumption by editors and CI.
+/// The format follows the diagnostics of `rustc --error-format=json`.
+pub struct JsonEmitter<W: Write>(W);
+
+impl JsonEmitter<io::Stdout> {
+    /// Returns a JSON emitter writing to the standard output, which is left to the diagnostics.
+    pub fn stdout() -> Self {
+        Self(io::stdout())
+    }
+}
+
+impl<W: Write> JsonEmitter<W> {
+    /// Returns a JSON emitter writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self(writer)
+    }
+
+    /// Extracts the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.0
+    }
+}
+
+impl<W: Write> Emitter for JsonEmitter<W> {
+    fn emit_err(&mut self, err: LeoError) {
+        // There is nowhere left to report a failure to write a diagnostic.
+        let _ = writeln!(self.0, "{}", json_diagnostic(&err));
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        let _ = writeln!(self.0, "{}", json_warning(&warning));
+    }
+}
+
+/// Returns `span` as the span of a JSON diagnostic.
+fn json_span(span: &Span, suggested_replacement: Option<&str>) -> serde_json::Value {
+    json!({
+        "file_name": span.path.as_str(),
+        "line_start": span.line_start,
+        "line_end": span.line_stop,
+        "column_start": span.col_start,
+        "column_end": span.col_stop,
+        "is_primary": true,
+        "text": &*span.content,
+        "suggested_replacement": suggested_replacement,
+    })
+}
+
+/// Returns `err` as a JSON diagnostic.
+pub fn json_diagnostic(err: &LeoError) -> serde_json::Value {
+    let (message, help) = match err.backtraced() {
+        Some(backtraced) => (backtraced.message.clone(), backtraced.help.clone()),
+        None => (err.to_string(), None),
+    };
+    let spans: Vec<_> = err.span().map(|span| json_span(span, None)).into_iter().collect();
+    let mut children: Vec<_> = help
+        .map(|help| json!({ "level": "help", "message": help, "spans": [] }))
+        .into_iter()
+        .collect();
+    for label in err.labels() {
+        children.push(json!({
+            "level": "note",
+            "message": label.message,
+            "spans": [json_span(&label.span, None)],
+        }));
+    }
+    if let Some(suggestion) = err.suggestion() {
+        children.push(json!({
+            "level": "help",
+            "message": suggestion.message,
+            "spans": [json_span(&suggestion.span, Some(&suggestion.replacement))],
+        }));
+    }
+
+    json!({
+        "level": "error",
+        "code": { "code": err.error_code() },
+        "message": message,
+        "spans": spans,
+        "children": children,
+        "rendered": without_colors(|| err.to_string()),
+    })
+}
+
+/// Returns `warning` as a JSON diagnostic. The code of a warning is the name of its lint.
+pub fn json_warning(warning: &LeoWarning) -> serde_json::Value {
+    let mut children: Vec<_> = warning
+        .help
+        .iter()
+        .map(|help| json!({ "level": "help", "message": help, "spans": [] }))
+        .collect();
+    if let Some(suggestion) = &warning.suggestion {
+        children.push(json!({
+            "level": "help",
+            "message": suggestion.message,
+            "spans": [json_span(&suggestion.span, Some(&suggestion.replacement))],
+        }));
+    }
+
+    json!({
+        "level": "warning",
+        "code": { "code": warning.lint.name() },
+        "message": warning.message,
+        "spans": [json_span(&warning.span, None)],
+        "children": children,
+        "rendered": without_colors(|| warning.to_string()),
+    })
+}
+
+/// Returns `path:line:col: ` for `span`, or nothing if there is no span.
+fn compact_location(span: Option<&Span>) -> String {
+    span.map(|span| format!("{}:{}:{}: ", span.path, span.line_start, span.col_start))
+        .unwrap_or_default()
+}
+
+/// Returns `err` on a single line, with its code, location, and message but without the source code.
+pub fn compact_diagnostic(err: &LeoError) -> String {
+    let message = match err.backtraced() {
+        Some(backtraced) => backtraced.message.clone(),
+        None => without_colors(|| err.to_string()),
+    };
+    format!(
+        "error[{}] {}{}",
+        err.error_code(),
+        compact_location(err.span()),
+        message
+    )
+}
+
+/// Returns `warning` on a single line, with the name of its lint, location, and message.
+pub fn compact_warning(warning: &LeoWarning) -> String {
+    format!(
+        "warning[{}] {}{}",
+        warning.lint.name(),
+        compact_location(Some(&warning.span)),
+        warning.message
+    )
+}
+
+/// A diagnostic waiting to be emitted.
+enum Diagnostic {
+    /// An error.
+    Error(LeoError),
+    /// A warning.
+    Warning(LeoWarning),
+}
+
+impl Diagnostic {
+    /// Returns the span the diagnostic points at, if any.
+    fn span(&self) -> Option<&Span> {
+        match self {
+            Diagnostic::Error(err) => err.span(),
+            Diagnostic::Warning(warning) => Some(&warning.span),
+        }
+    }
+
+    /// Returns what identifies the diagnostic, such that a diagnostic reported twice,
+    /// e.g., by several passes, has the same key both times.
+    fn key(&self) -> DiagnosticKey {
+        let (code, message) = match self {
+            Diagnostic::Error(err) => (
+                err.error_code(),
+                err.backtraced().map(|b| b.message.clone()).unwrap_or_default(),
+            ),
+            Diagnostic::Warning(warning) => (warning.lint.to_string(), warning.message.clone()),
+        };
+        DiagnosticKey {
+            code,
+            message,
+            location: self.span().map(|span| {
+                (
+                    span.path.to_string(),
+                    span.line_start,
+                    span.col_start,
+                    span.line_stop,
+                    span.col_stop,
+                )
+            }),
+        }
+    }
+}
+
+/// Identifies a diagnostic by its code, message and location.
+#[derive(PartialEq, Eq, Hash)]
+struct DiagnosticKey {
+    /// The error code, or the name of the lint for warnings.
+    code: String,
+    /// The message of the diagnostic.
+    message: String,
+    /// The file and the start and stop positions of the span, if any.
+    location: Option<(String, usize, usize, usize, usize)>,
 }
 
 /// Contains the actual data for `Handler`.
@@ -100,6 +328,14 @@ impl Emitter for BufferEmitter {
 struct HandlerInner {
     /// Number of errors emitted thus far.
     count: usize,
+    /// Number of warnings emitted thus far.
+    warn_count: usize,
+    /// Whether warnings are reported as errors.
+    deny_warnings: bool,
+    /// The diagnostics reported so far, so that one reported twice is only emitted once.
+    reported: HashSet<DiagnosticKey>,
+    /// The diagnostics held back until `flush`, if they are emitted in source order.
+    pending: Option<Vec<Diagnostic>>,
     /// The sink through which errors will be emitted.
     emitter: Box<dyn Emitter>,
 }
@@ -107,8 +343,58 @@ struct HandlerInner {
 impl HandlerInner {
     /// Emit the error `err`.
     fn emit_err(&mut self, err: LeoError) {
-        self.count = self.count.saturating_add(1);
-        self.emitter.emit_err(err);
+        self.report(Diagnostic::Error(err));
+    }
+
+    /// Emit the warning `warning`, or the error for it if warnings are denied.
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        if self.deny_warnings {
+            self.emit_err(warning.into_error());
+        } else {
+            self.report(Diagnostic::Warning(warning));
+        }
+    }
+
+    /// Counts `diagnostic` and emits it, or holds it back until `flush`,
+    /// unless the same diagnostic was reported before.
+    fn report(&mut self, diagnostic: Diagnostic) {
+        if !self.reported.insert(diagnostic.key()) {
+            return;
+        }
+        match diagnostic {
+            Diagnostic::Error(_) => self.count = self.count.saturating_add(1),
+            Diagnostic::Warning(_) => self.warn_count = self.warn_count.saturating_add(1),
+        }
+        match &mut self.pending {
+            Some(pending) => pending.push(diagnostic),
+            None => self.send(diagnostic),
+        }
+    }
+
+    /// Emits the held back diagnostics, sorted by file and position.
+    /// Diagnostics without a span come last, and the sort keeps the order of equal positions.
+    fn flush(&mut self) {
+        let mut pending = match &mut self.pending {
+            Some(pending) => std::mem::take(pending),
+            None => return,
+        };
+        pending.sort_by(|a, b| match (a.span(), b.span()) {
+            (Some(a), Some(b)) => (&a.path, a.line_start, a.col_start).cmp(&(&b.path, b.line_start, b.col_start)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        for diagnostic in pending {
+            self.send(diagnostic);
+        }
+    }
+
+    /// Passes `diagnostic` to the emitter.
+    fn send(&mut self, diagnostic: Diagnostic) {
+        match diagnostic {
+            Diagnostic::Error(err) => self.emitter.emit_err(err),
+            Diagnostic::Warning(warning) => self.emitter.emit_warning(warning),
+        }
     }
 }
 
@@ -128,10 +414,31 @@ impl Default for Handler {
 impl Handler {
     /// Construct a `Handler` using the given `emitter`.
     pub fn new(emitter: Box<dyn Emitter>) -> Self {
-        let inner = RefCell::new(HandlerInner { count: 0, emitter });
+        let inner = RefCell::new(HandlerInner {
+            count: 0,
+            warn_count: 0,
+            deny_warnings: false,
+            reported: HashSet::new(),
+            pending: None,
+            emitter,
+        });
         Self { inner }
     }
 
+    /// Sets whether warnings are reported as errors.
+    pub fn with_deny_warnings(self, deny_warnings: bool) -> Self {
+        self.inner.borrow_mut().deny_warnings = deny_warnings;
+        self
+    }
+
+    /// Holds back diagnostics until the handler is flushed or dropped,
+    /// then emits them sorted by file and position, so that the output does not depend
+    /// on the order in which passes report them.
+    pub fn with_sorted_diagnostics(self) -> Self {
+        self.inner.borrow_mut().pending = Some(Vec::new());
+        self
+    }
+
     /// Construct a `Handler` that will append to `buf`.
     pub fn new_with_buf() -> (Self, BufferEmitter) {
         let buf = BufferEmitter::default();
@@ -151,19 +458,36 @@ impl Handler {
         self.inner.borrow_mut().emit_err(err);
     }
 
+    /// Emit the warning `warning`.
+    /// If warnings are denied, the warning is emitted and counted as an error instead.
+    pub fn emit_warning(&self, warning: LeoWarning) {
+        self.inner.borrow_mut().emit_warning(warning);
+    }
+
     /// Emits the error `err`.
     /// This will immediately abort compilation.
     pub fn fatal_err(&self, err: LeoError) -> ! {
         let code = err.exit_code();
         self.emit_err(err);
+        self.flush();
         std::process::exit(code);
     }
 
+    /// Emits the diagnostics held back by `with_sorted_diagnostics`.
+    pub fn flush(&self) {
+        self.inner.borrow_mut().flush();
+    }
+
     /// The number of errors thus far.
     pub fn err_count(&self) -> usize {
         self.inner.borrow().count
     }
 
+    /// The number of warnings thus far, excluding those reported as errors.
+    pub fn warning_count(&self) -> usize {
+        self.inner.borrow().warn_count
+    }
+
     /// Did we have any errors thus far?
     pub fn had_errors(&self) -> bool {
         self.err_count() > 0
@@ -183,11 +507,16 @@ impl Handler {
     }
 }
 
+impl Drop for Handler {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ParserError;
-    use leo_span::Span;
 
     #[test]
     fn fresh_no_errors() {
@@ -222,4 +551,115 @@ mod tests {
 
         let () = Handler::with(|_| Ok(())).unwrap();
     }
+
+    #[test]
+    fn deny_warnings_works() {
+        let span = Span::default();
+
+        let buf = BufferEmitter::new();
+        let handler = Handler::new(Box::new(buf.clone()));
+        handler.emit_warning(LeoWarning::unused_variable("x", &span));
+        assert_eq!((handler.warning_count(), handler.err_count()), (1, 0));
+        assert_eq!(
+            buf.extract_warnings().into_inner()[0].lint,
+            crate::LintName::UnusedVariables
+        );
+
+        let buf = BufferEmitter::new();
+        let handler = Handler::new(Box::new(buf.clone())).with_deny_warnings(true);
+        handler.emit_warning(LeoWarning::unused_variable("x", &span));
+        assert_eq!((handler.warning_count(), handler.err_count()), (0, 1));
+        assert!(buf.extract().to_string().contains("unused variable `x`"));
+    }
+
+    #[test]
+    fn sorts_and_dedups_diagnostics() {
+        let at = |line_start, col_start| Span {
+            line_start,
+            line_stop: line_start,
+            col_start,
+            col_stop: col_start + 1,
+            path: std::sync::Arc::new("main.leo".to_string()),
+            content: "let x".into(),
+        };
+
+        let buf = BufferEmitter::new();
+        let handler = Handler::new(Box::new(buf.clone())).with_sorted_diagnostics();
+        handler.emit_err(ParserError::unexpected_eof(&at(3, 1)).into());
+        handler.emit_err(ParserError::invalid_import_list(&at(1, 5)).into());
+        handler.emit_err(ParserError::unexpected_eof(&at(3, 1)).into());
+        handler.emit_warning(LeoWarning::unused_variable("x", &at(2, 1)));
+        handler.emit_err(ParserError::invalid_import_list(&at(1, 2)).into());
+        assert_eq!((handler.err_count(), handler.warning_count()), (3, 1));
+        assert!(buf.extract().into_inner().is_empty());
+
+        handler.flush();
+        let lines: Vec<usize> = buf
+            .extract()
+            .into_inner()
+            .iter()
+            .map(|err| err.span().unwrap().col_start + 10 * err.span().unwrap().line_start)
+            .collect();
+        assert_eq!(lines, vec![12, 15, 31]);
+        assert_eq!(buf.extract_warnings().into_inner().len(), 1);
+    }
+
+    #[test]
+    fn json_emitter_works() {
+        let span = Span {
+            line_start: 2,
+            line_stop: 2,
+            col_start: 5,
+            col_stop: 8,
+            path: std::sync::Arc::new("main.leo".to_string()),
+            content: "let x".into(),
+        };
+        let code = LeoError::from(ParserError::invalid_import_list(&span)).error_code();
+        let mut emitter = JsonEmitter::new(Vec::new());
+        emitter.emit_err(ParserError::invalid_import_list(&span).into());
+        emitter.emit_err(ParserError::unexpected_eof(&span).into());
+
+        let output = String::from_utf8(emitter.into_inner()).unwrap();
+        let lines: Vec<serde_json::Value> = output.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "error");
+        assert_eq!(lines[0]["code"]["code"], code.as_str());
+        assert_eq!(lines[0]["spans"][0]["file_name"], "main.leo");
+        assert_eq!(lines[0]["spans"][0]["line_start"], 2);
+        assert_eq!(lines[0]["spans"][0]["column_end"], 8);
+        assert!(!lines[0]["rendered"].as_str().unwrap().contains('\u{1b}'));
+
+        let err = ParserError::unexpected_eof(&span).with_suggestion("try adding a semicolon", ";", &span);
+        let diagnostic = json_diagnostic(&err.into());
+        assert_eq!(diagnostic["children"][0]["message"], "try adding a semicolon");
+        assert_eq!(diagnostic["children"][0]["spans"][0]["suggested_replacement"], ";");
+    }
+
+    #[test]
+    fn compact_diagnostics_work() {
+        let span = Span {
+            line_start: 2,
+            line_stop: 2,
+            col_start: 5,
+            col_stop: 8,
+            path: std::sync::Arc::new("main.leo".to_string()),
+            content: "let x".into(),
+        };
+        let err: LeoError = ParserError::unexpected_eof(&span).into();
+        assert_eq!(
+            compact_diagnostic(&err),
+            format!("error[{}] main.leo:2:5: unexpected EOF", err.error_code())
+        );
+        assert_eq!(
+            compact_warning(&LeoWarning::unused_variable("x", &span)),
+            "warning[unused_variables] main.leo:2:5: unused variable `x`"
+        );
+    }
+
+    #[test]
+    fn without_colors_works() {
+        let enabled = crate::colors_enabled();
+        without_colors(|| assert!(!crate::colors_enabled()));
+        assert_eq!(crate::colors_enabled(), enabled);
+    }
 }
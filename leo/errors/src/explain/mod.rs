@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    AsgError, AstError, CliError, CompilerError, ImportError, InputError, LeoErrorCode, PackageError, ParserError,
+    StateError,
+};
+
+/// The extended descriptions of error codes, each with an example of erroneous code and its fix.
+/// Codes are stable, as new errors are only ever added at the end of their type.
+const EXPLANATIONS: &[(&str, &str)] = &[
+    ("EAST0372014", include_str!("codes/EAST0372014.md")),
+    ("EAST0372015", include_str!("codes/EAST0372015.md")),
+    ("EAST0372016", include_str!("codes/EAST0372016.md")),
+    ("EAST0372017", include_str!("codes/EAST0372017.md")),
+    ("EPAR0370001", include_str!("codes/EPAR0370001.md")),
+    ("EPAR0370002", include_str!("codes/EPAR0370002.md")),
+    ("EPAR0370003", include_str!("codes/EPAR0370003.md")),
+    ("EPAR0370004", include_str!("codes/EPAR0370004.md")),
+    ("EPAR0370005", include_str!("codes/EPAR0370005.md")),
+    ("EPAR0370006", include_str!("codes/EPAR0370006.md")),
+    ("EPAR0370009", include_str!("codes/EPAR0370009.md")),
+    ("EPAR0370010", include_str!("codes/EPAR0370010.md")),
+    ("EPAR0370011", include_str!("codes/EPAR0370011.md")),
+    ("EPAR0370012", include_str!("codes/EPAR0370012.md")),
+    ("EPAR0370013", include_str!("codes/EPAR0370013.md")),
+    ("EPAR0370014", include_str!("codes/EPAR0370014.md")),
+    ("EPAR0370015", include_str!("codes/EPAR0370015.md")),
+    ("EPAR0370016", include_str!("codes/EPAR0370016.md")),
+    ("EPAR0370018", include_str!("codes/EPAR0370018.md")),
+    ("EPAR0370019", include_str!("codes/EPAR0370019.md")),
+    ("EPAR0370020", include_str!("codes/EPAR0370020.md")),
+    ("EPAR0370021", include_str!("codes/EPAR0370021.md")),
+    ("EPAR0370022", include_str!("codes/EPAR0370022.md")),
+    ("EPAR0370025", include_str!("codes/EPAR0370025.md")),
+    ("EPAR0370026", include_str!("codes/EPAR0370026.md")),
+    ("EPAR0370036", include_str!("codes/EPAR0370036.md")),
+    ("EPAR0370038", include_str!("codes/EPAR0370038.md")),
+    ("EPAR0370039", include_str!("codes/EPAR0370039.md")),
+    ("EPAR0370042", include_str!("codes/EPAR0370042.md")),
+    ("EPAR0370044", include_str!("codes/EPAR0370044.md")),
+    ("EPAR0370048", include_str!("codes/EPAR0370048.md")),
+];
+
+/// The codes of the errors which are no longer emitted, kept so that later codes stay stable.
+const RETIRED: &[&str] = &[
+    // `lexer_hex_number_provided`, as hexadecimal literals are supported.
+    "EPAR0370035",
+];
+
+/// Returns the name of the error with the given `code`, e.g., `unexpected_eof` for `EPAR0370003`.
+pub fn error_name(code: &str) -> Option<&'static str> {
+    /// Finds `code` among the errors of `$error_type`.
+    macro_rules! find_in {
+        ($($error_type:ty),*) => {
+            $(
+                if code.starts_with(&format!("E{}", <$error_type>::error_type())) {
+                    return (0..<$error_type>::error_names().len())
+                        .find(|index| <$error_type>::error_code_of(*index) == code)
+                        .map(|index| <$error_type>::error_names()[index]);
+                }
+            )*
+        };
+    }
+
+    find_in!(
+        AsgError,
+        AstError,
+        CliError,
+        CompilerError,
+        ImportError,
+        InputError,
+        PackageError,
+        ParserError,
+        StateError
+    );
+    None
+}
+
+/// Returns the explanation of the error with the given `code`, as printed by `leo --explain`,
+/// or `None` if no error has that code.
+pub fn explain(code: &str) -> Option<String> {
+    let name = error_name(code)?;
+    let description = match EXPLANATIONS.iter().find(|(explained, _)| *explained == code) {
+        Some((_, description)) => description,
+        None if RETIRED.contains(&code) => "This error is no longer emitted by the compiler.\n",
+        None => "No extended description is available for this error yet.\n",
+    };
+    Some(format!("{} (`{}`)\n\n{}", code, name, description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::Span;
+    use std::{fs, path::Path};
+
+    #[test]
+    fn codes_are_stable() {
+        let span = Span::default();
+        assert_eq!(ParserError::unexpected_eof(&span).error_code(), "EPAR0370003");
+        assert_eq!(ParserError::unexpected("a", "b", &span).error_code(), "EPAR0370005");
+        assert_eq!(
+            AstError::recursive_function_call("f", "f", &span).error_code(),
+            "EAST0372014"
+        );
+        assert_eq!(error_name("EPAR0370005"), Some("unexpected"));
+        assert_eq!(error_name("EPAR0379999"), None);
+        assert_eq!(error_name("E0001"), None);
+    }
+
+    #[test]
+    fn explanations_name_known_codes() {
+        for (code, description) in EXPLANATIONS {
+            assert!(error_name(code).is_some(), "{} is not an error code", code);
+            assert!(
+                description.contains("Erroneous code example:"),
+                "{} has no example",
+                code
+            );
+        }
+        assert!(explain("EPAR0370003")
+            .unwrap()
+            .starts_with("EPAR0370003 (`unexpected_eof`)"));
+        assert!(explain("EPAR0370000").unwrap().contains("No extended description"));
+        assert!(explain("EPAR0370035").unwrap().contains("no longer emitted"));
+    }
+
+    /// Returns the contents of the Rust files under `path`, except those of build outputs.
+    fn sources(path: &Path, contents: &mut String) {
+        for entry in fs::read_dir(path).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() && !path.ends_with("target") {
+                sources(&path, contents);
+            } else if path.extension().map_or(false, |extension| extension == "rs") {
+                contents.push_str(&fs::read_to_string(&path).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn explained_codes_are_emitted() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let mut contents = String::new();
+        for directory in ["compiler", "leo"] {
+            sources(&root.join(directory), &mut contents);
+        }
+        // The errors are constructed as `ParserError::name(...)`, and so on.
+        let emitted = |code: &str| contents.contains(&format!("::{}(", error_name(code).unwrap()));
+
+        for (code, _) in EXPLANATIONS {
+            assert!(emitted(code), "{} is explained but never emitted", code);
+            assert!(!RETIRED.contains(code), "{} is explained but retired", code);
+        }
+        for code in RETIRED {
+            assert!(!emitted(code), "{} is retired but still emitted", code);
+        }
+    }
+}
@@ -37,6 +37,9 @@ pub use self::cli::*;
 pub mod common;
 pub use self::common::*;
 
+/// Contains the extended descriptions of error codes, as shown by `leo --explain`.
+pub mod explain;
+
 /// Contains the Compiler error definitions.
 pub mod compiler;
 pub use self::compiler::*;
@@ -65,6 +68,10 @@ pub use self::snarkvm::*;
 pub mod state;
 pub use self::state::*;
 
+/// Contains the lints and the warnings they report.
+pub mod warnings;
+pub use self::warnings::*;
+
 #[macro_use]
 extern crate thiserror;
 
@@ -132,6 +139,78 @@ impl LeoError {
         }
     }
 
+    /// Returns the message, help, and codes of the error, if it is not from SnarkVM.
+    pub fn backtraced(&self) -> Option<&BacktracedError> {
+        use LeoError::*;
+
+        Some(match self {
+            AsgError(error) => error.backtraced(),
+            AstError(error) => error.backtraced(),
+            CliError(error) => error.backtraced(),
+            CompilerError(error) => error.backtraced(),
+            ImportError(error) => error.backtraced(),
+            InputError(error) => error.backtraced(),
+            PackageError(error) => error.backtraced(),
+            ParserError(error) => error.backtraced(),
+            SnarkVMError(_error) => return None,
+            StateError(error) => error.backtraced(),
+        })
+    }
+
+    /// Returns the span the error points at, if any.
+    pub fn span(&self) -> Option<&leo_span::Span> {
+        use LeoError::*;
+
+        match self {
+            AsgError(error) => error.span(),
+            AstError(error) => error.span(),
+            CliError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            ImportError(error) => error.span(),
+            InputError(error) => error.span(),
+            PackageError(error) => error.span(),
+            ParserError(error) => error.span(),
+            SnarkVMError(_error) => None,
+            StateError(error) => error.span(),
+        }
+    }
+
+    /// Returns the related spans of the error, if any.
+    pub fn labels(&self) -> &[Label] {
+        use LeoError::*;
+
+        match self {
+            AsgError(error) => error.labels(),
+            AstError(error) => error.labels(),
+            CliError(error) => error.labels(),
+            CompilerError(error) => error.labels(),
+            ImportError(error) => error.labels(),
+            InputError(error) => error.labels(),
+            PackageError(error) => error.labels(),
+            ParserError(error) => error.labels(),
+            SnarkVMError(_error) => &[],
+            StateError(error) => error.labels(),
+        }
+    }
+
+    /// Returns the suggested fix for the error, if any.
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        use LeoError::*;
+
+        match self {
+            AsgError(error) => error.suggestion(),
+            AstError(error) => error.suggestion(),
+            CliError(error) => error.suggestion(),
+            CompilerError(error) => error.suggestion(),
+            ImportError(error) => error.suggestion(),
+            InputError(error) => error.suggestion(),
+            PackageError(error) => error.suggestion(),
+            ParserError(error) => error.suggestion(),
+            SnarkVMError(_error) => None,
+            StateError(error) => error.suggestion(),
+        }
+    }
+
     /// Implement exit code for each type of Error, even the ones that don't have one.
     pub fn exit_code(&self) -> i32 {
         use LeoError::*;
@@ -558,4 +558,28 @@ create_errors!(
         msg: format!("i/o operation failed, file: {}, error: {}", file, error),
         help: None,
     }
+
+    /// For when a member listed in a workspace manifest is not a package.
+    @backtraced
+    workspace_member_not_found {
+        args: (member: impl Display),
+        msg: format!("workspace member `{}` does not contain a Leo.toml file", member),
+        help: Some("Remove the member from the `members` of the `[workspace]` table, or create the package.".to_string()),
+    }
+
+    /// For when the packages of a workspace depend on each other in a cycle.
+    @backtraced
+    workspace_dependency_cycle {
+        args: (packages: impl Display),
+        msg: format!("the workspace packages {} depend on each other in a cycle", packages),
+        help: None,
+    }
+
+    /// For when a dependency of the manifest is declared with neither a registry version nor a git URL.
+    @backtraced
+    invalid_dependency {
+        args: (name: impl Display, reason: impl Display),
+        msg: format!("invalid dependency `{}`: {}", name, reason),
+        help: Some("Declare dependencies as `name = { package = \"package-name\", author = \"author\", version = \"version\" }` or `name = { package = \"package-name\", git = \"url\", rev = \"commit\" }`.".to_string()),
+    }
 );
@@ -312,6 +312,7 @@ create_errors!(
         help: None,
     }
     /// When a hex number is provided.
+    /// No longer emitted, as hexadecimal numbers are supported, but kept so that later codes stay stable.
     @backtraced
     lexer_hex_number_provided {
         args: (input: impl Display),
@@ -358,4 +359,79 @@ create_errors!(
         msg: format!("Found the char `{}`, but expected `{}`", found, expected),
         help: None,
     }
+
+    /// For when parsing recovered from errors that were already reported.
+    @backtraced
+    aborted_due_to_errors {
+        args: (count: impl Display),
+        msg: format!("aborting due to {} previous error(s)", count),
+        help: None,
+    }
+
+    /// For when `@allow` names a lint that does not exist.
+    @formatted
+    unknown_lint {
+        args: (name: impl Display),
+        msg: format!("unknown lint `{}`", name),
+        help: Some(format!(
+            "the lints are {}",
+            crate::LintName::ALL.iter().map(|lint| format!("`{}`", lint)).collect::<Vec<_>>().join(", ")
+        )),
+    }
+
+    /// For when expressions, statements or types are nested too deeply to be parsed.
+    @formatted
+    nesting_too_deep {
+        args: (limit: impl Display),
+        msg: format!("expressions, statements and types cannot be nested more than {} deep", limit),
+        help: None,
+    }
+
+    /// For when `@cfg` has an argument which is not `name = "value"`, or no argument.
+    @formatted
+    invalid_cfg {
+        args: (),
+        msg: "`@cfg` expects arguments of the form `name = \"value\"`",
+        help: Some("for example, `@cfg(network = \"testnet\")`".to_string()),
+    }
+
+    /// For when a binary, octal, or hexadecimal number has no digits after its prefix.
+    @backtraced
+    lexer_missing_digits {
+        args: (input: impl Display),
+        msg: format!("The number `{}` has no digits after its prefix.", input),
+        help: None,
+    }
+
+    /// For when a binary or octal number has a digit out of its base.
+    @backtraced
+    lexer_invalid_digit {
+        args: (digit: impl Display, base: impl Display, input: impl Display),
+        msg: format!("`{}` is not a digit of the {} number `{}`.", digit, base, input),
+        help: None,
+    }
+
+    /// For when a binary, octal, or hexadecimal number does not fit in the integer type of its suffix.
+    @backtraced
+    lexer_integer_out_of_range {
+        args: (input: impl Display, type_: impl Display),
+        msg: format!("The number `{}` does not fit in the type `{}`.", input, type_),
+        help: None,
+    }
+
+    /// For when a chain of comparisons such as `a < b < c` has a middle operand which does more than read values.
+    @formatted
+    chained_comparison {
+        args: (operand: impl Display),
+        msg: format!("comparisons cannot be chained through `{}`", operand),
+        help: Some("`a < b < c` means `a < b && b < c`, which evaluates `b` twice: bind it to a variable first, or join the comparisons with `&&`".to_string()),
+    }
+
+    /// For when an underscore in a number does not separate two digits.
+    @backtraced
+    lexer_misplaced_underscore {
+        args: (input: impl Display),
+        msg: format!("The number `{}` has an underscore which does not separate two digits.", input),
+        help: None,
+    }
 );
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// The name of a check reporting code that is valid but likely a mistake.
+/// Each lint is reported as a warning, unless it is allowed with `@allow(name)` on the
+/// enclosing function, or warnings are denied, in which case it is reported as an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LintName {
+    /// A variable that is never read.
+    UnusedVariables,
+    /// A function input that is never read.
+    UnusedParameters,
+    /// A statement that can never execute, as it follows a `return`.
+    UnreachableCode,
+    /// A variable that hides another variable of the same name.
+    Shadowing,
+    /// A condition whose value is known without running the program.
+    ConstantConditions,
+    /// An assignment of a variable to itself.
+    SelfAssignment,
+    /// An unsigned integer operation or cast which overflows whatever the values of its operands.
+    ArithmeticOverflow,
+    /// An expression statement without side effects, whose value is discarded.
+    UnusedResults,
+    /// A chain of comparisons such as `a < b < c`, which means `a < b && b < c`.
+    ChainedComparisons,
+    /// An integer division whose result is multiplied, losing the precision the division rounded away.
+    DivisionBeforeMultiplication,
+    /// A call to a function of an imported package whose result is discarded.
+    UncheckedCallResults,
+    /// A public output of `main` which depends on its private inputs.
+    PrivateDataLeaks,
+}
+
+impl LintName {
+    /// All the lints, in the order they are documented.
+    pub const ALL: &'static [LintName] = &[
+        LintName::UnusedVariables,
+        LintName::UnusedParameters,
+        LintName::UnreachableCode,
+        LintName::Shadowing,
+        LintName::ConstantConditions,
+        LintName::SelfAssignment,
+        LintName::ArithmeticOverflow,
+        LintName::UnusedResults,
+        LintName::ChainedComparisons,
+        LintName::DivisionBeforeMultiplication,
+        LintName::UncheckedCallResults,
+        LintName::PrivateDataLeaks,
+    ];
+
+    /// Returns the name of the lint, as written in `@allow(name)`.
+    pub fn name(self) -> &'static str {
+        match self {
+            LintName::UnusedVariables => "unused_variables",
+            LintName::UnusedParameters => "unused_parameters",
+            LintName::UnreachableCode => "unreachable_code",
+            LintName::Shadowing => "shadowing",
+            LintName::ConstantConditions => "constant_conditions",
+            LintName::SelfAssignment => "self_assignment",
+            LintName::ArithmeticOverflow => "arithmetic_overflow",
+            LintName::UnusedResults => "unused_results",
+            LintName::ChainedComparisons => "chained_comparisons",
+            LintName::DivisionBeforeMultiplication => "division_before_multiplication",
+            LintName::UncheckedCallResults => "unchecked_call_results",
+            LintName::PrivateDataLeaks => "private_data_leaks",
+        }
+    }
+
+    /// Returns how severe a vulnerability the lint points to, or `None` if it is not a security lint.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            LintName::DivisionBeforeMultiplication => Some(Severity::Medium),
+            LintName::UncheckedCallResults | LintName::PrivateDataLeaks => Some(Severity::High),
+            _ => None,
+        }
+    }
+
+    /// Returns the lint called `name`, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|lint| lint.name() == name)
+    }
+}
+
+impl fmt::Display for LintName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// How severe a vulnerability a security lint points to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A weakness which is unlikely to be exploitable by itself.
+    Low,
+    /// A flaw which may lead to wrong results, e.g., amounts off by a rounding error.
+    Medium,
+    /// A flaw which may let the program go on after something it relies on went wrong.
+    High,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Low => write!(f, "low"),
+            Severity::Medium => write!(f, "medium"),
+            Severity::High => write!(f, "high"),
+        }
+    }
+}
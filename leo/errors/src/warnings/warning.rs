@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    common::formatted::{write_note, write_snippet},
+    paint, CompilerError, LeoError, LintName, Suggestion,
+};
+
+use leo_span::Span;
+
+use colored::Color;
+use std::fmt;
+
+/// A warning raised by a lint.
+///     unused variable `x`
+///     --> file.leo: 2:9
+///      |
+///    2 | let x = 1u8;
+///      |     ^
+///      |
+///      = help: remove the variable, or add `@allow(unused_variables)` to the function
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeoWarning {
+    /// The lint which raised the warning.
+    pub lint: LintName,
+    /// The warning message.
+    pub message: String,
+    /// A hint on how to address the warning, if any.
+    pub help: Option<String>,
+    /// The code the warning points at.
+    pub span: Span,
+    /// A fix for the warning that tools may apply automatically, e.g., with `leo lint --fix`.
+    pub suggestion: Option<Box<Suggestion>>,
+}
+
+impl LeoWarning {
+    /// Creates a warning for `lint` at `span`.
+    pub fn new(lint: LintName, message: impl ToString, help: Option<String>, span: &Span) -> Self {
+        Self {
+            lint,
+            message: message.to_string(),
+            help,
+            span: span.clone(),
+            suggestion: None,
+        }
+    }
+
+    /// Attaches a suggestion to replace the source at `span` with `replacement`.
+    pub fn with_suggestion(mut self, message: impl ToString, replacement: impl ToString, span: &Span) -> Self {
+        self.suggestion = Some(Box::new(Suggestion {
+            message: message.to_string(),
+            replacement: replacement.to_string(),
+            span: span.clone(),
+        }));
+        self
+    }
+
+    /// For when a variable is never read.
+    pub fn unused_variable(name: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::UnusedVariables,
+            format!("unused variable `{}`", name),
+            Some(format!(
+                "remove the variable, or add `@allow({})` to the function",
+                LintName::UnusedVariables
+            )),
+            span,
+        )
+    }
+
+    /// For when a function input is never read.
+    pub fn unused_parameter(name: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::UnusedParameters,
+            format!("unused function input `{}`", name),
+            Some(format!(
+                "remove the input, or add `@allow({})` to the function",
+                LintName::UnusedParameters
+            )),
+            span,
+        )
+    }
+
+    /// For when a statement follows a `return` in the same block.
+    pub fn unreachable_statement(span: &Span) -> Self {
+        Self::new(LintName::UnreachableCode, "unreachable statement", None, span)
+    }
+
+    /// For when a variable hides a variable of the same name in an enclosing scope.
+    pub fn shadowed_variable(name: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::Shadowing,
+            format!("`{}` shadows a variable of the same name", name),
+            Some("consider renaming the variable".to_string()),
+            span,
+        )
+    }
+
+    /// For when a condition only depends on literals, so it always has the same value.
+    pub fn constant_condition(span: &Span) -> Self {
+        Self::new(
+            LintName::ConstantConditions,
+            "this condition is constant",
+            Some("the condition does not depend on any variable, so one of its branches never runs".to_string()),
+            span,
+        )
+    }
+
+    /// For when a variable is assigned to itself.
+    pub fn self_assignment(name: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::SelfAssignment,
+            format!("`{}` is assigned to itself", name),
+            Some("the assignment has no effect and can be removed".to_string()),
+            span,
+        )
+    }
+
+    /// For when an operation or cast overflows the range of its type whatever the values of its operands.
+    pub fn arithmetic_overflow(expression: impl fmt::Display, type_: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::ArithmeticOverflow,
+            format!("`{}` always overflows `{}`", expression, type_),
+            Some("the program fails whenever it evaluates this expression".to_string()),
+            span,
+        )
+    }
+
+    /// For when the value of an expression without side effects is discarded.
+    pub fn unused_result(span: &Span) -> Self {
+        Self::new(
+            LintName::UnusedResults,
+            "unused result of an expression without side effects",
+            Some("remove the statement, or bind its value with `let`".to_string()),
+            span,
+        )
+    }
+
+    /// For when comparisons are chained as in `a < b < c`, whose `operand` is compared on both sides.
+    pub fn chained_comparison(operand: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::ChainedComparisons,
+            "comparisons are chained",
+            Some(format!(
+                "this is read as comparisons joined with `&&`, which compare `{}` on both sides",
+                operand
+            )),
+            span,
+        )
+    }
+
+    /// For when the result of an integer division is multiplied, as in `a / b * c`.
+    pub fn division_before_multiplication(span: &Span) -> Self {
+        Self::new(
+            LintName::DivisionBeforeMultiplication,
+            "the result of an integer division is multiplied",
+            Some("the division rounds down before the multiplication scales the error up; multiply first".to_string()),
+            span,
+        )
+    }
+
+    /// For when the result of `function`, a function of an imported package, is discarded.
+    pub fn unchecked_call_result(function: impl fmt::Display, span: &Span) -> Self {
+        Self::new(
+            LintName::UncheckedCallResults,
+            format!(
+                "the result of `{}`, a function of an imported package, is discarded",
+                function
+            ),
+            Some("check the returned value, e.g., with `console.assert`".to_string()),
+            span,
+        )
+    }
+
+    /// For when the public output of `main` depends on its private `inputs`.
+    pub fn private_data_leak(inputs: &[impl fmt::Display], span: &Span) -> Self {
+        let inputs = inputs.iter().map(|input| format!("`{}`", input)).collect::<Vec<_>>();
+        let plural = if inputs.len() > 1 { "s" } else { "" };
+        Self::new(
+            LintName::PrivateDataLeaks,
+            format!(
+                "the public output of `main` depends on the private input{} {}",
+                plural,
+                inputs.join(", ")
+            ),
+            Some("hash or commit to the value before returning it, or declare the output `private`".to_string()),
+            span,
+        )
+    }
+
+    /// Returns the error reported for the warning when warnings are denied.
+    pub fn into_error(self) -> LeoError {
+        CompilerError::denied_warning(self.message, self.lint, &self.span).into()
+    }
+}
+
+impl fmt::Display for LeoWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self.lint.severity() {
+            Some(severity) => format!("Warning [{}, {} severity]: {}", self.lint, severity, self.message),
+            None => format!("Warning [{}]: {}", self.lint, self.message),
+        };
+
+        write!(f, "{}", paint(&message, Color::Yellow))?;
+
+        write_snippet(f, &self.span, Color::Yellow)?;
+
+        if let Some(help) = &self.help {
+            write_note(f, help)?;
+        }
+
+        // A removal is described by the help, so only replacements are shown.
+        if let Some(suggestion) = self.suggestion.as_ref().filter(|s| !s.replacement.is_empty()) {
+            write_note(
+                f,
+                format_args!("help: {}: `{}`", suggestion.message, suggestion.replacement),
+            )?;
+        }
+        Ok(())
+    }
+}
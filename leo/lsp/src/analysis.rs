@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Document;
+use leo_ast::Program;
+use leo_compiler::{Compiler, ExpressionTypes, SymbolTable};
+use leo_errors::{emitter::Handler, LeoError, LeoWarning};
+
+use lsp_types::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Url};
+
+/// The result of checking a document.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The errors and warnings of the document.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The program, if the document parses.
+    /// It is the output of the compiler passes if they succeed, and the parsed program otherwise.
+    pub program: Option<Program>,
+    /// The names defined in the program, and their uses, if the document parses.
+    pub symbols: Option<SymbolTable>,
+    /// The types of the expressions of the program, if the document parses.
+    pub types: Option<ExpressionTypes>,
+}
+
+/// Runs the compiler passes on the `document` at `uri`, collecting its diagnostics.
+/// Must be called within a symbol session, which the returned program refers to.
+pub fn analyze(uri: &Url, document: &Document) -> Analysis {
+    let path = uri.as_str();
+    let (handler, buf) = Handler::new_with_buf();
+    let result = Compiler::check_source(&handler, path, &document.text);
+    let mut errors = buf.extract().into_inner();
+    let warnings = buf.extract_warnings().into_inner();
+
+    let program = match result {
+        Ok(ast) => Some(ast.into_repr()),
+        Err(error) => {
+            errors.push(error);
+            // The passes stop at their first error, while the parsed program is enough for navigation.
+            let (handler, _) = Handler::new_with_buf();
+            leo_parser::parse(&handler, path, &document.text).ok()
+        }
+    };
+
+    // Errors without a span, such as the count of errors the parser recovered from,
+    // only add noise next to the errors they summarize.
+    if errors.iter().any(|error| error.span().is_some()) {
+        errors.retain(|error| error.span().is_some());
+    }
+
+    let diagnostics = errors
+        .iter()
+        .map(|error| error_diagnostic(uri, document, error))
+        .chain(warnings.iter().map(|warning| warning_diagnostic(document, warning)))
+        .collect();
+    let symbols = program.as_ref().map(SymbolTable::new);
+    let types = program.as_ref().map(ExpressionTypes::new);
+    Analysis {
+        diagnostics,
+        program,
+        symbols,
+        types,
+    }
+}
+
+/// Returns the diagnostic reporting `error`, with its labels as related information.
+fn error_diagnostic(uri: &Url, document: &Document, error: &LeoError) -> Diagnostic {
+    let (message, help) = match error.backtraced() {
+        Some(backtraced) => (backtraced.message.clone(), backtraced.help.clone()),
+        None => (error.to_string(), None),
+    };
+    let related_information = error
+        .labels()
+        .iter()
+        .map(|label| DiagnosticRelatedInformation {
+            location: Location::new(uri.clone(), document.range(&label.span)),
+            message: label.message.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    Diagnostic {
+        range: error.span().map(|span| document.range(span)).unwrap_or_default(),
+        severity: Some(DiagnosticSeverity::ERROR),
+        code: Some(NumberOrString::String(error.error_code())),
+        source: Some("leo".to_string()),
+        message: with_help(message, help),
+        related_information: Some(related_information).filter(|related| !related.is_empty()),
+        ..Default::default()
+    }
+}
+
+/// Returns the diagnostic reporting `warning`.
+fn warning_diagnostic(document: &Document, warning: &LeoWarning) -> Diagnostic {
+    Diagnostic {
+        range: document.range(&warning.span),
+        severity: Some(DiagnosticSeverity::WARNING),
+        code: Some(NumberOrString::String(warning.lint.to_string())),
+        source: Some("leo".to_string()),
+        message: with_help(warning.message.clone(), warning.help.clone()),
+        ..Default::default()
+    }
+}
+
+/// Appends `help`, if any, to `message`.
+fn with_help(message: String, help: Option<String>) -> String {
+    match help {
+        Some(help) => format!("{}\n{}", message, help),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn reports_errors_and_warnings() {
+        create_session_if_not_set_then(|_| {
+            let uri = Url::parse("file:///main.leo").unwrap();
+
+            let document = Document::new("function main() {\n    let a = 1u8;\n}".to_string());
+            let analysis = analyze(&uri, &document);
+            assert!(analysis.program.is_some());
+            assert_eq!(analysis.diagnostics.len(), 1);
+            let warning = &analysis.diagnostics[0];
+            assert_eq!(warning.severity, Some(DiagnosticSeverity::WARNING));
+            assert_eq!(
+                warning.code,
+                Some(NumberOrString::String("unused_variables".to_string()))
+            );
+            assert_eq!(warning.range.start, lsp_types::Position::new(1, 8));
+
+            let document = Document::new("function main() {\n    let a = 1u8\n}\nfunction main() {}".to_string());
+            let analysis = analyze(&uri, &document);
+            assert!(analysis.program.is_none());
+            assert_eq!(analysis.diagnostics.len(), 1);
+            let error = &analysis.diagnostics[0];
+            assert_eq!(error.severity, Some(DiagnosticSeverity::ERROR));
+            assert_eq!(error.range.start.line, 2);
+
+            let document = Document::new("function main() {}\nfunction main() {}".to_string());
+            let analysis = analyze(&uri, &document);
+            assert!(analysis.program.is_some());
+            let related = analysis.diagnostics[0].related_information.as_ref().unwrap();
+            assert_eq!(related[0].location.range.start, lsp_types::Position::new(0, 9));
+        });
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Span;
+use lsp_types::{Position, Range};
+
+/// The text of an open file, with conversions between spans and LSP positions.
+/// Spans count lines and columns from 1, with columns in bytes,
+/// while LSP positions count from 0, with columns in UTF-16 code units.
+#[derive(Clone, Debug, Default)]
+pub struct Document {
+    /// The text of the file.
+    pub text: String,
+}
+
+impl Document {
+    /// Creates a document with the given `text`.
+    pub fn new(text: String) -> Self {
+        Self { text }
+    }
+
+    /// Returns the line of the document at the 1-based `line`, if any.
+    fn line(&self, line: usize) -> Option<&str> {
+        line.checked_sub(1).and_then(|index| self.text.lines().nth(index))
+    }
+
+    /// Returns the LSP position of the 1-based `line` and byte `column`.
+    fn position(&self, line: usize, column: usize) -> Position {
+        let text = self.line(line).unwrap_or_default();
+        let mut byte = column.saturating_sub(1).min(text.len());
+        while !text.is_char_boundary(byte) {
+            byte -= 1;
+        }
+        Position::new(
+            line.saturating_sub(1) as u32,
+            text[..byte].encode_utf16().count() as u32,
+        )
+    }
+
+    /// Returns the LSP range of `span`.
+    pub fn range(&self, span: &Span) -> Range {
+        Range::new(
+            self.position(span.line_start, span.col_start),
+            self.position(span.line_stop, span.col_stop),
+        )
+    }
+
+    /// Returns the 1-based line and byte column of the LSP `position`, as in spans.
+    pub fn span_position(&self, position: Position) -> (usize, usize) {
+        let line = position.line as usize + 1;
+        let text = self.line(line).unwrap_or_default();
+        let mut units = 0;
+        let byte = text
+            .char_indices()
+            .find(|(_, c)| {
+                let reached = units >= position.character as usize;
+                units += c.len_utf16();
+                reached
+            })
+            .map(|(byte, _)| byte)
+            .unwrap_or(text.len());
+        (line, byte + 1)
+    }
+}
+
+/// Returns whether `span` contains the 1-based `line` and byte `column`.
+pub fn span_contains(span: &Span, (line, column): (usize, usize)) -> bool {
+    (span.line_start, span.col_start) <= (line, column) && (line, column) <= (span.line_stop, span.col_stop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_positions() {
+        let document = Document::new("let a = 1u8;\nlet é = \"ü\"; b".to_string());
+        let span = Span::new(2, 2, 16, 17, Default::default(), String::new());
+        // `é` and `ü` take two bytes, but one UTF-16 code unit.
+        assert_eq!(
+            document.range(&span),
+            Range::new(Position::new(1, 13), Position::new(1, 14))
+        );
+        assert_eq!(document.span_position(Position::new(1, 13)), (2, 16));
+        assert_eq!(document.span_position(Position::new(0, 4)), (1, 5));
+        assert!(span_contains(&span, (2, 16)));
+        assert!(!span_contains(&span, (1, 16)));
+    }
+}
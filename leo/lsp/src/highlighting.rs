@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::Document;
+use leo_compiler::TokenClass;
+use leo_span::Span;
+
+use lsp_types::{Position, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensLegend};
+
+/// The token types of the semantic tokens, in the order of their indices.
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::KEYWORD,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::DECORATOR,
+    SemanticTokenType::NUMBER,
+    SemanticTokenType::STRING,
+    SemanticTokenType::COMMENT,
+    SemanticTokenType::OPERATOR,
+];
+
+/// Returns the token types and modifiers of the semantic tokens.
+pub fn semantic_tokens_legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: vec![SemanticTokenModifier::READONLY],
+    }
+}
+
+/// Returns the semantic tokens of the tokens of the `document` with the given `classes`,
+/// in the order they appear, each positioned relative to the previous one.
+/// Tokens spanning several lines, such as block comments, are left out, as clients need not support them.
+pub fn encode_semantic_tokens(document: &Document, classes: &[(Span, TokenClass)]) -> Vec<SemanticToken> {
+    let mut tokens = Vec::new();
+    let mut previous = Position::new(0, 0);
+    for (span, class) in classes {
+        let range = document.range(span);
+        if range.start.line != range.end.line {
+            continue;
+        }
+        let delta_start = if range.start.line == previous.line {
+            range.start.character - previous.character
+        } else {
+            range.start.character
+        };
+        let (token_type, token_modifiers_bitset) = token_type(*class);
+        tokens.push(SemanticToken {
+            delta_line: range.start.line - previous.line,
+            delta_start,
+            length: range.end.character - range.start.character,
+            token_type,
+            token_modifiers_bitset,
+        });
+        previous = range.start;
+    }
+    tokens
+}
+
+/// Returns the index of the token type of `class`, and the bits of its modifiers.
+fn token_type(class: TokenClass) -> (u32, u32) {
+    let (token_type, readonly) = match class {
+        TokenClass::Keyword => (SemanticTokenType::KEYWORD, false),
+        TokenClass::Type | TokenClass::Alias => (SemanticTokenType::TYPE, false),
+        TokenClass::Namespace => (SemanticTokenType::NAMESPACE, false),
+        TokenClass::Circuit => (SemanticTokenType::STRUCT, false),
+        TokenClass::Function => (SemanticTokenType::FUNCTION, false),
+        TokenClass::Method => (SemanticTokenType::METHOD, false),
+        TokenClass::Member => (SemanticTokenType::PROPERTY, false),
+        TokenClass::Constant => (SemanticTokenType::VARIABLE, true),
+        TokenClass::Parameter => (SemanticTokenType::PARAMETER, false),
+        TokenClass::Variable => (SemanticTokenType::VARIABLE, false),
+        TokenClass::Annotation => (SemanticTokenType::DECORATOR, false),
+        TokenClass::Number => (SemanticTokenType::NUMBER, false),
+        TokenClass::String => (SemanticTokenType::STRING, false),
+        TokenClass::Comment => (SemanticTokenType::COMMENT, false),
+        TokenClass::Operator => (SemanticTokenType::OPERATOR, false),
+    };
+    let index = TOKEN_TYPES
+        .iter()
+        .position(|type_| *type_ == token_type)
+        .unwrap_or_default();
+    (index as u32, readonly as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_relative_positions() {
+        let document = Document::new("let é = ZERO;\n/* a\n */ x".to_string());
+        let span =
+            |line, col_start, col_stop| Span::new(line, line, col_start, col_stop, Default::default(), String::new());
+        let mut comment = span(2, 1, 4);
+        comment.line_stop = 3;
+        let classes = vec![
+            (span(1, 1, 4), TokenClass::Keyword),
+            (span(1, 10, 14), TokenClass::Constant),
+            (comment, TokenClass::Comment),
+            (span(3, 5, 6), TokenClass::Variable),
+        ];
+        let tokens = encode_semantic_tokens(&document, &classes)
+            .into_iter()
+            .map(|token| {
+                (
+                    token.delta_line,
+                    token.delta_start,
+                    token.length,
+                    token.token_type,
+                    token.token_modifiers_bitset,
+                )
+            })
+            .collect::<Vec<_>>();
+        // `é` takes two bytes, but one UTF-16 code unit.
+        assert_eq!(tokens, vec![(0, 0, 3, 0, 0), (0, 8, 4, 7, 1), (2, 4, 1, 7, 0)]);
+    }
+}
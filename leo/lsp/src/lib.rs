@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The language server for Leo programs.
+//!
+//! The [`run()`] function serves a client over a [`lsp_server::Connection`].
+
+#![doc = include_str!("../README.md")]
+
+pub mod analysis;
+pub use analysis::*;
+
+pub mod document;
+pub use document::*;
+
+pub mod highlighting;
+pub use highlighting::*;
+
+pub mod server;
+pub use server::*;
+
+pub mod symbols;
+pub use symbols::*;
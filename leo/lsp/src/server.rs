@@ -0,0 +1,384 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{analyze, document_symbols, encode_semantic_tokens, semantic_tokens_legend, Analysis, Document};
+use leo_compiler::{type_at, SymbolTable};
+use leo_parser::Token;
+use leo_span::symbol::create_session_if_not_set_then;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics,
+    },
+    request::{
+        DocumentSymbolRequest, GotoDefinition, HoverRequest, References, Rename, Request as _,
+        SemanticTokensFullRequest,
+    },
+    DocumentSymbolResponse, GotoDefinitionResponse, Hover, HoverContents, HoverProviderCapability, Location,
+    MarkupContent, MarkupKind, OneOf, PublishDiagnosticsParams, SemanticTokens, SemanticTokensFullOptions,
+    SemanticTokensOptions, SemanticTokensResult, SemanticTokensServerCapabilities, ServerCapabilities,
+    TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Url, WorkspaceEdit,
+};
+use std::{collections::HashMap, error::Error};
+
+/// The errors which stop the server, such as a closed connection.
+pub type ServerError = Box<dyn Error + Send + Sync>;
+
+/// Returns the features the server supports.
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Left(true)),
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                legend: semantic_tokens_legend(),
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    }
+}
+
+/// Serves the client at the other end of `connection` until it shuts the server down.
+pub fn run(connection: Connection) -> Result<(), ServerError> {
+    create_session_if_not_set_then(|_| {
+        connection.initialize(serde_json::to_value(capabilities())?)?;
+        let mut server = Server {
+            connection: &connection,
+            documents: HashMap::new(),
+        };
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    let response = server.handle_request(request);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => server.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    })
+}
+
+/// The state of the server: the documents open in the client, with the result of checking them.
+struct Server<'a> {
+    connection: &'a Connection,
+    documents: HashMap<Url, (Document, Analysis)>,
+}
+
+impl Server<'_> {
+    fn handle_request(&self, request: Request) -> Response {
+        match request.method.as_str() {
+            HoverRequest::METHOD => {
+                handle::<HoverRequest>(request, |params| self.hover(&params.text_document_position_params))
+            }
+            GotoDefinition::METHOD => handle::<GotoDefinition>(request, |params| {
+                self.definition(&params.text_document_position_params)
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            References::METHOD => handle::<References>(request, |params| {
+                self.references(&params.text_document_position, params.context.include_declaration)
+            }),
+            Rename::METHOD => handle::<Rename>(request, |params| {
+                self.rename(&params.text_document_position, &params.new_name)
+            }),
+            SemanticTokensFullRequest::METHOD => handle::<SemanticTokensFullRequest>(request, |params| {
+                self.semantic_tokens(&params.text_document.uri)
+                    .map(SemanticTokensResult::Tokens)
+            }),
+            DocumentSymbolRequest::METHOD => handle::<DocumentSymbolRequest>(request, |params| {
+                let (document, analysis) = self.documents.get(&params.text_document.uri)?;
+                let program = analysis.program.as_ref()?;
+                Some(DocumentSymbolResponse::Nested(document_symbols(document, program)))
+            }),
+            method => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("unsupported request `{}`", method),
+            ),
+        }
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), ServerError> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: <DidOpenTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params)?;
+                self.update(params.text_document.uri, params.text_document.text)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: <DidChangeTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params)?;
+                // With full synchronization, the last change holds the whole text.
+                match params.content_changes.into_iter().last() {
+                    Some(change) => self.update(params.text_document.uri, change.text),
+                    None => Ok(()),
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: <DidCloseTextDocument as lsp_types::notification::Notification>::Params =
+                    serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                self.publish(params.text_document.uri, Vec::new())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the new `text` of the document at `uri`, and publishes its diagnostics.
+    fn update(&mut self, uri: Url, text: String) -> Result<(), ServerError> {
+        let document = Document::new(text);
+        let analysis = analyze(&uri, &document);
+        let diagnostics = analysis.diagnostics.clone();
+        self.documents.insert(uri.clone(), (document, analysis));
+        self.publish(uri, diagnostics)
+    }
+
+    fn publish(&self, uri: Url, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<(), ServerError> {
+        let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+        let notification = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+        self.connection.sender.send(Message::Notification(notification))?;
+        Ok(())
+    }
+
+    /// Returns the signature or type of the definition of the name at `position`,
+    /// or else the type of the expression there.
+    fn hover(&self, position: &TextDocumentPositionParams) -> Option<Hover> {
+        let (document, analysis) = self.documents.get(&position.text_document.uri)?;
+        let symbols = analysis.symbols.as_ref()?;
+        let position = document.span_position(position.position);
+        let (value, span) = match symbols.name_at(position) {
+            Some((span, id)) => (symbols.definition(id).detail.clone(), span.clone()),
+            None => {
+                let type_at = type_at(analysis.types.as_ref()?, symbols, position)?;
+                (type_at.type_.source_name(), type_at.span)
+            }
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```leo\n{}\n```", value),
+            }),
+            range: Some(document.range(&span)),
+        })
+    }
+
+    /// Returns the location of the definition of the name at `position`.
+    fn definition(&self, position: &TextDocumentPositionParams) -> Option<Location> {
+        let uri = &position.text_document.uri;
+        let (document, analysis) = self.documents.get(uri)?;
+        let symbols = analysis.symbols.as_ref()?;
+        let (_, id) = symbols.name_at(document.span_position(position.position))?;
+        Some(Location::new(uri.clone(), document.range(&symbols.definition(id).span)))
+    }
+
+    /// Returns the locations of the uses of the name at `position`, and of its definition if `include_declaration`.
+    fn references(&self, position: &TextDocumentPositionParams, include_declaration: bool) -> Option<Vec<Location>> {
+        let uri = &position.text_document.uri;
+        let (document, analysis) = self.documents.get(uri)?;
+        let symbols = analysis.symbols.as_ref()?;
+        let (_, id) = symbols.name_at(document.span_position(position.position))?;
+        let definition = Some(&symbols.definition(id).span).filter(|_| include_declaration);
+        Some(
+            definition
+                .into_iter()
+                .chain(symbols.references(id))
+                .map(|span| Location::new(uri.clone(), document.range(span)))
+                .collect(),
+        )
+    }
+
+    /// Returns the edits renaming the name at `position` to `new_name`, or `None` if `new_name` is not an identifier.
+    fn rename(&self, position: &TextDocumentPositionParams, new_name: &str) -> Option<WorkspaceEdit> {
+        let uri = &position.text_document.uri;
+        let (document, analysis) = self.documents.get(uri)?;
+        let symbols = analysis.symbols.as_ref()?;
+        let (_, id) = symbols.name_at(document.span_position(position.position))?;
+        if !is_identifier(new_name) {
+            return None;
+        }
+        let edits = symbols
+            .rename(id, new_name)
+            .into_iter()
+            .map(|edit| TextEdit::new(document.range(&edit.span), edit.new_text))
+            .collect();
+        Some(WorkspaceEdit::new(HashMap::from([(uri.clone(), edits)])))
+    }
+
+    /// Returns the semantic tokens of the document at `uri`, whose names are classified if it parses.
+    fn semantic_tokens(&self, uri: &Url) -> Option<SemanticTokens> {
+        let (document, analysis) = self.documents.get(uri)?;
+        let empty = SymbolTable::default();
+        let symbols = analysis.symbols.as_ref().unwrap_or(&empty);
+        let classes = leo_compiler::semantic_tokens(uri.as_str(), &document.text, symbols).ok()?;
+        Some(SemanticTokens {
+            result_id: None,
+            data: encode_semantic_tokens(document, &classes),
+        })
+    }
+}
+
+/// Returns `true` if `name` is an identifier, rather than a keyword or other text.
+fn is_identifier(name: &str) -> bool {
+    match leo_parser::tokenize_with_source("", name).as_deref() {
+        Ok([(token, source)]) => matches!(token.token, Token::Ident(_)) && *source == name,
+        _ => false,
+    }
+}
+
+/// Responds to `request` with the result of `handler` on its parameters.
+fn handle<R: lsp_types::request::Request>(request: Request, handler: impl FnOnce(R::Params) -> R::Result) -> Response {
+    match serde_json::from_value(request.params) {
+        Ok(params) => Response::new_ok(request.id, handler(params)),
+        Err(error) => Response::new_err(request.id, ErrorCode::InvalidParams as i32, error.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_types::{
+        notification::{Exit, Initialized},
+        request::{Initialize, Shutdown},
+        DidOpenTextDocumentParams, HoverParams, InitializeParams, InitializedParams, Position, ReferenceContext,
+        ReferenceParams, RenameParams, TextDocumentIdentifier, TextDocumentItem,
+    };
+
+    #[test]
+    fn serves_requests() {
+        let (server, client) = Connection::memory();
+        let server = std::thread::spawn(move || run(server).unwrap());
+        let request = |id: i32, method: &str, params: serde_json::Value| {
+            client
+                .sender
+                .send(Message::Request(Request::new(id.into(), method.to_string(), params)))
+                .unwrap();
+            match client.receiver.recv().unwrap() {
+                Message::Response(response) => response.result.unwrap(),
+                message => panic!("unexpected message {:?}", message),
+            }
+        };
+        let notify = |method: &str, params: serde_json::Value| {
+            client
+                .sender
+                .send(Message::Notification(Notification::new(method.to_string(), params)))
+                .unwrap();
+        };
+
+        let result = request(
+            1,
+            Initialize::METHOD,
+            serde_json::to_value(InitializeParams::default()).unwrap(),
+        );
+        assert_eq!(result["capabilities"]["hoverProvider"], true);
+        notify(Initialized::METHOD, serde_json::to_value(InitializedParams {}).unwrap());
+
+        let uri = Url::parse("file:///main.leo").unwrap();
+        let text = "function main(a: u8) -> u8 {\n    let b = 1u8;\n    return a;\n}";
+        notify(
+            DidOpenTextDocument::METHOD,
+            serde_json::to_value(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem::new(uri.clone(), "leo".to_string(), 1, text.to_string()),
+            })
+            .unwrap(),
+        );
+        let diagnostics: PublishDiagnosticsParams = match client.receiver.recv().unwrap() {
+            Message::Notification(notification) => serde_json::from_value(notification.params).unwrap(),
+            message => panic!("unexpected message {:?}", message),
+        };
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert!(diagnostics.diagnostics[0].message.contains("unused variable `b`"));
+
+        let position = TextDocumentPositionParams::new(TextDocumentIdentifier::new(uri.clone()), Position::new(2, 11));
+        let hover = request(
+            2,
+            HoverRequest::METHOD,
+            serde_json::to_value(HoverParams {
+                text_document_position_params: position.clone(),
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap(),
+        );
+        assert_eq!(hover["contents"]["value"], "```leo\na: u8\n```");
+        let literal = TextDocumentPositionParams::new(TextDocumentIdentifier::new(uri.clone()), Position::new(1, 13));
+        let hover = request(
+            3,
+            HoverRequest::METHOD,
+            serde_json::to_value(HoverParams {
+                text_document_position_params: literal,
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap(),
+        );
+        assert_eq!(hover["contents"]["value"], "```leo\nu8\n```");
+
+        let definition = request(4, GotoDefinition::METHOD, serde_json::to_value(&position).unwrap());
+        assert_eq!(definition["range"]["start"]["line"], 0);
+        assert_eq!(definition["range"]["start"]["character"], 14);
+
+        let references = request(
+            5,
+            References::METHOD,
+            serde_json::to_value(ReferenceParams {
+                text_document_position: position.clone(),
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                context: ReferenceContext {
+                    include_declaration: true,
+                },
+            })
+            .unwrap(),
+        );
+        let lines = references
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|location| location["range"]["start"]["line"].as_u64().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(lines, vec![0, 2]);
+
+        let rename = |id, new_name: &str| {
+            request(
+                id,
+                Rename::METHOD,
+                serde_json::to_value(RenameParams {
+                    text_document_position: position.clone(),
+                    new_name: new_name.to_string(),
+                    work_done_progress_params: Default::default(),
+                })
+                .unwrap(),
+            )
+        };
+        let edits = &rename(6, "c")["changes"]["file:///main.leo"];
+        assert_eq!(edits.as_array().unwrap().len(), 2);
+        assert_eq!(edits[1]["newText"], "c");
+        assert!(rename(7, "let").is_null());
+
+        request(8, Shutdown::METHOD, serde_json::Value::Null);
+        notify(Exit::METHOD, serde_json::Value::Null);
+        server.join().unwrap();
+    }
+}
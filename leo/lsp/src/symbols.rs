@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Document;
+use leo_ast::{CircuitMember, DefinitionStatement, Function, Program, Type};
+use leo_span::Span;
+
+use lsp_types::{DocumentSymbol, SymbolKind};
+
+/// Returns the outline of `program`: its aliases, constants, circuits with their members, and functions,
+/// in the order they appear in the `document`.
+pub fn document_symbols(document: &Document, program: &Program) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for alias in program.aliases.values() {
+        symbols.push(symbol(
+            document,
+            alias.name.to_string(),
+            Some(alias.represents.source_name()),
+            SymbolKind::TYPE_PARAMETER,
+            (&alias.span, &alias.name.span),
+            None,
+        ));
+    }
+    for definition in program.global_consts.values() {
+        symbols.extend(constants(document, definition));
+    }
+    for circuit in program.circuits.values() {
+        let name = &circuit.circuit_name;
+        let mut range = name.span.clone();
+        let mut members = Vec::new();
+        for member in circuit.members.iter() {
+            let (member, span) = match member {
+                CircuitMember::CircuitVariable(identifier, type_) => (
+                    symbol(
+                        document,
+                        identifier.to_string(),
+                        Some(type_.source_name()),
+                        SymbolKind::FIELD,
+                        (&identifier.span, &identifier.span),
+                        None,
+                    ),
+                    &identifier.span,
+                ),
+                CircuitMember::CircuitConst(identifier, type_, _) => (
+                    symbol(
+                        document,
+                        identifier.to_string(),
+                        Some(type_.source_name()),
+                        SymbolKind::CONSTANT,
+                        (&identifier.span, &identifier.span),
+                        None,
+                    ),
+                    &identifier.span,
+                ),
+                CircuitMember::CircuitFunction(function) => {
+                    (function_symbol(document, function, SymbolKind::METHOD), &function.span)
+                }
+            };
+            range = &range + span;
+            members.push(member);
+        }
+        symbols.push(symbol(
+            document,
+            name.to_string(),
+            None,
+            SymbolKind::STRUCT,
+            (&range, &name.span),
+            Some(members),
+        ));
+    }
+    for function in program.functions.values() {
+        symbols.push(function_symbol(document, function, SymbolKind::FUNCTION));
+    }
+    symbols.sort_by_key(|symbol| (symbol.range.start.line, symbol.range.start.character));
+    symbols
+}
+
+fn function_symbol(document: &Document, function: &Function, kind: SymbolKind) -> DocumentSymbol {
+    symbol(
+        document,
+        function.identifier.to_string(),
+        Some(function.signature()),
+        kind,
+        (&function.span, &function.identifier.span),
+        None,
+    )
+}
+
+/// Returns a symbol for each constant `definition` defines.
+fn constants(document: &Document, definition: &DefinitionStatement) -> Vec<DocumentSymbol> {
+    definition
+        .variable_names
+        .iter()
+        .map(|variable| {
+            symbol(
+                document,
+                variable.identifier.to_string(),
+                definition.type_.as_ref().map(Type::source_name),
+                SymbolKind::CONSTANT,
+                (&definition.span, &variable.identifier.span),
+                None,
+            )
+        })
+        .collect()
+}
+
+/// Returns a symbol spanning the first of `spans`, with its name at the second.
+#[allow(deprecated)] // `DocumentSymbol::deprecated` must be set, although `tags` replaces it.
+fn symbol(
+    document: &Document,
+    name: String,
+    detail: Option<String>,
+    kind: SymbolKind,
+    (range, selection): (&Span, &Span),
+    children: Option<Vec<DocumentSymbol>>,
+) -> DocumentSymbol {
+    DocumentSymbol {
+        name,
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: document.range(range),
+        selection_range: document.range(selection),
+        children,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leo_errors::emitter::Handler;
+    use leo_span::symbol::create_session_if_not_set_then;
+
+    #[test]
+    fn outlines_programs() {
+        create_session_if_not_set_then(|_| {
+            let text = "type Byte = u8;\ncircuit Point {\n    x: Byte,\n    function get(self) -> Byte { return self.x; }\n}\n\
+                        const ZERO: u8 = 0u8;\nfunction main() {}";
+            let (handler, _) = Handler::new_with_buf();
+            let program = leo_parser::parse(&handler, "main.leo", text).unwrap();
+            let symbols = document_symbols(&Document::new(text.to_string()), &program);
+
+            let outline: Vec<(String, SymbolKind)> = symbols
+                .iter()
+                .map(|symbol| (symbol.name.clone(), symbol.kind))
+                .collect();
+            assert_eq!(
+                outline,
+                vec![
+                    ("Byte".to_string(), SymbolKind::TYPE_PARAMETER),
+                    ("Point".to_string(), SymbolKind::STRUCT),
+                    ("ZERO".to_string(), SymbolKind::CONSTANT),
+                    ("main".to_string(), SymbolKind::FUNCTION),
+                ]
+            );
+
+            let point = &symbols[1];
+            assert_eq!((point.range.start.line, point.range.end.line), (1, 3));
+            let members = point.children.as_ref().unwrap();
+            assert_eq!(members[0].name, "x");
+            assert_eq!(members[1].kind, SymbolKind::METHOD);
+            assert_eq!(members[1].detail.as_deref(), Some("function get(self) -> Byte"));
+        });
+    }
+}
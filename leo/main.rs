@@ -24,11 +24,20 @@ pub mod updater;
 use commands::{
     // package::{Clone, Fetch, Login, Logout, Publish},
     Build,
-    // Clean,
+    Clean,
     Command,
-    // Deploy, Init, Lint, New, Prove, Run, Setup, Test, Update, Watch,
+    Debug,
+    Doc,
+    Fmt,
+    Lint,
+    Mutate,
+    Repl,
+    Run,
+    Test,
+    // Deploy, Init, New, Prove, Setup, Update, Watch,
 };
-use leo_errors::Result;
+use leo_errors::{CliError, Result};
+use leo_span::symbol::create_session_if_not_set_then;
 // use snarkvm_utilities::Write;
 
 use std::{path::PathBuf, process::exit};
@@ -45,7 +54,10 @@ struct Opt {
     quiet: bool,
 
     #[structopt(subcommand)]
-    command: CommandOpts,
+    command: Option<CommandOpts>,
+
+    #[structopt(long, help = "Print an extended description of an error code, e.g., EPAR0370005")]
+    explain: Option<String>,
 
     #[structopt(help = "Custom Aleo PM backend URL", env = "APM_URL")]
     api: Option<String>,
@@ -79,6 +91,60 @@ enum CommandOpts {
         #[structopt(flatten)]
         command: Build,
     },
+
+    #[structopt(about = "Remove the build artifacts of the current package")]
+    Clean {
+        #[structopt(flatten)]
+        command: Clean,
+    },
+
+    #[structopt(about = "Run the current package under the control of a debugger")]
+    Debug {
+        #[structopt(flatten)]
+        command: Debug,
+    },
+
+    #[structopt(about = "Generate documentation for the Leo files of the current package")]
+    Doc {
+        #[structopt(flatten)]
+        command: Doc,
+    },
+
+    #[structopt(about = "Format the Leo files of the current package")]
+    Fmt {
+        #[structopt(flatten)]
+        command: Fmt,
+    },
+
+    #[structopt(about = "Lint the Leo files of the current package, optionally applying the suggested fixes")]
+    Lint {
+        #[structopt(flatten)]
+        command: Lint,
+    },
+
+    #[structopt(about = "Report the changes to operators and literals of the current package its tests do not catch")]
+    Mutate {
+        #[structopt(flatten)]
+        command: Mutate,
+    },
+
+    #[structopt(about = "Evaluate expressions and statements against the current package")]
+    Repl {
+        #[structopt(flatten)]
+        command: Repl,
+    },
+
+    #[structopt(about = "Run the current package with the inputs in its input file, environment, or command line")]
+    Run {
+        #[structopt(flatten)]
+        command: Run,
+    },
+
+    #[structopt(about = "Run the tests of the current package")]
+    Test {
+        #[structopt(flatten)]
+        command: Test,
+    },
     //
     // #[structopt(about = "Run a program setup")]
     // Setup {
@@ -92,18 +158,6 @@ enum CommandOpts {
     //     command: Prove,
     // },
     //
-    // #[structopt(about = "Run a program with input variables")]
-    // Run {
-    //     #[structopt(flatten)]
-    //     command: Run,
-    // },
-    //
-    // #[structopt(about = "Clean the output directory")]
-    // Clean {
-    //     #[structopt(flatten)]
-    //     command: Clean,
-    // },
-    //
     // #[structopt(about = "Watch for changes of Leo source files")]
     // Watch {
     //     #[structopt(flatten)]
@@ -116,12 +170,6 @@ enum CommandOpts {
     //     command: Update,
     // },
     //
-    // #[structopt(about = "Compile and run all tests in the current package")]
-    // Test {
-    //     #[structopt(flatten)]
-    //     command: Test,
-    // },
-    //
     // #[structopt(about = "Import a package from the Aleo Package Manager")]
     // Add {
     //     #[structopt(flatten)]
@@ -162,11 +210,6 @@ enum CommandOpts {
     //     #[structopt(flatten)]
     //     command: Remove,
     // },
-    // #[structopt(about = "Lints the Leo files in the package (*)")]
-    // Lint {
-    //     #[structopt(flatten)]
-    //     command: Lint,
-    // },
     //
     // #[structopt(about = "Deploy the current package as a program to the network (*)")]
     // Deploy {
@@ -181,7 +224,25 @@ fn main() {
 
 /// Run command with custom build arguments.
 fn run_with_args(opt: Opt) -> Result<()> {
-    if !opt.quiet {
+    if let Some(code) = opt.explain {
+        let explanation = leo_errors::explain::explain(&code).ok_or_else(|| CliError::unknown_error_code(&code))?;
+        print!("{}", explanation);
+        return Ok(());
+    }
+
+    let command = match opt.command {
+        Some(command) => command,
+        None => {
+            Opt::clap().print_help().map_err(CliError::cli_io_error)?;
+            println!();
+            return Ok(());
+        }
+    };
+
+    // The JSON diagnostics of a build are its only output, so that the standard output stays valid JSON.
+    let json_diagnostics =
+        matches!(&command, CommandOpts::Build { command } if command.compiler_options.json_diagnostics);
+    if !opt.quiet && !json_diagnostics {
         // Init logger with optional debug flag.
         logger::init_logger(
             "leo",
@@ -199,15 +260,22 @@ fn run_with_args(opt: Opt) -> Result<()> {
         None => context::get_context(opt.api),
     });
 
-    match opt.command {
+    // Commands keep the compiled AST, whose names refer to the session, past compilation.
+    create_session_if_not_set_then(|_| match command {
         // CommandOpts::Init { command } => command.try_execute(context),
         // CommandOpts::New { command } => command.try_execute(context),
         CommandOpts::Build { command } => command.try_execute(context),
+        CommandOpts::Clean { command } => command.try_execute(context),
+        CommandOpts::Debug { command } => command.try_execute(context),
+        CommandOpts::Doc { command } => command.try_execute(context),
+        CommandOpts::Fmt { command } => command.try_execute(context),
+        CommandOpts::Lint { command } => command.try_execute(context),
+        CommandOpts::Mutate { command } => command.try_execute(context),
+        CommandOpts::Repl { command } => command.try_execute(context),
+        CommandOpts::Run { command } => command.try_execute(context),
+        CommandOpts::Test { command } => command.try_execute(context),
         // CommandOpts::Setup { command } => command.try_execute(context),
         // CommandOpts::Prove { command } => command.try_execute(context),
-        // CommandOpts::Test { command } => command.try_execute(context),
-        // CommandOpts::Run { command } => command.try_execute(context),
-        // CommandOpts::Clean { command } => command.try_execute(context),
         // CommandOpts::Watch { command } => command.try_execute(context),
         // CommandOpts::Update { command } => command.try_execute(context),
         //
@@ -218,9 +286,8 @@ fn run_with_args(opt: Opt) -> Result<()> {
         // CommandOpts::Logout { command } => command.try_execute(context),
         // CommandOpts::Publish { command } => command.try_execute(context),
         // // CommandOpts::Remove { command } => command.try_execute(context),
-        // CommandOpts::Lint { command } => command.try_execute(context),
         // CommandOpts::Deploy { command } => command.try_execute(context),
-    }
+    })
 }
 
 fn handle_error<T>(res: Result<T>) -> T {
@@ -333,8 +400,8 @@ fn handle_error<T>(res: Result<T>) -> T {
 //         let build_path = &Some(path.join("setup-test"));
 //
 //         assert!(run_cmd("leo build --disable-all-optimizations", build_path).is_ok());
-//         assert!(run_cmd("leo build --disable-code-elimination", build_path).is_ok());
-//         assert!(run_cmd("leo build --disable-constant-folding", build_path).is_ok());
+//         assert!(run_cmd("leo build --disable-strength-reduction", build_path).is_ok());
+//         assert!(run_cmd("leo build --opt-level 0", build_path).is_ok());
 //     }
 //
 //     #[test]
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+//! The layout of the outputs directory of a package, and the metadata versioning its artifacts.
+
+use crate::outputs::OUTPUTS_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The name of the metadata file within the outputs directory.
+pub static ARTIFACTS_METADATA_FILE_NAME: &str = "artifacts.toml";
+
+/// The name of the directory of the parse cache within the outputs directory.
+pub static PARSE_CACHE_DIRECTORY_NAME: &str = "parse_cache";
+
+/// The name of the directory of the generated documentation within the outputs directory.
+pub static DOC_DIRECTORY_NAME: &str = "doc";
+
+/// The version of the layout of the outputs directory.
+/// Increase it when an artifact is added, moved, or changes format, so the artifacts of older layouts are removed.
+pub const ARTIFACTS_SCHEMA_VERSION: u32 = 1;
+
+/// The metadata of the artifacts in an outputs directory: the layout and the compiler which wrote them.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactsMetadata {
+    pub schema: u32,
+    pub compiler_version: String,
+}
+
+impl ArtifactsMetadata {
+    /// Returns the metadata of the artifacts written by the compiler at `compiler_version`.
+    pub fn new(compiler_version: &str) -> Self {
+        Self {
+            schema: ARTIFACTS_SCHEMA_VERSION,
+            compiler_version: compiler_version.to_string(),
+        }
+    }
+}
+
+/// The artifacts of a package in its outputs directory:
+///
+/// ```text
+/// outputs/
+///     artifacts.toml          the metadata of the artifacts
+///     {package}.sum           the fingerprint the compiled AST was built from, see `ChecksumFile`
+///     {package}.ast.json      the compiled AST, see `CompiledAstFile`
+///     {stage}_ast.json        the AST dumps requested with `--emit`
+///     parse_cache/            the parsed AST of each source file
+///     doc/                    the generated documentation
+/// ```
+pub struct BuildArtifacts {
+    package_path: PathBuf,
+}
+
+impl BuildArtifacts {
+    /// Returns the artifacts of the package at `package_path`.
+    pub fn new(package_path: &Path) -> Self {
+        Self {
+            package_path: package_path.to_path_buf(),
+        }
+    }
+
+    /// Returns the outputs directory.
+    pub fn directory(&self) -> PathBuf {
+        self.package_path.join(OUTPUTS_DIRECTORY_NAME)
+    }
+
+    /// Returns the directory of the parse cache.
+    pub fn parse_cache_directory(&self) -> PathBuf {
+        self.directory().join(PARSE_CACHE_DIRECTORY_NAME)
+    }
+
+    /// Returns the directory of the generated documentation.
+    pub fn doc_directory(&self) -> PathBuf {
+        self.directory().join(DOC_DIRECTORY_NAME)
+    }
+
+    /// Returns the metadata of the artifacts, if it can be read.
+    pub fn metadata(&self) -> Option<ArtifactsMetadata> {
+        let metadata = fs::read_to_string(self.metadata_path()).ok()?;
+        toml::from_str(&metadata).ok()
+    }
+
+    /// Creates the outputs directory for the compiler at `compiler_version`, and returns its path.
+    /// If its artifacts were written by another compiler version or layout, or predate the metadata,
+    /// they are stale, so they are removed to be generated again.
+    pub fn prepare(&self, compiler_version: &str) -> Result<PathBuf> {
+        let directory = self.directory();
+        let metadata = ArtifactsMetadata::new(compiler_version);
+        if self.metadata().as_ref() == Some(&metadata) {
+            return Ok(directory);
+        }
+
+        let stale = directory.exists()
+            && fs::read_dir(&directory)
+                .map_err(|e| PackageError::io_error(directory.display(), e))?
+                .next()
+                .is_some();
+        self.clean()?;
+        fs::create_dir_all(&directory).map_err(|e| PackageError::io_error(directory.display(), e))?;
+
+        let metadata = toml::to_string(&metadata).map_err(|e| PackageError::io_error(directory.display(), e))?;
+        let metadata_path = self.metadata_path();
+        fs::write(&metadata_path, metadata).map_err(|e| PackageError::io_error(metadata_path.display(), e))?;
+        if stale {
+            tracing::info!("Removed the build artifacts of another compiler version");
+        }
+        Ok(directory)
+    }
+
+    /// Removes the outputs directory. Returns `true` if it existed.
+    pub fn clean(&self) -> Result<bool> {
+        let directory = self.directory();
+        if !directory.exists() {
+            return Ok(false);
+        }
+        fs::remove_dir_all(&directory).map_err(|e| PackageError::io_error(directory.display(), e))?;
+        Ok(true)
+    }
+
+    fn metadata_path(&self) -> PathBuf {
+        self.directory().join(ARTIFACTS_METADATA_FILE_NAME)
+    }
+}
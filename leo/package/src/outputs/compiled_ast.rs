@@ -0,0 +1,67 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The compiled AST file, read back when the package is built again without changes.
+
+use crate::outputs::OUTPUTS_DIRECTORY_NAME;
+use leo_errors::{PackageError, Result};
+
+use serde::Deserialize;
+use std::{borrow::Cow, fs, path::Path};
+
+pub static COMPILED_AST_FILE_EXTENSION: &str = ".ast.json";
+
+#[derive(Deserialize)]
+pub struct CompiledAstFile {
+    pub package_name: String,
+}
+
+impl CompiledAstFile {
+    pub fn new(package_name: &str) -> Self {
+        Self {
+            package_name: package_name.to_string(),
+        }
+    }
+
+    pub fn exists_at(&self, path: &Path) -> bool {
+        let path = self.setup_file_path(path);
+        path.exists()
+    }
+
+    /// Reads the compiled AST, as JSON, from the given file path if it exists.
+    pub fn read_from(&self, path: &Path) -> Result<String> {
+        let path = self.setup_file_path(path);
+        fs::read_to_string(&path).map_err(|e| PackageError::io_error(path.display(), e).into())
+    }
+
+    /// Writes the given compiled AST, as JSON, to a file.
+    pub fn write_to(&self, path: &Path, ast: String) -> Result<()> {
+        let path = self.setup_file_path(path);
+        fs::write(&path, ast).map_err(|e| PackageError::io_error(path.display(), e).into())
+    }
+
+    fn setup_file_path<'a>(&self, path: &'a Path) -> Cow<'a, Path> {
+        let mut path = Cow::from(path);
+        if path.is_dir() {
+            if !path.ends_with(OUTPUTS_DIRECTORY_NAME) {
+                path.to_mut().push(OUTPUTS_DIRECTORY_NAME);
+            }
+            path.to_mut()
+                .push(format!("{}{}", self.package_name, COMPILED_AST_FILE_EXTENSION));
+        }
+        path
+    }
+}
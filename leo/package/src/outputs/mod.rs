@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod artifacts;
+pub use self::artifacts::*;
+
 pub mod ast_snapshot;
 pub use self::ast_snapshot::*;
 
@@ -23,6 +26,9 @@ pub use self::circuit::*;
 pub mod checksum;
 pub use self::checksum::*;
 
+pub mod compiled_ast;
+pub use self::compiled_ast::*;
+
 pub mod directory;
 pub use directory::*;
 
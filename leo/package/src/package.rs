@@ -15,16 +15,18 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    imports::ImportsDirectory,
+    imports::{ImportsDirectory, IMPORTS_DIRECTORY_NAME},
     inputs::{InputFile, InputsDirectory, StateFile},
-    root::{Gitignore, Manifest, README},
-    source::{MainFile, SourceDirectory},
+    root::{Gitignore, Manifest, LOCKFILE_FILENAME, MANIFEST_FILENAME, README},
+    source::{MainFile, SourceDirectory, SOURCE_DIRECTORY_NAME},
 };
 
 use leo_errors::{PackageError, Result};
 
 use serde::Deserialize;
-use std::path::Path;
+use sha2::{Digest, Sha256};
+use std::{fs, path::Path};
+use walkdir::WalkDir;
 
 #[derive(Deserialize)]
 pub struct Package {
@@ -236,6 +238,38 @@ impl Package {
     pub fn remove_imported_package(package_name: &str, path: &Path) -> Result<()> {
         ImportsDirectory::remove_import(path, package_name)
     }
+
+    /// Returns a fingerprint of the package at the given path compiled with `options`, a description of the compiler options.
+    ///
+    /// The fingerprint covers the manifest, the lock file, and every file of the source and imports directories,
+    /// so it changes whenever the program, or a program it imports, may compile differently.
+    pub fn fingerprint(path: &Path, options: &str) -> Result<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(options.as_bytes());
+
+        let mut files = vec![path.join(MANIFEST_FILENAME), path.join(LOCKFILE_FILENAME)];
+        for directory in [SOURCE_DIRECTORY_NAME, IMPORTS_DIRECTORY_NAME] {
+            files.extend(
+                WalkDir::new(path.join(directory))
+                    .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+                    .into_iter()
+                    .filter_map(|entry| entry.ok())
+                    .filter(|entry| entry.file_type().is_file())
+                    .map(|entry| entry.into_path()),
+            );
+        }
+
+        for file in files.iter().filter(|file| file.exists()) {
+            let content = fs::read(file).map_err(|e| PackageError::io_error(file.display(), e))?;
+            // Hash the path and length of each file as well, so that moving content between files changes the fingerprint.
+            let relative = file.strip_prefix(path).unwrap_or(file);
+            hasher.update(relative.to_string_lossy().as_bytes());
+            hasher.update((content.len() as u64).to_le_bytes());
+            hasher.update(&content);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
 }
 
 #[cfg(test)]
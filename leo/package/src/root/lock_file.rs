@@ -44,6 +44,9 @@ pub struct Package {
     pub version: String,
     pub author: String,
     pub import_name: Option<String>,
+    /// The git repository of the package, as `git+url`, if it is not from the registry.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub source: Option<String>,
     #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
     pub dependencies: IndexMap<String, String>,
 }
@@ -62,6 +65,18 @@ impl LockFile {
         path.exists()
     }
 
+    /// Resolves the `dependencies` of a manifest into a lock file recording where each is fetched from.
+    pub fn resolve(dependencies: &IndexMap<String, Dependency>) -> Result<Self> {
+        let mut lock_file = Self::new();
+        for (import_name, dependency) in dependencies.iter() {
+            dependency.source(import_name)?;
+            let mut package = Package::from(dependency);
+            package.import_name = Some(import_name.clone());
+            lock_file.add_package(package);
+        }
+        Ok(lock_file)
+    }
+
     /// Add Package record to the lock file. Chainable.
     pub fn add_package(&mut self, package: Package) -> &mut Self {
         self.package.push(package);
@@ -142,18 +157,33 @@ impl Display for Package {
     /// Form an path identifier for a package. It is the path under which package is stored
     /// inside the `imports/` directory.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}-{}@{}", self.author, self.name, self.version)
+        match self.source {
+            Some(_) => write!(f, "{}@{}", self.name, self.version),
+            None => write!(f, "{}-{}@{}", self.author, self.name, self.version),
+        }
     }
 }
 
 impl From<&Dependency> for Package {
+    /// The version of a package from a git repository is its revision, or `HEAD` for its default branch.
     fn from(dependency: &Dependency) -> Package {
-        Package {
-            name: dependency.package.clone(),
-            author: dependency.author.clone(),
-            version: dependency.version.clone(),
-            dependencies: Default::default(),
-            import_name: None,
+        match &dependency.git {
+            Some(url) => Package {
+                name: dependency.package.clone(),
+                author: dependency.author.clone(),
+                version: dependency.rev.clone().unwrap_or_else(|| "HEAD".to_string()),
+                dependencies: Default::default(),
+                import_name: None,
+                source: Some(format!("git+{}", url)),
+            },
+            None => Package {
+                name: dependency.package.clone(),
+                author: dependency.author.clone(),
+                version: dependency.version.clone(),
+                dependencies: Default::default(),
+                import_name: None,
+                source: None,
+            },
         }
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::package::Package;
+use crate::{package::Package, root::lock_file};
 use leo_errors::{PackageError, Result};
 
 use indexmap::IndexMap;
@@ -36,11 +36,54 @@ pub struct Remote {
     pub author: String,
 }
 
+/// A dependency of the manifest, either published to the registry
+/// with `author` and `version`, or stored in the `git` repository at revision `rev`.
 #[derive(Clone, Debug, Deserialize)]
 pub struct Dependency {
+    #[serde(default)]
     pub author: String,
+    #[serde(default)]
     pub version: String,
     pub package: String,
+    pub git: Option<String>,
+    pub rev: Option<String>,
+}
+
+/// Where a dependency is fetched from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DependencySource {
+    /// The package `author/package` of the registry, at `version`.
+    Registry { author: String, version: String },
+    /// The git repository at `url`, at the revision `rev`, or its default branch if `None`.
+    Git { url: String, rev: Option<String> },
+}
+
+impl Dependency {
+    /// Returns where the dependency `name` is fetched from,
+    /// or an error if it is declared with neither a registry version nor a git URL, or with both.
+    pub fn source(&self, name: &str) -> Result<DependencySource> {
+        match &self.git {
+            Some(_) if !self.version.is_empty() => {
+                Err(PackageError::invalid_dependency(name, "it has both a `version` and a `git` URL").into())
+            }
+            Some(url) => Ok(DependencySource::Git {
+                url: url.clone(),
+                rev: self.rev.clone(),
+            }),
+            None if self.rev.is_some() => {
+                Err(PackageError::invalid_dependency(name, "a `rev` requires a `git` URL").into())
+            }
+            None if self.author.is_empty() || self.version.is_empty() => Err(PackageError::invalid_dependency(
+                name,
+                "it needs an `author` and a `version`, or a `git` URL",
+            )
+            .into()),
+            None => Ok(DependencySource::Registry {
+                author: self.author.clone(),
+                version: self.version.clone(),
+            }),
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -94,12 +137,7 @@ impl Manifest {
         self.dependencies.clone().map(|dependencies| {
             dependencies
                 .into_iter()
-                .map(|(name, dependency)| {
-                    (
-                        name,
-                        format!("{}-{}@{}", dependency.author, dependency.package, dependency.version),
-                    )
-                })
+                .map(|(name, dependency)| (name, lock_file::Package::from(&dependency).to_string()))
                 .collect()
         })
     }
@@ -149,6 +187,8 @@ proving_system = "groth16"
 [dependencies]
 # Define dependencies here in format:
 # name = {{ package = "package-name", author = "author", version = "version" }}
+# or, for a package in a git repository:
+# name = {{ package = "package-name", git = "https://github.com/author/repository", rev = "commit" }}
 "#,
             name = self.project.name,
             author = author
@@ -28,3 +28,6 @@ pub use self::readme::*;
 
 pub mod zip;
 pub use self::zip::*;
+
+pub mod workspace;
+pub use self::workspace::*;
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::root::{Manifest, MANIFEST_FILENAME};
+use leo_errors::{PackageError, Result};
+
+use serde::Deserialize;
+use std::{
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The `[workspace]` table of a manifest, listing the packages which are built together.
+///
+/// ```toml
+/// [workspace]
+/// members = ["token", "exchange"]
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkspaceTable {
+    /// The directories of the member packages, relative to the workspace root.
+    pub members: Vec<String>,
+}
+
+/// A manifest read for its `[workspace]` table only.
+#[derive(Deserialize)]
+struct WorkspaceManifest {
+    workspace: Option<WorkspaceTable>,
+}
+
+/// A package of a workspace.
+#[derive(Clone, Debug)]
+pub struct Member {
+    /// The name of the package.
+    pub name: String,
+    /// The root directory of the package.
+    pub path: PathBuf,
+    /// The names of the other members the package depends on.
+    pub dependencies: Vec<String>,
+}
+
+/// A directory of packages built together, in the order of their dependencies.
+#[derive(Clone, Debug)]
+pub struct Workspace {
+    pub members: Vec<Member>,
+}
+
+impl Workspace {
+    /// Returns the workspace at `path`, or `None` if its manifest has no `[workspace]` table.
+    pub fn open(path: &Path) -> Result<Option<Self>> {
+        let manifest_path = path.join(MANIFEST_FILENAME);
+        let content = match fs::read_to_string(&manifest_path) {
+            Ok(content) => content,
+            Err(_) => return Ok(None),
+        };
+        let table = match toml::from_str::<WorkspaceManifest>(&content)
+            .map_err(|e| PackageError::failed_to_parse_manifest_file(MANIFEST_FILENAME, e))?
+            .workspace
+        {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+
+        let mut manifests = Vec::with_capacity(table.members.len());
+        for member in table.members.iter() {
+            let member_path = path.join(member);
+            if !Manifest::exists_at(&member_path) {
+                return Err(PackageError::workspace_member_not_found(member).into());
+            }
+            manifests.push((Manifest::try_from(member_path.as_path())?, member_path));
+        }
+
+        let names: Vec<String> = manifests
+            .iter()
+            .map(|(manifest, _)| manifest.get_package_name())
+            .collect();
+        let members = manifests
+            .into_iter()
+            .map(|(manifest, path)| Member {
+                name: manifest.get_package_name(),
+                path,
+                // Only the dependencies on other members are built by the workspace.
+                dependencies: manifest
+                    .get_package_dependencies()
+                    .unwrap_or_default()
+                    .values()
+                    .map(|dependency| dependency.package.clone())
+                    .filter(|package| names.contains(package))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Some(Self { members }))
+    }
+
+    /// Returns the members such that each comes after the members it depends on.
+    /// Members which do not depend on each other keep the order of the manifest.
+    pub fn build_order(&self) -> Result<Vec<&Member>> {
+        let mut order: Vec<&Member> = Vec::with_capacity(self.members.len());
+        while order.len() < self.members.len() {
+            let built = |name: &String| order.iter().any(|member| &member.name == name);
+            let next = self
+                .members
+                .iter()
+                .find(|member| !built(&member.name) && member.dependencies.iter().all(&built));
+            match next {
+                Some(member) => order.push(member),
+                None => {
+                    let cycle: Vec<String> = self
+                        .members
+                        .iter()
+                        .filter(|member| !built(&member.name))
+                        .map(|member| format!("`{}`", member.name))
+                        .collect();
+                    return Err(PackageError::workspace_dependency_cycle(cycle.join(", ")).into());
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, dependencies: &[&str]) -> Member {
+        Member {
+            name: name.to_string(),
+            path: PathBuf::from(name),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn orders_members_by_dependencies() {
+        let workspace = Workspace {
+            members: vec![
+                member("app", &["token", "math"]),
+                member("token", &["math"]),
+                member("math", &[]),
+            ],
+        };
+        let order: Vec<&str> = workspace
+            .build_order()
+            .unwrap()
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect();
+        assert_eq!(order, vec!["math", "token", "app"]);
+
+        let workspace = Workspace {
+            members: vec![member("a", &["b"]), member("b", &["a"]), member("c", &[])],
+        };
+        let err = workspace.build_order().unwrap_err().to_string();
+        assert!(err.contains("`a`, `b`"));
+    }
+}
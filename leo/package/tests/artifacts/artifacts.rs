@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::test_dir;
+use leo_package::outputs::{ArtifactsMetadata, BuildArtifacts, ARTIFACTS_SCHEMA_VERSION};
+
+use std::fs;
+
+#[test]
+fn stale_artifacts_are_removed() {
+    let test_directory = test_dir();
+    let artifacts = BuildArtifacts::new(&test_directory);
+
+    // Preparing the directory records the compiler which writes the artifacts
+    let directory = artifacts.prepare("1.0.0").unwrap();
+    assert_eq!(directory, artifacts.directory());
+    assert_eq!(artifacts.metadata(), Some(ArtifactsMetadata::new("1.0.0")));
+    assert_eq!(artifacts.metadata().unwrap().schema, ARTIFACTS_SCHEMA_VERSION);
+
+    // The artifacts of the same compiler are kept
+    fs::create_dir_all(artifacts.parse_cache_directory()).unwrap();
+    fs::write(directory.join("test-package.ast.json"), "{}").unwrap();
+    artifacts.prepare("1.0.0").unwrap();
+    assert!(directory.join("test-package.ast.json").exists());
+    assert!(artifacts.parse_cache_directory().exists());
+
+    // The artifacts of another compiler are removed
+    artifacts.prepare("1.1.0").unwrap();
+    assert!(!directory.join("test-package.ast.json").exists());
+    assert!(!artifacts.parse_cache_directory().exists());
+    assert_eq!(artifacts.metadata(), Some(ArtifactsMetadata::new("1.1.0")));
+
+    // The artifacts written before the metadata are removed
+    fs::remove_file(directory.join("artifacts.toml")).unwrap();
+    fs::write(directory.join("test-package.sum"), "").unwrap();
+    artifacts.prepare("1.1.0").unwrap();
+    assert!(!directory.join("test-package.sum").exists());
+}
+
+#[test]
+fn clean_removes_the_outputs_directory() {
+    let test_directory = test_dir();
+    let artifacts = BuildArtifacts::new(&test_directory);
+
+    assert!(!artifacts.clean().unwrap());
+    artifacts.prepare("1.0.0").unwrap();
+    fs::create_dir_all(artifacts.doc_directory()).unwrap();
+    assert!(artifacts.clean().unwrap());
+    assert!(!artifacts.directory().exists());
+    assert_eq!(artifacts.metadata(), None);
+}
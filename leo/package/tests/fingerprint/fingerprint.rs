@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::test_dir;
+use leo_package::package::Package;
+
+use std::fs;
+
+const TEST_PACKAGE_NAME: &str = "test-package";
+
+#[test]
+fn fingerprint_changes_with_package() {
+    let test_directory = test_dir();
+    Package::initialize(TEST_PACKAGE_NAME, &test_directory, None).unwrap();
+
+    // The fingerprint is stable while nothing changes
+    let fingerprint = Package::fingerprint(&test_directory, "").unwrap();
+    assert_eq!(fingerprint, Package::fingerprint(&test_directory, "").unwrap());
+
+    // The fingerprint changes with the compiler options
+    assert_ne!(fingerprint, Package::fingerprint(&test_directory, "O0").unwrap());
+
+    // The fingerprint changes with the source files
+    fs::write(test_directory.join("src/main.leo"), "function main() {}").unwrap();
+    let edited = Package::fingerprint(&test_directory, "").unwrap();
+    assert_ne!(fingerprint, edited);
+
+    // The fingerprint changes with the imported programs
+    fs::create_dir_all(test_directory.join("imports/dependency/src")).unwrap();
+    fs::write(
+        test_directory.join("imports/dependency/src/main.leo"),
+        "function f() -> u8 { return 1; }",
+    )
+    .unwrap();
+    let imported = Package::fingerprint(&test_directory, "").unwrap();
+    assert_ne!(edited, imported);
+
+    fs::write(
+        test_directory.join("imports/dependency/src/main.leo"),
+        "function f() -> u16 { return 1; }",
+    )
+    .unwrap();
+    assert_ne!(imported, Package::fingerprint(&test_directory, "").unwrap());
+}
@@ -17,7 +17,7 @@
 // Tests for package manifest
 
 use crate::test_dir;
-use leo_package::root::{Manifest, MANIFEST_FILENAME};
+use leo_package::root::{DependencySource, LockFile, Manifest, MANIFEST_FILENAME};
 
 use std::{
     convert::TryFrom,
@@ -39,6 +39,17 @@ const NEW_REMOTE_FORMAT: &str = r#"
 author = "author"
 "#;
 
+const DEPENDENCIES_MANIFEST: &str = r#"[project]
+name = "test-package"
+version = "0.1.0"
+description = "Testing manifest dependencies."
+license = "MIT"
+
+[dependencies]
+token = { package = "token", author = "author", version = "1.0.0" }
+math = { package = "math", git = "https://github.com/author/math", rev = "abc123" }
+"#;
+
 const OLD_PROJECT_FORMAT: &str = "[package]";
 const NEW_PROJECT_FORMAT: &str = "[project]";
 
@@ -167,3 +178,48 @@ fn test_manifest_refactors() {
     // Check that the manifest file remote has been updated.
     assert!(remote_is_updated(&manifest_path));
 }
+
+#[test]
+fn test_manifest_dependencies() {
+    // Create a manifest file with a registry and a git dependency.
+    let mut manifest_path = test_dir();
+    manifest_path.push(MANIFEST_FILENAME);
+    File::create(&manifest_path)
+        .unwrap()
+        .write_all(DEPENDENCIES_MANIFEST.as_bytes())
+        .unwrap();
+
+    let manifest = Manifest::try_from(manifest_path.as_path()).unwrap();
+    let dependencies = manifest.get_package_dependencies().unwrap();
+
+    // Check that each dependency is fetched from where it is declared.
+    assert_eq!(
+        dependencies["token"].source("token").unwrap(),
+        DependencySource::Registry {
+            author: "author".to_string(),
+            version: "1.0.0".to_string(),
+        }
+    );
+    assert_eq!(
+        dependencies["math"].source("math").unwrap(),
+        DependencySource::Git {
+            url: "https://github.com/author/math".to_string(),
+            rev: Some("abc123".to_string()),
+        }
+    );
+
+    // Check that the lock file records the source and version of each dependency.
+    let lock_file = LockFile::resolve(&dependencies).unwrap();
+    let imports = lock_file.to_import_map();
+    assert_eq!(imports["token"], "author-token@1.0.0");
+    assert_eq!(imports["math"], "math@abc123");
+    assert_eq!(
+        lock_file.package[1].source.as_deref(),
+        Some("git+https://github.com/author/math")
+    );
+
+    // Check that a dependency without a version or a git URL is rejected.
+    let mut invalid = dependencies["token"].clone();
+    invalid.version.clear();
+    assert!(invalid.source("token").is_err());
+}
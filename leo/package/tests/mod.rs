@@ -16,6 +16,8 @@
 
 #![allow(clippy::module_inception)]
 
+pub mod artifacts;
+pub mod fingerprint;
 pub mod initialize;
 pub mod manifest;
 
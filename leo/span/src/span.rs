@@ -23,7 +23,10 @@ use serde::Deserialize;
 
 /// The span type which tracks where formatted errors originate from in a Leo file.
 /// This is used in many spots throughout the rest of the Leo crates.
-#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq)]
+///
+/// Spans are cloned throughout the compiler, so the path and content are shared, not copied.
+/// The tokens of a line share the content of that line.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq)]
 pub struct Span {
     /// The line number where the error started.
     pub line_start: usize,
@@ -36,7 +39,20 @@ pub struct Span {
     /// The path to the Leo file containing the error.
     pub path: Arc<String>,
     /// The content of the line(s) that the span is found on.
-    pub content: String,
+    pub content: Arc<str>,
+}
+
+impl Default for Span {
+    fn default() -> Self {
+        Self {
+            line_start: 0,
+            line_stop: 0,
+            col_start: 0,
+            col_stop: 0,
+            path: Arc::default(),
+            content: Arc::from(""),
+        }
+    }
 }
 
 impl Span {
@@ -53,7 +69,7 @@ impl Span {
         col_start: usize,
         col_stop: usize,
         path: Arc<String>,
-        content: String,
+        content: impl Into<Arc<str>>,
     ) -> Self {
         Self {
             line_start,
@@ -61,7 +77,7 @@ impl Span {
             col_start,
             col_stop,
             path,
-            content,
+            content: content.into(),
         }
     }
 }
@@ -88,7 +104,7 @@ impl Serialize for Span {
         } else {
             state.serialize_field("path", "")?;
         }
-        state.serialize_field("content", &self.content)?;
+        state.serialize_field("content", &*self.content)?;
         state.end()
     }
 }
@@ -154,7 +170,7 @@ impl std::ops::Add for Span {
                     new_content.push(format!("{:<1$}...", " ", other.col_start + 4));
                 }
             }
-            let new_content = new_content.join("\n");
+            let new_content: Arc<str> = new_content.join("\n").into();
             if self.line_start < other.line_stop {
                 Span {
                     line_start: self.line_start,
@@ -24,7 +24,7 @@ use core::{fmt, str};
 use fxhash::FxBuildHasher;
 use indexmap::IndexSet;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::intrinsics::transmute;
 use std::marker::PhantomData;
 
@@ -100,6 +100,7 @@ macro_rules! symbols {
 
 symbols! {
     address,
+    allow,
     AlwaysConst,
     Ampersand: "&",
     array,
@@ -107,14 +108,18 @@ symbols! {
     assert,
     At: "@",
     bool,
+    BoundedVec,
+    cfg,
     char,
     circuit,
     Class: "class",
     context,
+    constant,
     constants,
     CoreFunction,
     console,
     Const: "const",
+    constructor,
     Else: "else",
     error,
     False: "false",
@@ -136,9 +141,11 @@ symbols! {
     main,
     Mut: "mut",
     prelude,
+    program,
     Return: "return",
     SelfLower: "self",
     SelfUpper: "Self",
+    should_fail,
     Star: "*",
     std,
     Static: "static",
@@ -151,6 +158,7 @@ symbols! {
     u32,
     u64,
     u128,
+    version,
 
     CONTAINER_PSEUDO_CIRCUIT: "$InputContainer",
     REGISTERS_PSEUDO_CIRCUIT: "$InputRegister",
@@ -293,14 +301,25 @@ impl fmt::Display for SymbolStr {
 pub struct SessionGlobals {
     /// The interner for `Symbol`s used in the compiler.
     symbol_interner: Interner,
+    /// The number of AST nodes given an ID so far.
+    node_count: Cell<usize>,
 }
 
 impl SessionGlobals {
     fn new() -> Self {
         Self {
             symbol_interner: Interner::prefilled(),
+            node_count: Cell::new(0),
         }
     }
+
+    /// Returns the ID of a new AST node, counting from zero in each session,
+    /// so that a compilation numbers its nodes the same way each time.
+    pub fn next_node_id(&self) -> usize {
+        let id = self.node_count.get();
+        self.node_count.set(id + 1);
+        id
+    }
 }
 
 scoped_tls::scoped_thread_local!(static SESSION_GLOBALS: SessionGlobals);
@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Line diffs between expected and actual test outputs.
+
+/// The number of unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+/// A line of a diff.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Returns the lines of `expected` and `output`, marked as removed from `expected`, added in `output`,
+/// or the same in both, in the order of a longest common subsequence.
+fn lines<'a>(expected: &'a str, output: &'a str) -> Vec<Line<'a>> {
+    let expected: Vec<&str> = expected.lines().collect();
+    let output: Vec<&str> = output.lines().collect();
+
+    // Only the lines between the common prefix and suffix need to be matched.
+    let prefix = expected.iter().zip(&output).take_while(|(a, b)| a == b).count();
+    let suffix = expected[prefix..]
+        .iter()
+        .rev()
+        .zip(output[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old = &expected[prefix..expected.len() - suffix];
+    let new = &output[prefix..output.len() - suffix];
+
+    // `common[i][j]` is the length of the longest common subsequence of `old[i..]` and `new[j..]`.
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<Line> = expected[..prefix].iter().copied().map(Line::Same).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(expected[expected.len() - suffix..].iter().copied().map(Line::Same));
+    lines
+}
+
+/// Returns the lines which differ between `expected` and `output`, prefixed with `-` if they are only
+/// in `expected` and with `+` if they are only in `output`, with a few unchanged lines around them.
+/// Unchanged lines further away from any change are elided.
+pub fn diff(expected: &str, output: &str) -> String {
+    let lines = lines(expected, output);
+    let changed: Vec<usize> = (0..lines.len())
+        .filter(|index| !matches!(lines[*index], Line::Same(_)))
+        .collect();
+
+    let mut out = String::new();
+    // The numbers of the next lines of `expected` and `output`.
+    let (mut expected_line, mut output_line) = (1, 1);
+    let mut shown_until = 0;
+    for (index, line) in lines.iter().enumerate() {
+        let near_change = changed
+            .iter()
+            .any(|change| index + CONTEXT >= *change && index <= change + CONTEXT);
+        if near_change {
+            if index == 0 || index > shown_until {
+                out.push_str(&format!("@@ -{} +{} @@\n", expected_line, output_line));
+            }
+            shown_until = index + 1;
+            let (marker, text) = match line {
+                Line::Same(text) => (' ', text),
+                Line::Removed(text) => ('-', text),
+                Line::Added(text) => ('+', text),
+            };
+            out.push(marker);
+            out.push_str(text);
+            out.push('\n');
+        }
+        match line {
+            Line::Same(_) => {
+                expected_line += 1;
+                output_line += 1;
+            }
+            Line::Removed(_) => expected_line += 1,
+            Line::Added(_) => output_line += 1,
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shows_changed_lines_with_context() {
+        let expected: String = (1..=12).map(|line| format!("{}\n", line)).collect();
+        let output = expected.replacen("2\n", "two\n", 1).replace("11\n", "eleven\n") + "13\n";
+        assert_eq!(
+            diff(&expected, &output),
+            "@@ -1 +1 @@\n 1\n-2\n+two\n 3\n 4\n 5\n@@ -8 +8 @@\n 8\n 9\n 10\n-11\n+eleven\n 12\n+13\n"
+        );
+    }
+
+    #[test]
+    fn is_empty_for_equal_outputs() {
+        assert_eq!(diff("a\nb\n", "a\nb\n"), "");
+    }
+}
@@ -18,7 +18,7 @@ use std::fmt;
 
 use serde_yaml::Value;
 
-use crate::test::TestExpectationMode;
+use crate::{diff::diff, test::TestExpectationMode};
 
 pub struct TestFailure {
     pub path: String,
@@ -84,11 +84,13 @@ impl fmt::Display for TestError {
             } => {
                 write!(
                     f,
-                    "test #{}: {}expected\n{}\ngot\n{}",
+                    "test #{}: {}output differs from the expectation (-) with (+):\n{}",
                     index + 1,
                     format_test(test),
-                    serde_yaml::to_string(&expected).expect("serialization failed"),
-                    serde_yaml::to_string(&output).expect("serialization failed")
+                    diff(
+                        &serde_yaml::to_string(&expected).expect("serialization failed"),
+                        &serde_yaml::to_string(&output).expect("serialization failed")
+                    )
                 )
             }
             TestError::PassedAndShouldntHave { test, index } => {
@@ -111,11 +113,10 @@ impl fmt::Display for TestError {
             } => {
                 write!(
                     f,
-                    "test #{}: {}expected error\n{}\ngot\n{}",
+                    "test #{}: {}error differs from the expectation (-) with (+):\n{}",
                     index + 1,
                     format_test(test),
-                    expected,
-                    output
+                    diff(expected, output)
                 )
             }
             TestError::MismatchedTestExpectationLength => write!(f, "invalid number of test expectations"),
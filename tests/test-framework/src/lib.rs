@@ -24,6 +24,8 @@
 
 #![doc = include_str!("../README.md")]
 
+pub mod diff;
+
 pub mod error;
 
 pub mod fetch;